@@ -65,5 +65,16 @@ pub fn session_from_test_config(config: &TestConfig, strategy: NewPlannerStrateg
 
 	session.redact_volatile_explain_attrs = env.redact_volatile_explain_attrs.unwrap_or(true);
 
+	if let Some(policy) = env.null_coercion {
+		session.null_coercion = policy.into();
+	}
+
+	session.max_output_depth = env.max_output_depth;
+	if let Some(policy) = env.output_depth_policy {
+		session.output_depth_policy = policy.into();
+	}
+
+	session.default_limit = env.default_limit;
+
 	session
 }
@@ -4,10 +4,10 @@ use std::str::FromStr;
 
 use semver::VersionReq;
 use serde::{Deserialize, Serialize, de};
-use surrealdb_core::dbs::NewPlannerStrategy;
 use surrealdb_core::dbs::capabilities::{
 	ExperimentalTarget, FuncTarget, MethodTarget, NetTarget, RouteTarget,
 };
+use surrealdb_core::dbs::{NewPlannerStrategy, NullCoercion, OutputDepthPolicy};
 use surrealdb_core::syn::parser::ParserSettings;
 use surrealdb_core::syn::{self};
 use surrealdb_types::{Object, RecordId, ToSql, Value};
@@ -101,6 +101,22 @@ pub struct TestEnv {
 	/// actual elapsed times.
 	pub redact_volatile_explain_attrs: Option<bool>,
 
+	/// The policy for coercing between NONE and NULL values in the session.
+	/// Defaults to `strict`.
+	pub null_coercion: Option<NullCoercionConfig>,
+
+	/// The maximum nesting depth of the values returned in the session.
+	/// Defaults to no limit.
+	pub max_output_depth: Option<u32>,
+
+	/// What happens to returned values nested deeper than the maximum output
+	/// depth. Defaults to `truncate`.
+	pub output_depth_policy: Option<OutputDepthPolicyConfig>,
+
+	/// The LIMIT of SELECT statements which do not specify one in the
+	/// session. Defaults to no limit.
+	pub default_limit: Option<u32>,
+
 	#[serde(skip_serializing)]
 	#[serde(flatten)]
 	_unused_keys: BTreeMap<String, toml::Value>,
@@ -123,6 +139,10 @@ impl Default for TestEnv {
 			versioned: Default::default(),
 			planner_strategy: default_planner_strategy(),
 			redact_volatile_explain_attrs: Default::default(),
+			null_coercion: Default::default(),
+			max_output_depth: Default::default(),
+			output_depth_policy: Default::default(),
+			default_limit: Default::default(),
 			_unused_keys: Default::default(),
 		}
 	}
@@ -206,6 +226,49 @@ impl From<NewPlannerStrategyConfig> for NewPlannerStrategy {
 	}
 }
 
+/// Policy for coercing between NONE and NULL values in language tests.
+///
+/// Maps to `surrealdb_core::dbs::NullCoercion`.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NullCoercionConfig {
+	Strict,
+	NullAsNone,
+	NoneAsNull,
+	Interchangeable,
+}
+
+impl From<NullCoercionConfig> for NullCoercion {
+	fn from(policy: NullCoercionConfig) -> Self {
+		match policy {
+			NullCoercionConfig::Strict => NullCoercion::Strict,
+			NullCoercionConfig::NullAsNone => NullCoercion::NullAsNone,
+			NullCoercionConfig::NoneAsNull => NullCoercion::NoneAsNull,
+			NullCoercionConfig::Interchangeable => NullCoercion::Interchangeable,
+		}
+	}
+}
+
+/// Policy for returned values which exceed the maximum output depth in
+/// language tests.
+///
+/// Maps to `surrealdb_core::dbs::OutputDepthPolicy`.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OutputDepthPolicyConfig {
+	Truncate,
+	Error,
+}
+
+impl From<OutputDepthPolicyConfig> for OutputDepthPolicy {
+	fn from(policy: OutputDepthPolicyConfig) -> Self {
+		match policy {
+			OutputDepthPolicyConfig::Truncate => OutputDepthPolicy::Truncate,
+			OutputDepthPolicyConfig::Error => OutputDepthPolicy::Error,
+		}
+	}
+}
+
 #[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
 pub enum TestExpectation {
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 fn main() {
 	if cfg!(target_family = "wasm") {
 		println!("cargo:rustc-cfg=wasm");
@@ -12,4 +14,38 @@ fn main() {
 		println!("cargo:rustc-cfg=storage");
 		println!("cargo::rustc-check-cfg=cfg(storage)");
 	}
+	engine_versions();
+}
+
+/// Exposes the versions of the storage engine crates, as recorded in the
+/// workspace lockfile, so that they can be reported by `meta::engine()`.
+fn engine_versions() {
+	const ENGINES: [(&str, &str); 5] = [
+		("surrealmx", "MEMORY"),
+		("surrealkv", "SURREALKV"),
+		("surrealdb-rocksdb", "ROCKSDB"),
+		("surrealdb-tikv-client", "TIKV"),
+		("indxdb", "INDXDB"),
+	];
+	let Ok(dir) = std::env::var("CARGO_MANIFEST_DIR") else {
+		return;
+	};
+	let path = Path::new(&dir).join("../../Cargo.lock");
+	let Ok(lock) = std::fs::read_to_string(&path) else {
+		return;
+	};
+	println!("cargo:rerun-if-changed={}", path.display());
+	let mut lines = lock.lines();
+	while let Some(line) = lines.next() {
+		let Some(name) = line.strip_prefix("name = \"").and_then(|x| x.strip_suffix('"')) else {
+			continue;
+		};
+		let Some((_, engine)) = ENGINES.iter().find(|(x, _)| *x == name) else {
+			continue;
+		};
+		let version = lines.next().and_then(|x| x.strip_prefix("version = \""));
+		if let Some(version) = version.and_then(|x| x.strip_suffix('"')) {
+			println!("cargo:rustc-env=SURREAL_ENGINE_VERSION_{engine}={version}");
+		}
+	}
 }
@@ -57,10 +57,11 @@ use crate::expr::statements::{
 };
 use crate::expr::visit::{MutVisitor, VisitMut};
 use crate::expr::{Expr, Field, Fields, Function, Groups, Idiom, Part, SelectStatement};
+use crate::fnc::util::math::hyperloglog::HyperLogLog;
 use crate::val::{Array, Datetime, Number, Object, TryAdd as _, TryFloatDiv, TryMul, Value};
 
 /// An expression which will be aggregated over for each group.
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum Aggregation {
 	Count,
@@ -76,6 +77,8 @@ pub enum Aggregation {
 	DatetimeMax(usize),
 	DatetimeMin(usize),
 	Accumulate(usize),
+	#[revision(start = 2)]
+	ApproxDistinct(usize),
 }
 
 impl Aggregation {
@@ -129,12 +132,16 @@ impl Aggregation {
 				arg,
 				values: Vec::new(),
 			},
+			Aggregation::ApproxDistinct(arg) => AggregationStat::ApproxDistinct {
+				arg,
+				registers: HyperLogLog::default().into_registers(),
+			},
 		}
 	}
 }
 
 /// A enum containing the data for an aggregation.
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, PartialEq)]
 pub enum AggregationStat {
 	Count {
@@ -186,6 +193,12 @@ pub enum AggregationStat {
 		arg: usize,
 		values: Vec<Value>,
 	},
+	#[revision(start = 2)]
+	ApproxDistinct {
+		arg: usize,
+		/// The registers of a HyperLogLog sketch of the values.
+		registers: Vec<u8>,
+	},
 }
 
 impl AggregationStat {
@@ -405,6 +418,14 @@ pub fn add_to_aggregation_stats(arguments: &[Value], stats: &mut [AggregationSta
 			} => {
 				values.push(arguments[*arg].clone());
 			}
+			AggregationStat::ApproxDistinct {
+				arg,
+				registers,
+			} => {
+				let mut hll = HyperLogLog::from_registers(mem::take(registers));
+				hll.insert(&arguments[*arg]);
+				*registers = hll.into_registers();
+			}
 		}
 	}
 	Ok(())
@@ -486,6 +507,10 @@ pub fn create_field_document(group: &[Value], stats: &[AggregationStat]) -> Obje
 				values,
 				..
 			} => Value::Array(Array(values.clone())),
+			AggregationStat::ApproxDistinct {
+				registers,
+				..
+			} => Value::from(HyperLogLog::from_registers(registers.clone()).estimate()),
 		};
 		res.0.insert(aggregate_field_name(idx), value);
 	}
@@ -602,6 +627,21 @@ impl MutVisitor for AggregateExprCollector<'_> {
 								Aggregation::DatetimeMin,
 							)?;
 						}
+						"count::approx_distinct" => {
+							// The sketch can not remove values, so it can not be kept up to date
+							// when the records of a materialized view are updated or deleted.
+							ensure!(
+								self.support_acummulate,
+								Error::Query {
+									message: "The `count::approx_distinct` aggregate is not supported in materialized aggregate views".to_string()
+								}
+							);
+							self.push_aggregate_function(
+								"count::approx_distinct",
+								&f.arguments,
+								Aggregation::ApproxDistinct,
+							)?;
+						}
 						_ => {
 							return f.visit_mut(self);
 						}
@@ -31,8 +31,8 @@ use crate::dbs::node::{Node, Timestamp};
 use crate::expr::field::Selector;
 use crate::expr::reference::{Reference, ReferenceDeleteStrategy};
 use crate::expr::{
-	Block, ChangeFeed, Cond, Expr, Fetch, Fetchs, Field, Fields, Filter, Groups, Idiom, Kind,
-	Literal, Operation, Tokenizer,
+	Block, ChangeFeed, Cond, Expr, Fetch, Fetchs, Field, Fields, Filter, Groups, IdType, Idiom,
+	Kind, Literal, Operation, Tokenizer, UnknownFields,
 };
 use crate::iam::Auth;
 use crate::idx::ft::fulltext::{DocLengthAndCount, TermDocument};
@@ -133,6 +133,9 @@ pub fn table_basic() -> TableDefinition {
 		cache_events_ts: UuidExt::nil(),
 		cache_tables_ts: UuidExt::nil(),
 		cache_indexes_ts: UuidExt::nil(),
+		ttl: None,
+		unknown_fields: UnknownFields::Reject,
+		id_type: IdType::Rand,
 	}
 }
 
@@ -165,6 +168,9 @@ pub fn table_with_view() -> TableDefinition {
 		cache_events_ts: UuidExt::nil(),
 		cache_tables_ts: UuidExt::nil(),
 		cache_indexes_ts: UuidExt::nil(),
+		ttl: None,
+		unknown_fields: UnknownFields::Reject,
+		id_type: IdType::Rand,
 	}
 }
 
@@ -189,6 +195,9 @@ pub fn table_schemafull() -> TableDefinition {
 		cache_events_ts: UuidExt::nil(),
 		cache_tables_ts: UuidExt::nil(),
 		cache_indexes_ts: UuidExt::nil(),
+		ttl: None,
+		unknown_fields: UnknownFields::Reject,
+		id_type: IdType::Rand,
 	}
 }
 
@@ -221,6 +230,9 @@ pub fn table_relation() -> TableDefinition {
 		cache_events_ts: UuidExt::nil(),
 		cache_tables_ts: UuidExt::nil(),
 		cache_indexes_ts: UuidExt::nil(),
+		ttl: None,
+		unknown_fields: UnknownFields::Reject,
+		id_type: IdType::Rand,
 	}
 }
 
@@ -246,6 +258,9 @@ pub fn table_with_materialized_view() -> TableDefinition {
 		cache_events_ts: UuidExt::nil(),
 		cache_tables_ts: UuidExt::nil(),
 		cache_indexes_ts: UuidExt::nil(),
+		ttl: None,
+		unknown_fields: UnknownFields::Reject,
+		id_type: IdType::Rand,
 	}
 }
 
@@ -267,6 +282,9 @@ pub fn table_any_type() -> TableDefinition {
 		cache_events_ts: UuidExt::nil(),
 		cache_tables_ts: UuidExt::nil(),
 		cache_indexes_ts: UuidExt::nil(),
+		ttl: None,
+		unknown_fields: UnknownFields::Reject,
+		id_type: IdType::Rand,
 	}
 }
 
@@ -972,6 +990,8 @@ pub fn index_basic() -> IndexDefinition {
 		index: Index::Idx,
 		comment: None,
 		prepare_remove: false,
+		cond: None,
+		rebuilding: None,
 	}
 }
 
@@ -985,6 +1005,8 @@ pub fn index_unique() -> IndexDefinition {
 		index: Index::Uniq,
 		comment: Some("Unique email constraint".to_string()),
 		prepare_remove: false,
+		cond: None,
+		rebuilding: None,
 	}
 }
 
@@ -1009,6 +1031,8 @@ pub fn index_hnsw() -> IndexDefinition {
 		}),
 		comment: Some("Vector similarity search index".to_string()),
 		prepare_remove: false,
+		cond: None,
+		rebuilding: None,
 	}
 }
 
@@ -1029,6 +1053,8 @@ pub fn index_fulltext() -> IndexDefinition {
 		}),
 		comment: Some("Full-text search on articles".to_string()),
 		prepare_remove: false,
+		cond: None,
+		rebuilding: None,
 	}
 }
 
@@ -1044,6 +1070,8 @@ pub fn index_count() -> IndexDefinition {
 		))))),
 		comment: None,
 		prepare_remove: true,
+		cond: None,
+		rebuilding: None,
 	}
 }
 
@@ -1073,6 +1101,7 @@ pub fn param_bool() -> ParamDefinition {
 		value: Value::Bool(true),
 		comment: Some("Debug mode flag".to_string()),
 		permissions: Permission::Full,
+		param_kind: None,
 	}
 }
 
@@ -1083,6 +1112,7 @@ pub fn param_string() -> ParamDefinition {
 		value: Value::String("MyApp".to_string()),
 		comment: None,
 		permissions: Permission::Full,
+		param_kind: None,
 	}
 }
 
@@ -50,7 +50,7 @@ impl From<u32> for IndexId {
 	}
 }
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 3)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[non_exhaustive]
 pub struct IndexDefinition {
@@ -64,6 +64,16 @@ pub struct IndexDefinition {
 	/// Indexes marked for removal are excluded from query planning and document
 	/// indexing, and any in-progress index builds are cancelled.
 	pub(crate) prepare_remove: bool,
+	/// The condition of a partial index. Only the documents matching the
+	/// condition are stored in the index, so partial indexes are excluded
+	/// from query planning.
+	#[revision(start = 2)]
+	pub(crate) cond: Option<Cond>,
+	/// The id under which this index is being rebuilt online via `REBUILD
+	/// INDEX`. Document changes are applied to both indexes, until the rebuilt
+	/// index replaces this one.
+	#[revision(start = 3)]
+	pub(crate) rebuilding: Option<IndexId>,
 }
 
 impl_kv_value_revisioned!(IndexDefinition);
@@ -81,6 +91,7 @@ impl IndexDefinition {
 				.clone()
 				.map(|x| sql::Expr::Literal(sql::Literal::String(x)))
 				.unwrap_or(sql::Expr::Literal(sql::Literal::None)),
+			cond: self.cond.clone().map(Into::into),
 			concurrently: false,
 		}
 	}
@@ -112,6 +123,7 @@ impl InfoStructure for IndexDefinition {
 			"table".to_string() => self.table_name.into_string().into(),
 			"cols".to_string() => Value::Array(Array(self.cols.into_iter().map(|x| x.structure()).collect())),
 			"index".to_string() => self.index.structure(),
+			"cond".to_string(), if let Some(v) = self.cond => v.0.structure(),
 			"comment".to_string(), if let Some(v) = self.comment => v.into(),
 			"prepare_remove".to_string(), if self.prepare_remove => self.prepare_remove.into()
 		})
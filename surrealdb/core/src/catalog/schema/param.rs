@@ -2,13 +2,14 @@ use revision::revisioned;
 use surrealdb_types::{SqlFormat, ToSql};
 
 use crate::catalog::Permission;
+use crate::expr::Kind;
 use crate::expr::statements::info::InfoStructure;
 use crate::kvs::impl_kv_value_revisioned;
 use crate::sql;
 use crate::sql::statements::define::{DefineKind, DefineParamStatement};
 use crate::val::Value;
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[non_exhaustive]
 pub struct ParamDefinition {
@@ -16,6 +17,9 @@ pub struct ParamDefinition {
 	pub(crate) value: Value,
 	pub(crate) comment: Option<String>,
 	pub(crate) permissions: Permission,
+	/// The kind which the value is coerced to whenever it is set
+	#[revision(start = 2)]
+	pub(crate) param_kind: Option<Kind>,
 }
 impl_kv_value_revisioned!(ParamDefinition);
 
@@ -24,6 +28,7 @@ impl ParamDefinition {
 		DefineParamStatement {
 			kind: DefineKind::Default,
 			name: self.name.clone(),
+			param_kind: self.param_kind.clone().map(Into::into),
 			value: {
 				let public_val: crate::types::PublicValue =
 					self.value.clone().try_into().expect("value conversion should succeed");
@@ -51,6 +56,7 @@ impl InfoStructure for ParamDefinition {
 		Value::from(map! {
 			"name".to_string() => self.name.into(),
 			"value".to_string() => self.value.structure(),
+			"kind".to_string(), if let Some(v) = self.param_kind => v.structure(),
 			"permissions".to_string() => self.permissions.structure(),
 			"comment".to_string(), if let Some(v) = self.comment => v.into(),
 		})
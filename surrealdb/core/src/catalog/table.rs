@@ -4,7 +4,7 @@ use uuid::Uuid;
 
 use crate::catalog::{DatabaseId, NamespaceId, Permissions, ViewDefinition};
 use crate::expr::statements::info::InfoStructure;
-use crate::expr::{ChangeFeed, Kind};
+use crate::expr::{ChangeFeed, IdType, Kind, Ttl, UnknownFields};
 use crate::fmt::EscapeKwFreeIdent;
 use crate::kvs::impl_kv_value_revisioned;
 use crate::sql;
@@ -40,7 +40,7 @@ impl DeserializeRevisioned for TableId {
 	}
 }
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 4)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct TableDefinition {
 	pub(crate) namespace_id: NamespaceId,
@@ -63,6 +63,15 @@ pub struct TableDefinition {
 	pub(crate) cache_tables_ts: Uuid,
 	/// The last time that a DEFINE INDEX was added to this table
 	pub(crate) cache_indexes_ts: Uuid,
+	/// The time-to-live of the records in this table
+	#[revision(start = 2)]
+	pub(crate) ttl: Option<Ttl>,
+	/// What happens to undefined fields when the table is schemafull
+	#[revision(start = 3)]
+	pub(crate) unknown_fields: UnknownFields,
+	/// The type of the ids generated for records created without an id
+	#[revision(start = 4)]
+	pub(crate) id_type: IdType,
 }
 
 impl_kv_value_revisioned!(TableDefinition);
@@ -91,6 +100,9 @@ impl TableDefinition {
 			cache_events_ts: now,
 			cache_tables_ts: now,
 			cache_indexes_ts: now,
+			ttl: None,
+			unknown_fields: UnknownFields::Reject,
+			id_type: IdType::Rand,
 		}
 	}
 
@@ -109,9 +121,12 @@ impl TableDefinition {
 			name: sql::Expr::Table(self.name.clone().into_string()),
 			drop: self.drop,
 			full: self.schemafull,
+			unknown_fields: self.unknown_fields.clone().into(),
 			view: self.view.clone().map(|v| v.to_sql_definition()),
 			permissions: self.permissions.clone().into(),
 			changefeed: self.changefeed.map(|v| v.into()),
+			ttl: self.ttl.clone().map(|v| v.into()),
+			id_type: self.id_type.clone().into(),
 			comment: self
 				.comment
 				.clone()
@@ -135,9 +150,13 @@ impl InfoStructure for TableDefinition {
 			"name".to_string() => self.name.into_string().into(),
 			"drop".to_string() => self.drop.into(),
 			"schemafull".to_string() => self.schemafull.into(),
+			"unknown_fields".to_string(), if self.unknown_fields != UnknownFields::Reject =>
+				self.unknown_fields.structure(),
 			"kind".to_string() => self.table_type.structure(),
 			"view".to_string(), if let Some(v) = self.view => v.structure(),
 			"changefeed".to_string(), if let Some(v) = self.changefeed => v.structure(),
+			"ttl".to_string(), if let Some(v) = self.ttl => v.structure(),
+			"id_type".to_string(), if self.id_type != IdType::Rand => self.id_type.structure(),
 			"permissions".to_string() => self.permissions.structure(),
 			"comment".to_string(), if let Some(v) = self.comment => v.into(),
 			"id".to_string() => self.table_id.0.into(),
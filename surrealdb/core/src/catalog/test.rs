@@ -11,8 +11,8 @@ use crate::catalog::record::Record;
 use crate::catalog::schema::base::Base;
 use crate::expr::field::Selector;
 use crate::expr::{
-	Block, ChangeFeed, Expr, Fetch, Fetchs, Field, Fields, Filter, Groups, Idiom, Kind, Literal,
-	Tokenizer,
+	Block, ChangeFeed, Expr, Fetch, Fetchs, Field, Fields, Filter, Groups, IdType, Idiom, Kind,
+	Literal, Tokenizer, UnknownFields,
 };
 use crate::iam::Auth;
 use crate::kvs::KVValue;
@@ -64,7 +64,10 @@ use crate::val::{Datetime, TableName, Value};
 	cache_events_ts: Uuid::default(),
 	cache_tables_ts: Uuid::default(),
 	cache_indexes_ts: Uuid::default(),
-}, 149)]
+	ttl: None,
+	unknown_fields: UnknownFields::Reject,
+	id_type: IdType::Rand,
+}, 152)]
 #[case::subscription(SubscriptionDefinition {
 	id: Uuid::default(),
 	node: Uuid::default(),
@@ -207,7 +210,9 @@ use crate::val::{Datetime, TableName, Value};
 	index: Index::Idx,
 	comment: Some("comment".to_string()),
 	prepare_remove: false,
-}, 34)]
+	cond: None,
+	rebuilding: None,
+}, 36)]
 #[case::model(MlModelDefinition {
 	name: "model".to_string(),
 	hash: "hash".to_string(),
@@ -220,7 +225,8 @@ use crate::val::{Datetime, TableName, Value};
 	value: Value::Bool(true),
 	comment: Some("comment".to_string()),
 	permissions: Permission::Full,
-}, 21)]
+	param_kind: None,
+}, 22)]
 #[case::sequence(SequenceDefinition {
 	name: "sequence".to_string(),
 	batch: 123,
@@ -173,11 +173,22 @@ pub static NORMAL_FETCH_SIZE: LazyLock<u32> =
 pub static EXPORT_BATCH_SIZE: LazyLock<u32> =
 	lazy_env_parse!("SURREAL_EXPORT_BATCH_SIZE", u32, 1000);
 
+/// The maximum number of records that should be inserted at once for CSV
+/// imports (default: 1000)
+pub static IMPORT_BATCH_SIZE: LazyLock<u32> =
+	lazy_env_parse!("SURREAL_IMPORT_BATCH_SIZE", u32, 1000);
+
 /// The maximum number of keys that should be scanned at once for count queries
 /// (default: 50,000)
 pub static COUNT_BATCH_SIZE: LazyLock<u32> =
 	lazy_env_parse!("SURREAL_COUNT_BATCH_SIZE", u32, 50_000);
 
+/// The maximum number of records which are checked in each table by a single
+/// TTL sweep, the next sweep resumes from where this one stopped
+/// (default: 10,000)
+pub static TTL_SWEEP_SCAN_LIMIT: LazyLock<u32> =
+	lazy_env_parse!("SURREAL_TTL_SWEEP_SCAN_LIMIT", u32, 10_000);
+
 /// The maximum number of keys to scan at once per concurrent indexing batch
 /// (default: 250)
 pub static INDEXING_BATCH_SIZE: LazyLock<u32> =
@@ -221,6 +232,16 @@ pub static MAX_HTTP_IDLE_CONNECTIONS_PER_HOST: LazyLock<usize> =
 pub static MAX_HTTP_IDLE_CONNECTIONS: LazyLock<usize> =
 	lazy_env_parse!("SURREAL_MAX_HTTP_IDLE_CONNECTIONS", usize, 1000);
 
+/// The maximum number of bytes of the body of an HTTP error response which
+/// are included in the error (default: 4 KiB)
+pub static MAX_HTTP_ERROR_BODY_SIZE: LazyLock<usize> =
+	lazy_env_parse!("SURREAL_MAX_HTTP_ERROR_BODY_SIZE", usize, 4 * 1024);
+
+/// The maximum number of bytes of a single line of a response read with
+/// `http::stream` (default: 1 MiB)
+pub static MAX_HTTP_STREAM_LINE_SIZE: LazyLock<usize> =
+	lazy_env_parse!("SURREAL_MAX_HTTP_STREAM_LINE_SIZE", usize, 1024 * 1024);
+
 /// The timeout for idle HTTP connections before closing (default: 90 seconds)
 pub static HTTP_IDLE_TIMEOUT_SECS: LazyLock<u64> =
 	lazy_env_parse!("SURREAL_HTTP_IDLE_TIMEOUT_SECS", u64, 90);
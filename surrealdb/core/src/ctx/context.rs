@@ -11,6 +11,7 @@ use std::time::Duration;
 use anyhow::Context as _;
 use anyhow::{Result, bail};
 use async_channel::Sender;
+use parking_lot::Mutex;
 #[cfg(feature = "surrealism")]
 use surrealism_runtime::package::{SurrealismPackage, UnpackOptions};
 #[cfg(feature = "surrealism")]
@@ -34,7 +35,7 @@ use crate::dbs::capabilities::ExperimentalTarget;
 use crate::dbs::capabilities::NetTarget;
 #[cfg(all(feature = "http", feature = "surrealism"))]
 use crate::dbs::capabilities::Targets;
-use crate::dbs::{Capabilities, NewPlannerStrategy, Options, Session, Variables};
+use crate::dbs::{Capabilities, NewPlannerStrategy, NullCoercion, Options, Session, Variables};
 use crate::err::Error;
 use crate::exec::function::FunctionRegistry;
 #[cfg(feature = "http")]
@@ -63,6 +64,9 @@ pub struct Context {
 	parent: Option<FrozenContext>,
 	// An optional deadline.
 	deadline: Option<(Instant, Duration)>,
+	// An optional deadline after which iteration stops early, without the
+	// statement timing out.
+	scan_deadline: Option<Instant>,
 	// An optional slow log configuration used by the executor to log statements
 	// that exceed a given duration threshold. This configuration is propagated
 	// from the datastore into the context for the lifetime of a request.
@@ -73,6 +77,8 @@ pub struct Context {
 	values: HashMap<Cow<'static, str>, Arc<Value>>,
 	// Stores the notification channel if available
 	notifications: Option<Sender<PublicNotification>>,
+	// An optional sink collecting non-fatal warnings raised during execution
+	warnings: Option<Arc<Mutex<Vec<String>>>>,
 	// An optional query planner
 	query_planner: Option<Arc<QueryPlanner>>,
 	// An optional query executor
@@ -107,6 +113,8 @@ pub struct Context {
 	new_planner_strategy: NewPlannerStrategy,
 	// When true, EXPLAIN ANALYZE omits elapsed durations for deterministic test output
 	redact_volatile_explain_attrs: bool,
+	// How NONE and NULL are coerced into one another
+	null_coercion: NullCoercion,
 	// Matches context for index functions (search::highlight, search::score, etc.)
 	matches_context: Option<Arc<crate::exec::function::MatchesContext>>,
 	// KNN context for index functions (vector::distance::knn)
@@ -121,6 +129,7 @@ impl Debug for Context {
 		f.debug_struct("Context")
 			.field("parent", &self.parent)
 			.field("deadline", &self.deadline)
+			.field("scan_deadline", &self.scan_deadline)
 			.field("cancelled", &self.cancelled)
 			.field("values", &self.values)
 			.finish()
@@ -134,9 +143,11 @@ impl Context {
 			values: HashMap::default(),
 			parent: None,
 			deadline: None,
+			scan_deadline: None,
 			slow_log: None,
 			cancelled: Arc::new(AtomicBool::new(false)),
 			notifications: None,
+			warnings: None,
 			query_planner: None,
 			query_executor: None,
 			iteration_stage: None,
@@ -155,6 +166,7 @@ impl Context {
 			function_registry: Arc::new(FunctionRegistry::with_builtins()),
 			new_planner_strategy: NewPlannerStrategy::default(),
 			redact_volatile_explain_attrs: false,
+			null_coercion: NullCoercion::default(),
 			matches_context: None,
 			knn_context: None,
 			#[cfg(feature = "http")]
@@ -183,9 +195,11 @@ impl Context {
 		Context {
 			values: HashMap::default(),
 			deadline: parent.deadline,
+			scan_deadline: parent.scan_deadline,
 			slow_log: parent.slow_log.clone(),
 			cancelled: Arc::new(AtomicBool::new(false)),
 			notifications: parent.notifications.clone(),
+			warnings: parent.warnings.clone(),
 			query_planner: parent.query_planner.clone(),
 			query_executor: parent.query_executor.clone(),
 			iteration_stage: parent.iteration_stage.clone(),
@@ -205,6 +219,7 @@ impl Context {
 			function_registry: parent.function_registry.clone(),
 			new_planner_strategy: parent.new_planner_strategy.clone(),
 			redact_volatile_explain_attrs: parent.redact_volatile_explain_attrs,
+			null_coercion: parent.null_coercion,
 			matches_context: parent.matches_context.clone(),
 			knn_context: parent.knn_context.clone(),
 			#[cfg(feature = "http")]
@@ -219,9 +234,11 @@ impl Context {
 		Self {
 			values: HashMap::default(),
 			deadline: parent.deadline,
+			scan_deadline: parent.scan_deadline,
 			slow_log: parent.slow_log.clone(),
 			cancelled: Arc::new(AtomicBool::new(false)),
 			notifications: parent.notifications.clone(),
+			warnings: parent.warnings.clone(),
 			query_planner: parent.query_planner.clone(),
 			query_executor: parent.query_executor.clone(),
 			iteration_stage: parent.iteration_stage.clone(),
@@ -241,6 +258,7 @@ impl Context {
 			function_registry: parent.function_registry.clone(),
 			new_planner_strategy: parent.new_planner_strategy.clone(),
 			redact_volatile_explain_attrs: parent.redact_volatile_explain_attrs,
+			null_coercion: parent.null_coercion,
 			matches_context: parent.matches_context.clone(),
 			knn_context: parent.knn_context.clone(),
 			#[cfg(feature = "http")]
@@ -262,9 +280,11 @@ impl Context {
 			// Flatten all values from the parent chain into this context
 			values: from.collect_values(HashMap::default()),
 			deadline: from.deadline,
+			scan_deadline: from.scan_deadline,
 			slow_log: from.slow_log.clone(),
 			cancelled: Arc::new(AtomicBool::new(false)),
 			notifications: from.notifications.clone(),
+			warnings: from.warnings.clone(),
 			query_planner: from.query_planner.clone(),
 			query_executor: from.query_executor.clone(),
 			iteration_stage: from.iteration_stage.clone(),
@@ -284,6 +304,7 @@ impl Context {
 			function_registry: from.function_registry.clone(),
 			new_planner_strategy: from.new_planner_strategy.clone(),
 			redact_volatile_explain_attrs: from.redact_volatile_explain_attrs,
+			null_coercion: from.null_coercion,
 			matches_context: from.matches_context.clone(),
 			knn_context: from.knn_context.clone(),
 			#[cfg(feature = "http")]
@@ -298,9 +319,11 @@ impl Context {
 		Self {
 			values: HashMap::default(),
 			deadline: None,
+			scan_deadline: None,
 			slow_log: from.slow_log.clone(),
 			cancelled: Arc::new(AtomicBool::new(false)),
 			notifications: from.notifications.clone(),
+			warnings: from.warnings.clone(),
 			query_planner: from.query_planner.clone(),
 			query_executor: from.query_executor.clone(),
 			iteration_stage: from.iteration_stage.clone(),
@@ -320,6 +343,7 @@ impl Context {
 			function_registry: from.function_registry.clone(),
 			new_planner_strategy: from.new_planner_strategy.clone(),
 			redact_volatile_explain_attrs: from.redact_volatile_explain_attrs,
+			null_coercion: from.null_coercion,
 			matches_context: from.matches_context.clone(),
 			knn_context: from.knn_context.clone(),
 			#[cfg(feature = "http")]
@@ -347,9 +371,11 @@ impl Context {
 			values: HashMap::default(),
 			parent: None,
 			deadline: None,
+			scan_deadline: None,
 			slow_log,
 			cancelled: Arc::new(AtomicBool::new(false)),
 			notifications: None,
+			warnings: None,
 			query_planner: None,
 			query_executor: None,
 			iteration_stage: None,
@@ -368,6 +394,7 @@ impl Context {
 			function_registry: Arc::new(FunctionRegistry::with_builtins()),
 			new_planner_strategy: planner_strategy,
 			redact_volatile_explain_attrs: false,
+			null_coercion: NullCoercion::default(),
 			matches_context: None,
 			knn_context: None,
 			#[cfg(feature = "http")]
@@ -386,9 +413,11 @@ impl Context {
 			values: HashMap::default(),
 			parent: None,
 			deadline: None,
+			scan_deadline: None,
 			slow_log: None,
 			cancelled: Arc::new(AtomicBool::new(false)),
 			notifications: None,
+			warnings: None,
 			query_planner: None,
 			query_executor: None,
 			iteration_stage: None,
@@ -407,6 +436,7 @@ impl Context {
 			function_registry: Arc::new(FunctionRegistry::with_builtins()),
 			new_planner_strategy: NewPlannerStrategy::default(),
 			redact_volatile_explain_attrs: false,
+			null_coercion: NullCoercion::default(),
 			matches_context: None,
 			knn_context: None,
 			#[cfg(feature = "http")]
@@ -550,12 +580,32 @@ impl Context {
 		}
 	}
 
+	/// Add a timeout after which iteration stops early, without the statement
+	/// timing out, so that the records gathered so far can still be
+	/// processed. If the result of the addition causes an overflow, this
+	/// method returns an error.
+	pub(crate) fn add_scan_timeout(&mut self, timeout: Duration) -> Result<(), Error> {
+		match Instant::now().checked_add(timeout) {
+			Some(deadline) => {
+				self.scan_deadline = Some(deadline);
+				Ok(())
+			}
+			None => Err(Error::InvalidTimeout(timeout.as_secs())),
+		}
+	}
+
 	/// Add the LIVE query notification channel to the context, so that we
 	/// can send notifications to any subscribers.
 	pub(crate) fn add_notifications(&mut self, chn: Option<&Sender<PublicNotification>>) {
 		self.notifications = chn.cloned()
 	}
 
+	/// Enable or disable the collection of non-fatal warnings raised while
+	/// executing statements within this context.
+	pub(crate) fn set_warnings(&mut self, enabled: bool) {
+		self.warnings = enabled.then(Default::default);
+	}
+
 	pub(crate) fn set_query_planner(&mut self, qp: QueryPlanner) {
 		self.query_planner = Some(Arc::new(qp));
 	}
@@ -608,6 +658,31 @@ impl Context {
 		self.notifications.is_some()
 	}
 
+	/// Whether non-fatal warnings are being collected.
+	pub(crate) fn collects_warnings(&self) -> bool {
+		self.warnings.is_some()
+	}
+
+	/// Record a non-fatal warning, if warnings are being collected.
+	/// Identical warnings raised by the same statement are only kept once.
+	pub(crate) fn add_warning(&self, warning: impl Into<String>) {
+		if let Some(sink) = &self.warnings {
+			let warning = warning.into();
+			let mut warnings = sink.lock();
+			if !warnings.contains(&warning) {
+				warnings.push(warning);
+			}
+		}
+	}
+
+	/// Take all warnings collected since the last call.
+	pub(crate) fn take_warnings(&self) -> Vec<String> {
+		match &self.warnings {
+			Some(sink) => std::mem::take(&mut *sink.lock()),
+			None => Vec::new(),
+		}
+	}
+
 	pub(crate) fn get_query_planner(&self) -> Option<&QueryPlanner> {
 		self.query_planner.as_ref().map(|qp| qp.as_ref())
 	}
@@ -688,6 +763,11 @@ impl Context {
 			{
 				return Ok(Some(Reason::Timedout(timeout.into())));
 			}
+			if let Some(deadline) = self.scan_deadline
+				&& deadline <= now
+			{
+				return Ok(Some(Reason::Canceled));
+			}
 		}
 		if let Some(ctx) = &self.parent {
 			return ctx.done(deep_check);
@@ -812,6 +892,7 @@ impl Context {
 		if session.redact_volatile_explain_attrs {
 			self.redact_volatile_explain_attrs = true;
 		}
+		self.null_coercion = session.null_coercion;
 		if !session.variables.is_empty() {
 			self.attach_variables(session.variables.clone().into())?;
 		}
@@ -889,6 +970,11 @@ impl Context {
 		self.redact_volatile_explain_attrs
 	}
 
+	/// The policy for coercing between NONE and NULL values.
+	pub(crate) fn null_coercion(&self) -> NullCoercion {
+		self.null_coercion
+	}
+
 	/// Check if scripting is allowed
 	#[cfg_attr(not(feature = "scripting"), expect(dead_code))]
 	pub(crate) fn check_allowed_scripting(&self) -> Result<()> {
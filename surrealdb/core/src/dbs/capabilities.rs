@@ -123,6 +123,7 @@ impl std::str::FromStr for FuncTarget {
 pub enum ExperimentalTarget {
 	Files,
 	Surrealism,
+	Debug,
 }
 
 impl fmt::Display for ExperimentalTarget {
@@ -130,6 +131,7 @@ impl fmt::Display for ExperimentalTarget {
 		match self {
 			Self::Files => write!(f, "files"),
 			Self::Surrealism => write!(f, "surrealism"),
+			Self::Debug => write!(f, "debug"),
 		}
 	}
 }
@@ -145,6 +147,7 @@ impl Target<str> for ExperimentalTarget {
 		match self {
 			Self::Files => elem.eq_ignore_ascii_case("files"),
 			Self::Surrealism => elem.eq_ignore_ascii_case("surrealism"),
+			Self::Debug => elem.eq_ignore_ascii_case("debug"),
 		}
 	}
 }
@@ -172,6 +175,7 @@ impl std::str::FromStr for ExperimentalTarget {
 		match s.trim().to_ascii_lowercase().as_str() {
 			"files" => Ok(ExperimentalTarget::Files),
 			"surrealism" => Ok(ExperimentalTarget::Surrealism),
+			"debug" => Ok(ExperimentalTarget::Debug),
 			_ => Err(ParseExperimentalTargetError::InvalidName),
 		}
 	}
@@ -596,13 +600,16 @@ pub struct Capabilities {
 	allow_arbitrary_query: Targets<ArbitraryQueryTarget>,
 	deny_arbitrary_query: Targets<ArbitraryQueryTarget>,
 	planner_strategy: NewPlannerStrategy,
+	max_statements: Option<usize>,
+	max_http_response_size: Option<usize>,
+	max_query_length: Option<usize>,
 }
 
 impl fmt::Display for Capabilities {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		write!(
 			f,
-			"scripting={}, guest_access={}, live_query_notifications={}, allow_funcs={}, deny_funcs={}, allow_net={}, deny_net={}, allow_rpc={}, deny_rpc={}, allow_http={}, deny_http={}, allow_experimental={}, deny_experimental={}, allow_arbitrary_query={}, deny_arbitrary_query={}, planner_strategy={}",
+			"scripting={}, guest_access={}, live_query_notifications={}, allow_funcs={}, deny_funcs={}, allow_net={}, deny_net={}, allow_rpc={}, deny_rpc={}, allow_http={}, deny_http={}, allow_experimental={}, deny_experimental={}, allow_arbitrary_query={}, deny_arbitrary_query={}, planner_strategy={}, max_statements={}, max_http_response_size={}, max_query_length={}",
 			self.scripting,
 			self.guest_access,
 			self.live_query_notifications,
@@ -619,6 +626,11 @@ impl fmt::Display for Capabilities {
 			self.allow_arbitrary_query,
 			self.deny_arbitrary_query,
 			self.planner_strategy,
+			self.max_statements.map(|x| x.to_string()).unwrap_or_else(|| "unlimited".to_string()),
+			self.max_http_response_size
+				.map(|x| x.to_string())
+				.unwrap_or_else(|| "unlimited".to_string()),
+			self.max_query_length.map(|x| x.to_string()).unwrap_or_else(|| "unlimited".to_string()),
 		)
 	}
 }
@@ -643,6 +655,9 @@ impl Default for Capabilities {
 			allow_arbitrary_query: Targets::All,
 			deny_arbitrary_query: Targets::None,
 			planner_strategy: NewPlannerStrategy::default(),
+			max_statements: None,
+			max_http_response_size: None,
+			max_query_length: None,
 		}
 	}
 }
@@ -667,6 +682,9 @@ impl Capabilities {
 			allow_arbitrary_query: Targets::All,
 			deny_arbitrary_query: Targets::None,
 			planner_strategy: NewPlannerStrategy::default(),
+			max_statements: None,
+			max_http_response_size: None,
+			max_query_length: None,
 		}
 	}
 
@@ -689,6 +707,9 @@ impl Capabilities {
 			allow_arbitrary_query: Targets::None,
 			deny_arbitrary_query: Targets::None,
 			planner_strategy: NewPlannerStrategy::default(),
+			max_statements: None,
+			max_http_response_size: None,
+			max_query_length: None,
 		}
 	}
 
@@ -810,6 +831,38 @@ impl Capabilities {
 		&self.planner_strategy
 	}
 
+	/// Limit the number of statements which a single query may contain
+	pub fn with_max_statements(mut self, max_statements: Option<usize>) -> Self {
+		self.max_statements = max_statements;
+		self
+	}
+
+	pub fn max_statements(&self) -> Option<usize> {
+		self.max_statements
+	}
+
+	/// Limit the length in bytes of the text of a query, which is checked
+	/// before the query is parsed
+	pub fn with_max_query_length(mut self, max_query_length: Option<usize>) -> Self {
+		self.max_query_length = max_query_length;
+		self
+	}
+
+	pub fn max_query_length(&self) -> Option<usize> {
+		self.max_query_length
+	}
+
+	/// Limit the size in bytes of the response bodies which the http
+	/// functions will read
+	pub fn with_max_http_response_size(mut self, max_http_response_size: Option<usize>) -> Self {
+		self.max_http_response_size = max_http_response_size;
+		self
+	}
+
+	pub fn max_http_response_size(&self) -> Option<usize> {
+		self.max_http_response_size
+	}
+
 	pub fn allows_scripting(&self) -> bool {
 		self.scripting
 	}
@@ -1,11 +1,13 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::pin::{Pin, pin};
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Result, anyhow, bail};
+use chrono::Utc;
 use futures::{Stream, StreamExt};
 use reblessive::TreeStack;
-use surrealdb_types::{Error as TypesError, QueryError, ToSql};
+use surrealdb_types::{Datetime, Error as TypesError, QueryError, ToSql};
 #[cfg(not(target_family = "wasm"))]
 use tokio::spawn;
 use tracing::instrument;
@@ -16,22 +18,25 @@ use web_time::Instant;
 use crate::catalog::providers::{CatalogProvider, NamespaceProvider, RootProvider};
 use crate::ctx::reason::Reason;
 use crate::ctx::{Context, FrozenContext};
-use crate::dbs::response::QueryResult;
-use crate::dbs::{Force, Options, QueryType};
+use crate::dbs::response::{QueryMetrics, QueryResult};
+use crate::dbs::{DivByZero, Force, Options, QueryType};
 use crate::doc::DefaultBroker;
 use crate::err::Error;
+use crate::exe::try_join_all_buffered;
 use crate::exec::planner::try_plan_expr;
 use crate::expr::parameterize::expr_to_ident;
 use crate::expr::paths::{DB, NS};
 use crate::expr::plan::LogicalPlan;
 use crate::expr::statements::{OptionStatement, UseStatement};
-use crate::expr::{Base, ControlFlow, Expr, FlowResult, TopLevelExpr};
+use crate::expr::{Base, ControlFlow, Expr, FlowResult, Limit, Literal, TopLevelExpr};
 use crate::iam::{Action, ResourceKind};
+use crate::kvs::audit::{self, AuditEvent};
+use crate::kvs::result_cache;
 use crate::kvs::slowlog::SlowLogVisit;
-use crate::kvs::{Datastore, LockType, Transaction, TransactionType};
+use crate::kvs::{Datastore, LockType, Transaction, TransactionType, is_conflict};
 use crate::rpc::types_error_from_anyhow;
 use crate::types::PublicNotification;
-use crate::val::{Array, Value, convert_value_to_public_value};
+use crate::val::{Array, Object, RecordId, Value, convert_value_to_public_value};
 use crate::{err, expr, sql};
 
 const TARGET: &str = "surrealdb::core::dbs";
@@ -45,6 +50,14 @@ pub struct Executor {
 	/// Cached session info to avoid re-extracting from context on every query.
 	/// Session values don't change between statements in the same executor batch.
 	cached_session: Option<Arc<crate::exec::context::SessionInfo>>,
+	/// When set, completed results are sent here instead of being collected.
+	sender: Option<async_channel::Sender<QueryResult>>,
+	/// The read version of the last committed write, when requested.
+	version: Option<Datetime>,
+	/// Whether the default LIMIT left rows out of the last result.
+	truncated: bool,
+	/// The reads of the last statement, when the session requested metrics.
+	metrics: Option<QueryMetrics>,
 }
 
 impl Executor {
@@ -70,6 +83,102 @@ impl Executor {
 			opt,
 			ctx,
 			cached_session: None,
+			sender: None,
+			version: None,
+			truncated: false,
+			metrics: None,
+		}
+	}
+
+	/// Returns the version at which the writes of a committed transaction
+	/// can be read back, if the session requested it.
+	///
+	/// The storage engines do not expose the version assigned at commit, so
+	/// this is the wall-clock time read after the commit returns, at the
+	/// precision of the versions of the storage engine. It is not the commit
+	/// version itself: reads at this version may also observe writes which
+	/// were committed after this one.
+	fn committed_version(&self, txn: &Transaction) -> Option<Datetime> {
+		if !self.opt.return_version {
+			return None;
+		}
+		let ts = txn.timestamp_impl().create_from_datetime(Utc::now())?;
+		ts.as_datetime().map(Datetime::from)
+	}
+
+	/// Applies the default LIMIT of the session to a SELECT statement which
+	/// does not have a LIMIT, returning the limit. One more row than the limit
+	/// is selected, so that a result with rows left out can be detected.
+	fn apply_default_limit(&self, expr: &mut Expr) -> Option<usize> {
+		let limit = self.opt.default_limit?;
+		let Expr::Select(stm) = expr else {
+			return None;
+		};
+		if stm.limit.is_some() || stm.only || stm.explain.is_some() {
+			return None;
+		}
+		stm.limit = Some(Limit(Expr::Literal(Literal::Integer(i64::from(limit) + 1))));
+		Some(limit as usize)
+	}
+
+	/// Truncates a result to the default LIMIT, marking it as truncated if
+	/// rows were left out.
+	fn truncate_to_default_limit(&mut self, value: Value, limit: usize) -> Value {
+		match value {
+			Value::Array(mut rows) if rows.len() > limit => {
+				rows.truncate(limit);
+				self.truncated = true;
+				Value::Array(rows)
+			}
+			value => value,
+		}
+	}
+
+	/// Creates the audit event for a statement which ran successfully, and
+	/// wrote the given records. Read-only statements only have an event if they
+	/// wrote records, such as through a function.
+	fn audit_event(
+		&self,
+		written: Vec<RecordId>,
+		read_only: bool,
+		statement: String,
+	) -> Option<AuditEvent> {
+		if read_only && written.is_empty() {
+			return None;
+		}
+		Some(AuditEvent::new(&self.opt, statement, written))
+	}
+
+	/// Takes the completed results, limiting the depth of their values if the
+	/// session set a maximum output depth.
+	fn take_results(&mut self) -> Vec<QueryResult> {
+		let mut results = std::mem::take(&mut self.results);
+		if let Some(max) = self.opt.max_output_depth {
+			for res in results.iter_mut() {
+				res.limit_depth(max, self.opt.output_depth_policy);
+			}
+		}
+		results
+	}
+
+	/// Sends any completed results to the result channel, if there is one.
+	///
+	/// This is only called between top-level statements, so that the results
+	/// of a transaction block are sent together once the block has finished.
+	async fn flush_results(&mut self) {
+		if let Some(sender) = self.sender.clone() {
+			if self.results.is_empty() {
+				return;
+			}
+			for res in self.take_results() {
+				// Wait for room in the channel, so that no results are dropped
+				if sender.send(res).await.is_err() {
+					debug!(target: TARGET, "Discarding query results, as the receiver was dropped");
+					break;
+				}
+			}
+			// Yield so that the results can be received before the next statement
+			yield_now!();
 		}
 	}
 
@@ -86,6 +195,30 @@ impl Executor {
 				Force::None
 			};
 			self.opt.force = force;
+		} else if stmt.name.eq_ignore_ascii_case("WARNINGS") {
+			Arc::get_mut(&mut self.ctx)
+				.ok_or_else(|| {
+					Error::unreachable("Tried to unfreeze a Context with multiple references")
+				})?
+				.set_warnings(stmt.what);
+		} else if stmt.name.eq_ignore_ascii_case("DIV_BY_ZERO") {
+			let div_by_zero = match stmt.value.as_deref() {
+				Some(v) if v.eq_ignore_ascii_case("ERROR") => DivByZero::Error,
+				Some(v) if v.eq_ignore_ascii_case("NONE") => DivByZero::None,
+				Some(v) if v.eq_ignore_ascii_case("INFINITY") => DivByZero::Infinity,
+				_ => bail!(Error::InvalidStatement(
+					"OPTION DIV_BY_ZERO must be set to one of ERROR, NONE, or INFINITY".to_string()
+				)),
+			};
+			self.opt.set_div_by_zero(div_by_zero);
+		} else if stmt.name.eq_ignore_ascii_case("STRICT") {
+			self.opt.set_strict(stmt.what);
+		} else if stmt.name.eq_ignore_ascii_case("RECORD_ERRORS") {
+			self.opt.set_record_errors(stmt.what);
+		} else if stmt.name.eq_ignore_ascii_case("PARALLEL") {
+			self.opt.set_parallel(stmt.what);
+		} else if stmt.name.eq_ignore_ascii_case("READONLY") {
+			self.opt.set_read_only(stmt.what);
 		}
 
 		Ok(())
@@ -236,7 +369,7 @@ impl Executor {
 			session: self.get_session_info(),
 			current_value: None,
 			skip_fetch_perms: false,
-			version_stamp: None,
+			version_stamp: self.opt.version,
 		};
 
 		// Check what level of context we need
@@ -348,6 +481,8 @@ impl Executor {
 					.map_err(anyhow::Error::new)?
 			};
 		}
+		// Keep the transaction to take the counts of its reads afterwards
+		let metrics_txn = self.opt.metrics.then(|| txn.clone());
 		let res = match plan {
 			TopLevelExpr::Use(stmt) => {
 				let opt_ref = self.opt.clone();
@@ -529,9 +664,10 @@ impl Executor {
 				self.stack.enter(|stk| s.compute(stk, &self.ctx, &self.opt, None)).finish().await
 			}
 			// Process all other normal statements
-			TopLevelExpr::Expr(e) => {
+			TopLevelExpr::Expr(mut e) => {
+				let default_limit = self.apply_default_limit(&mut e);
 				// Try the new streaming execution path first
-				match try_plan_expr!(&e, &self.ctx, txn.clone()) {
+				let res = match try_plan_expr!(&e, &self.ctx, txn.clone()) {
 					Ok(plan) => {
 						// Set the transaction on the context
 						ctx_mut!().set_transaction(txn.clone());
@@ -559,10 +695,26 @@ impl Executor {
 						res
 					}
 					Err(e) => Err(ControlFlow::Err(anyhow::Error::new(e))),
+				};
+				match default_limit {
+					Some(limit) => res.map(|v| self.truncate_to_default_limit(v, limit)),
+					None => res,
 				}
 			}
 		};
 
+		// Take the reads of the statement, and count the records it returned
+		if let Some(txn) = metrics_txn {
+			self.metrics = txn.take_read_metrics().map(|mut metrics| {
+				metrics.records_returned = match &res {
+					Ok(Value::Array(values)) => values.len() as u64,
+					Ok(Value::None) | Err(_) => 0,
+					Ok(_) => 1,
+				};
+				metrics
+			});
+		}
+
 		// Catch cancellation during running.
 		match self.ctx.done(true)? {
 			None => res,
@@ -596,19 +748,111 @@ impl Executor {
 		self.execute_plan_impl(kvs, start, stmt).await
 	}
 
+	/// Execute a run of statements which can run in parallel, each in its own
+	/// transaction, with a limited number running at a time. The results are
+	/// added in the order of the statements.
+	async fn execute_parallel_statements(
+		&mut self,
+		kvs: &Datastore,
+		stmts: Vec<TopLevelExpr>,
+	) -> Result<()> {
+		let futures = stmts.into_iter().map(|stmt| {
+			// Each statement collects its own warnings
+			let mut ctx = Context::new_child(&self.ctx);
+			ctx.set_warnings(self.ctx.collects_warnings());
+			let mut executor = Executor::new(ctx.freeze(), self.opt.clone());
+			async move {
+				let query_type = QueryType::for_toplevel_expr(&stmt);
+				let statement_type = stmt.statement_type();
+				let now = Instant::now();
+				let result = executor.execute_bare_statement(kvs, &now, stmt).await;
+				kvs.query_counters().record_statement(statement_type, result.is_ok());
+				let result = match result {
+					Ok(value) => Ok(convert_value_to_public_value(value)?),
+					Err(err) => Err(types_error_from_anyhow(err)),
+				};
+				Ok::<_, anyhow::Error>(QueryResult {
+					warnings: executor.ctx.take_warnings(),
+					truncated: executor.truncated,
+					metrics: executor.metrics,
+					..QueryResult::new(now.elapsed(), result, query_type)
+				})
+			}
+		});
+		let results = try_join_all_buffered(futures).await?;
+		self.results.extend(results);
+		Ok(())
+	}
+
 	async fn execute_plan_impl(
 		&mut self,
 		kvs: &Datastore,
 		start: &Instant,
-		plan: TopLevelExpr,
+		mut plan: TopLevelExpr,
 	) -> Result<Value> {
-		let transaction_type = if plan.read_only() {
+		let mut retries = kvs.transaction_retries();
+		loop {
+			// Keep a copy of the statement to run it again if its commit conflicts
+			let retry = (retries > 0).then(|| plan.clone());
+			let value = self.execute_plan_attempt(kvs, start, plan, retry.is_some()).await?;
+			match (value, retry) {
+				(Some(value), _) => return Ok(value),
+				(None, Some(stmt)) => {
+					// Nothing was written, and no results have been sent yet,
+					// so the statement can be run again in a new transaction
+					// Running the statement again raises its warnings again, so
+					// the warnings of the attempt which conflicted are discarded
+					drop(self.ctx.take_warnings());
+					self.truncated = false;
+					kvs.conflict_counters().record_retry();
+					retries -= 1;
+					plan = stmt;
+				}
+				(None, None) => bail!(Error::unreachable("a statement was retried without a copy")),
+			}
+		}
+	}
+
+	/// Runs a statement in its own transaction.
+	///
+	/// Returns `None` if `retry` is set and the commit failed due to a
+	/// conflict with a concurrent transaction.
+	async fn execute_plan_attempt(
+		&mut self,
+		kvs: &Datastore,
+		start: &Instant,
+		plan: TopLevelExpr,
+		retry: bool,
+	) -> Result<Option<Value>> {
+		// With OPTION READONLY, statements which may write run in a read-only
+		// transaction, which rejects any write they attempt
+		let transaction_type = if self.opt.read_only || plan.read_only() {
 			TransactionType::Read
 		} else {
 			TransactionType::Write
 		};
-		let txn = Arc::new(kvs.transaction(transaction_type, LockType::Optimistic).await?);
+		// Return the cached result of the statement, if it is still valid
+		let cacheable = kvs.result_cache().and_then(|c| c.cacheable(&self.ctx, &self.opt, &plan));
+		if let (Some(cache), Some(stmt)) = (kvs.result_cache(), &cacheable)
+			&& let Some((value, truncated)) = cache.get(stmt)
+		{
+			self.truncated = truncated;
+			return Ok(Some(value));
+		}
+		let schema_written = matches!(transaction_type, TransactionType::Write)
+			&& !result_cache::writes_only_records(&plan);
+		let txn = kvs.transaction(transaction_type, LockType::Optimistic).await?;
+		let txn = Arc::new(if self.opt.metrics {
+			txn.with_read_metrics()
+		} else {
+			txn
+		});
 		let receiver = self.prepare_broker();
+		// Keep the statement text for the audit event of the write
+		let statement = kvs
+			.audit_sink()
+			.filter(|_| matches!(transaction_type, TransactionType::Write))
+			.map(|_| audit::statement(&plan));
 
 		let exec_result = match kvs.transaction_timeout() {
 			Some(timeout) => {
@@ -635,14 +879,36 @@ impl Executor {
 				// has nothing to commit anyway.
 				if let TransactionType::Read = transaction_type {
 					let _ = txn.cancel().await;
-					return Ok(value);
+					if let (Some(cache), Some(stmt)) = (kvs.result_cache(), cacheable) {
+						cache.insert(stmt, value.clone(), self.truncated);
+					}
+					return Ok(Some(value));
 				}
 
-				if let Err(e) = txn.commit().await {
+				let res = txn.commit().await;
+				kvs.conflict_counters().record_commit(&res);
+				if let Err(e) = res {
+					if retry && is_conflict(&e) {
+						return Ok(None);
+					}
 					bail!(Error::QueryNotExecuted {
 						message: e.to_string(),
 					});
 				}
+				self.version = self.committed_version(&txn);
+
+				// Invalidate every cached result if anything but records changed
+				if schema_written && let Some(cache) = kvs.result_cache() {
+					cache.schema_written();
+				}
+
+				// Send the audit event for the committed write
+				if let (Some(sink), Some(statement)) = (kvs.audit_sink(), statement)
+					&& let Some(event) =
+						self.audit_event(txn.take_written_records(), false, statement)
+				{
+					audit::send(sink, vec![event]);
+				}
 
 				// flush notifications.
 				if let Some(recv) = receiver {
@@ -658,7 +924,7 @@ impl Executor {
 					}
 				}
 
-				Ok(value)
+				Ok(Some(value))
 			}
 			Err(ControlFlow::Continue) | Err(ControlFlow::Break) => {
 				let _ = txn.cancel().await;
@@ -681,7 +947,7 @@ impl Executor {
 	where
 		S: Stream<Item = Result<TopLevelExpr>>,
 	{
-		let Ok(txn) = kvs.transaction(TransactionType::Write, LockType::Optimistic).await else {
+		let Ok(txn) = self.begin_transaction(kvs).await else {
 			// couldn't create a transaction.
 			// Fast forward until we hit CANCEL or COMMIT
 			while let Some(stmt) = stream.next().await {
@@ -691,15 +957,15 @@ impl Executor {
 					return Ok(());
 				}
 
-				self.results.push(QueryResult {
-					time: Duration::ZERO,
-					result: Err(TypesError::query(
+				self.results.push(QueryResult::new(
+					Duration::ZERO,
+					Err(TypesError::query(
 						"Tried to start a transaction while another transaction was open"
 							.to_string(),
 						Some(QueryError::NotExecuted),
 					)),
-					query_type: QueryType::Other,
-				});
+					QueryType::Other,
+				));
 			}
 
 			// Ran out of statements but still didn't hit a COMMIT or CANCEL
@@ -707,14 +973,84 @@ impl Executor {
 			// effectively canceled.
 			return Ok(());
 		};
-		let txn = Arc::new(txn);
+		// Keep the statements of the block to run them again if the commit conflicts
+		let mut retries = kvs.transaction_retries();
+		let mut stmts = Vec::new();
+		let start_results = self.results.len();
+		let mut conflicted = self
+			.execute_transaction_block(kvs, txn, stream, (retries > 0).then_some(&mut stmts))
+			.await?;
+		while conflicted {
+			let Ok(txn) = self.begin_transaction(kvs).await else {
+				return Ok(());
+			};
+			// The results of a transaction block are only sent once the block
+			// has finished, so the results of the attempt which conflicted can
+			// be replaced by the results of the next attempt
+			self.results.truncate(start_results);
+			kvs.conflict_counters().record_retry();
+			retries -= 1;
+			let replay = futures::stream::iter(
+				std::mem::take(&mut stmts).into_iter().map(Ok::<_, anyhow::Error>),
+			);
+			conflicted = self
+				.execute_transaction_block(
+					kvs,
+					txn,
+					pin!(replay),
+					(retries > 0).then_some(&mut stmts),
+				)
+				.await?;
+		}
+		Ok(())
+	}
+
+	/// Starts the transaction of a transaction block, which is read-only when
+	/// the session set OPTION READONLY.
+	async fn begin_transaction(&self, kvs: &Datastore) -> Result<Arc<Transaction>> {
+		let transaction_type = if self.opt.read_only {
+			TransactionType::Read
+		} else {
+			TransactionType::Write
+		};
+		let txn = kvs.transaction(transaction_type, LockType::Optimistic).await?;
+		// Track the written records when they are needed for the COMMIT summary
+		let txn = if self.opt.commit_summary {
+			txn.with_written_records()
+		} else {
+			txn
+		};
+		// Count the reads of each statement when the session requested metrics
+		let txn = if self.opt.metrics {
+			txn.with_read_metrics()
+		} else {
+			txn
+		};
+		Ok(Arc::new(txn))
+	}
 
+	/// Runs the statements of a transaction block, up to and including the
+	/// COMMIT or CANCEL statement, within the transaction timeout.
+	///
+	/// The statements are added to `retry`, if given, so that the block can be
+	/// run again. Returns `true` if `retry` was given and the commit failed due
+	/// to a conflict with a concurrent transaction.
+	async fn execute_transaction_block<S>(
+		&mut self,
+		kvs: &Datastore,
+		txn: Arc<Transaction>,
+		stream: Pin<&mut S>,
+		retry: Option<&mut Vec<TopLevelExpr>>,
+	) -> Result<bool>
+	where
+		S: Stream<Item = Result<TopLevelExpr>>,
+	{
 		match kvs.transaction_timeout() {
 			Some(timeout) => {
 				let start_results = self.results.len();
 				match tokio::time::timeout(
 					timeout,
-					self.execute_begin_statement_inner(txn.clone(), stream),
+					self.execute_begin_statement_inner(kvs, txn.clone(), stream, retry),
 				)
 				.await
 				{
@@ -737,15 +1073,17 @@ impl Executor {
 					}
 				}
 			}
-			None => self.execute_begin_statement_inner(txn, stream).await,
+			None => self.execute_begin_statement_inner(kvs, txn, stream, retry).await,
 		}
 	}
 
 	async fn execute_begin_statement_inner<S>(
 		&mut self,
+		kvs: &Datastore,
 		txn: Arc<Transaction>,
 		mut stream: Pin<&mut S>,
-	) -> Result<()>
+		mut retry: Option<&mut Vec<TopLevelExpr>>,
+	) -> Result<bool>
 	where
 		S: Stream<Item = Result<TopLevelExpr>>,
 	{
@@ -754,6 +1092,12 @@ impl Executor {
 		let receiver = self.prepare_broker();
 		let start_results = self.results.len();
 		let mut skip_remaining = false;
+		// The audit events of the statements, sent once the transaction commits
+		let mut audit_events = Vec::new();
+		// Whether anything but records was written, invalidating every cached result
+		let mut schema_written = false;
+		// The records written by the statements, when COMMIT returns a summary
+		let mut summary = self.opt.commit_summary.then(BTreeSet::new);
 
 		// loop over the statements until we hit a cancel or a commit statement.
 		while let Some(stmt) = stream.next().await {
@@ -766,6 +1110,9 @@ impl Executor {
 					return Err(e);
 				}
 			};
+			if let Some(stmts) = &mut retry {
+				stmts.push(stmt.clone());
+			}
 
 			// check for timeout and cancellation.
 			if let Some(done) = self.ctx.done(true)? {
@@ -791,9 +1138,9 @@ impl Executor {
 							// has one row per statement (mirrors successful COMMIT, which
 							// pushes Ok(NONE) in the main `TopLevelExpr::Commit` branch
 							// below) (#7207).
-							self.results.push(QueryResult {
-								time: Duration::ZERO,
-								result: Err(match done {
+							self.results.push(QueryResult::new(
+								Duration::ZERO,
+								Err(match done {
 									Reason::Timedout(d) => TypesError::query(
 										format!("Cannot COMMIT: timed out ({d})"),
 										Some(QueryError::TimedOut {
@@ -805,9 +1152,9 @@ impl Executor {
 										Some(QueryError::Cancelled),
 									),
 								}),
-								query_type: QueryType::Other,
-							});
-							return Ok(());
+								QueryType::Other,
+							));
+							return Ok(false);
 						}
 						ref stmt => {
 							let result = Err(match done {
@@ -823,20 +1170,20 @@ impl Executor {
 									Some(QueryError::Cancelled),
 								),
 							});
-							self.results.push(QueryResult {
-								time: Duration::ZERO,
+							self.results.push(QueryResult::new(
+								Duration::ZERO,
 								result,
-								query_type: QueryType::Other,
-							});
+								QueryType::Other,
+							));
 							if matches!(stmt, TopLevelExpr::Cancel) {
-								return Ok(());
+								return Ok(false);
 							}
 						}
 					}
 				}
 
 				// Missing CANCEL/COMMIT statement, statement already canceled so nothing todo.
-				return Ok(());
+				return Ok(false);
 			}
 
 			if skip_remaining && !matches!(stmt, TopLevelExpr::Cancel | TopLevelExpr::Commit) {
@@ -868,14 +1215,14 @@ impl Executor {
 						));
 					}
 
-					self.results.push(QueryResult {
-						time: Duration::ZERO,
-						result: Err(TypesError::internal(
+					self.results.push(QueryResult::new(
+						Duration::ZERO,
+						Err(TypesError::internal(
 							"Tried to start a transaction while another transaction was open"
 								.to_string(),
 						)),
-						query_type: QueryType::Other,
-					});
+						QueryType::Other,
+					));
 
 					self.opt.broker = None;
 
@@ -884,38 +1231,38 @@ impl Executor {
 						let stmt = stmt?;
 						match stmt {
 							TopLevelExpr::Commit => {
-								self.results.push(QueryResult {
-									time: Duration::ZERO,
-									result: Err(TypesError::query(
+								self.results.push(QueryResult::new(
+									Duration::ZERO,
+									Err(TypesError::query(
 										"Cannot COMMIT: the transaction was aborted due to a nested BEGIN"
 											.to_string(),
 										Some(QueryError::NotExecuted),
 									)),
-									query_type: QueryType::Other,
-								});
-								return Ok(());
+									QueryType::Other,
+								));
+								return Ok(false);
 							}
 							ref stmt => {
-								self.results.push(QueryResult {
-									time: Duration::ZERO,
-									result: Err(TypesError::query(
+								self.results.push(QueryResult::new(
+									Duration::ZERO,
+									Err(TypesError::query(
 										format!(
 											"The query was not executed due to a failed transaction: {}",
 											stmt.to_sql()
 										),
 										Some(QueryError::NotExecuted),
 									)),
-									query_type: QueryType::Other,
-								});
+									QueryType::Other,
+								));
 								if matches!(stmt, TopLevelExpr::Cancel) {
-									return Ok(());
+									return Ok(false);
 								}
 							}
 						}
 					}
 
 					// Missing CANCEL/COMMIT statement, statement already canceled so nothing todo.
-					return Ok(());
+					return Ok(false);
 				}
 				TopLevelExpr::Cancel => {
 					let _ = txn.cancel().await;
@@ -932,22 +1279,40 @@ impl Executor {
 					self.opt.broker = None;
 
 					// CANCEL returns NONE
-					self.results.push(QueryResult {
-						time: before.elapsed(),
-						result: Ok(convert_value_to_public_value(Value::None)?),
-						query_type: QueryType::Other,
-					});
+					self.results.push(QueryResult::new(
+						before.elapsed(),
+						Ok(convert_value_to_public_value(Value::None)?),
+						QueryType::Other,
+					));
 
-					return Ok(());
+					return Ok(false);
 				}
 				TopLevelExpr::Commit => {
 					// Commit the transaction.
 					// If error undo results.
-					let e = if let Err(e) = txn.commit().await {
+					let res = if txn.writeable() {
+						let res = txn.commit().await;
+						kvs.conflict_counters().record_commit(&res);
+						res
+					} else {
+						// A read-only transaction has nothing to commit
+						txn.cancel().await
+					};
+					let e = if let Err(e) = res {
 						e
 					} else {
 						// Successfully commited. everything is fine.
 
+						// Invalidate every cached result if anything but records changed
+						if schema_written && let Some(cache) = kvs.result_cache() {
+							cache.schema_written();
+						}
+
+						// Send the audit events for the committed writes
+						if let Some(sink) = kvs.audit_sink() {
+							audit::send(sink, std::mem::take(&mut audit_events));
+						}
+
 						// flush notifications.
 						if let Some(recv) = receiver {
 							self.opt.broker = None;
@@ -962,16 +1327,26 @@ impl Executor {
 							}
 						}
 
-						// COMMIT returns NONE
+						// COMMIT returns NONE, unless the session requested a summary
+						let value = match summary.take() {
+							Some(written) => commit_summary(written, schema_written),
+							None => Value::None,
+						};
 						self.results.push(QueryResult {
-							time: before.elapsed(),
-							result: Ok(convert_value_to_public_value(Value::None)?),
-							query_type: QueryType::Other,
+							version: self.committed_version(&txn),
+							..QueryResult::new(
+								before.elapsed(),
+								Ok(convert_value_to_public_value(value)?),
+								QueryType::Other,
+							)
 						});
 
-						return Ok(());
+						return Ok(false);
 					};
 
+					// A conflicting block is run again when retries are enabled
+					let conflicted = retry.is_some() && is_conflict(&e);
+
 					// `txn.commit()` failed (e.g. constraint on commit, or txn already finished).
 					// Surface the failure on a dedicated COMMIT result row; mark prior statement
 					// slots as not executed so nothing implies a successful commit (#7207).
@@ -985,25 +1360,25 @@ impl Executor {
 
 					self.opt.broker = None;
 
-					self.results.push(QueryResult {
-						time: before.elapsed(),
-						result: Err(TypesError::query(
+					self.results.push(QueryResult::new(
+						before.elapsed(),
+						Err(TypesError::query(
 							format!("Cannot COMMIT: {e}"),
 							Some(QueryError::NotExecuted),
 						)),
-						query_type: QueryType::Other,
-					});
+						QueryType::Other,
+					));
 
-					return Ok(());
+					return Ok(conflicted);
 				}
 				TopLevelExpr::Option(stmt) => match self.execute_option_statement(stmt) {
 					Ok(_) => {
 						// OPTION returns NONE
-						self.results.push(QueryResult {
-							time: before.elapsed(),
-							result: Ok(convert_value_to_public_value(Value::None)?),
-							query_type: QueryType::Other,
-						});
+						self.results.push(QueryResult::new(
+							before.elapsed(),
+							Ok(convert_value_to_public_value(Value::None)?),
+							QueryType::Other,
+						));
 						continue;
 					}
 					Err(e) => Err(TypesError::internal(e.to_string())),
@@ -1011,6 +1386,12 @@ impl Executor {
 				stmt => {
 					// reintroduce planner later.
 					let plan = stmt;
+					let statement_type = plan.statement_type();
+					schema_written |=
+						!plan.read_only() && !result_cache::writes_only_records(&plan);
+					// Keep the statement text for the audit event of any write
+					let audited =
+						kvs.audit_sink().map(|_| (plan.read_only(), audit::statement(&plan)));
 
 					let r = match self.execute_plan_in_transaction(txn.clone(), &before, plan).await
 					{
@@ -1035,9 +1416,12 @@ impl Executor {
 							// statement return an error. Consume all the other statement until
 							// we hit a cancel or commit.
 							self.results.push(QueryResult {
-								time: before.elapsed(),
-								result: Err(types_error_from_anyhow(e)),
-								query_type,
+								warnings: self.ctx.take_warnings(),
+								..QueryResult::new(
+									before.elapsed(),
+									Err(types_error_from_anyhow(e)),
+									query_type,
+								)
 							});
 
 							let _ = txn.cancel().await;
@@ -1052,40 +1436,57 @@ impl Executor {
 										// Aborted txn: COMMIT must error (same intent as
 										// `txn.commit()` failure above — descriptive
 										// `Cannot COMMIT:` prefix) (#7207).
-										self.results.push(QueryResult {
-												time: Duration::ZERO,
-												result: Err(TypesError::query(
-													"Cannot COMMIT: the transaction was aborted due to a prior error"
-														.to_string(),
-													Some(QueryError::NotExecuted),
-												)),
-												query_type: QueryType::Other,
-											});
-										return Ok(());
+										self.results.push(QueryResult::new(
+											Duration::ZERO,
+											Err(TypesError::query(
+												"Cannot COMMIT: the transaction was aborted due to a prior error"
+													.to_string(),
+												Some(QueryError::NotExecuted),
+											)),
+											QueryType::Other,
+										));
+										return Ok(false);
 									}
 									TopLevelExpr::Cancel => {
-										return Ok(());
+										return Ok(false);
 									}
 									_ => {
-										self.results.push(QueryResult {
-												time: Duration::ZERO,
-												result: Err(TypesError::query(
-													"The query was not executed due to a cancelled transaction"
-														.to_string(),
-													Some(QueryError::Cancelled),
-												)),
-												query_type: QueryType::Other,
-											});
+										self.results.push(QueryResult::new(
+											Duration::ZERO,
+											Err(TypesError::query(
+												"The query was not executed due to a cancelled transaction"
+													.to_string(),
+												Some(QueryError::Cancelled),
+											)),
+											QueryType::Other,
+										));
 									}
 								}
 							}
 
 							// ran out of statements before the transaction ended.
 							// Just break as we have nothing else we can do.
-							return Ok(());
+							return Ok(false);
 						}
 					};
 
+					kvs.query_counters().record_statement(statement_type, r.is_ok());
+
+					// The written records are taken even if the statement failed,
+					// so they are not attributed to the next statement
+					let written = txn.take_written_records();
+					if let Some(summary) = &mut summary
+						&& r.is_ok()
+					{
+						summary.extend(written.iter().cloned());
+					}
+					if let Some((read_only, statement)) = audited
+						&& let Some(event) = self.audit_event(written, read_only, statement)
+						&& r.is_ok()
+					{
+						audit_events.push(event);
+					}
+
 					match r {
 						Ok(value) => Ok(convert_value_to_public_value(value)?),
 						Err(err) => Err(TypesError::internal(err.to_string())),
@@ -1094,9 +1495,10 @@ impl Executor {
 			};
 
 			self.results.push(QueryResult {
-				time: before.elapsed(),
-				result,
-				query_type,
+				warnings: self.ctx.take_warnings(),
+				truncated: std::mem::take(&mut self.truncated),
+				metrics: self.metrics.take(),
+				..QueryResult::new(before.elapsed(), result, query_type)
 			});
 		}
 
@@ -1111,7 +1513,7 @@ impl Executor {
 
 		self.opt.broker = None;
 
-		Ok(())
+		Ok(false)
 	}
 
 	#[instrument(level = "debug", name = "executor", target = "surrealdb::core::dbs", skip_all)]
@@ -1122,7 +1524,30 @@ impl Executor {
 		plan: LogicalPlan,
 	) -> Result<Vec<QueryResult>> {
 		let stream = futures::stream::iter(plan.expressions.into_iter().map(Ok));
-		Self::execute_expr_stream(kvs, ctx, opt, false, stream).await
+		Self::execute_expr_stream(kvs, ctx, opt, false, stream, None).await
+	}
+
+	/// Execute a logical plan, sending the result of each top-level statement
+	/// to the channel as soon as it completes.
+	#[instrument(level = "debug", name = "executor", target = "surrealdb::core::dbs", skip_all)]
+	pub(crate) async fn execute_plan_streaming(
+		kvs: &Datastore,
+		ctx: FrozenContext,
+		opt: Options,
+		plan: LogicalPlan,
+		sender: async_channel::Sender<QueryResult>,
+	) -> Result<()> {
+		let stream = futures::stream::iter(plan.expressions.into_iter().map(Ok));
+		let rest =
+			Self::execute_expr_stream(kvs, ctx, opt, false, stream, Some(sender.clone())).await?;
+		// Send any results which were not flushed before returning
+		for res in rest {
+			if sender.send(res).await.is_err() {
+				debug!(target: TARGET, "Discarding query results, as the receiver was dropped");
+				break;
+			}
+		}
+		Ok(())
 	}
 
 	/// Execute a logical plan with an existing transaction
@@ -1145,25 +1570,31 @@ impl Executor {
 			let time = start.elapsed();
 			let query_result = match result {
 				Ok(value) | Err(ControlFlow::Return(value)) => QueryResult {
-					time,
-					result: crate::val::convert_value_to_public_value(value)
-						.map_err(|e| TypesError::internal(e.to_string())),
-					query_type: QueryType::Other,
-				},
-				Err(ControlFlow::Err(e)) => QueryResult {
-					time,
-					result: Err(types_error_from_anyhow(e)),
-					query_type: QueryType::Other,
+					truncated: std::mem::take(&mut executor.truncated),
+					metrics: executor.metrics.take(),
+					..QueryResult::new(
+						time,
+						crate::val::convert_value_to_public_value(value)
+							.map_err(|e| TypesError::internal(e.to_string())),
+						QueryType::Other,
+					)
 				},
-				Err(ControlFlow::Continue) | Err(ControlFlow::Break) => QueryResult {
+				Err(ControlFlow::Err(e)) => {
+					QueryResult::new(time, Err(types_error_from_anyhow(e)), QueryType::Other)
+				}
+				Err(ControlFlow::Continue) | Err(ControlFlow::Break) => QueryResult::new(
 					time,
-					result: Err(TypesError::internal("Invalid control flow".to_string())),
-					query_type: QueryType::Other,
-				},
+					Err(TypesError::internal("Invalid control flow".to_string())),
+					QueryType::Other,
+				),
 			};
 			results.push(query_result);
 		}
-
+		if let Some(max) = executor.opt.max_output_depth {
+			for res in results.iter_mut() {
+				res.limit_depth(max, executor.opt.output_depth_policy);
+			}
+		}
 		Ok(results)
 	}
 
@@ -1184,6 +1615,7 @@ impl Executor {
 			opt,
 			skip_success_results,
 			stream.map(|x| x.map(expr::TopLevelExpr::from)),
+			None,
 		)
 		.await
 	}
@@ -1195,11 +1627,13 @@ impl Executor {
 		opt: Options,
 		skip_success_results: bool,
 		stream: S,
+		sender: Option<async_channel::Sender<QueryResult>>,
 	) -> Result<Vec<QueryResult>>
 	where
 		S: Stream<Item = Result<TopLevelExpr>>,
 	{
 		let mut this = Executor::new(ctx, opt);
+		this.sender = sender;
 		let mut stream = pin!(stream);
 
 		if skip_success_results {
@@ -1227,17 +1661,26 @@ impl Executor {
 			}
 		}
 
-		while let Some(stmt) = stream.next().await {
+		// A statement which was read after a run of parallel statements
+		let mut pending = None;
+		loop {
+			let stmt = match pending.take() {
+				Some(stmt) => stmt,
+				None => match stream.next().await {
+					Some(stmt) => stmt,
+					None => break,
+				},
+			};
 			let stmt = match stmt {
 				Ok(x) => x,
 				Err(e) => {
-					this.results.push(QueryResult {
-						time: Duration::ZERO,
-						result: Err(TypesError::internal(e.to_string())),
-						query_type: QueryType::Other,
-					});
+					this.results.push(QueryResult::new(
+						Duration::ZERO,
+						Err(TypesError::internal(e.to_string())),
+						QueryType::Other,
+					));
 
-					return Ok(this.results);
+					return Ok(this.take_results());
 				}
 			};
 
@@ -1252,43 +1695,66 @@ impl Executor {
 					}
 					this.execute_option_statement(stmt)?;
 					if !skip_success_results {
-						this.results.push(QueryResult {
-							time: Duration::ZERO,
-							result: Ok(convert_value_to_public_value(Value::None)?),
-							query_type: QueryType::Other,
-						});
+						this.results.push(QueryResult::new(
+							Duration::ZERO,
+							Ok(convert_value_to_public_value(Value::None)?),
+							QueryType::Other,
+						));
 					}
 				}
 				TopLevelExpr::Begin => {
 					if !skip_success_results {
-						this.results.push(QueryResult {
-							time: Duration::ZERO,
-							result: Ok(convert_value_to_public_value(Value::None)?),
-							query_type: QueryType::Other,
-						});
+						this.results.push(QueryResult::new(
+							Duration::ZERO,
+							Ok(convert_value_to_public_value(Value::None)?),
+							QueryType::Other,
+						));
 					}
 
 					if let Err(e) = this.execute_begin_statement(kvs, stream.as_mut()).await {
-						this.results.push(QueryResult {
-							time: Duration::ZERO,
-							result: Err(types_error_from_anyhow(e)),
-							query_type: QueryType::Other,
-						});
+						this.results.push(QueryResult::new(
+							Duration::ZERO,
+							Err(types_error_from_anyhow(e)),
+							QueryType::Other,
+						));
 
-						return Ok(this.results);
+						return Ok(this.take_results());
 					}
 				}
+				stmt if this.opt.parallel && !skip_success_results && runs_in_parallel(&stmt) => {
+					// Read ahead to the end of the run of statements which can run together
+					let mut stmts = vec![stmt];
+					while let Some(next) = stream.next().await {
+						match next {
+							Ok(stmt) if runs_in_parallel(&stmt) => stmts.push(stmt),
+							next => {
+								pending = Some(next);
+								break;
+							}
+						}
+					}
+					this.execute_parallel_statements(kvs, stmts).await?;
+				}
 				stmt => {
 					let query_type: QueryType = QueryType::for_toplevel_expr(&stmt);
+					let statement_type = stmt.statement_type();
 
 					let now = Instant::now();
 					let result = this.execute_bare_statement(kvs, &now, stmt).await;
+					kvs.query_counters().record_statement(statement_type, result.is_ok());
+					let warnings = this.ctx.take_warnings();
+					let version = this.version.take();
+					let truncated = std::mem::take(&mut this.truncated);
+					let metrics = this.metrics.take();
 					if skip_success_results {
 						if let Err(err) = result {
 							this.results.push(QueryResult {
-								time: now.elapsed(),
-								result: Err(types_error_from_anyhow(err)),
-								query_type,
+								warnings,
+								..QueryResult::new(
+									now.elapsed(),
+									Err(types_error_from_anyhow(err)),
+									query_type,
+								)
 							});
 						}
 					} else {
@@ -1297,24 +1763,51 @@ impl Executor {
 							Err(err) => Err(types_error_from_anyhow(err)),
 						};
 						this.results.push(QueryResult {
-							time: now.elapsed(),
-							result,
-							query_type,
+							warnings,
+							version,
+							truncated,
+							metrics,
+							..QueryResult::new(now.elapsed(), result, query_type)
 						});
 					}
 				}
 			}
+			this.flush_results().await;
 			yield_now!();
 		}
-		Ok(this.results)
+		Ok(this.take_results())
+	}
+}
+
+/// Whether a statement can run at the same time as the statements around
+/// it, which is the case for read-only SELECT statements outside of a
+/// transaction block.
+fn runs_in_parallel(stmt: &TopLevelExpr) -> bool {
+	matches!(stmt, TopLevelExpr::Expr(Expr::Select(_))) && stmt.read_only()
+}
+
+/// Summarises the writes of a committed transaction, with the number of
+/// records written to each table, and whether anything was changed at all.
+fn commit_summary(written: BTreeSet<RecordId>, schema_written: bool) -> Value {
+	let mut tables = BTreeMap::<String, i64>::new();
+	for rid in &written {
+		*tables.entry(rid.table.as_str().to_owned()).or_default() += 1;
 	}
+	Value::Object(Object(map! {
+		"changed".to_string() => Value::Bool(schema_written || !written.is_empty()),
+		"records".to_string() => Value::from(written.len() as i64),
+		"tables".to_string() => Value::Object(
+			tables.into_iter().map(|(table, count)| (table, Value::from(count))).collect(),
+		),
+	}))
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::dbs::Session;
+	use crate::dbs::{NewPlannerStrategy, Session};
 	use crate::iam::{Level, Role};
 	use crate::kvs::Datastore;
+	use crate::types::{PublicValue, PublicVariables};
 
 	#[tokio::test]
 	async fn check_execute_option_permissions() {
@@ -1493,6 +1986,189 @@ mod tests {
 		}
 	}
 
+	#[tokio::test]
+	async fn check_option_warnings() {
+		for strategy in [NewPlannerStrategy::ComputeOnly, NewPlannerStrategy::AllReadOnlyStatements]
+		{
+			let ds = Datastore::new("memory").await.unwrap();
+			let mut ses = Session::owner().with_ns("test").with_db("test");
+			ses.new_planner_strategy = strategy.clone();
+			ds.execute(
+				"DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;
+				DEFINE INDEX name ON person FIELDS name;
+				CREATE person:1 SET name = 'Tobie', age = 33;",
+				&ses,
+				None,
+			)
+			.await
+			.unwrap();
+
+			let res = ds
+				.execute(
+					"SELECT * FROM person WHERE age = 33;
+					OPTION WARNINGS;
+					SELECT * FROM person WHERE age = 33;
+					SELECT * FROM person WHERE name = 'Tobie';
+					SELECT * FROM person;
+					SELECT * FROM person WITH NOINDEX WHERE age = 33;
+					OPTION WARNINGS = false;
+					SELECT * FROM person WHERE age = 33;",
+					&ses,
+					None,
+				)
+				.await
+				.unwrap();
+			assert_eq!(res.len(), 8, "{strategy:?}");
+			for (i, r) in res.iter().enumerate() {
+				assert!(r.result.is_ok(), "{strategy:?} statement {i}: {:?}", r.result);
+				if i == 2 {
+					assert_eq!(
+						r.warnings,
+						vec![
+							"The query on table `person` performed a full table scan as no index could be used for the WHERE condition"
+						],
+						"{strategy:?}"
+					);
+				} else {
+					assert!(r.warnings.is_empty(), "{strategy:?} statement {i}: {:?}", r.warnings);
+				}
+			}
+		}
+	}
+
+	#[tokio::test]
+	async fn check_return_version() {
+		let ds = Datastore::new("memory?versioned=true").await.unwrap();
+		let ses = Session::owner().with_ns("test").with_db("test").with_return_version(true);
+		// Writes return the version at which they can be read
+		let res = ds.execute("CREATE person:1 SET name = 'Tobie'", &ses, None).await.unwrap();
+		let created = res[0].version.expect("a version for the write");
+		// Transactions return the version on the COMMIT result
+		let res = ds
+			.execute("BEGIN; UPDATE person:1 SET name = 'Jaime'; COMMIT;", &ses, None)
+			.await
+			.unwrap();
+		assert_eq!(res.len(), 4);
+		assert!(res[2].version.is_none());
+		let updated = res[3].version.expect("a version for the commit");
+		assert!(updated > created);
+		// Reads do not return a version
+		let res = ds.execute("SELECT * FROM person", &ses, None).await.unwrap();
+		assert!(res[0].version.is_none());
+		// The returned versions can be used to read the writes back
+		for (version, name) in [(created, "Tobie"), (updated, "Jaime")] {
+			let vars = PublicVariables::from(map! {
+				"version".to_string() => PublicValue::Datetime(version),
+			});
+			let mut res = ds
+				.execute("SELECT VALUE name FROM person:1 VERSION $version", &ses, Some(vars))
+				.await
+				.unwrap();
+			let val = res.remove(0).result.unwrap();
+			assert_eq!(val, PublicValue::from_t(vec![name.to_string()]));
+		}
+		// Sessions which do not request it do not return a version
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let res = ds.execute("CREATE person:2", &ses, None).await.unwrap();
+		assert!(res[0].version.is_none());
+	}
+
+	#[tokio::test]
+	async fn check_commit_summary() {
+		let ds = Datastore::new("memory").await.unwrap();
+		let ses = Session::owner().with_ns("test").with_db("test").with_commit_summary(true);
+		// The COMMIT result summarises the writes of the transaction
+		let res = ds
+			.execute(
+				"BEGIN; CREATE person:1; RELATE person:1->knows->person:1; SELECT * FROM person; COMMIT;",
+				&ses,
+				None,
+			)
+			.await
+			.unwrap();
+		assert_eq!(res.len(), 5);
+		let summary = res[4].result.clone().unwrap();
+		let expected = ds
+			.execute("{ changed: true, records: 2, tables: { knows: 1, person: 1 } }", &ses, None)
+			.await
+			.unwrap()
+			.remove(0)
+			.result
+			.unwrap();
+		assert_eq!(summary, expected);
+		// A transaction which writes nothing is a no-op
+		let res = ds.execute("BEGIN; SELECT * FROM person; COMMIT;", &ses, None).await.unwrap();
+		let summary = res[2].result.clone().unwrap();
+		let expected = ds
+			.execute("{ changed: false, records: 0, tables: {} }", &ses, None)
+			.await
+			.unwrap()
+			.remove(0)
+			.result
+			.unwrap();
+		assert_eq!(summary, expected);
+		// Sessions which do not request it return NONE
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let res = ds.execute("BEGIN; CREATE person:2; COMMIT;", &ses, None).await.unwrap();
+		assert_eq!(res[2].result, Ok(PublicValue::None));
+	}
+
+	#[tokio::test]
+	async fn check_statement_metrics() {
+		let ds = Datastore::new("memory").await.unwrap();
+		let ses = Session::owner().with_ns("test").with_db("test");
+		ds.execute(
+			"FOR $i IN 1..=5 { CREATE type::record('person', $i) SET age = 30 };",
+			&ses,
+			None,
+		)
+		.await
+		.unwrap();
+		// Results carry no metrics unless the session enables them
+		let res = ds.execute("SELECT * FROM person", &ses, None).await.unwrap();
+		assert!(res[0].metrics.is_none());
+		// A full table scan reads every record of the table
+		let ses = ses.with_metrics(true);
+		let res = ds.execute("SELECT * FROM person WHERE age > 40", &ses, None).await.unwrap();
+		let metrics = res[0].metrics.unwrap();
+		assert_eq!(metrics.records_scanned, 5);
+		assert_eq!(metrics.records_returned, 0);
+		assert!(!metrics.index_used);
+		assert!(metrics.bytes_read > 0);
+		// Reading a single record scans only that record
+		let res = ds.execute("SELECT * FROM person:1", &ses, None).await.unwrap();
+		let metrics = res[0].metrics.unwrap();
+		assert_eq!(metrics.records_scanned, 1);
+		assert_eq!(metrics.records_returned, 1);
+	}
+
+	#[tokio::test]
+	async fn check_default_limit() {
+		let ds = Datastore::new("memory").await.unwrap();
+		let ses = Session::owner().with_ns("test").with_db("test").with_default_limit(Some(2));
+		ds.execute("CREATE |person:1..3|", &ses, None).await.unwrap();
+		// Selects without a LIMIT return at most the default, and are marked as truncated
+		let mut res = ds.execute("SELECT * FROM person", &ses, None).await.unwrap();
+		assert!(res[0].truncated);
+		let PublicValue::Array(rows) = res.remove(0).result.unwrap() else {
+			panic!("expected an array");
+		};
+		assert_eq!(rows.len(), 2);
+		// An explicit LIMIT overrides the default
+		let mut res = ds.execute("SELECT * FROM person LIMIT 3", &ses, None).await.unwrap();
+		assert!(!res[0].truncated);
+		let PublicValue::Array(rows) = res.remove(0).result.unwrap() else {
+			panic!("expected an array");
+		};
+		assert_eq!(rows.len(), 3);
+		// Results which fit within the default are not marked as truncated
+		let res = ds.execute("SELECT * FROM person WHERE id > person:1", &ses, None).await.unwrap();
+		assert!(!res[0].truncated);
+		// Selects within a transaction are capped too
+		let res = ds.execute("BEGIN; SELECT * FROM person; COMMIT;", &ses, None).await.unwrap();
+		assert_eq!(res.iter().filter(|r| r.truncated).count(), 1);
+	}
+
 	#[tokio::test]
 	async fn check_execute_timeout() {
 		// With small timeout
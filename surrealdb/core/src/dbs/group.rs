@@ -106,6 +106,9 @@ impl GroupCollector {
 					aggregation::Aggregation::DatetimeMax(x) => format!("DatetimeMax(expr{x})"),
 					aggregation::Aggregation::DatetimeMin(x) => format!("DatetimeMin(expr{x})"),
 					aggregation::Aggregation::Accumulate(x) => format!("Accumulate(expr{x})"),
+					aggregation::Aggregation::ApproxDistinct(x) => {
+						format!("ApproxDistinct(expr{x})")
+					}
 				};
 				(format!("_a{idx}"), Value::from(res))
 			})
@@ -5,6 +5,7 @@ use std::sync::Arc;
 use anyhow::{Result, bail, ensure};
 use reblessive::tree::Stk;
 use surrealdb_types::ToSql;
+use web_time::Instant;
 
 use crate::catalog::Record;
 use crate::catalog::providers::TableProvider;
@@ -26,7 +27,7 @@ use crate::idx::planner::{
 	GrantedPermission, IterationStage, QueryPlanner, RecordStrategy, ScanDirection,
 	StatementContext,
 };
-use crate::val::{RecordId, RecordIdKey, RecordIdKeyRange, TableName, Value};
+use crate::val::{Object, RecordId, RecordIdKey, RecordIdKeyRange, TableName, Value};
 
 const TARGET: &str = "surrealdb::core::dbs";
 
@@ -65,8 +66,9 @@ pub(crate) enum Iterable {
 	Range(NsDbTbCtx, TableName, RecordIdKeyRange, RecordStrategy, ScanDirection),
 	/// An iterable which fetches a record from storage, and
 	/// which has the specific value to update the record with.
-	/// This is used in INSERT statements, where each value
-	/// passed in to the iterable is unique for each record.
+	/// This is used in INSERT statements, and in UPDATE and
+	/// UPSERT statements with a MERGE EACH clause, where each
+	/// value passed in to the iterable is unique for each record.
 	/// This tuples takes in:
 	/// - The table name
 	/// - The optional id key. When none is provided, it will be generated at a later stage and no
@@ -158,6 +160,10 @@ pub(crate) struct Iterator {
 	/// - Otherwise, we must collect `start + limit` results so that the final START can be applied
 	///   in post-processing without starving the LIMIT.
 	cancel_threshold: Option<usize>,
+	/// Number of documents processed by the iterator
+	processed: usize,
+	/// Number of documents accepted into the results
+	accepted: usize,
 }
 
 impl Clone for Iterator {
@@ -173,6 +179,8 @@ impl Clone for Iterator {
 			guaranteed: None,
 			cancel_on_limit: None,
 			cancel_threshold: None,
+			processed: 0,
+			accepted: 0,
 		}
 	}
 }
@@ -389,6 +397,117 @@ impl Iterator {
 		Ok(())
 	}
 
+	/// Prepares the records of a MERGE EACH clause for processing
+	///
+	/// The changes must be an object keyed by record id, where each
+	/// record id belongs to one of the target tables, and where each
+	/// value is the data which is merged into that specific record.
+	#[allow(clippy::too_many_arguments)]
+	pub(crate) async fn prepare_merge_each(
+		&mut self,
+		stk: &mut Stk,
+		ctx: &FrozenContext,
+		opt: &Options,
+		doc: Option<&CursorDoc>,
+		planner: &mut QueryPlanner,
+		stm_ctx: &StatementContext<'_>,
+		doc_ctx: &NsDbCtx,
+		what: &[Expr],
+		changes: &Expr,
+	) -> Result<()> {
+		// Each of the targets must be a table
+		let mut tables = Vec::with_capacity(what.len());
+		for w in what.iter() {
+			match stk.run(|stk| w.compute(stk, ctx, opt, doc)).await.catch_return()? {
+				Value::Table(tb) => tables.push(tb),
+				v => bail!(Error::InvalidStatementTarget {
+					value: v.to_sql(),
+				}),
+			}
+		}
+		// The changes must be keyed by record id
+		let changes =
+			match stk.run(|stk| changes.compute(stk, ctx, opt, doc)).await.catch_return()? {
+				Value::Object(v) => v,
+				v => bail!(Error::InvalidMergeEach {
+					value: v.to_sql(),
+				}),
+			};
+		for (key, value) in changes {
+			let rid: RecordId = match crate::syn::record_id(&key) {
+				Ok(rid) => rid.into(),
+				Err(_) => bail!(Error::InvalidMergeEachKey {
+					key,
+				}),
+			};
+			ensure!(
+				tables.contains(&rid.table),
+				Error::InvalidMergeEachKey {
+					key,
+				}
+			);
+			self.prepare_mergeable(ctx, opt, planner, stm_ctx, doc_ctx, rid, value).await?;
+		}
+		// All ingested ok
+		Ok(())
+	}
+
+	/// Prepares a RecordId with its own data for processing
+	#[allow(clippy::too_many_arguments)]
+	async fn prepare_mergeable(
+		&mut self,
+		ctx: &FrozenContext,
+		opt: &Options,
+		planner: &mut QueryPlanner,
+		stm_ctx: &StatementContext<'_>,
+		doc_ctx: &NsDbCtx,
+		rid: RecordId,
+		value: Value,
+	) -> Result<()> {
+		let tb = if stm_ctx.stm.requires_table_existence() {
+			ctx.tx()
+				.get_tb(doc_ctx.ns.namespace_id, doc_ctx.db.database_id, &rid.table, opt.version)
+				.await?
+				.ok_or_else(|| {
+					anyhow::anyhow!(Error::TbNotFound {
+						name: rid.table.clone(),
+					})
+				})?
+		} else {
+			ctx.tx()
+				.get_or_add_tb(
+					Some(ctx),
+					&doc_ctx.ns.name,
+					&doc_ctx.db.name,
+					&rid.table,
+					opt.version,
+				)
+				.await?
+		};
+		let fields = ctx
+			.tx()
+			.all_tb_fields(doc_ctx.ns.namespace_id, doc_ctx.db.database_id, &rid.table, opt.version)
+			.await?;
+
+		let doc_ctx = NsDbTbCtx {
+			ns: Arc::clone(&doc_ctx.ns),
+			db: Arc::clone(&doc_ctx.db),
+			tb,
+			fields,
+		};
+		// We add the iterable only if we have a permission
+		if matches!(
+			planner.check_table_permission(stm_ctx, &rid.table).await?,
+			GrantedPermission::None
+		) {
+			return Ok(());
+		}
+		// Add the record to the iterator
+		self.ingest(Iterable::Mergeable(doc_ctx, rid.table, Some(rid.key), value));
+		// All ingested ok
+		Ok(())
+	}
+
 	/// Prepares a value for processing
 	pub(crate) async fn prepare_mock(
 		&mut self,
@@ -712,6 +831,9 @@ impl Iterator {
 		// Enable context override
 		let mut cancel_ctx = Context::new_child(ctx);
 		self.canceller = cancel_ctx.add_cancel();
+		// Stop iterating once any SCAN TIMEOUT has passed, while still
+		// processing the records which were already gathered
+		stm.setup_scan_timeout(stk, ctx, opt, &mut cancel_ctx).await?;
 		let mut cancel_ctx = cancel_ctx.freeze();
 		// Process the query LIMIT clause
 		self.setup_limit(stk, &cancel_ctx, opt, stm).await?;
@@ -1070,7 +1192,23 @@ impl Iterator {
 		if let Results::Groups(g) = &mut self.results {
 			// Get the grouped values from the collector
 			let mut collector = g.output(stk, ctx, opt).await?;
-			let values = collector.take_vec();
+			let mut values = collector.take_vec();
+			// Filter the grouped values by any HAVING clause
+			if let Some(having) = stm.having() {
+				let mut filtered = Vec::with_capacity(values.len());
+				for val in values {
+					let doc = CursorDoc::new(None, None, val);
+					if stk
+						.run(|stk| having.0.compute(stk, ctx, opt, Some(&doc)))
+						.await
+						.catch_return()?
+						.is_truthy()
+					{
+						filtered.push(doc.doc.into_owned());
+					}
+				}
+				values = filtered;
+			}
 
 			// Create the appropriate Results variant based on ORDER BY clause
 			self.results = if let Some(ordering) = stm.order() {
@@ -1155,11 +1293,11 @@ impl Iterator {
 		opt: &Options,
 		stm: &Statement<'_>,
 		is_specific_permission: bool,
-		exp: Option<&mut Explanation>,
+		mut exp: Option<&mut Explanation>,
 	) -> Result<()> {
 		// Compute iteration limits
 		self.compute_start_limit(ctx, stm, is_specific_permission);
-		if let Some(e) = exp
+		if let Some(e) = exp.as_deref_mut()
 			&& (self.start_skip.is_some() || self.cancel_on_limit.is_some())
 		{
 			e.add_start_limit(self.start_skip, self.cancel_on_limit);
@@ -1169,9 +1307,17 @@ impl Iterator {
 		// If any iterator requires distinct, we need to create a global distinct
 		// instance
 		let mut distinct = SyncDistinct::new(ctx);
+		// EXPLAIN ANALYZE reports what each iterable actually produced
+		let analyze = matches!(stm.explain(), Some(expr::Explain::Analyze));
 		// Process all prepared values
 		for (count, v) in mem::take(&mut self.entries).into_iter().enumerate() {
+			let (processed, accepted, now) = (self.processed, self.accepted, Instant::now());
 			v.iterate(stk, ctx, &opt, stm, self, distinct.as_mut()).await?;
+			// Record what this iterable actually produced
+			if analyze && let Some(e) = exp.as_deref_mut() {
+				let elapsed = (!ctx.redact_volatile_explain_attrs()).then(|| now.elapsed());
+				e.add_actual(count, self.processed - processed, self.accepted - accepted, elapsed);
+			}
 			// MOCK can create a large collection of iterators,
 			// we need to make space for possible cancellations
 			if ctx.is_done(Some(count)).await? {
@@ -1193,8 +1339,32 @@ impl Iterator {
 		pro: Processable,
 	) -> Result<()> {
 		let rs = pro.record_strategy;
+		let rid = pro.rid.clone();
+		// The writes of a record which fails are undone, so that it can be
+		// returned as an error while the other records are written
+		let save_point = opt.record_errors && !stm.is_select();
+		if save_point {
+			ctx.tx().new_save_point().await?;
+		}
+		// Count the processed document
+		self.processed += 1;
 		// Extract the value
 		let res = Self::extract_value(stk, ctx, opt, stm, pro).await;
+		// Return the error for this record, if only the record failed
+		let res = match res {
+			Err(IgnoreError::Error(e)) if opt.record_errors => {
+				if save_point {
+					ctx.tx().rollback_to_save_point().await?;
+				}
+				Self::record_error(rid, e)
+			}
+			res => {
+				if save_point {
+					ctx.tx().release_last_save_point().await?;
+				}
+				res
+			}
+		};
 		// Process the result
 		self.result(stk, ctx, opt, rs, res).await;
 		// Everything ok
@@ -1223,6 +1393,19 @@ impl Iterator {
 		stk.run(|stk| Document::process(stk, ctx, opt, stm, pro)).await
 	}
 
+	/// Converts an error which only affects a single record into an object
+	/// with the id of the record and the error, so that the other records are
+	/// still processed.
+	fn record_error(rid: Option<Arc<RecordId>>, e: anyhow::Error) -> Result<Value, IgnoreError> {
+		let Some(err) = e.downcast_ref::<Error>().filter(|err| err.is_record_error()) else {
+			return Err(IgnoreError::Error(e));
+		};
+		Ok(Value::Object(Object(map! {
+			"id".to_string() => rid.map(|rid| Value::RecordId(rid.as_ref().clone())).unwrap_or_default(),
+			"error".to_string() => Value::String(err.to_string()),
+		})))
+	}
+
 	/// Accept a processed record result
 	async fn result(
 		&mut self,
@@ -1250,6 +1433,7 @@ impl Iterator {
 					self.canceller.cancel();
 					return;
 				}
+				self.accepted += 1;
 			}
 		}
 		// Check if we have collected enough accepted results to stop.
@@ -26,9 +26,10 @@ pub(crate) use variables::{ParameterCapturePass, Variables};
 pub use self::capabilities::Capabilities;
 pub(crate) use self::executor::Executor;
 pub(crate) use self::iterator::{Iterable, Iterator, Operable, Processable, Workable};
-pub(crate) use self::options::{Force, MessageBroker, Options};
-pub use self::response::{QueryResult, QueryResultBuilder, QueryType, Status};
-pub use self::session::{NewPlannerStrategy, Session};
+pub(crate) use self::options::{DivByZero, Force, MessageBroker, Options};
+pub use self::plan::{ExplainPlan, ExplainStep};
+pub use self::response::{QueryMetrics, QueryResult, QueryResultBuilder, QueryType, Status};
+pub use self::session::{NewPlannerStrategy, NullCoercion, OutputDepthPolicy, Session};
 pub(crate) use self::statement::Statement;
 
 #[cfg(storage)]
@@ -9,6 +9,7 @@ use crate::catalog;
 use crate::catalog::SubscriptionDefinition;
 use crate::cnf::MAX_COMPUTATION_DEPTH;
 use crate::cnf::dynamic::DynamicConfiguration;
+use crate::dbs::OutputDepthPolicy;
 use crate::err::Error;
 use crate::expr::Base;
 use crate::iam::{Action, Auth, ResourceKind};
@@ -44,6 +45,28 @@ pub struct Options {
 	pub(crate) perms: bool,
 	/// Should we process field queries?
 	pub(crate) import: bool,
+	/// How should division or remainder by zero be handled?
+	pub(crate) div_by_zero: DivByZero,
+	/// Should schemaless tables reject undefined fields?
+	pub(crate) strict: bool,
+	/// Should records which fail be returned as errors instead of failing?
+	pub(crate) record_errors: bool,
+	/// Should independent read-only statements run concurrently?
+	pub(crate) parallel: bool,
+	/// Should statements run in read-only transactions?
+	pub(crate) read_only: bool,
+	/// Should the read version be returned after a write commits?
+	pub(crate) return_version: bool,
+	/// Should COMMIT return a summary of the writes of the transaction?
+	pub(crate) commit_summary: bool,
+	/// Should each statement return metrics of the reads it made?
+	pub(crate) metrics: bool,
+	/// The maximum nesting depth of returned values
+	pub(crate) max_output_depth: Option<u32>,
+	/// What happens when a returned value is nested too deeply?
+	pub(crate) output_depth_policy: OutputDepthPolicy,
+	/// The LIMIT of top-level SELECT statements without one
+	pub(crate) default_limit: Option<u32>,
 	/// The data version as a timestamp
 	pub(crate) version: Option<u64>,
 	/// Optional message broker for live notifications
@@ -61,6 +84,20 @@ pub enum Force {
 	Table(Arc<[catalog::TableDefinition]>),
 }
 
+/// The result of a division or remainder by zero, as set with
+/// `OPTION DIV_BY_ZERO`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum DivByZero {
+	/// Return an error
+	#[default]
+	Error,
+	/// Return NONE
+	None,
+	/// Return the IEEE 754 floating point result, which is an infinity for
+	/// division, and NaN for remainders or zero divided by zero
+	Infinity,
+}
+
 /// Trait for a pluggable message broker used to forward live query events across nodes.
 /// Default implementation can be a no-op. Implementations should be cheap to clone behind Arc.
 pub trait MessageBroker: Send + Sync + Debug {
@@ -85,6 +122,17 @@ impl Options {
 			perms: true,
 			force: Force::None,
 			import: false,
+			div_by_zero: DivByZero::Error,
+			strict: false,
+			record_errors: false,
+			parallel: false,
+			read_only: false,
+			return_version: false,
+			commit_summary: false,
+			metrics: false,
+			max_output_depth: None,
+			output_depth_policy: OutputDepthPolicy::Truncate,
+			default_limit: None,
 			auth_enabled: true,
 			broker: None,
 			auth: Arc::new(Auth::default()),
@@ -166,6 +214,74 @@ impl Options {
 		self.import = import;
 	}
 
+	/// Specify how division or remainder by zero should be handled
+	pub fn set_div_by_zero(&mut self, div_by_zero: DivByZero) {
+		self.div_by_zero = div_by_zero;
+	}
+
+	/// Specify if all tables should be treated as schemafull
+	pub fn set_strict(&mut self, strict: bool) {
+		self.strict = strict;
+	}
+
+	/// Specify if records which fail should be returned as errors, instead
+	/// of failing the statement
+	pub fn set_record_errors(&mut self, record_errors: bool) {
+		self.record_errors = record_errors;
+	}
+
+	/// Specify if consecutive read-only SELECT statements should run
+	/// concurrently
+	pub fn set_parallel(&mut self, parallel: bool) {
+		self.parallel = parallel;
+	}
+
+	/// Specify if statements should run in read-only
+	/// transactions, so that any write is rejected
+	pub fn set_read_only(&mut self, read_only: bool) {
+		self.read_only = read_only;
+	}
+
+	/// Specify whether the read version should be returned
+	/// after a write commits, with chaining.
+	pub fn with_return_version(mut self, return_version: bool) -> Self {
+		self.return_version = return_version;
+		self
+	}
+
+	/// Specify whether COMMIT should return a summary of the writes of
+	/// the transaction, with chaining.
+	pub fn with_commit_summary(mut self, commit_summary: bool) -> Self {
+		self.commit_summary = commit_summary;
+		self
+	}
+
+	/// Specify whether each statement should return metrics of the reads
+	/// it made, with chaining.
+	pub fn with_metrics(mut self, metrics: bool) -> Self {
+		self.metrics = metrics;
+		self
+	}
+
+	/// Specify the maximum nesting depth of returned values, and what
+	/// happens when it is exceeded, with chaining.
+	pub fn with_max_output_depth(
+		mut self,
+		max_output_depth: Option<u32>,
+		policy: OutputDepthPolicy,
+	) -> Self {
+		self.max_output_depth = max_output_depth;
+		self.output_depth_policy = policy;
+		self
+	}
+
+	/// Specify the LIMIT of top-level SELECT statements without one, with
+	/// chaining.
+	pub fn with_default_limit(mut self, default_limit: Option<u32>) -> Self {
+		self.default_limit = default_limit;
+		self
+	}
+
 	/// Create a new Options object with auth enabled
 	pub fn with_auth_enabled(mut self, auth_enabled: bool) -> Self {
 		self.auth_enabled = auth_enabled;
@@ -1,10 +1,14 @@
 use std::collections::HashMap;
+use std::time::Duration;
+
+use surrealdb_types::{Error as TypesError, SerializationError};
 
 use crate::ctx::FrozenContext;
 use crate::dbs::result::Results;
 use crate::dbs::{Iterable, Statement};
 use crate::expr::lookup::LookupKind;
 use crate::idx::planner::RecordStrategy;
+use crate::types::{PublicObject, PublicValue};
 use crate::val::{Object, RecordId, Value};
 
 pub(super) struct Plan {
@@ -32,7 +36,7 @@ impl Plan {
 					}
 				}
 				results.explain(&mut exp);
-				(e.0, Some(exp))
+				(e.executes(), Some(exp))
 			}
 		};
 		Self {
@@ -50,6 +54,21 @@ impl Explanation {
 		self.0.push(ExplainItem::new_iter(ctx, iter));
 	}
 
+	/// Annotates an iterable with the number of documents it actually
+	/// scanned and returned during execution. The items are ordered by
+	/// iterable, so the index matches the position of the iterable.
+	pub(super) fn add_actual(
+		&mut self,
+		index: usize,
+		scanned: usize,
+		returned: usize,
+		elapsed: Option<Duration>,
+	) {
+		if let Some(item) = self.0.get_mut(index) {
+			item.add_actual(scanned, returned, elapsed);
+		}
+	}
+
 	pub(super) fn add_fetch(&mut self, count: usize) {
 		self.0.push(ExplainItem::new_fetch(count));
 	}
@@ -88,6 +107,16 @@ struct ExplainItem {
 }
 
 impl ExplainItem {
+	fn add_actual(&mut self, scanned: usize, returned: usize, elapsed: Option<Duration>) {
+		let mut actual = Object::default();
+		actual.insert("scanned".to_string(), scanned.into());
+		actual.insert("returned".to_string(), returned.into());
+		if let Some(elapsed) = elapsed {
+			actual.insert("elapsed".to_string(), elapsed.into());
+		}
+		self.details.push(("actual", actual.into()));
+	}
+
 	fn new_fetch(count: usize) -> Self {
 		Self {
 			name: "Fetch".into(),
@@ -259,3 +288,127 @@ impl From<ExplainItem> for Value {
 		Value::from(explain)
 	}
 }
+
+/// The plan for a SELECT statement, as returned by
+/// [`Datastore::explain`](crate::kvs::Datastore::explain).
+///
+/// This is the typed form of the rows returned by `SELECT ... EXPLAIN`,
+/// with one step for each row.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExplainPlan {
+	/// The steps of the plan, in the order they are reported
+	pub steps: Vec<ExplainStep>,
+}
+
+/// A single step of an [`ExplainPlan`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExplainStep {
+	/// The operation, such as `Iterate Index`, `Collector`, or `Fetch`
+	pub operation: String,
+	/// The details of the operation, keyed by name
+	pub detail: PublicObject,
+}
+
+impl ExplainStep {
+	/// Whether this step iterates over a source of records
+	pub fn is_iterator(&self) -> bool {
+		self.operation.starts_with("Iterate")
+	}
+
+	/// The table iterated over by this step, if any
+	pub fn table(&self) -> Option<&str> {
+		match self.detail.get("table") {
+			Some(PublicValue::String(tb)) => Some(tb),
+			_ => None,
+		}
+	}
+
+	/// The name of the index iterated over by this step, if any
+	pub fn index(&self) -> Option<&str> {
+		match self.detail.get("plan") {
+			Some(PublicValue::Object(plan)) => match plan.get("index") {
+				Some(PublicValue::String(ix)) => Some(ix),
+				_ => None,
+			},
+			_ => None,
+		}
+	}
+
+	/// The type detail of this step, as reported for collectors and record
+	/// strategies
+	fn kind(&self) -> Option<&str> {
+		match self.detail.get("type") {
+			Some(PublicValue::String(t)) => Some(t),
+			_ => None,
+		}
+	}
+}
+
+impl ExplainPlan {
+	/// The steps which iterate over a source of records
+	pub fn iterators(&self) -> impl Iterator<Item = &ExplainStep> {
+		self.steps.iter().filter(|s| s.is_iterator())
+	}
+
+	/// The names of the indexes used by the plan
+	pub fn indexes(&self) -> Vec<&str> {
+		self.iterators().filter_map(ExplainStep::index).collect()
+	}
+
+	/// The strategy used to read records, such as `KeysAndValues` or `Count`
+	pub fn record_strategy(&self) -> Option<&str> {
+		self.steps.iter().find(|s| s.operation == "RecordStrategy").and_then(ExplainStep::kind)
+	}
+
+	/// The collector used to gather the results, such as `Memory`
+	pub fn collector(&self) -> Option<&str> {
+		self.steps.iter().find(|s| s.operation == "Collector").and_then(ExplainStep::kind)
+	}
+
+	/// The reasons reported by the query planner for not using an index
+	pub fn fallbacks(&self) -> Vec<&str> {
+		self.steps
+			.iter()
+			.filter(|s| s.operation == "Fallback")
+			.filter_map(|s| match s.detail.get("reason") {
+				Some(PublicValue::String(r)) => Some(r.as_str()),
+				_ => None,
+			})
+			.collect()
+	}
+}
+
+impl TryFrom<PublicValue> for ExplainPlan {
+	type Error = TypesError;
+
+	fn try_from(value: PublicValue) -> Result<Self, Self::Error> {
+		let invalid = |msg: &str| {
+			TypesError::serialization(msg.to_string(), SerializationError::Deserialization)
+		};
+		let PublicValue::Array(rows) = value else {
+			return Err(invalid("Expected an array of steps for the plan"));
+		};
+		let steps = rows
+			.into_iter()
+			.map(|row| {
+				let PublicValue::Object(mut row) = row else {
+					return Err(invalid("Expected each step of the plan to be an object"));
+				};
+				let Some(PublicValue::String(operation)) = row.remove("operation") else {
+					return Err(invalid("Missing operation for a step of the plan"));
+				};
+				let detail = match row.remove("detail") {
+					Some(PublicValue::Object(detail)) => detail,
+					_ => PublicObject::new(),
+				};
+				Ok(ExplainStep {
+					operation,
+					detail,
+				})
+			})
+			.collect::<Result<_, _>>()?;
+		Ok(ExplainPlan {
+			steps,
+		})
+	}
+}
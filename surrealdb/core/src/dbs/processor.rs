@@ -821,6 +821,7 @@ pub(super) trait Collector {
 				count += 1;
 			}
 		}
+		txn.record_scanned(count);
 		// Everything ok
 		Ok(())
 	}
@@ -957,6 +958,7 @@ pub(super) trait Collector {
 				count += 1;
 			}
 		}
+		txn.record_scanned(count);
 		// Everything ok
 		Ok(())
 	}
@@ -1127,6 +1129,7 @@ pub(super) trait Collector {
 		};
 
 		let txn = ctx.tx();
+		txn.record_index_used();
 		match rs {
 			RecordStrategy::Count => {
 				self.collect_index_item_count(ctx, &txn, doc_ctx, iterator).await?
@@ -1,13 +1,17 @@
+use std::collections::BTreeMap;
 use std::fmt;
 use std::time::Duration;
 
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use surrealdb_types::{
-	Error as TypesError, ErrorDetails, Kind, SerializationError, SurrealValue, Value, kind, object,
+	Datetime, Error as TypesError, ErrorDetails, Kind, SerializationError, SurrealValue, Value,
+	kind, object,
 };
 use web_time::Instant;
 
+use crate::dbs::OutputDepthPolicy;
+use crate::err::{Error, into_types_error};
 use crate::expr::TopLevelExpr;
 
 #[revisioned(revision = 1)]
@@ -60,6 +64,22 @@ impl QueryType {
 	}
 }
 
+/// The reads made by a statement, returned when the session enables
+/// `metrics`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, SurrealValue)]
+#[surreal(crate = "surrealdb_types")]
+#[non_exhaustive]
+pub struct QueryMetrics {
+	/// The number of records read from the datastore
+	pub records_scanned: u64,
+	/// The number of records returned by the statement
+	pub records_returned: u64,
+	/// Whether the statement read any records through an index
+	pub index_used: bool,
+	/// The number of bytes read from the datastore
+	pub bytes_read: u64,
+}
+
 /// The return value when running a query set on the database.
 #[derive(Debug, Clone)]
 pub struct QueryResult {
@@ -68,13 +88,101 @@ pub struct QueryResult {
 	// Record the query type in case processing the response is necessary (such as tracking live
 	// queries).
 	pub query_type: QueryType,
+	// Non-fatal warnings raised while executing the query, collected when
+	// `OPTION WARNINGS` is enabled.
+	pub warnings: Vec<String>,
+	// A version at which the committed writes of the query can be read back,
+	// returned when the session enables `return_version`. This is the
+	// wall-clock time read after the commit, not the commit version itself.
+	pub version: Option<Datetime>,
+	// Whether rows were left out of the result by the default LIMIT of the
+	// session, set with `default_limit`.
+	pub truncated: bool,
+	// The reads made by the statement, returned when the session enables
+	// `metrics`.
+	pub metrics: Option<QueryMetrics>,
 }
 
 impl QueryResult {
+	/// Create a result without warnings, version, truncation, or metrics
+	pub(crate) fn new(
+		time: Duration,
+		result: Result<Value, TypesError>,
+		query_type: QueryType,
+	) -> Self {
+		Self {
+			time,
+			result,
+			query_type,
+			warnings: Vec::new(),
+			version: None,
+			truncated: false,
+			metrics: None,
+		}
+	}
+
 	/// Retrieve the response as a normal result
 	pub fn output(self) -> Result<Value, TypesError> {
 		self.result
 	}
+
+	/// Limits the number of nested arrays, objects, and sets in the result
+	/// to the maximum output depth of the session.
+	pub(crate) fn limit_depth(&mut self, max: u32, policy: OutputDepthPolicy) {
+		if let Ok(value) = &mut self.result {
+			match limit_value_depth(std::mem::take(value), max, policy) {
+				Some(v) => *value = v,
+				None => {
+					self.result = Err(into_types_error(Error::OutputDepthExceeded {
+						max,
+					}))
+				}
+			}
+		}
+	}
+}
+
+/// Walks the value, allowing `remaining` further levels of nesting.
+///
+/// A collection found once no levels remain is replaced with the id of the
+/// record it holds, or NONE otherwise. Returns `None` instead when the policy
+/// is to error.
+fn limit_value_depth(value: Value, remaining: u32, policy: OutputDepthPolicy) -> Option<Value> {
+	if !matches!(value, Value::Array(_) | Value::Object(_) | Value::Set(_)) {
+		return Some(value);
+	}
+	if remaining == 0 {
+		return match (policy, value) {
+			(OutputDepthPolicy::Error, _) => None,
+			(OutputDepthPolicy::Truncate, Value::Object(obj)) => match obj.get("id") {
+				Some(Value::RecordId(id)) => Some(Value::RecordId(id.clone())),
+				_ => Some(Value::None),
+			},
+			(OutputDepthPolicy::Truncate, _) => Some(Value::None),
+		};
+	}
+	let remaining = remaining - 1;
+	Some(match value {
+		Value::Array(arr) => Value::Array(
+			arr.into_iter()
+				.map(|v| limit_value_depth(v, remaining, policy))
+				.collect::<Option<Vec<_>>>()?
+				.into(),
+		),
+		Value::Object(obj) => Value::Object(
+			obj.into_iter()
+				.map(|(k, v)| Some((k, limit_value_depth(v, remaining, policy)?)))
+				.collect::<Option<BTreeMap<_, _>>>()?
+				.into(),
+		),
+		Value::Set(set) => Value::Set(
+			set.into_iter()
+				.map(|v| limit_value_depth(v, remaining, policy))
+				.collect::<Option<Vec<_>>>()?
+				.into(),
+		),
+		v => v,
+	})
 }
 
 /// Serialise this error into the query-result wire shape: `result` (message string), optional
@@ -128,6 +236,11 @@ impl SurrealValue for QueryResult {
 				time: string,
 				result: any,
 				query_type: (QueryType::kind_of()),
+				warnings: none | array<string>,
+				version: none | datetime,
+				truncated: none | bool,
+				truncated: false,
+				metrics: none | (QueryMetrics::kind_of()),
 			} | {
 				status: "ERR",
 				time: string,
@@ -135,6 +248,11 @@ impl SurrealValue for QueryResult {
 				kind: string,
 				details: any,
 				query_type: (QueryType::kind_of()),
+				warnings: none | array<string>,
+				version: none | datetime,
+				truncated: none | bool,
+				truncated: false,
+				metrics: none | (QueryMetrics::kind_of()),
 			}
 		)
 	}
@@ -154,6 +272,18 @@ impl SurrealValue for QueryResult {
 			time: format!("{:?}", self.time).into_value(),
 			type: self.query_type.into_value(),
 		};
+		if !self.warnings.is_empty() {
+			map.insert("warnings", self.warnings.into_value());
+		}
+		if let Some(version) = self.version {
+			map.insert("version", version.into_value());
+		}
+		if self.truncated {
+			map.insert("truncated", Value::Bool(true));
+		}
+		if let Some(metrics) = self.metrics {
+			map.insert("metrics", metrics.into_value());
+		}
 		match self.result {
 			Ok(v) => {
 				map.insert("result", v);
@@ -201,6 +331,12 @@ impl SurrealValue for QueryResult {
 		let status = Status::from_value(status)?;
 		let query_type =
 			map.remove("type").map(QueryType::from_value).transpose()?.unwrap_or_default();
+		let warnings =
+			map.remove("warnings").map(Vec::<String>::from_value).transpose()?.unwrap_or_default();
+		let version = map.remove("version").map(Datetime::from_value).transpose()?;
+		let truncated =
+			map.remove("truncated").map(bool::from_value).transpose()?.unwrap_or_default();
+		let metrics = map.remove("metrics").map(QueryMetrics::from_value).transpose()?;
 
 		let time = humantime::parse_duration(&time.into_string().map_err(|e| {
 			TypesError::serialization(e.to_string(), SerializationError::Deserialization)
@@ -223,6 +359,10 @@ impl SurrealValue for QueryResult {
 			time,
 			result,
 			query_type,
+			warnings,
+			version,
+			truncated,
+			metrics,
 		})
 	}
 }
@@ -243,11 +383,7 @@ impl QueryResultBuilder {
 	}
 
 	pub fn instant_none() -> QueryResult {
-		QueryResult {
-			time: Duration::ZERO,
-			result: Ok(Value::None),
-			query_type: QueryType::Other,
-		}
+		QueryResult::new(Duration::ZERO, Ok(Value::None), QueryType::Other)
 	}
 
 	pub fn with_result(mut self, result: Result<Value, TypesError>) -> Self {
@@ -261,19 +397,11 @@ impl QueryResultBuilder {
 	}
 
 	pub fn finish(self) -> QueryResult {
-		QueryResult {
-			time: self.start_time.elapsed(),
-			result: self.result,
-			query_type: self.query_type,
-		}
+		QueryResult::new(self.start_time.elapsed(), self.result, self.query_type)
 	}
 
 	pub fn finish_with_result(self, result: Result<Value, TypesError>) -> QueryResult {
-		QueryResult {
-			time: self.start_time.elapsed(),
-			result,
-			query_type: self.query_type,
-		}
+		QueryResult::new(self.start_time.elapsed(), result, self.query_type)
 	}
 }
 
@@ -337,9 +465,45 @@ mod tests {
 			time: Duration::from_millis(42),
 			result: Err(error),
 			query_type: QueryType::Other,
+			warnings: Vec::new(),
+			version: None,
+			truncated: false,
+			metrics: None,
 		}
 	}
 
+	fn nested(depth: usize) -> Value {
+		(0..depth).fold(Value::from_t(1), |v, _| Value::Array(vec![v].into()))
+	}
+
+	#[test]
+	fn query_result_limit_depth() {
+		let ok = |value| QueryResult {
+			result: Ok(value),
+			..error_query_result(TypesError::internal(String::new()))
+		};
+		// Values within the maximum depth are unchanged
+		let mut qr = ok(nested(3));
+		qr.limit_depth(3, OutputDepthPolicy::Truncate);
+		assert_eq!(qr.result.unwrap(), nested(3));
+		// Collections at the maximum depth are truncated
+		let mut qr = ok(nested(5));
+		qr.limit_depth(3, OutputDepthPolicy::Truncate);
+		assert_eq!(
+			qr.result.unwrap(),
+			Value::Array(
+				vec![Value::Array(vec![Value::Array(vec![Value::None].into())].into())].into()
+			)
+		);
+		// Or return an error with the error policy
+		let mut qr = ok(nested(5));
+		qr.limit_depth(3, OutputDepthPolicy::Error);
+		assert_eq!(
+			qr.result.unwrap_err().message(),
+			"The result is nested more than 3 levels deep, which exceeds the maximum output depth"
+		);
+	}
+
 	/// Verify that the `kind` field in a serialized error QueryResult is NOT
 	/// duplicated inside `details`. This was a bug where `into_query_result_value`
 	/// manually added `kind` and then also included it inside `details` via
@@ -440,6 +604,10 @@ mod tests {
 			time: Duration::from_millis(10),
 			result: Ok(Value::String("hello".into())),
 			query_type: QueryType::Other,
+			warnings: Vec::new(),
+			version: None,
+			truncated: false,
+			metrics: None,
 		};
 		let val = qr.into_value();
 		let parsed = QueryResult::from_value(val).expect("round-trip should succeed");
@@ -447,4 +615,109 @@ mod tests {
 		let v = parsed.result.unwrap();
 		assert_eq!(v, Value::String("hello".into()));
 	}
+
+	#[test]
+	fn query_result_warnings_round_trip() {
+		let qr = QueryResult {
+			time: Duration::from_millis(10),
+			result: Ok(Value::None),
+			query_type: QueryType::Other,
+			warnings: vec!["Something looked off".to_string()],
+			version: None,
+			truncated: false,
+			metrics: None,
+		};
+		let val = qr.into_value();
+		let Value::Object(ref obj) = val else {
+			panic!("Expected object");
+		};
+		assert_eq!(
+			obj.get("warnings"),
+			Some(&Value::Array(vec![Value::String("Something looked off".into())].into()))
+		);
+		let parsed = QueryResult::from_value(val).expect("round-trip should succeed");
+		assert_eq!(parsed.warnings, vec!["Something looked off".to_string()]);
+
+		// Results without warnings keep the existing wire shape
+		let qr = QueryResult {
+			time: Duration::from_millis(10),
+			result: Ok(Value::None),
+			query_type: QueryType::Other,
+			warnings: Vec::new(),
+			version: None,
+			truncated: false,
+			metrics: None,
+		};
+		let Value::Object(obj) = qr.into_value() else {
+			panic!("Expected object");
+		};
+		assert!(obj.get("warnings").is_none());
+	}
+
+	#[test]
+	fn query_result_version_round_trip() {
+		let version = Datetime::from_timestamp(1_700_000_000, 0).unwrap();
+		let qr = QueryResult {
+			time: Duration::from_millis(10),
+			result: Ok(Value::None),
+			query_type: QueryType::Other,
+			warnings: Vec::new(),
+			version: Some(version),
+			truncated: false,
+			metrics: None,
+		};
+		let val = qr.into_value();
+		let Value::Object(ref obj) = val else {
+			panic!("Expected object");
+		};
+		assert_eq!(obj.get("version"), Some(&Value::Datetime(version)));
+		let parsed = QueryResult::from_value(val).expect("round-trip should succeed");
+		assert_eq!(parsed.version, Some(version));
+	}
+
+	#[test]
+	fn query_result_truncated_round_trip() {
+		let qr = QueryResult {
+			time: Duration::from_millis(10),
+			result: Ok(Value::None),
+			query_type: QueryType::Other,
+			warnings: Vec::new(),
+			version: None,
+			truncated: true,
+			metrics: None,
+		};
+		let val = qr.into_value();
+		let Value::Object(ref obj) = val else {
+			panic!("Expected object");
+		};
+		assert_eq!(obj.get("truncated"), Some(&Value::Bool(true)));
+		let parsed = QueryResult::from_value(val).expect("round-trip should succeed");
+		assert!(parsed.truncated);
+	}
+
+	#[test]
+	fn query_result_metrics_round_trip() {
+		let metrics = QueryMetrics {
+			records_scanned: 10,
+			records_returned: 2,
+			index_used: true,
+			bytes_read: 512,
+		};
+		let qr = QueryResult {
+			time: Duration::from_millis(10),
+			result: Ok(Value::None),
+			query_type: QueryType::Other,
+			warnings: Vec::new(),
+			version: None,
+			truncated: false,
+			metrics: Some(metrics),
+		};
+		let val = qr.into_value();
+		let Value::Object(ref obj) = val else {
+			panic!("Expected object");
+		};
+		assert!(obj.get("metrics").is_some());
+		let parsed = QueryResult::from_value(val).expect("round-trip should succeed");
+		assert_eq!(parsed.metrics, Some(metrics));
+	}
 }
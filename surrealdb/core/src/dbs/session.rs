@@ -3,7 +3,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use chrono::Utc;
-use surrealdb_types::ToSql;
+use surrealdb_types::{JsonOptions, ToSql};
 use uuid::Uuid;
 
 use crate::iam::{Auth, Level, Role};
@@ -21,6 +21,8 @@ pub struct Session {
 	pub ip: Option<String>,
 	/// The current connection origin
 	pub or: Option<String>,
+	/// The current connection protocol
+	pub pr: Option<String>,
 	/// The current session ID
 	pub id: Option<Uuid>,
 	/// The currently selected namespace
@@ -42,6 +44,34 @@ pub struct Session {
 	/// When true, EXPLAIN ANALYZE output omits elapsed durations, making
 	/// output deterministic for testing.
 	pub redact_volatile_explain_attrs: bool,
+	/// Options for encoding query responses as json
+	pub json_options: JsonOptions,
+	/// When true, ISO-8601 strings in incoming RPC and HTTP data are parsed as
+	/// datetimes, even when the field they are stored in is not typed
+	pub auto_parse_datetimes: bool,
+	/// When true, the result of each statement which commits a write
+	/// includes a version at which it can be read back. This is the
+	/// wall-clock time read after the commit, not the exact version which
+	/// the storage engine assigned to the writes, so reads at this version
+	/// may also observe later writes
+	pub return_version: bool,
+	/// When true, the COMMIT of a transaction returns a summary of the
+	/// records which the transaction wrote
+	pub commit_summary: bool,
+	/// When true, each statement returns metrics of the reads it made
+	pub metrics: bool,
+	/// How NONE and NULL values are coerced into one another when a value
+	/// is coerced to a type which allows only one of them
+	pub null_coercion: NullCoercion,
+	/// The maximum number of nested arrays, objects, or sets in the value
+	/// returned by each statement, with no limit when not set
+	pub max_output_depth: Option<u32>,
+	/// What happens when a returned value is nested deeper than the
+	/// maximum output depth
+	pub output_depth_policy: OutputDepthPolicy,
+	/// The LIMIT applied to top-level SELECT statements which do not specify
+	/// one, with the response marked as truncated when rows were left out
+	pub default_limit: Option<u32>,
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
@@ -81,6 +111,102 @@ impl FromStr for NewPlannerStrategy {
 	}
 }
 
+/// The policy for coercing between NONE and NULL values.
+///
+/// NONE represents the absence of a value, whereas NULL is a value which
+/// has been explicitly set to null. By default the two are never coerced
+/// into one another, so a NULL value can not be stored in an `option<T>`
+/// field. This policy only applies when the type being coerced to allows
+/// one of NONE or NULL but not the other.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum NullCoercion {
+	/// NONE and NULL are never coerced into one another.
+	#[default]
+	Strict,
+	/// NULL is coerced to NONE for types which allow NONE but not NULL,
+	/// such as `option<string>`.
+	NullAsNone,
+	/// NONE is coerced to NULL for types which allow NULL but not NONE,
+	/// such as `string | null`.
+	NoneAsNull,
+	/// NULL and NONE are coerced into whichever one the type allows.
+	Interchangeable,
+}
+
+impl NullCoercion {
+	/// Whether NULL is coerced to NONE under this policy
+	pub(crate) fn null_as_none(&self) -> bool {
+		matches!(self, Self::NullAsNone | Self::Interchangeable)
+	}
+
+	/// Whether NONE is coerced to NULL under this policy
+	pub(crate) fn none_as_null(&self) -> bool {
+		matches!(self, Self::NoneAsNull | Self::Interchangeable)
+	}
+}
+
+impl fmt::Display for NullCoercion {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Strict => f.write_str("strict"),
+			Self::NullAsNone => f.write_str("null-as-none"),
+			Self::NoneAsNull => f.write_str("none-as-null"),
+			Self::Interchangeable => f.write_str("interchangeable"),
+		}
+	}
+}
+
+impl FromStr for NullCoercion {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"strict" => Ok(Self::Strict),
+			"null-as-none" => Ok(Self::NullAsNone),
+			"none-as-null" => Ok(Self::NoneAsNull),
+			"interchangeable" => Ok(Self::Interchangeable),
+			_ => Err(format!(
+				"unknown null coercion policy: '{s}' (expected 'strict', 'null-as-none', 'none-as-null', or 'interchangeable')"
+			)),
+		}
+	}
+}
+
+/// The policy for returned values which are nested deeper than the maximum
+/// output depth of a session.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum OutputDepthPolicy {
+	/// Values at the maximum depth are replaced with their record id, when
+	/// they are a record, and otherwise with NONE.
+	#[default]
+	Truncate,
+	/// The statement returns an error instead of its value.
+	Error,
+}
+
+impl fmt::Display for OutputDepthPolicy {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Truncate => f.write_str("truncate"),
+			Self::Error => f.write_str("error"),
+		}
+	}
+}
+
+impl FromStr for OutputDepthPolicy {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"truncate" => Ok(Self::Truncate),
+			"error" => Ok(Self::Error),
+			_ => {
+				Err(format!("unknown output depth policy: '{s}' (expected 'truncate' or 'error')"))
+			}
+		}
+	}
+}
+
 impl Session {
 	/// Set the selected namespace for the session
 	pub fn with_ns(mut self, ns: &str) -> Session {
@@ -106,6 +232,61 @@ impl Session {
 		self
 	}
 
+	/// Set the json encoding options for the session
+	pub fn with_json_options(mut self, opts: JsonOptions) -> Session {
+		self.json_options = opts;
+		self
+	}
+
+	/// Set whether ISO-8601 strings in incoming data are parsed as datetimes
+	pub fn with_auto_parse_datetimes(mut self, enabled: bool) -> Session {
+		self.auto_parse_datetimes = enabled;
+		self
+	}
+
+	/// Set whether committed writes return a version, taken from the
+	/// wall-clock time after the commit, at which they can be read back
+	pub fn with_return_version(mut self, enabled: bool) -> Session {
+		self.return_version = enabled;
+		self
+	}
+
+	/// Set whether the COMMIT of a transaction returns a summary of its writes
+	pub fn with_commit_summary(mut self, enabled: bool) -> Session {
+		self.commit_summary = enabled;
+		self
+	}
+
+	/// Set whether each statement returns metrics of the reads it made
+	pub fn with_metrics(mut self, enabled: bool) -> Session {
+		self.metrics = enabled;
+		self
+	}
+
+	/// Set the policy for coercing between NONE and NULL values
+	pub fn with_null_coercion(mut self, policy: NullCoercion) -> Session {
+		self.null_coercion = policy;
+		self
+	}
+
+	/// Set the maximum nesting depth of returned values
+	pub fn with_max_output_depth(mut self, depth: Option<u32>) -> Session {
+		self.max_output_depth = depth;
+		self
+	}
+
+	/// Set the policy for returned values which exceed the maximum depth
+	pub fn with_output_depth_policy(mut self, policy: OutputDepthPolicy) -> Session {
+		self.output_depth_policy = policy;
+		self
+	}
+
+	/// Set the LIMIT applied to SELECT statements without one
+	pub fn with_default_limit(mut self, limit: Option<u32>) -> Session {
+		self.default_limit = limit;
+		self
+	}
+
 	/// Set the new planner strategy for the session
 	pub fn new_planner_strategy(mut self, strategy: NewPlannerStrategy) -> Session {
 		self.new_planner_strategy = strategy;
@@ -150,6 +331,7 @@ impl Session {
 			"ip".to_string() => self.ip.clone().map(|x| x.into()).unwrap_or(Value::None),
 			"ns".to_string() => self.ns.clone().map(|x| x.into()).unwrap_or(Value::None),
 			"or".to_string() => self.or.clone().map(|x| x.into()).unwrap_or(Value::None),
+			"pr".to_string() => self.pr.clone().map(|x| x.into()).unwrap_or(Value::None),
 			"rd".to_string() => auth.clone(),
 			"tk".to_string() => token.clone(),
 		});
@@ -188,6 +370,7 @@ impl Session {
 			rt: false,
 			ip: None,
 			or: None,
+			pr: None,
 			id: None,
 			ns: Some(ns.to_owned()),
 			db: Some(db.to_owned()),
@@ -197,6 +380,15 @@ impl Session {
 			variables: Default::default(),
 			new_planner_strategy: NewPlannerStrategy::default(),
 			redact_volatile_explain_attrs: false,
+			json_options: JsonOptions::default(),
+			auto_parse_datetimes: false,
+			return_version: false,
+			commit_summary: false,
+			metrics: false,
+			null_coercion: NullCoercion::default(),
+			max_output_depth: None,
+			output_depth_policy: OutputDepthPolicy::default(),
+			default_limit: None,
 		}
 	}
 
@@ -375,6 +375,17 @@ impl Statement<'_> {
 		}
 	}
 
+	/// Returns any HAVING clause if specified
+	pub(crate) fn having(&self) -> Option<&Cond> {
+		match self {
+			Statement::Select {
+				stmt,
+				..
+			} => stmt.having.as_ref(),
+			_ => None,
+		}
+	}
+
 	/// Returns any ORDER clause if specified
 	pub(crate) fn order(&self) -> Option<&Ordering> {
 		match self {
@@ -554,6 +565,38 @@ impl Statement<'_> {
 		}
 	}
 
+	/// Returns any SCAN TIMEOUT clause
+	pub(crate) fn scan_timeout(&self) -> Option<&Expr> {
+		match self {
+			Statement::Select {
+				stmt,
+				..
+			} => Some(&stmt.scan_timeout),
+			_ => None,
+		}
+	}
+
+	/// Bounds the time the iteration may run for, with any SCAN TIMEOUT
+	/// clause. The clause is computed within `ctx`, and applied to `scan_ctx`.
+	pub(crate) async fn setup_scan_timeout(
+		&self,
+		stk: &mut Stk,
+		ctx: &FrozenContext,
+		opt: &Options,
+		scan_ctx: &mut Context,
+	) -> Result<()> {
+		if let Some(t) = self.scan_timeout()
+			&& let Some(x) = stk
+				.run(|stk| t.compute(stk, ctx, opt, None))
+				.await
+				.catch_return()?
+				.cast_to::<Option<Duration>>()?
+		{
+			scan_ctx.add_scan_timeout(x.0)?;
+		}
+		Ok(())
+	}
+
 	pub(crate) fn setup_query_planner<'a>(
 		&self,
 		planner: QueryPlanner,
@@ -12,8 +12,8 @@ use crate::doc::Permitted::*;
 use crate::err::Error;
 use crate::expr::data::Data;
 use crate::expr::paths::{ID, IN, OUT};
-use crate::expr::{AssignOperator, FlowResultExt, Idiom};
-use crate::val::{RecordId, Value};
+use crate::expr::{AssignOperator, FlowResultExt, IdType, Idiom};
+use crate::val::{RecordId, RecordIdKey, TableName, Value};
 
 impl Document {
 	/// Generate a record ID for CREATE, UPSERT, and UPDATE statements
@@ -21,11 +21,15 @@ impl Document {
 	/// This method handles record ID generation from various sources:
 	/// - Existing document IDs
 	/// - Data clause specified IDs (including function calls and expressions)
-	/// - Randomly generated IDs when no ID is specified
+	/// - Generated IDs, of the ID type of the table, when no ID is specified
 	///
 	/// The method ensures that all expressions are properly evaluated before
 	/// being used as record IDs.
-	pub(super) fn generate_record_id(&mut self) -> Result<()> {
+	pub(super) async fn generate_record_id(
+		&mut self,
+		ctx: &FrozenContext,
+		opt: &Options,
+	) -> Result<()> {
 		// Check if we need to generate a record id
 		if let Some(tb) = &self.r#gen {
 			// This is a CREATE, UPSERT, UPDATE, RELATE statement
@@ -39,13 +43,13 @@ impl Document {
 				match &self.input_data {
 					// There is a data clause so fetch a record id
 					Some(data) => match data.rid() {
-						Value::None => RecordId::random_for_table(tb.clone()),
+						// Generate a new table id
+						Value::None => self.new_record_id(ctx, opt, tb).await?,
 						// Generate a new id from the id field
 						id => id.generate(tb.clone(), false)?,
-						// Generate a new random table id
 					},
 					// There is no data clause so create a record id
-					None => RecordId::random_for_table(tb.clone()),
+					None => self.new_record_id(ctx, opt, tb).await?,
 				}
 			};
 
@@ -62,6 +66,27 @@ impl Document {
 		//
 		Ok(())
 	}
+	/// Generates a new id for a record in the given table, of the id type
+	/// which is defined on the table.
+	async fn new_record_id(
+		&self,
+		ctx: &FrozenContext,
+		opt: &Options,
+		tb: &TableName,
+	) -> Result<RecordId> {
+		let key = match self.doc_ctx.tb()?.id_type {
+			IdType::Rand => RecordIdKey::rand(),
+			IdType::Uuid => RecordIdKey::uuid(),
+			IdType::Ulid => RecordIdKey::ulid(),
+			IdType::Sequence => {
+				let (ns, db) = ctx.expect_ns_db_ids(opt).await?;
+				let id =
+					ctx.try_get_sequences()?.next_record_id(Some(ctx), ns, db, tb.clone()).await?;
+				RecordIdKey::Number(id)
+			}
+		};
+		Ok(RecordId::new(tb.clone(), key))
+	}
 	/// Clears all of the content of this document.
 	/// This is used to empty the current content
 	/// of the document within a `DELETE` statement.
@@ -235,6 +260,11 @@ impl Document {
 				Data::MergeExpression(data) => ComputedData::Merge(Arc::new(
 					data.compute(stk, ctx, opt, Some(doc)).await.catch_return()?,
 				)),
+				// Each record has its own data from the MERGE EACH clause
+				Data::MergeEachExpression(_) => match &self.extras {
+					Workable::Insert(value) => ComputedData::Merge(value.clone()),
+					_ => bail!("Unexpected MERGE EACH clause without record data"),
+				},
 				Data::ReplaceExpression(data) => ComputedData::Replace(Arc::new(
 					data.compute(stk, ctx, opt, Some(doc)).await.catch_return()?,
 				)),
@@ -149,10 +149,12 @@ impl Document {
 			let opt = AuthLimit::try_from(&fd.auth_limit)?.limit_opt(opt);
 			let mut val = computed.compute(stk, ctx, &opt, Some(doc)).await.catch_return()?;
 			if let Some(kind) = fd.field_kind.as_ref() {
-				val = val.coerce_to_kind(kind).map_err(|e| Error::FieldCoerce {
-					record: rid.to_sql(),
-					field_name: fd.name.to_sql(),
-					error: Box::new(e),
+				val = val.coerce_to_kind_with(kind, ctx.null_coercion()).map_err(|e| {
+					Error::FieldCoerce {
+						record: rid.to_sql(),
+						field_name: fd.name.to_sql(),
+						error: Box::new(e),
+					}
 				})?;
 			}
 
@@ -15,7 +15,7 @@ impl Document {
 		stm: &Statement<'_>,
 	) -> Result<Value, IgnoreError> {
 		self.process_record_data(stk, ctx, opt, stm).await?;
-		self.generate_record_id()?;
+		self.generate_record_id(ctx, opt).await?;
 		self.check_permissions_quick(opt, stm).await?;
 		self.check_table_type(stm).await?;
 		self.check_data_fields(stk, ctx, opt, stm).await?;
@@ -14,6 +14,7 @@ use crate::expr::FlowResultExt as _;
 use crate::expr::data::Data;
 use crate::expr::idiom::{Idiom, IdiomTrie, IdiomTrieContains};
 use crate::expr::kind::{Kind, KindLiteral};
+use crate::expr::unknown_fields::UnknownFields;
 use crate::iam::{Action, AuthLimit};
 use crate::val::value::CoerceError;
 use crate::val::value::every::ArrayBehaviour;
@@ -43,6 +44,11 @@ impl Document {
 	/// SCHEMAFULL table are cleaned up and removed.
 	/// If a field is defined as FLEX, then any
 	/// nested fields or array values are untouched.
+	/// With `OPTION STRICT`, every table is treated
+	/// as SCHEMAFULL. Depending on the `ON UNKNOWN
+	/// FIELD` clause of the table, undefined fields
+	/// are rejected, removed, or moved into the
+	/// overflow field.
 	pub(super) async fn cleanup_table_fields(
 		&mut self,
 		ctx: &FrozenContext,
@@ -51,8 +57,10 @@ impl Document {
 	) -> Result<()> {
 		// Get the table
 		let tb = Arc::clone(self.tb().await?);
+		// Strict mode treats every table as schemafull
+		let schemafull = tb.schemafull || opt.strict;
 		// This table is schemafull
-		if tb.schemafull {
+		if schemafull {
 			// Prune unspecified fields from the document that are not defined via
 			// `DefineFieldStatement`s.
 
@@ -108,6 +116,22 @@ impl Document {
 				}
 			}
 
+			// The overflow field, and any nested fields, are always allowed
+			if let UnknownFields::Store(overflow) = &tb.unknown_fields {
+				if !explicit_field_names.contains(overflow) {
+					defined_field_names.insert(overflow, true);
+				}
+				for i in 1..overflow.len() {
+					let ancestor = Idiom(overflow[..i].to_vec());
+					if !explicit_field_names.contains(&ancestor) {
+						defined_field_names.insert(&overflow[..i], true);
+					}
+				}
+			}
+
+			// The undefined fields which are moved into the overflow field
+			let mut unknown = Vec::new();
+
 			// Loop over every field in the document
 			for current_doc_field_idiom in
 				self.current.doc.as_ref().every(None, true, ArrayBehaviour::Full).iter()
@@ -142,36 +166,25 @@ impl Document {
 
 						// This field is not explicitly defined in the schema or it is not a child
 						// of a flex field.
-						ensure!(
-							!tb.schemafull,
-							// If strict, then throw an error on an undefined field
-							Error::FieldUndefined {
-								table: tb.name.clone().into_string(),
-								field: current_doc_field_idiom.clone(),
-							}
-						);
-
-						// Otherwise, delete the field silently and don't error
-						self.current.doc.to_mut().cut(current_doc_field_idiom);
+						self.unknown_field(&tb, current_doc_field_idiom, &mut unknown)?;
 					}
 
 					IdiomTrieContains::None => {
 						// This field is not explicitly defined in the schema or it is not a child
 						// of a flex field.
-						ensure!(
-							!tb.schemafull,
-							// If strict, then throw an error on an undefined field
-							Error::FieldUndefined {
-								table: tb.name.clone().into_string(),
-								field: current_doc_field_idiom.clone(),
-							}
-						);
-
-						// Otherwise, delete the field silently and don't error
-						self.current.doc.to_mut().cut(current_doc_field_idiom);
+						self.unknown_field(&tb, current_doc_field_idiom, &mut unknown)?;
 					}
 				}
 			}
+
+			// Move the undefined fields into the overflow field
+			if let UnknownFields::Store(overflow) = &tb.unknown_fields {
+				for (field, value) in unknown {
+					let mut path = overflow.clone();
+					path.0.extend(field.0);
+					self.current.doc.to_mut().put(&path, value);
+				}
+			}
 		}
 
 		// Loop over every field in the document
@@ -181,6 +194,34 @@ impl Document {
 		Ok(())
 	}
 
+	/// Handles a field which is not defined on a schemafull table, as
+	/// specified by the `ON UNKNOWN FIELD` clause of the table.
+	fn unknown_field(
+		&mut self,
+		tb: &catalog::TableDefinition,
+		field: &Idiom,
+		unknown: &mut Vec<(Idiom, Value)>,
+	) -> Result<()> {
+		match tb.unknown_fields {
+			// Throw an error on an undefined field
+			UnknownFields::Reject => bail!(Error::FieldUndefined {
+				table: tb.name.clone().into_string(),
+				field: field.clone(),
+			}),
+			// Delete the field silently and don't error
+			UnknownFields::Ignore => {}
+			// Keep the field, to be moved into the overflow field
+			UnknownFields::Store(_) => {
+				let value = self.current.doc.as_ref().pick(field);
+				if !value.is_none() {
+					unknown.push((field.clone(), value));
+				}
+			}
+		}
+		self.current.doc.to_mut().cut(field);
+		Ok(())
+	}
+
 	/// Processes `DEFINE FIELD` statements which
 	/// have been defined on the table for this
 	/// record. These fields are executed for
@@ -513,10 +554,12 @@ impl FieldEditContext<'_> {
 			// This is not the `id` field
 			else {
 				// Check the type of the field value
-				let val = val.coerce_to_kind(kind).map_err(|e| Error::FieldCoerce {
-					record: self.rid.to_sql(),
-					field_name: self.def.name.to_sql(),
-					error: Box::new(e),
+				let val = val.coerce_to_kind_with(kind, self.ctx.null_coercion()).map_err(|e| {
+					Error::FieldCoerce {
+						record: self.rid.to_sql(),
+						field_name: self.def.name.to_sql(),
+						error: Box::new(e),
+					}
 				})?;
 				// Return the modified value
 				return Ok(val);
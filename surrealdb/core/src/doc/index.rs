@@ -81,6 +81,17 @@ impl Document {
 
 			// Update the index entries
 			if o != n {
+				// Keep any online rebuild of the index up to date
+				if let Some(index_id) = ix.rebuilding {
+					let rebuild = IndexDefinition {
+						index_id,
+						rebuilding: None,
+						..ix.clone()
+					};
+					let (o, n) = (o.clone(), n.clone());
+					Self::one_index(&db, tb, stk, ctx, opt, &rebuild, o, n, &rid, count_cond_match)
+						.await?;
+				}
 				Self::one_index(&db, tb, stk, ctx, opt, ix, o, n, &rid, count_cond_match).await?;
 			}
 		}
@@ -144,6 +155,7 @@ impl Document {
 	/// Eg. IF the index is composed of the columns `name` and `instrument`
 	/// Given this doc: { "id": 1, "instrument":"piano", "name":"Tobie" }
 	/// It will return: ["Tobie", "piano"]
+	/// Documents which do not match the condition of a partial index return `None`.
 	pub(crate) async fn build_opt_values(
 		stk: &mut Stk,
 		ctx: &FrozenContext,
@@ -154,6 +166,16 @@ impl Document {
 		if doc.doc.as_ref().is_nullish() {
 			return Ok(None);
 		}
+		// Partial indexes only hold the documents matching their condition
+		if let Some(cond) = &ix.cond
+			&& !stk
+				.run(|stk| cond.0.compute(stk, ctx, opt, Some(doc)))
+				.await
+				.catch_return()?
+				.is_truthy()
+		{
+			return Ok(None);
+		}
 		let mut o = Vec::with_capacity(ix.cols.len());
 		for i in ix.cols.iter() {
 			let v = i.compute(stk, ctx, opt, Some(doc)).await.catch_return()?;
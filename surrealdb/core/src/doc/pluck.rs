@@ -12,6 +12,7 @@ use crate::doc::compute::DocKind;
 use crate::doc::document::CursorDoc;
 use crate::exec::planner::Planner;
 use crate::expr::output::Output;
+use crate::expr::paths::ID;
 use crate::expr::{Expr, FlowResultExt as _, Idiom, Operation, SelectStatement};
 use crate::iam::{Action, AuthLimit};
 use crate::idx::planner::RecordStrategy;
@@ -60,6 +61,29 @@ impl Document {
 					let ops = initial.doc.as_ref().diff(current.doc.as_ref());
 					Ok(Operation::operations_to_value(ops))
 				}
+				Output::Changed => {
+					// Process the permitted documents
+					let (initial, current) = if self.reduced(stk, ctx, opt, Both).await? {
+						// Compute the computed fields
+						self.computed_fields(stk, ctx, opt, DocKind::InitialReduced, None).await?;
+						self.computed_fields(stk, ctx, opt, DocKind::CurrentReduced, None).await?;
+						(&self.initial_reduced, &self.current_reduced)
+					} else {
+						// Compute the computed fields
+						self.computed_fields(stk, ctx, opt, DocKind::Initial, None).await?;
+						self.computed_fields(stk, ctx, opt, DocKind::Current, None).await?;
+						(&self.initial, &self.current)
+					};
+					// Output only the fields which were changed, along with the id
+					let mut out = initial.doc.as_ref().changed(current.doc.as_ref())?;
+					if out.is_object() {
+						let id = current.doc.as_ref().pick(ID.as_ref());
+						if !id.is_none() {
+							out.put(ID.as_ref(), id);
+						}
+					}
+					Ok(out)
+				}
 				Output::After => {
 					// Process the permitted documents
 					if self.reduced(stk, ctx, opt, Current).await? {
@@ -35,6 +35,15 @@ impl Document {
 		// Setup a new workable
 		let ins = match val {
 			Operable::Value(v) => (v, Workable::Normal),
+			// An UPDATE with a MERGE EACH clause needs the stored record
+			Operable::Insert(_, o) if matches!(stm, Statement::Update(_)) => {
+				let Some(rid) = &rid else {
+					return Err(IgnoreError::Ignore);
+				};
+				let (ns, db) = ctx.expect_ns_db_ids(opt).await?;
+				let v = ctx.tx().get_record(ns, db, &rid.table, &rid.key, opt.version).await?;
+				(v, Workable::Insert(o))
+			}
 			Operable::Insert(v, o) => (v, Workable::Insert(o)),
 			Operable::Relate(f, v, w, o) => (v, Workable::Relate(f, w, o)),
 			Operable::Count(count) => {
@@ -31,6 +31,11 @@ impl Document {
 	/// record belongs to. This functions loops
 	/// through the tables and processes them all
 	/// within the currently running transaction.
+	///
+	/// Aggregated views are updated incrementally
+	/// from the previous and current versions of
+	/// the record, so the view is consistent with
+	/// the source table once the write completes.
 	pub(super) async fn process_table_views(
 		&self,
 		stk: &mut Stk,
@@ -620,6 +625,9 @@ impl Document {
 				AggregationStat::Accumulate {
 					..
 				} => fail!("Accumulate aggregation is not supported in materialized views"),
+				AggregationStat::ApproxDistinct {
+					..
+				} => fail!("ApproxDistinct aggregation is not supported in materialized views"),
 			}
 		}
 
@@ -672,12 +680,14 @@ impl Document {
 				omit: vec![],
 				with: None,
 				split: None,
+				having: None,
 				order: None,
 				limit: None,
 				start: None,
 				fetch: None,
 				version: Expr::Literal(Literal::None),
 				timeout: Expr::Literal(Literal::None),
+				scan_timeout: Expr::Literal(Literal::None),
 				explain: None,
 				tempfiles: false,
 			};
@@ -1030,6 +1040,9 @@ impl Document {
 				AggregationStat::Accumulate {
 					..
 				} => fail!("Accumulate aggregation is not supported in materialized views"),
+				AggregationStat::ApproxDistinct {
+					..
+				} => fail!("ApproxDistinct aggregation is not supported in materialized views"),
 			}
 		}
 
@@ -1082,12 +1095,14 @@ impl Document {
 				omit: vec![],
 				with: None,
 				split: None,
+				having: None,
 				order: None,
 				limit: None,
 				start: None,
 				fetch: None,
 				version: Expr::Literal(Literal::None),
 				timeout: Expr::Literal(Literal::None),
+				scan_timeout: Expr::Literal(Literal::None),
 				explain: None,
 				tempfiles: false,
 			};
@@ -70,6 +70,13 @@ pub(crate) enum Error {
 	#[error("Parse error: {0}")]
 	InvalidQuery(RenderedParserError),
 
+	/// A row in a CSV import could not be processed
+	#[error("Invalid CSV row {row}: {message}")]
+	InvalidCsvRow {
+		row: usize,
+		message: String,
+	},
+
 	/// There was an error with the SQL query
 	#[error("Cannot use {} in a CONTENT clause", value.to_sql())]
 	InvalidContent {
@@ -157,6 +164,13 @@ pub(crate) enum Error {
 		message: String,
 	},
 
+	/// The records could not be sorted as the edges between them contain a cycle
+	#[error("Cannot sort the records topologically as the edges in `{edge}` contain a cycle: {cycle}")]
+	GraphCycle {
+		edge: String,
+		cycle: String,
+	},
+
 	/// The wrong quantity or magnitude of arguments was given for the specified
 	/// function
 	#[error("Incorrect arguments for method {name}(). {message}")]
@@ -383,6 +397,13 @@ pub(crate) enum Error {
 	#[error("Reached excessive computation depth due to functions, subqueries, or computed values")]
 	ComputationDepthExceeded,
 
+	/// The value returned by a statement is nested deeper than the maximum
+	/// output depth of the session
+	#[error("The result is nested more than {max} levels deep, which exceeds the maximum output depth")]
+	OutputDepthExceeded {
+		max: u32,
+	},
+
 	/// Tried to execute a statement that can't be used here
 	#[error("Invalid statement: {0}")]
 	InvalidStatement(String),
@@ -411,6 +432,18 @@ pub(crate) enum Error {
 		value: String,
 	},
 
+	/// Cannot use the specified value in a MERGE EACH clause
+	#[error("Cannot use {value} in a MERGE EACH clause, expected an object keyed by record id")]
+	InvalidMergeEach {
+		value: String,
+	},
+
+	/// Cannot use the specified key in a MERGE EACH clause
+	#[error("Cannot use '{key}' in a MERGE EACH clause, expected a record id on a target table")]
+	InvalidMergeEachKey {
+		key: String,
+	},
+
 	/// Cannot execute RELATE statement using the specified value
 	#[error("Cannot execute RELATE statement where property 'in' is: {value}")]
 	RelateStatementIn {
@@ -671,6 +704,13 @@ pub(crate) enum Error {
 		exp: String,
 	},
 
+	/// The query planner could not use any of the indexes given in a
+	/// `WITH INDEX` clause
+	#[error("The query can not be executed using the index(es) specified in the WITH INDEX clause: {names}")]
+	IndexHintNotSatisfied {
+		names: String,
+	},
+
 	/// Represents an error when analyzing a value
 	#[error("A value can't be analyzed: {0}")]
 	AnalyzerError(String),
@@ -697,6 +737,13 @@ pub(crate) enum Error {
 		mr: MatchRef,
 	},
 
+	/// The query text was longer than allowed by the capabilities
+	#[error("The query is {length} bytes long, exceeding the maximum of {limit} bytes per query")]
+	QueryLengthLimitExceeded {
+		length: usize,
+		limit: usize,
+	},
+
 	/// Represents a failure in timestamp arithmetic related to database
 	/// internals
 	#[error("Timestamp arithmetic error: {0}")]
@@ -1077,6 +1124,13 @@ pub(crate) enum Error {
 	#[error("Size of query script exceeded maximum supported size of 4,294,967,295 bytes.")]
 	QueryTooLarge,
 
+	/// The query contained more statements than allowed by the capabilities
+	#[error("The query contains {count} statements, exceeding the maximum of {limit} statements per query")]
+	QueryStatementLimitExceeded {
+		count: usize,
+		limit: usize,
+	},
+
 	/// Represents a failure in timestamp arithmetic related to database
 	/// internals
 	#[error("Failed to compute: \"{0}\", as the operation results in an arithmetic overflow.")]
@@ -1272,6 +1326,17 @@ impl Error {
 				| Error::TryExtend(..)
 		)
 	}
+
+	/// Returns true if this error only affects the record which was being
+	/// processed, so that other records can still be processed.
+	pub fn is_record_error(&self) -> bool {
+		self.is_schema_related()
+			|| self.is_ignorable()
+			|| matches!(
+				self,
+				Error::Thrown(_) | Error::RecordExists { .. } | Error::IndexExists { .. }
+			)
+	}
 }
 
 impl From<Error> for String {
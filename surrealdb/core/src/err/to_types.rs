@@ -60,6 +60,9 @@ pub fn into_types_error(error: Error) -> TypesError {
 		NsEmpty => TypesError::validation(message, ValidationError::NamespaceEmpty),
 		DbEmpty => TypesError::validation(message, ValidationError::DatabaseEmpty),
 		InvalidQuery(_) => TypesError::validation(message, None),
+		InvalidCsvRow {
+			..
+		} => TypesError::validation(message, None),
 		InvalidParam {
 			name,
 		} => TypesError::validation(
@@ -94,6 +97,15 @@ pub fn into_types_error(error: Error) -> TypesError {
 			..
 		} => TypesError::validation(message, None),
 		AccessUnsupportedAlgorithm => TypesError::validation(message, None),
+		QueryStatementLimitExceeded {
+			..
+		} => TypesError::validation(message, None),
+		QueryLengthLimitExceeded {
+			..
+		} => TypesError::validation(message, None),
+		IndexHintNotSatisfied {
+			..
+		} => TypesError::validation(message, None),
 
 		// Not allowed (method, scripting, function, net target)
 		ScriptingNotAllowed => TypesError::not_allowed(message, NotAllowedError::Scripting),
@@ -129,6 +141,9 @@ pub fn into_types_error(error: Error) -> TypesError {
 			},
 		),
 		QueryCancelled => TypesError::query(message, QueryError::Cancelled),
+		OutputDepthExceeded {
+			..
+		} => TypesError::query(message, None),
 		QueryNotExecuted {
 			message,
 		} => TypesError::query(message, QueryError::NotExecuted),
@@ -663,6 +663,25 @@ impl ExecutionContext {
 		new
 	}
 
+	/// Create a new context with `cost` deducted from the computation depth budget.
+	///
+	/// The streaming executor does not descend through `Expr::compute`, so
+	/// function and closure calls consume the depth tracked by the legacy
+	/// Options instead. Returns `Error::ComputationDepthExceeded` once the
+	/// budget configured by `SURREAL_MAX_COMPUTATION_DEPTH` is exhausted.
+	pub fn dive(&self, cost: u8) -> Result<Self, Error> {
+		let mut new = self.clone();
+		let root = match &mut new {
+			Self::Root(r) => r,
+			Self::Namespace(n) => &mut n.root,
+			Self::Database(d) => &mut d.ns_ctx.root,
+		};
+		if let Some(ref opts) = root.options {
+			root.options = Some(opts.dive(cost)?);
+		}
+		Ok(new)
+	}
+
 	/// Create a new context at namespace level with the given namespace definition.
 	///
 	/// This is used by USE NS statements to switch namespace context.
@@ -4,7 +4,7 @@
 //! during GROUP BY query execution.
 //!
 //! Aggregates are organized by category:
-//! - [`count`]: Row and value counting (COUNT(), COUNT(field))
+//! - [`count`]: Row and value counting (COUNT(), COUNT(field), count::approx_distinct)
 //! - [`math`]: Mathematical aggregations (sum, mean, min, max, stddev, variance, median)
 //! - [`time`]: Datetime aggregations (min, max)
 //! - [`array`]: Array collection operations (group, join, distinct)
@@ -16,7 +16,7 @@ mod time;
 
 // Re-export all aggregate functions
 pub use array::{ArrayDistinct, ArrayGroup, ArrayJoin};
-pub use count::{Count, CountField};
+pub use count::{ApproxDistinct, Count, CountField};
 pub use math::{MathMax, MathMean, MathMedian, MathMin, MathStddev, MathSum, MathVariance};
 pub use time::{TimeMax, TimeMin};
 
@@ -27,6 +27,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 	// Count aggregates
 	registry.register_aggregate(Count);
 	// Note: CountField is handled specially - "count" with args becomes CountField
+	registry.register_aggregate(ApproxDistinct);
 
 	// Math aggregates
 	registry.register_aggregate(MathSum);
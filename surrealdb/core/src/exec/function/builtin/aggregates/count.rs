@@ -1,12 +1,14 @@
 //! Count aggregate functions.
 //!
-//! Provides `COUNT()` for counting all rows and `COUNT(field)` for counting
-//! truthy values.
+//! Provides `COUNT()` for counting all rows, `COUNT(field)` for counting
+//! truthy values, and `count::approx_distinct(field)` for estimating the
+//! number of distinct values.
 
 use anyhow::Result;
 
 use crate::exec::function::{Accumulator, AggregateFunction, Signature};
 use crate::expr::Kind;
+use crate::fnc::util::math::hyperloglog::HyperLogLog;
 use crate::val::{Number, Value};
 
 // ============================================================================
@@ -141,6 +143,66 @@ impl Accumulator for CountFieldAccumulator {
 	}
 }
 
+// ============================================================================
+// ApproxDistinct
+// ============================================================================
+
+/// count::approx_distinct(field) - estimates the number of distinct values
+/// using a HyperLogLog sketch, with a standard error of about 2%
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApproxDistinct;
+
+impl AggregateFunction for ApproxDistinct {
+	fn name(&self) -> &'static str {
+		"count::approx_distinct"
+	}
+
+	fn create_accumulator(&self) -> Box<dyn Accumulator> {
+		Box::new(ApproxDistinctAccumulator::default())
+	}
+
+	fn signature(&self) -> Signature {
+		Signature::new().arg("value", Kind::Any).returns(Kind::Int)
+	}
+}
+
+#[derive(Debug, Clone, Default)]
+struct ApproxDistinctAccumulator {
+	hll: HyperLogLog,
+}
+
+impl Accumulator for ApproxDistinctAccumulator {
+	fn update(&mut self, value: Value) -> Result<()> {
+		self.hll.insert(&value);
+		Ok(())
+	}
+
+	fn merge(&mut self, other: Box<dyn Accumulator>) -> Result<()> {
+		let other = other
+			.as_any()
+			.downcast_ref::<ApproxDistinctAccumulator>()
+			.ok_or_else(|| anyhow::anyhow!("Cannot merge incompatible accumulators"))?;
+		self.hll.merge(&other.hll);
+		Ok(())
+	}
+
+	fn finalize(&self) -> Result<Value> {
+		Ok(Value::Number(Number::Int(self.hll.estimate())))
+	}
+
+	fn reset(&mut self) {
+		self.hll.clear();
+	}
+
+	fn clone_box(&self) -> Box<dyn Accumulator> {
+		Box::new(self.clone())
+	}
+
+	fn as_any(&self) -> &dyn std::any::Any {
+		self
+	}
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -349,4 +411,63 @@ mod tests {
 		let result = acc.finalize().unwrap();
 		assert_eq!(as_int(&result), 2);
 	}
+
+	// -------------------------------------------------------------------------
+	// ApproxDistinct tests
+	// -------------------------------------------------------------------------
+
+	#[test]
+	fn approx_distinct_zero_items() {
+		let func = ApproxDistinct;
+		let acc = func.create_accumulator();
+		let result = acc.finalize().unwrap();
+		assert_eq!(as_int(&result), 0);
+	}
+
+	#[test]
+	fn approx_distinct_duplicates() {
+		let func = ApproxDistinct;
+		let mut acc = func.create_accumulator();
+		acc.update(Value::String("a".into())).unwrap();
+		acc.update(Value::String("a".into())).unwrap();
+		acc.update(Value::String("b".into())).unwrap();
+		acc.update(Value::None).unwrap();
+		let result = acc.finalize().unwrap();
+		assert_eq!(as_int(&result), 2);
+	}
+
+	#[test]
+	fn approx_distinct_high_cardinality() {
+		let func = ApproxDistinct;
+		let mut acc = func.create_accumulator();
+		let values: Vec<Value> =
+			(0..100_000).map(|i| Value::Number(Number::Int(i % 50_000))).collect();
+		acc.update_batch(&values).unwrap();
+		let result = as_int(&acc.finalize().unwrap());
+		assert!((result - 50_000).abs() < 2_500, "estimate {result} is not within 5% of 50000");
+	}
+
+	#[test]
+	fn approx_distinct_merge() {
+		let func = ApproxDistinct;
+		let mut acc1 = func.create_accumulator();
+		let mut acc2 = func.create_accumulator();
+		for i in 0..20_000 {
+			acc1.update(Value::Number(Number::Int(i))).unwrap();
+			acc2.update(Value::Number(Number::Int(i + 10_000))).unwrap();
+		}
+		acc1.merge(acc2).unwrap();
+		let result = as_int(&acc1.finalize().unwrap());
+		assert!((result - 30_000).abs() < 1_500, "estimate {result} is not within 5% of 30000");
+	}
+
+	#[test]
+	fn approx_distinct_reset() {
+		let func = ApproxDistinct;
+		let mut acc = func.create_accumulator();
+		acc.update(Value::Number(Number::Int(1))).unwrap();
+		acc.reset();
+		let result = acc.finalize().unwrap();
+		assert_eq!(as_int(&result), 0);
+	}
 }
@@ -30,7 +30,8 @@ define_pure_function!(ArraySort, "array::sort", (array: Any) -> Any, crate::fnc:
 define_pure_function!(ArraySortNatural, "array::sort_natural", (array: Any) -> Any, crate::fnc::array::sort_natural);
 define_pure_function!(ArraySortLexical, "array::sort_lexical", (array: Any) -> Any, crate::fnc::array::sort_lexical);
 define_pure_function!(ArraySortNaturalLexical, "array::sort_natural_lexical", (array: Any) -> Any, crate::fnc::array::sort_natural_lexical);
-define_pure_function!(ArrayTranspose, "array::transpose", (array: Any) -> Any, crate::fnc::array::transpose);
+define_pure_function!(ArraySortByKeys, "array::sort_by_keys", (array: Any, keys: Any) -> Any, crate::fnc::array::sort_by_keys);
+define_pure_function!(ArrayTranspose, "array::transpose", (array: Any, ?strict: Bool) -> Any, crate::fnc::array::transpose);
 define_pure_function!(ArrayBooleanNot, "array::boolean_not", (array: Any) -> Any, crate::fnc::array::boolean_not);
 
 // Two argument array functions
@@ -45,17 +46,20 @@ define_pure_function!(ArrayCombine, "array::combine", (a: Any, b: Any) -> Any, c
 define_pure_function!(ArrayComplement, "array::complement", (a: Any, b: Any) -> Any, crate::fnc::array::complement);
 define_pure_function!(ArrayConcat, "array::concat", (a: Any, b: Any) -> Any, crate::fnc::array::concat);
 define_pure_function!(ArrayDifference, "array::difference", (a: Any, b: Any) -> Any, crate::fnc::array::difference);
+define_pure_function!(ArrayEditScript, "array::edit_script", (a: Any, b: Any) -> Any, crate::fnc::array::edit_script);
 define_pure_function!(ArrayIntersect, "array::intersect", (a: Any, b: Any) -> Any, crate::fnc::array::intersect);
 define_pure_function!(ArrayJoin, "array::join", (array: Any, separator: String) -> String, crate::fnc::array::join);
 define_pure_function!(ArrayLogicalAnd, "array::logical_and", (a: Any, b: Any) -> Any, crate::fnc::array::logical_and);
 define_pure_function!(ArrayLogicalOr, "array::logical_or", (a: Any, b: Any) -> Any, crate::fnc::array::logical_or);
 define_pure_function!(ArrayLogicalXor, "array::logical_xor", (a: Any, b: Any) -> Any, crate::fnc::array::logical_xor);
 define_pure_function!(ArrayMatches, "array::matches", (array: Any, value: Any) -> Any, crate::fnc::array::matches);
+define_pure_function!(ArrayMergeSorted, "array::merge_sorted", (a: Any, b: Any) -> Any, crate::fnc::array::merge_sorted);
 define_pure_function!(ArrayPrepend, "array::prepend", (array: Any, value: Any) -> Any, crate::fnc::array::prepend);
 define_pure_function!(ArrayPush, "array::push", (array: Any, value: Any) -> Any, crate::fnc::array::push);
 define_pure_function!(ArrayRemove, "array::remove", (array: Any, index: Int) -> Any, crate::fnc::array::remove);
 define_pure_function!(ArrayRepeat, "array::repeat", (value: Any, count: Int) -> Any, crate::fnc::array::repeat);
 define_pure_function!(ArrayUnion, "array::union", (a: Any, b: Any) -> Any, crate::fnc::array::union);
+define_pure_function!(ArrayUniqueBy, "array::unique_by", (array: Any, keys: Any) -> Any, crate::fnc::array::unique_by);
 define_pure_function!(ArrayWindows, "array::windows", (array: Any, size: Int) -> Any, crate::fnc::array::windows);
 define_pure_function!(ArraySequence, "array::sequence", (start: Int, end: Int) -> Any, crate::fnc::array::sequence);
 
@@ -152,6 +156,12 @@ define_array_closure_function!(ArrayFold, "array::fold", crate::fnc::array::fold
 // array::map - Transform elements with closure
 define_array_closure_function!(ArrayMap, "array::map", crate::fnc::array::map, array: Any, mapper: Any => Any);
 
+// array::merge_sorted_by - Merge sorted arrays by the keys from a closure
+define_array_closure_function!(ArrayMergeSortedBy, "array::merge_sorted_by", crate::fnc::array::merge_sorted_by, a: Any, b: Any, key: Any => Any);
+
+// array::partition - Split elements by a closure predicate
+define_array_closure_function!(ArrayPartition, "array::partition", crate::fnc::array::partition, array: Any, check: Any => Any);
+
 // array::reduce - Reduce array with closure
 define_array_closure_function!(ArrayReduce, "array::reduce", crate::fnc::array::reduce, array: Any, mapper: Any => Any);
 
@@ -171,6 +181,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 		ArrayConcat,
 		ArrayDifference,
 		ArrayDistinct,
+		ArrayEditScript,
 		ArrayFill,
 		ArrayFirst,
 		ArrayFlatten,
@@ -186,6 +197,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 		ArrayLogicalXor,
 		ArrayMatches,
 		ArrayMax,
+		ArrayMergeSorted,
 		ArrayMin,
 		ArrayPop,
 		ArrayPrepend,
@@ -203,9 +215,11 @@ pub fn register(registry: &mut FunctionRegistry) {
 		ArraySortNatural,
 		ArraySortLexical,
 		ArraySortNaturalLexical,
+		ArraySortByKeys,
 		ArraySwap,
 		ArrayTranspose,
 		ArrayUnion,
+		ArrayUniqueBy,
 		ArrayWindows,
 	);
 
@@ -218,5 +232,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 	registry.register(ArrayFindIndex);
 	registry.register(ArrayFold);
 	registry.register(ArrayMap);
+	registry.register(ArrayMergeSortedBy);
+	registry.register(ArrayPartition);
 	registry.register(ArrayReduce);
 }
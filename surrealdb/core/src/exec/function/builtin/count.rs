@@ -1,10 +1,11 @@
-//! Count function
+//! Count functions
 
 use crate::exec::function::FunctionRegistry;
 use crate::{define_pure_function, register_functions};
 
 define_pure_function!(Count, "count", (value: Any) -> Int, crate::fnc::count::count);
+define_pure_function!(CountApproxDistinct, "count::approx_distinct", (array: Any) -> Int, crate::fnc::count::approx_distinct);
 
 pub fn register(registry: &mut FunctionRegistry) {
-	register_functions!(registry, Count);
+	register_functions!(registry, Count, CountApproxDistinct);
 }
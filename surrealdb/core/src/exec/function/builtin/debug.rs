@@ -0,0 +1,123 @@
+//! Debug functions
+
+use anyhow::Result;
+
+use crate::dbs::capabilities::ExperimentalTarget;
+use crate::err::Error;
+use crate::exec::function::{FunctionRegistry, ScalarFunction, Signature};
+use crate::exec::physical_expr::EvalContext;
+use crate::expr::Kind;
+use crate::fnc::args::FromArgs;
+use crate::val::Value;
+
+// =========================================================================
+// debug::kv_scan - Scan raw key-value pairs under a key prefix
+// =========================================================================
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DebugKvScan;
+
+impl ScalarFunction for DebugKvScan {
+	fn name(&self) -> &'static str {
+		"debug::kv_scan"
+	}
+
+	fn signature(&self) -> Signature {
+		Signature::new()
+			.arg("prefix", Kind::String)
+			.optional("limit", Kind::Int)
+			.returns(Kind::Array(Box::new(Kind::Object), None))
+	}
+
+	fn is_pure(&self) -> bool {
+		false
+	}
+
+	fn is_async(&self) -> bool {
+		true
+	}
+
+	fn invoke(&self, _args: Vec<Value>) -> Result<Value> {
+		Err(anyhow::anyhow!("Function '{}' requires async execution", self.name()))
+	}
+
+	fn invoke_async<'a>(
+		&'a self,
+		ctx: &'a EvalContext<'_>,
+		args: Vec<Value>,
+	) -> crate::exec::BoxFut<'a, Result<Value>> {
+		Box::pin(async move {
+			if !ctx.capabilities().allows_experimental(&ExperimentalTarget::Debug) {
+				return Err(Error::InvalidFunction {
+					name: self.name().to_string(),
+					message: format!(
+						"Experimental feature {} is not enabled",
+						ExperimentalTarget::Debug
+					),
+				}
+				.into());
+			}
+			let args = FromArgs::from_args("debug::kv_scan", args)?;
+			let frozen = ctx.exec_ctx.ctx();
+			let opt = ctx.exec_ctx.options();
+			crate::fnc::debug::kv_scan((frozen, opt), args).await
+		})
+	}
+}
+
+// =========================================================================
+// debug::table_checksum - Compute an order-independent checksum of a table
+// =========================================================================
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DebugTableChecksum;
+
+impl ScalarFunction for DebugTableChecksum {
+	fn name(&self) -> &'static str {
+		"debug::table_checksum"
+	}
+
+	fn signature(&self) -> Signature {
+		Signature::new().arg("table", Kind::String).returns(Kind::String)
+	}
+
+	fn is_pure(&self) -> bool {
+		false
+	}
+
+	fn is_async(&self) -> bool {
+		true
+	}
+
+	fn invoke(&self, _args: Vec<Value>) -> Result<Value> {
+		Err(anyhow::anyhow!("Function '{}' requires async execution", self.name()))
+	}
+
+	fn invoke_async<'a>(
+		&'a self,
+		ctx: &'a EvalContext<'_>,
+		args: Vec<Value>,
+	) -> crate::exec::BoxFut<'a, Result<Value>> {
+		Box::pin(async move {
+			if !ctx.capabilities().allows_experimental(&ExperimentalTarget::Debug) {
+				return Err(Error::InvalidFunction {
+					name: self.name().to_string(),
+					message: format!(
+						"Experimental feature {} is not enabled",
+						ExperimentalTarget::Debug
+					),
+				}
+				.into());
+			}
+			let args = FromArgs::from_args("debug::table_checksum", args)?;
+			let frozen = ctx.exec_ctx.ctx();
+			let opt = ctx.exec_ctx.options();
+			crate::fnc::debug::table_checksum((frozen, opt), args).await
+		})
+	}
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+	registry.register(DebugKvScan);
+	registry.register(DebugTableChecksum);
+}
@@ -3,6 +3,18 @@
 use crate::exec::function::FunctionRegistry;
 use crate::{define_pure_function, register_functions};
 
+// Base32 encoding
+define_pure_function!(EncodingBase32Decode, "encoding::base32::decode", (value: String) -> Any, crate::fnc::encoding::base32::decode);
+define_pure_function!(EncodingBase32Encode, "encoding::base32::encode", (value: Any, ?padded: Any) -> String, crate::fnc::encoding::base32::encode);
+
+// Base58 encoding
+define_pure_function!(EncodingBase58Decode, "encoding::base58::decode", (value: String) -> Any, crate::fnc::encoding::base58::decode);
+define_pure_function!(EncodingBase58Encode, "encoding::base58::encode", (value: Any) -> String, crate::fnc::encoding::base58::encode);
+
+// Base62 encoding
+define_pure_function!(EncodingBase62Decode, "encoding::base62::decode", (value: String) -> Any, crate::fnc::encoding::base62::decode);
+define_pure_function!(EncodingBase62Encode, "encoding::base62::encode", (value: Any) -> String, crate::fnc::encoding::base62::encode);
+
 // Base64 encoding
 define_pure_function!(EncodingBase64Decode, "encoding::base64::decode", (value: String) -> Any, crate::fnc::encoding::base64::decode);
 define_pure_function!(EncodingBase64Encode, "encoding::base64::encode", (value: Any) -> String, crate::fnc::encoding::base64::encode);
@@ -18,6 +30,12 @@ define_pure_function!(EncodingJsonEncode, "encoding::json::encode", (value: Any)
 pub fn register(registry: &mut FunctionRegistry) {
 	register_functions!(
 		registry,
+		EncodingBase32Decode,
+		EncodingBase32Encode,
+		EncodingBase58Decode,
+		EncodingBase58Encode,
+		EncodingBase62Decode,
+		EncodingBase62Encode,
 		EncodingBase64Decode,
 		EncodingBase64Encode,
 		EncodingCborDecode,
@@ -7,6 +7,7 @@ define_pure_function!(GeoArea, "geo::area", (geometry: Any) -> Float, crate::fnc
 define_pure_function!(GeoBearing, "geo::bearing", (a: Any, b: Any) -> Float, crate::fnc::geo::bearing);
 define_pure_function!(GeoCentroid, "geo::centroid", (geometry: Any) -> Any, crate::fnc::geo::centroid);
 define_pure_function!(GeoDistance, "geo::distance", (a: Any, b: Any) -> Float, crate::fnc::geo::distance);
+define_pure_function!(GeoWithin, "geo::within", (geometry: Any, center: Any, radius: Number) -> Bool, crate::fnc::geo::within);
 
 // Geo hash functions
 define_pure_function!(GeoHashDecode, "geo::hash::decode", (hash: String) -> Any, crate::fnc::geo::hash::decode);
@@ -25,5 +26,6 @@ pub fn register(registry: &mut FunctionRegistry) {
 		GeoHashDecode,
 		GeoHashEncode,
 		GeoIsValid,
+		GeoWithin,
 	);
 }
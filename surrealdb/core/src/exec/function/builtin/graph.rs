@@ -0,0 +1,124 @@
+//! Graph functions
+
+use anyhow::Result;
+use reblessive::tree::TreeStack;
+
+use crate::exec::function::{FunctionRegistry, ScalarFunction, Signature};
+use crate::exec::physical_expr::EvalContext;
+use crate::expr::Kind;
+use crate::fnc::args::FromArgs;
+use crate::val::Value;
+
+// =========================================================================
+// graph::expand - Replace record links with the linked records
+// =========================================================================
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GraphExpand;
+
+impl ScalarFunction for GraphExpand {
+	fn name(&self) -> &'static str {
+		"graph::expand"
+	}
+
+	fn signature(&self) -> Signature {
+		Signature::new().arg("value", Kind::Any).arg("depth", Kind::Int).returns(Kind::Any)
+	}
+
+	fn is_pure(&self) -> bool {
+		false
+	}
+
+	fn is_async(&self) -> bool {
+		true
+	}
+
+	fn invoke(&self, _args: Vec<Value>) -> Result<Value> {
+		Err(anyhow::anyhow!("Function '{}' requires async execution", self.name()))
+	}
+
+	fn invoke_async<'a>(
+		&'a self,
+		ctx: &'a EvalContext<'_>,
+		args: Vec<Value>,
+	) -> crate::exec::BoxFut<'a, Result<Value>> {
+		Box::pin(async move {
+			use crate::doc::CursorDoc;
+			let args = FromArgs::from_args("graph::expand", args)?;
+			let frozen = ctx.exec_ctx.ctx();
+			let opt = ctx.exec_ctx.options();
+			let doc = ctx
+				.document_root
+				.or(ctx.current_value)
+				.map(|v| CursorDoc::new(None, None, v.clone()));
+			let mut stack = TreeStack::new();
+			stack
+				.enter(|stk| async move {
+					crate::fnc::graph::expand((stk, frozen, opt, doc.as_ref()), args).await
+				})
+				.finish()
+				.await
+		})
+	}
+}
+
+// =========================================================================
+// graph::topo_sort - Sort the records of a table by the edges between them
+// =========================================================================
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GraphTopoSort;
+
+impl ScalarFunction for GraphTopoSort {
+	fn name(&self) -> &'static str {
+		"graph::topo_sort"
+	}
+
+	fn signature(&self) -> Signature {
+		Signature::new()
+			.arg("table", Kind::String)
+			.arg("edge", Kind::String)
+			.returns(Kind::Array(Box::new(Kind::Record(vec![])), None))
+	}
+
+	fn is_pure(&self) -> bool {
+		false
+	}
+
+	fn is_async(&self) -> bool {
+		true
+	}
+
+	fn invoke(&self, _args: Vec<Value>) -> Result<Value> {
+		Err(anyhow::anyhow!("Function '{}' requires async execution", self.name()))
+	}
+
+	fn invoke_async<'a>(
+		&'a self,
+		ctx: &'a EvalContext<'_>,
+		args: Vec<Value>,
+	) -> crate::exec::BoxFut<'a, Result<Value>> {
+		Box::pin(async move {
+			use crate::doc::CursorDoc;
+			let args = FromArgs::from_args("graph::topo_sort", args)?;
+			let frozen = ctx.exec_ctx.ctx();
+			let opt = ctx.exec_ctx.options();
+			let doc = ctx
+				.document_root
+				.or(ctx.current_value)
+				.map(|v| CursorDoc::new(None, None, v.clone()));
+			let mut stack = TreeStack::new();
+			stack
+				.enter(|stk| async move {
+					crate::fnc::graph::topo_sort((stk, frozen, opt, doc.as_ref()), args).await
+				})
+				.finish()
+				.await
+		})
+	}
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+	registry.register(GraphExpand);
+	registry.register(GraphTopoSort);
+}
@@ -1,6 +1,7 @@
 //! HTTP functions for the streaming executor.
 //!
-//! These provide HTTP client functionality (GET, POST, PUT, PATCH, DELETE, HEAD).
+//! These provide HTTP client functionality (GET, POST, PUT, PATCH, DELETE, HEAD, OPTIONS,
+//! TRACE).
 //! Note: HTTP functions require the "http" feature to be enabled.
 
 use anyhow::Result;
@@ -99,6 +100,42 @@ async fn http_get_impl(_ctx: &EvalContext<'_>, _args: Vec<Value>) -> Result<Valu
 	http_disabled().await
 }
 
+// =========================================================================
+// HTTP GET with the response status and headers
+// =========================================================================
+
+#[cfg(feature = "http")]
+async fn http_get_with_meta_impl(ctx: &EvalContext<'_>, args: Vec<Value>) -> Result<Value> {
+	let uri = extract_uri(&args, "http::get_with_meta")?;
+	let opts = extract_opts(&args, 1, "http::get_with_meta")?;
+
+	// The URL is checked, and the response decoded, by the shared implementation
+	crate::fnc::util::http::get_with_meta(&ctx.exec_ctx.root().ctx, uri, opts).await
+}
+
+#[cfg(not(feature = "http"))]
+async fn http_get_with_meta_impl(_ctx: &EvalContext<'_>, _args: Vec<Value>) -> Result<Value> {
+	http_disabled().await
+}
+
+// =========================================================================
+// HTTP GET of a newline delimited JSON body
+// =========================================================================
+
+#[cfg(feature = "http")]
+async fn http_stream_impl(ctx: &EvalContext<'_>, args: Vec<Value>) -> Result<Value> {
+	let uri = extract_uri(&args, "http::stream")?;
+	let opts = extract_opts(&args, 1, "http::stream")?;
+
+	// The URL is checked, and the response parsed, by the shared implementation
+	crate::fnc::util::http::stream(&ctx.exec_ctx.root().ctx, uri, opts).await
+}
+
+#[cfg(not(feature = "http"))]
+async fn http_stream_impl(_ctx: &EvalContext<'_>, _args: Vec<Value>) -> Result<Value> {
+	http_disabled().await
+}
+
 // =========================================================================
 // HTTP PUT
 // =========================================================================
@@ -186,6 +223,48 @@ async fn http_delete_impl(_ctx: &EvalContext<'_>, _args: Vec<Value>) -> Result<V
 	http_disabled().await
 }
 
+// =========================================================================
+// HTTP OPTIONS
+// =========================================================================
+
+#[cfg(feature = "http")]
+async fn http_options_impl(ctx: &EvalContext<'_>, args: Vec<Value>) -> Result<Value> {
+	let uri = extract_uri(&args, "http::options")?;
+	let opts = extract_opts(&args, 1, "http::options")?;
+
+	// Check if URL is allowed
+	let url = url::Url::parse(&uri).map_err(|_| crate::err::Error::InvalidUrl(uri.clone()))?;
+	ctx.check_allowed_net(&url).await?;
+
+	http_request(ctx, reqwest::Method::OPTIONS, uri, None, opts).await
+}
+
+#[cfg(not(feature = "http"))]
+async fn http_options_impl(_ctx: &EvalContext<'_>, _args: Vec<Value>) -> Result<Value> {
+	http_disabled().await
+}
+
+// =========================================================================
+// HTTP TRACE
+// =========================================================================
+
+#[cfg(feature = "http")]
+async fn http_trace_impl(ctx: &EvalContext<'_>, args: Vec<Value>) -> Result<Value> {
+	let uri = extract_uri(&args, "http::trace")?;
+	let opts = extract_opts(&args, 1, "http::trace")?;
+
+	// Check if URL is allowed
+	let url = url::Url::parse(&uri).map_err(|_| crate::err::Error::InvalidUrl(uri.clone()))?;
+	ctx.check_allowed_net(&url).await?;
+
+	http_request(ctx, reqwest::Method::TRACE, uri, None, opts).await
+}
+
+#[cfg(not(feature = "http"))]
+async fn http_trace_impl(_ctx: &EvalContext<'_>, _args: Vec<Value>) -> Result<Value> {
+	http_disabled().await
+}
+
 // =========================================================================
 // HTTP Request implementation
 // =========================================================================
@@ -197,22 +276,24 @@ async fn http_request(
 	method: reqwest::Method,
 	uri: String,
 	body: Option<Value>,
-	opts: Object,
+	mut opts: Object,
 ) -> Result<Value> {
-	use http::header::CONTENT_TYPE;
-
 	use crate::err::Error;
 	use crate::sql::expression::convert_public_value_to_internal;
-	use crate::types::{PublicBytes, PublicValue};
 
 	let url = url::Url::parse(&uri).map_err(|_| Error::InvalidUrl(uri.clone()))?;
 
 	let client = ctx.exec_ctx.root().ctx.http_client();
 
-	let is_head = matches!(method, reqwest::Method::HEAD);
-
-	// Start the request
-	let mut req = client.request(method, url);
+	// Start the request, with the authorization from opts
+	let mut req = client.request(method.clone(), url);
+	req = crate::fnc::util::http::encode_auth(req, &method, &mut opts)?;
+	#[cfg_attr(target_family = "wasm", expect(unused_variables))]
+	let timeout = crate::fnc::util::http::parse_timeout(&method, &mut opts)?;
+	#[cfg(not(target_family = "wasm"))]
+	if let Some(timeout) = timeout {
+		req = req.timeout(timeout);
+	}
 
 	// Add custom headers from opts
 	for (k, v) in opts.iter() {
@@ -222,65 +303,23 @@ async fn http_request(
 	// Add body if present
 	if let Some(b) = body {
 		let public_body = crate::val::convert_value_to_public_value(b)?;
-		req = match public_body {
-			PublicValue::Bytes(v) => req.body(v.into_inner()),
-			PublicValue::String(v) => req.body(v),
-			_ if !public_body.is_nullish() => req.json(&public_body.into_json_value()),
-			_ => req,
-		};
+		req = crate::fnc::util::http::encode_body(req, public_body)?;
 	}
 
 	// Send the request
 	let res = req.send().await.map_err(Error::from)?;
 
-	if is_head {
+	let res = crate::fnc::util::http::error_for_status(res).await?;
+	match method {
 		// For HEAD, just check status
-		match res.error_for_status() {
-			Ok(_) => Ok(Value::None),
-			Err(err) => match err.status() {
-				Some(s) => Err(anyhow::anyhow!(Error::Http(format!(
-					"{} {}",
-					s.as_u16(),
-					s.canonical_reason().unwrap_or_default(),
-				)))),
-				None => Err(anyhow::anyhow!(Error::Http(err.to_string()))),
-			},
-		}
-	} else {
-		// Decode response
-
-		match res.error_for_status() {
-			Ok(res) => match res.headers().get(CONTENT_TYPE) {
-				Some(mime) => match mime.to_str() {
-					Ok(v) if v.starts_with("application/json") => {
-						let txt = res.text().await.map_err(Error::from)?;
-						let json: serde_json::Value = serde_json::from_str(&txt)
-							.map_err(|e| Error::Http(format!("Failed to parse JSON: {}", e)))?;
-						let val = crate::rpc::format::json::json_to_value(json);
-						Ok(convert_public_value_to_internal(val))
-					}
-					Ok(v) if v.starts_with("application/octet-stream") => {
-						let bytes = res.bytes().await.map_err(Error::from)?;
-						Ok(convert_public_value_to_internal(PublicValue::Bytes(PublicBytes::from(
-							bytes,
-						))))
-					}
-					Ok(v) if v.starts_with("text") => {
-						let txt = res.text().await.map_err(Error::from)?;
-						Ok(convert_public_value_to_internal(PublicValue::String(txt)))
-					}
-					_ => Ok(Value::None),
-				},
-				_ => Ok(Value::None),
-			},
-			Err(err) => match err.status() {
-				Some(s) => Err(anyhow::anyhow!(Error::Http(format!(
-					"{} {}",
-					s.as_u16(),
-					s.canonical_reason().unwrap_or_default(),
-				)))),
-				None => Err(anyhow::anyhow!(Error::Http(err.to_string()))),
-			},
+		reqwest::Method::HEAD => Ok(Value::None),
+		// For OPTIONS, return the headers which describe what is allowed
+		reqwest::Method::OPTIONS => Ok(Value::Object(crate::fnc::util::http::decode_headers(&res))),
+		_ => {
+			// Decode the response, within the maximum size
+			let max = ctx.exec_ctx.root().ctx.get_capabilities().max_http_response_size();
+			let val = crate::fnc::util::http::decode_body(res, max).await?;
+			Ok(convert_public_value_to_internal(val))
 		}
 	}
 }
@@ -291,15 +330,31 @@ async fn http_request(
 
 define_async_function!(HttpHead, "http::head", (uri: String, ?opts: Object) -> Any, http_head_impl);
 define_async_function!(HttpGet, "http::get", (uri: String, ?opts: Object) -> Any, http_get_impl);
+define_async_function!(HttpGetWithMeta, "http::get_with_meta", (uri: String, ?opts: Object) -> Object, http_get_with_meta_impl);
+define_async_function!(HttpStream, "http::stream", (uri: String, ?opts: Object) -> Any, http_stream_impl);
 define_async_function!(HttpPut, "http::put", (uri: String, ?body: Any, ?opts: Object) -> Any, http_put_impl);
 define_async_function!(HttpPost, "http::post", (uri: String, ?body: Any, ?opts: Object) -> Any, http_post_impl);
 define_async_function!(HttpPatch, "http::patch", (uri: String, ?body: Any, ?opts: Object) -> Any, http_patch_impl);
 define_async_function!(HttpDelete, "http::delete", (uri: String, ?opts: Object) -> Any, http_delete_impl);
+define_async_function!(HttpOptions, "http::options", (uri: String, ?opts: Object) -> Object, http_options_impl);
+define_async_function!(HttpTrace, "http::trace", (uri: String, ?opts: Object) -> Any, http_trace_impl);
 
 // =========================================================================
 // Registration
 // =========================================================================
 
 pub fn register(registry: &mut FunctionRegistry) {
-	register_functions!(registry, HttpHead, HttpGet, HttpPut, HttpPost, HttpPatch, HttpDelete,);
+	register_functions!(
+		registry,
+		HttpHead,
+		HttpGet,
+		HttpGetWithMeta,
+		HttpStream,
+		HttpPut,
+		HttpPost,
+		HttpPatch,
+		HttpDelete,
+		HttpOptions,
+		HttpTrace,
+	);
 }
@@ -29,6 +29,7 @@ define_pure_function!(MathBottom, "math::bottom", (array: Any, count: Int) -> An
 define_pure_function!(MathFixed, "math::fixed", (value: Number, precision: Int) -> Number, crate::fnc::math::fixed);
 define_pure_function!(MathLog, "math::log", (value: Number, base: Number) -> Number, crate::fnc::math::log);
 define_pure_function!(MathPow, "math::pow", (base: Number, exponent: Number) -> Number, crate::fnc::math::pow);
+define_pure_function!(MathTimeWeightedAvg, "math::time_weighted_avg", (samples: Any) -> Number, crate::fnc::math::time_weighted_avg);
 define_pure_function!(MathTop, "math::top", (array: Any, count: Int) -> Any, crate::fnc::math::top);
 
 // Three argument math functions
@@ -54,6 +55,11 @@ define_pure_function!(MathVariance, "math::variance", (array: Any) -> Number, cr
 // Two argument array aggregate functions
 define_pure_function!(MathNearestrank, "math::nearestrank", (array: Any, percentile: Number) -> Number, crate::fnc::math::nearestrank);
 define_pure_function!(MathPercentile, "math::percentile", (array: Any, percentile: Number) -> Number, crate::fnc::math::percentile);
+define_pure_function!(MathEma, "math::ema", (array: Any, alpha: Number) -> Any, crate::fnc::math::ema);
+define_pure_function!(MathMovingAverage, "math::moving_average", (array: Any, window: Int) -> Any, crate::fnc::math::moving_average);
+
+// Value and array functions
+define_pure_function!(MathPercentRank, "math::percent_rank", (value: Number, array: Any) -> Float, crate::fnc::math::percent_rank);
 
 pub fn register(registry: &mut FunctionRegistry) {
 	register_functions!(
@@ -69,6 +75,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 		MathCos,
 		MathCot,
 		MathDeg2rad,
+		MathEma,
 		MathFixed,
 		MathFloor,
 		MathInterquartile,
@@ -84,7 +91,9 @@ pub fn register(registry: &mut FunctionRegistry) {
 		MathMidhinge,
 		MathMin,
 		MathMode,
+		MathMovingAverage,
 		MathNearestrank,
+		MathPercentRank,
 		MathPercentile,
 		MathPow,
 		MathProduct,
@@ -97,6 +106,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 		MathStddev,
 		MathSum,
 		MathTan,
+		MathTimeWeightedAvg,
 		MathTop,
 		MathTrimean,
 		MathVariance,
@@ -1,11 +1,20 @@
-//! Meta functions (aliases for record functions)
+//! Meta functions (aliases for record functions, and storage engine information)
+
+use anyhow::Result;
 
 use crate::exec::function::FunctionRegistry;
-use crate::{define_pure_function, register_functions};
+use crate::exec::physical_expr::EvalContext;
+use crate::val::Value;
+use crate::{define_context_function, define_pure_function, register_functions};
+
+fn meta_engine_impl(ctx: &EvalContext<'_>) -> Result<Value> {
+	Ok(crate::fnc::meta::engine_info(&ctx.txn()))
+}
 
+define_context_function!(MetaEngine, "meta::engine", () -> Object, meta_engine_impl);
 define_pure_function!(MetaId, "meta::id", (record: Any) -> Any, crate::fnc::record::id);
 define_pure_function!(MetaTb, "meta::tb", (record: Any) -> String, crate::fnc::record::tb);
 
 pub fn register(registry: &mut FunctionRegistry) {
-	register_functions!(registry, MetaId, MetaTb);
+	register_functions!(registry, MetaEngine, MetaId, MetaTb);
 }
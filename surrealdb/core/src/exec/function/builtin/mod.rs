@@ -10,16 +10,20 @@ mod bytes;
 mod count;
 mod crypto;
 mod crypto_async;
+mod debug;
 mod duration;
 mod encoding;
 mod file;
 mod geo;
+mod graph;
 mod http;
 mod math;
 mod meta;
 mod not;
+mod number;
 mod object;
 mod parse;
+mod query;
 mod rand;
 mod record;
 mod schema;
@@ -29,6 +33,7 @@ mod session;
 mod set;
 mod sleep;
 mod string;
+mod table;
 mod time;
 mod r#type;
 mod value;
@@ -45,16 +50,20 @@ pub fn register_all(registry: &mut FunctionRegistry) {
 	count::register(registry);
 	crypto::register(registry);
 	crypto_async::register(registry);
+	debug::register(registry);
 	duration::register(registry);
 	encoding::register(registry);
 	file::register(registry);
 	geo::register(registry);
+	graph::register(registry);
 	http::register(registry);
 	math::register(registry);
 	meta::register(registry);
 	not::register(registry);
+	number::register(registry);
 	object::register(registry);
 	parse::register(registry);
+	query::register(registry);
 	rand::register(registry);
 	record::register(registry);
 	schema::register(registry);
@@ -64,6 +73,7 @@ pub fn register_all(registry: &mut FunctionRegistry) {
 	set::register(registry);
 	sleep::register(registry);
 	string::register(registry);
+	table::register(registry);
 	time::register(registry);
 	r#type::register(registry);
 	value::register(registry);
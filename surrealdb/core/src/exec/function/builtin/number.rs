@@ -0,0 +1,11 @@
+//! Number functions
+
+use crate::exec::function::FunctionRegistry;
+use crate::{define_pure_function, register_functions};
+
+define_pure_function!(NumberFromBase, "number::from_base", (value: String, base: Int) -> Int, crate::fnc::number::from_base);
+define_pure_function!(NumberToBase, "number::to_base", (value: Int, base: Int) -> String, crate::fnc::number::to_base);
+
+pub fn register(registry: &mut FunctionRegistry) {
+	register_functions!(registry, NumberFromBase, NumberToBase,);
+}
@@ -0,0 +1,11 @@
+//! Query functions
+
+use crate::exec::function::FunctionRegistry;
+use crate::{define_pure_function, register_functions};
+
+define_pure_function!(QueryEscapeIdent, "query::escape_ident", (ident: String) -> String, crate::fnc::query::escape_ident);
+define_pure_function!(QueryEscapeLiteral, "query::escape_literal", (value: Any) -> String, crate::fnc::query::escape_literal);
+
+pub fn register(registry: &mut FunctionRegistry) {
+	register_functions!(registry, QueryEscapeIdent, QueryEscapeLiteral);
+}
@@ -9,6 +9,8 @@ define_pure_function!(RandBool, "rand::bool", () -> Bool, crate::fnc::rand::bool
 define_pure_function!(RandUlid, "rand::ulid", () -> String, crate::fnc::rand::ulid);
 define_pure_function!(RandUuid, "rand::uuid", () -> Uuid, crate::fnc::rand::uuid);
 define_pure_function!(RandUuidV4, "rand::uuid::v4", () -> Uuid, crate::fnc::rand::uuid::v4);
+define_pure_function!(RandUlidFromDatetime, "rand::ulid::from_datetime", (datetime: Datetime) -> String, crate::fnc::rand::ulid::from_datetime);
+define_pure_function!(RandUuidV5, "rand::uuid::v5", (namespace: Any, name: String) -> Uuid, crate::fnc::rand::uuid::v5);
 define_pure_function!(RandUuidV7, "rand::uuid::v7", () -> Uuid, crate::fnc::rand::uuid::v7);
 
 // Functions with optional arguments - use variadic for flexibility
@@ -18,6 +20,8 @@ define_pure_function!(RandId, "rand::id", (?length: Int, ?charset: String) -> St
 define_pure_function!(RandInt, "rand::int", (?min: Int, ?max: Int) -> Int, crate::fnc::rand::int);
 define_pure_function!(RandString, "rand::string", (?length: Int, ?charset: String) -> String, crate::fnc::rand::string);
 define_pure_function!(RandTime, "rand::time", (?min: Datetime, ?max: Datetime) -> Datetime, crate::fnc::rand::time);
+define_pure_function!(RandWeighted, "rand::weighted", (items: Any) -> Any, crate::fnc::rand::weighted);
+define_pure_function!(RandWeightedMany, "rand::weighted_many", (items: Any, n: Int, ?replace: Bool) -> Any, crate::fnc::rand::weighted_many);
 
 // Variadic function
 define_pure_function!(RandEnum, "rand::enum", (...values: Any) -> Any, crate::fnc::rand::r#enum);
@@ -35,8 +39,12 @@ pub fn register(registry: &mut FunctionRegistry) {
 		RandString,
 		RandTime,
 		RandUlid,
+		RandUlidFromDatetime,
 		RandUuid,
 		RandUuidV4,
+		RandUuidV5,
 		RandUuidV7,
+		RandWeighted,
+		RandWeightedMany,
 	);
 }
@@ -13,6 +13,8 @@ use crate::{define_pure_function, register_functions};
 define_pure_function!(RecordId, "record::id", (record: Any) -> Any, crate::fnc::record::id);
 define_pure_function!(RecordTb, "record::tb", (record: Any) -> String, crate::fnc::record::tb);
 define_pure_function!(RecordTable, "record::table", (record: Any) -> String, crate::fnc::record::tb);
+define_pure_function!(RecordParse, "record::parse", (value: String) -> Object, crate::fnc::record::parse);
+define_pure_function!(RecordRange, "record::range", (table: Any, start: Any, end: Any) -> Any, crate::fnc::record::range);
 
 // =========================================================================
 // record::exists - Check if a record exists in the database
@@ -121,7 +123,7 @@ impl ScalarFunction for RecordIsEdge {
 }
 
 pub fn register(registry: &mut FunctionRegistry) {
-	register_functions!(registry, RecordId, RecordTb, RecordTable);
+	register_functions!(registry, RecordId, RecordTb, RecordTable, RecordParse, RecordRange);
 	registry.register(RecordExists);
 	registry.register(RecordIsEdge);
 }
@@ -8,6 +8,51 @@ use crate::expr::Kind;
 use crate::fnc::args::FromArgs;
 use crate::val::Value;
 
+// =========================================================================
+// schema::diff - Compare the definitions of two tables
+// =========================================================================
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SchemaDiff;
+
+impl ScalarFunction for SchemaDiff {
+	fn name(&self) -> &'static str {
+		"schema::diff"
+	}
+
+	fn signature(&self) -> Signature {
+		Signature::new()
+			.arg("table_a", Kind::String)
+			.arg("table_b", Kind::String)
+			.returns(Kind::Object)
+	}
+
+	fn is_pure(&self) -> bool {
+		false
+	}
+
+	fn is_async(&self) -> bool {
+		true
+	}
+
+	fn invoke(&self, _args: Vec<Value>) -> Result<Value> {
+		Err(anyhow::anyhow!("Function '{}' requires async execution", self.name()))
+	}
+
+	fn invoke_async<'a>(
+		&'a self,
+		ctx: &'a EvalContext<'_>,
+		args: Vec<Value>,
+	) -> crate::exec::BoxFut<'a, Result<Value>> {
+		Box::pin(async move {
+			let args = FromArgs::from_args("schema::diff", args)?;
+			let frozen = ctx.exec_ctx.ctx();
+			let opt = ctx.exec_ctx.options();
+			crate::fnc::schema::diff((frozen, opt), args).await
+		})
+	}
+}
+
 // =========================================================================
 // schema::table::exists - Check if a table exists
 // =========================================================================
@@ -51,5 +96,6 @@ impl ScalarFunction for SchemaTableExists {
 }
 
 pub fn register(registry: &mut FunctionRegistry) {
+	registry.register(SchemaDiff);
 	registry.register(SchemaTableExists);
 }
@@ -37,6 +37,10 @@ fn session_id_impl(ctx: &EvalContext<'_>) -> Result<Value> {
 		.unwrap_or(Value::None))
 }
 
+fn session_info_impl(ctx: &EvalContext<'_>) -> Result<Value> {
+	Ok(crate::fnc::session::info_object(ctx.exec_ctx.ctx(), ctx.exec_ctx.auth()))
+}
+
 fn session_ip_impl(ctx: &EvalContext<'_>) -> Result<Value> {
 	Ok(ctx
 		.session()
@@ -76,6 +80,7 @@ fn session_token_impl(ctx: &EvalContext<'_>) -> Result<Value> {
 define_context_function!(SessionAc, "session::ac", () -> Any, session_ac_impl);
 define_context_function!(SessionDb, "session::db", () -> Any, session_db_impl);
 define_context_function!(SessionId, "session::id", () -> Any, session_id_impl);
+define_context_function!(SessionInfo, "session::info", () -> Object, session_info_impl);
 define_context_function!(SessionIp, "session::ip", () -> Any, session_ip_impl);
 define_context_function!(SessionNs, "session::ns", () -> Any, session_ns_impl);
 define_context_function!(SessionOrigin, "session::origin", () -> Any, session_origin_impl);
@@ -92,6 +97,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 		SessionAc,
 		SessionDb,
 		SessionId,
+		SessionInfo,
 		SessionIp,
 		SessionNs,
 		SessionOrigin,
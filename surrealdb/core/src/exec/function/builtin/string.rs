@@ -7,6 +7,7 @@ use crate::{define_pure_function, register_functions};
 define_pure_function!(StringCapitalize, "string::capitalize", (value: String) -> String, crate::fnc::string::capitalize);
 define_pure_function!(StringLen, "string::len", (value: String) -> Int, crate::fnc::string::len);
 define_pure_function!(StringLowercase, "string::lowercase", (value: String) -> String, crate::fnc::string::lowercase);
+define_pure_function!(StringNormalizeUrl, "string::normalize_url", (value: String) -> Any, crate::fnc::string::normalize_url);
 define_pure_function!(StringReverse, "string::reverse", (value: String) -> String, crate::fnc::string::reverse);
 define_pure_function!(StringSlug, "string::slug", (value: String) -> String, crate::fnc::string::slug);
 define_pure_function!(StringTrim, "string::trim", (value: String) -> String, crate::fnc::string::trim);
@@ -16,20 +17,27 @@ define_pure_function!(StringWords, "string::words", (value: String) -> Any, crat
 // Two string argument functions
 define_pure_function!(StringContains, "string::contains", (value: String, search: String) -> Bool, crate::fnc::string::contains);
 define_pure_function!(StringEndsWith, "string::ends_with", (value: String, suffix: String) -> Bool, crate::fnc::string::ends_with);
+define_pure_function!(StringFuzzyScore, "string::fuzzy_score", (a: String, b: String) -> Float, crate::fnc::string::fuzzy_score);
 define_pure_function!(StringMatches, "string::matches", (value: String, pattern: String) -> Bool, crate::fnc::string::matches);
 define_pure_function!(StringRepeat, "string::repeat", (value: String, count: Int) -> String, crate::fnc::string::repeat);
 define_pure_function!(StringSplit, "string::split", (value: String, delimiter: String) -> Any, crate::fnc::string::split);
 define_pure_function!(StringStartsWith, "string::starts_with", (value: String, prefix: String) -> Bool, crate::fnc::string::starts_with);
 
 // Three argument string functions
+define_pure_function!(StringFuzzyMatch, "string::fuzzy_match", (haystack: String, needle: String, threshold: Float) -> Bool, crate::fnc::string::fuzzy_match);
 define_pure_function!(StringReplace, "string::replace", (value: String, search: String, replacement: String) -> String, crate::fnc::string::replace);
 define_pure_function!(StringSlice, "string::slice", (value: String, start: Int, ?length: Int) -> String, crate::fnc::string::slice);
 
+// String parsing functions
+define_pure_function!(StringParseKv, "string::parse_kv", (value: String, ?options: Object) -> Object, crate::fnc::string::parse_kv);
+define_pure_function!(StringParseTemplate, "string::parse_template", (value: String, template: String, ?options: Object) -> Object, crate::fnc::string::parse_template);
+
 // Variadic string functions
 define_pure_function!(StringConcat, "string::concat", (...values: Any) -> String, crate::fnc::string::concat);
 define_pure_function!(StringJoin, "string::join", (separator: String, ...values: Any) -> String, crate::fnc::string::join);
 
 // String distance functions
+define_pure_function!(StringDistanceDamerau, "string::distance::damerau", (a: String, b: String) -> Int, crate::fnc::string::distance::damerau_levenshtein);
 define_pure_function!(StringDistanceDamerauLevenshtein, "string::distance::damerau_levenshtein", (a: String, b: String) -> Int, crate::fnc::string::distance::damerau_levenshtein);
 define_pure_function!(StringDistanceHamming, "string::distance::hamming", (a: String, b: String) -> Int, crate::fnc::string::distance::hamming);
 define_pure_function!(StringDistanceLevenshtein, "string::distance::levenshtein", (a: String, b: String) -> Int, crate::fnc::string::distance::levenshtein);
@@ -86,6 +94,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 		StringCapitalize,
 		StringConcat,
 		StringContains,
+		StringDistanceDamerau,
 		StringDistanceDamerauLevenshtein,
 		StringDistanceHamming,
 		StringDistanceLevenshtein,
@@ -93,6 +102,8 @@ pub fn register(registry: &mut FunctionRegistry) {
 		StringDistanceNormalizedLevenshtein,
 		StringDistanceOsa,
 		StringEndsWith,
+		StringFuzzyMatch,
+		StringFuzzyScore,
 		StringHtmlEncode,
 		StringHtmlSanitize,
 		StringIsAlpha,
@@ -117,6 +128,9 @@ pub fn register(registry: &mut FunctionRegistry) {
 		StringLen,
 		StringLowercase,
 		StringMatches,
+		StringNormalizeUrl,
+		StringParseKv,
+		StringParseTemplate,
 		StringRepeat,
 		StringReplace,
 		StringReverse,
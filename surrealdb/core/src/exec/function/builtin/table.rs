@@ -0,0 +1,71 @@
+//! Table functions
+
+use anyhow::Result;
+use reblessive::tree::TreeStack;
+
+use crate::exec::function::{FunctionRegistry, ScalarFunction, Signature};
+use crate::exec::physical_expr::EvalContext;
+use crate::expr::Kind;
+use crate::fnc::args::FromArgs;
+use crate::val::Value;
+
+// =========================================================================
+// table::distinct_values - Distinct values of a field on a table
+// =========================================================================
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TableDistinctValues;
+
+impl ScalarFunction for TableDistinctValues {
+	fn name(&self) -> &'static str {
+		"table::distinct_values"
+	}
+
+	fn signature(&self) -> Signature {
+		Signature::new()
+			.arg("table", Kind::String)
+			.arg("field", Kind::String)
+			.arg("limit", Kind::Int)
+			.returns(Kind::Array(Box::new(Kind::Any), None))
+	}
+
+	fn is_pure(&self) -> bool {
+		false
+	}
+
+	fn is_async(&self) -> bool {
+		true
+	}
+
+	fn invoke(&self, _args: Vec<Value>) -> Result<Value> {
+		Err(anyhow::anyhow!("Function '{}' requires async execution", self.name()))
+	}
+
+	fn invoke_async<'a>(
+		&'a self,
+		ctx: &'a EvalContext<'_>,
+		args: Vec<Value>,
+	) -> crate::exec::BoxFut<'a, Result<Value>> {
+		Box::pin(async move {
+			use crate::doc::CursorDoc;
+			let args = FromArgs::from_args("table::distinct_values", args)?;
+			let frozen = ctx.exec_ctx.ctx();
+			let opt = ctx.exec_ctx.options();
+			let doc = ctx
+				.document_root
+				.or(ctx.current_value)
+				.map(|v| CursorDoc::new(None, None, v.clone()));
+			let mut stack = TreeStack::new();
+			stack
+				.enter(|stk| async move {
+					crate::fnc::table::distinct_values((stk, frozen, opt, doc.as_ref()), args).await
+				})
+				.finish()
+				.await
+		})
+	}
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+	registry.register(TableDistinctValues);
+}
@@ -26,6 +26,7 @@ define_pure_function!(TimeYear, "time::year", (value: Datetime) -> Int, crate::f
 define_pure_function!(TimeCeil, "time::ceil", (value: Datetime, duration: Duration) -> Datetime, crate::fnc::time::ceil);
 define_pure_function!(TimeFloor, "time::floor", (value: Datetime, duration: Duration) -> Datetime, crate::fnc::time::floor);
 define_pure_function!(TimeFormat, "time::format", (value: Datetime, format: String) -> String, crate::fnc::time::format);
+define_pure_function!(TimeInZone, "time::in_zone", (value: Datetime, zone: String) -> String, crate::fnc::time::in_zone);
 define_pure_function!(TimeGroup, "time::group", (value: Datetime, group: String) -> Datetime, crate::fnc::time::group);
 define_pure_function!(TimeRound, "time::round", (value: Datetime, duration: Duration) -> Datetime, crate::fnc::time::round);
 define_pure_function!(TimeSetYear, "time::set_year", (dt: Datetime, year: Number) -> Datetime, crate::fnc::time::set_year);
@@ -36,6 +37,10 @@ define_pure_function!(TimeSetMinute, "time::set_minute", (dt: Datetime, minute:
 define_pure_function!(TimeSetSecond, "time::set_second", (dt: Datetime, minute: Number) -> Datetime, crate::fnc::time::set_second);
 define_pure_function!(TimeSetNanosecond, "time::set_nanosecond", (dt: Datetime, nanos: Number) -> Datetime, crate::fnc::time::set_nanosecond);
 
+// Three argument time functions
+define_pure_function!(TimeAddBusinessDays, "time::add_business_days", (value: Datetime, days: Int, ?holidays: Any) -> Datetime, crate::fnc::time::add_business_days);
+define_pure_function!(TimeFormatZoned, "time::format_zoned", (value: Datetime, format: String, zone: String) -> String, crate::fnc::time::format_zoned);
+
 // Array argument functions
 define_pure_function!(TimeMax, "time::max", (array: Any) -> Datetime, crate::fnc::time::max);
 define_pure_function!(TimeMin, "time::min", (array: Any) -> Datetime, crate::fnc::time::min);
@@ -55,10 +60,12 @@ define_pure_function!(TimeIsLeapYear, "time::is_leap_year", (value: Datetime) ->
 pub fn register(registry: &mut FunctionRegistry) {
 	register_functions!(
 		registry,
+		TimeAddBusinessDays,
 		TimeCeil,
 		TimeDay,
 		TimeFloor,
 		TimeFormat,
+		TimeFormatZoned,
 		TimeFromMicros,
 		TimeFromMillis,
 		TimeFromNanos,
@@ -68,6 +75,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 		TimeFromUuid,
 		TimeGroup,
 		TimeHour,
+		TimeInZone,
 		TimeIsLeapYear,
 		TimeMax,
 		TimeMicros,
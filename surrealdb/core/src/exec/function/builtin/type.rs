@@ -24,7 +24,9 @@ define_pure_function!(TypeDuration, "type::duration", (value: Any) -> Duration,
 define_pure_function!(TypeFile, "type::file", (bucket: String, key: String) -> Any, crate::fnc::r#type::file);
 define_pure_function!(TypeFloat, "type::float", (value: Any) -> Float, crate::fnc::r#type::float);
 define_pure_function!(TypeGeometry, "type::geometry", (value: Any) -> Any, crate::fnc::r#type::geometry);
+define_pure_function!(TypeIdFrom, "type::id_from", (table: Any, value: Any) -> Any, crate::fnc::r#type::id_from);
 define_pure_function!(TypeInt, "type::int", (value: Any) -> Int, crate::fnc::r#type::int);
+define_pure_function!(TypeIs, "type::is", (value: Any, kind: String) -> Bool, crate::fnc::r#type::is_kind);
 define_pure_function!(TypeNumber, "type::number", (value: Any) -> Number, crate::fnc::r#type::number);
 define_pure_function!(TypeOf, "type::of", (value: Any) -> String, crate::fnc::r#type::type_of);
 define_pure_function!(TypePoint, "type::point", (value: Any, ?y: Any) -> Any, crate::fnc::r#type::point);
@@ -204,7 +206,9 @@ pub fn register(registry: &mut FunctionRegistry) {
 		TypeFile,
 		TypeFloat,
 		TypeGeometry,
+		TypeIdFrom,
 		TypeInt,
+		TypeIs,
 		TypeIsArray,
 		TypeIsBool,
 		TypeIsBytes,
@@ -3,6 +3,7 @@
 use anyhow::Result;
 use reblessive::tree::TreeStack;
 
+use crate::define_pure_function;
 use crate::exec::function::{FunctionRegistry, ScalarFunction, Signature};
 use crate::exec::physical_expr::EvalContext;
 use crate::expr::Kind;
@@ -142,7 +143,21 @@ impl ScalarFunction for ValueChain {
 	}
 }
 
+// =========================================================================
+// value::canonicalize - Return the canonical form of a value
+// =========================================================================
+
+define_pure_function!(ValueCanonicalize, "value::canonicalize", (value: Any, ?sort_arrays: Bool) -> Any, crate::fnc::value::canonicalize);
+
+// =========================================================================
+// value::deduplicate_refs - Replace repeated record references with markers
+// =========================================================================
+
+define_pure_function!(ValueDeduplicateRefs, "value::deduplicate_refs", (value: Any) -> Any, crate::fnc::value::deduplicate_refs);
+
 pub fn register(registry: &mut FunctionRegistry) {
+	registry.register(ValueCanonicalize);
+	registry.register(ValueDeduplicateRefs);
 	registry.register(ValueDiff);
 	registry.register(ValuePatch);
 	registry.register(ValueChain);
@@ -278,10 +278,15 @@ pub fn build_method_registry(funcs: &FunctionRegistry) -> MethodRegistry {
 	m.register_typed("concat", ValueKind::String, get(funcs, "string::concat"));
 	m.register_typed("contains", ValueKind::String, get(funcs, "string::contains"));
 	m.register_typed("ends_with", ValueKind::String, get(funcs, "string::ends_with"));
+	m.register_typed("fuzzy_match", ValueKind::String, get(funcs, "string::fuzzy_match"));
+	m.register_typed("fuzzy_score", ValueKind::String, get(funcs, "string::fuzzy_score"));
 	m.register_typed("join", ValueKind::String, get(funcs, "string::join"));
 	m.register_typed("len", ValueKind::String, get(funcs, "string::len"));
 	m.register_typed("lowercase", ValueKind::String, get(funcs, "string::lowercase"));
 	m.register_typed("matches", ValueKind::String, get(funcs, "string::matches"));
+	m.register_typed("normalize_url", ValueKind::String, get(funcs, "string::normalize_url"));
+	m.register_typed("parse_kv", ValueKind::String, get(funcs, "string::parse_kv"));
+	m.register_typed("parse_template", ValueKind::String, get(funcs, "string::parse_template"));
 	// String has its own repeat, overriding the generic array::repeat
 	m.register_typed("repeat", ValueKind::String, get(funcs, "string::repeat"));
 	m.register_typed("replace", ValueKind::String, get(funcs, "string::replace"));
@@ -423,6 +428,7 @@ pub fn build_method_registry(funcs: &FunctionRegistry) -> MethodRegistry {
 	m.register_typed("concat", ValueKind::Array, get(funcs, "array::concat"));
 	m.register_typed("difference", ValueKind::Array, get(funcs, "array::difference"));
 	m.register_typed("distinct", ValueKind::Array, get(funcs, "array::distinct"));
+	m.register_typed("edit_script", ValueKind::Array, get(funcs, "array::edit_script"));
 	m.register_typed("fill", ValueKind::Array, get(funcs, "array::fill"));
 	m.register_typed("filter", ValueKind::Array, get(funcs, "array::filter"));
 	m.register_typed("filter_index", ValueKind::Array, get(funcs, "array::filter_index"));
@@ -444,7 +450,10 @@ pub fn build_method_registry(funcs: &FunctionRegistry) -> MethodRegistry {
 	m.register_typed("matches", ValueKind::Array, get(funcs, "array::matches"));
 	m.register_typed("map", ValueKind::Array, get(funcs, "array::map"));
 	m.register_typed("max", ValueKind::Array, get(funcs, "array::max"));
+	m.register_typed("merge_sorted", ValueKind::Array, get(funcs, "array::merge_sorted"));
+	m.register_typed("merge_sorted_by", ValueKind::Array, get(funcs, "array::merge_sorted_by"));
 	m.register_typed("min", ValueKind::Array, get(funcs, "array::min"));
+	m.register_typed("partition", ValueKind::Array, get(funcs, "array::partition"));
 	m.register_typed("pop", ValueKind::Array, get(funcs, "array::pop"));
 	m.register_typed("prepend", ValueKind::Array, get(funcs, "array::prepend"));
 	m.register_typed("push", ValueKind::Array, get(funcs, "array::push"));
@@ -461,9 +470,11 @@ pub fn build_method_registry(funcs: &FunctionRegistry) -> MethodRegistry {
 		ValueKind::Array,
 		get(funcs, "array::sort_natural_lexical"),
 	);
+	m.register_typed("sort_by_keys", ValueKind::Array, get(funcs, "array::sort_by_keys"));
 	m.register_typed("swap", ValueKind::Array, get(funcs, "array::swap"));
 	m.register_typed("transpose", ValueKind::Array, get(funcs, "array::transpose"));
 	m.register_typed("union", ValueKind::Array, get(funcs, "array::union"));
+	m.register_typed("unique_by", ValueKind::Array, get(funcs, "array::unique_by"));
 	m.register_typed("sort_asc", ValueKind::Array, get(funcs, "array::sort::asc"));
 	m.register_typed("sort_desc", ValueKind::Array, get(funcs, "array::sort::desc"));
 	m.register_typed("windows", ValueKind::Array, get(funcs, "array::windows"));
@@ -562,12 +573,15 @@ pub fn build_method_registry(funcs: &FunctionRegistry) -> MethodRegistry {
 	// =====================================================================
 	// Object methods
 	// =====================================================================
+	m.register_typed("defaults", ValueKind::Object, get(funcs, "object::defaults"));
 	m.register_typed("entries", ValueKind::Object, get(funcs, "object::entries"));
 	m.register_typed("extend", ValueKind::Object, get(funcs, "object::extend"));
+	m.register_typed("flatten", ValueKind::Object, get(funcs, "object::flatten"));
 	m.register_typed("is_empty", ValueKind::Object, get(funcs, "object::is_empty"));
 	m.register_typed("keys", ValueKind::Object, get(funcs, "object::keys"));
 	m.register_typed("len", ValueKind::Object, get(funcs, "object::len"));
 	m.register_typed("remove", ValueKind::Object, get(funcs, "object::remove"));
+	m.register_typed("unflatten", ValueKind::Object, get(funcs, "object::unflatten"));
 	m.register_typed("values", ValueKind::Object, get(funcs, "object::values"));
 
 	// =====================================================================
@@ -610,6 +624,7 @@ pub fn build_method_registry(funcs: &FunctionRegistry) -> MethodRegistry {
 	m.register_typed("sign", ValueKind::Number, get(funcs, "math::sign"));
 	m.register_typed("sin", ValueKind::Number, get(funcs, "math::sin"));
 	m.register_typed("tan", ValueKind::Number, get(funcs, "math::tan"));
+	m.register_typed("to_base", ValueKind::Number, get(funcs, "number::to_base"));
 
 	// =====================================================================
 	// Geometry (geo) methods
@@ -621,6 +636,7 @@ pub fn build_method_registry(funcs: &FunctionRegistry) -> MethodRegistry {
 	m.register_typed("hash_decode", ValueKind::Geometry, get(funcs, "geo::hash::decode"));
 	m.register_typed("hash_encode", ValueKind::Geometry, get(funcs, "geo::hash::encode"));
 	m.register_typed("is_valid", ValueKind::Geometry, get(funcs, "geo::is_valid"));
+	m.register_typed("within", ValueKind::Geometry, get(funcs, "geo::within"));
 
 	// =====================================================================
 	// RecordId methods
@@ -634,12 +650,19 @@ pub fn build_method_registry(funcs: &FunctionRegistry) -> MethodRegistry {
 	// =====================================================================
 	// Datetime (time) methods
 	// =====================================================================
+	m.register_typed(
+		"add_business_days",
+		ValueKind::Datetime,
+		get(funcs, "time::add_business_days"),
+	);
 	m.register_typed("ceil", ValueKind::Datetime, get(funcs, "time::ceil"));
 	m.register_typed("day", ValueKind::Datetime, get(funcs, "time::day"));
 	m.register_typed("floor", ValueKind::Datetime, get(funcs, "time::floor"));
 	m.register_typed("format", ValueKind::Datetime, get(funcs, "time::format"));
+	m.register_typed("format_zoned", ValueKind::Datetime, get(funcs, "time::format_zoned"));
 	m.register_typed("group", ValueKind::Datetime, get(funcs, "time::group"));
 	m.register_typed("hour", ValueKind::Datetime, get(funcs, "time::hour"));
+	m.register_typed("in_zone", ValueKind::Datetime, get(funcs, "time::in_zone"));
 	m.register_typed("is_leap_year", ValueKind::Datetime, get(funcs, "time::is_leap_year"));
 	m.register_typed("micros", ValueKind::Datetime, get(funcs, "time::micros"));
 	m.register_typed("millis", ValueKind::Datetime, get(funcs, "time::millis"));
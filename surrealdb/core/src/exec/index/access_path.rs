@@ -7,6 +7,7 @@ use std::sync::Arc;
 
 use super::IndexCandidate;
 use crate::catalog::IndexDefinition;
+use crate::err::Error;
 use crate::expr::BinaryOperator;
 use crate::expr::operator::MatchesOperator;
 use crate::expr::with::With;
@@ -222,8 +223,8 @@ pub fn select_access_path(
 	{
 		return candidate.to_access_path(direction);
 	}
-	// If hinted index not found, fall through to best effort
-	// (could also error here, but being lenient)
+	// If hinted index not found, fall through to best effort. A resulting
+	// table scan is then rejected by `check_index_hint`.
 
 	// No candidates - table scan
 	if candidates.is_empty() {
@@ -238,6 +239,21 @@ pub fn select_access_path(
 		.unwrap_or(AccessPath::TableScan)
 }
 
+/// Check that an access path satisfies the WITH INDEX hint, if any.
+///
+/// A WITH INDEX hint forces the use of the given indexes, so a query which
+/// can only be executed with a table scan is rejected.
+pub fn check_index_hint(path: &AccessPath, with_hints: Option<&With>) -> Result<(), Error> {
+	if let Some(With::Index(names)) = with_hints
+		&& matches!(path, AccessPath::TableScan)
+	{
+		return Err(Error::IndexHintNotSatisfied {
+			names: names.join(", "),
+		});
+	}
+	Ok(())
+}
+
 /// Find a candidate matching one of the hinted index names.
 fn find_hinted_index<'a>(
 	candidates: &'a [IndexCandidate],
@@ -66,6 +66,9 @@ impl<'a> IndexAnalyzer<'a> {
 			candidates.retain(|c| names.contains(&c.index_ref.name));
 		}
 
+		// Partial indexes do not hold every document of the table
+		candidates.retain(|c| c.index_ref.cond.is_none());
+
 		// Merge half-bounded ranges on the same index into bounded ranges
 		// (e.g. field > 5 AND field < 10 → Range(>5, <10))
 		self.merge_range_candidates(&mut candidates);
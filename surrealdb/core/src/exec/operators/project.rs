@@ -95,6 +95,16 @@ impl FieldSelection {
 		}
 	}
 
+	/// Create a new field selection with an explicit alias, from the output
+	/// path of the alias.
+	pub fn with_alias_path(output_path: FieldPath, expr: Arc<dyn PhysicalExpr>) -> Self {
+		Self {
+			output_path,
+			expr,
+			has_explicit_alias: true,
+		}
+	}
+
 	/// Create a new field selection from a FieldPath directly.
 	/// Used for graph traversals without aliases where the path represents nested structure.
 	pub fn from_field_path(output_path: FieldPath, expr: Arc<dyn PhysicalExpr>) -> Self {
@@ -91,6 +91,7 @@ pub(crate) async fn fetch_records_batch(
 		.getm_records(ns_id, db_id, rids, version, cache_policy)
 		.await
 		.context("Failed to fetch records")?;
+	txn.record_scanned(records.iter().filter(|r| !r.data.is_none()).count());
 
 	let mut values = Vec::with_capacity(rids.len());
 	for record in records {
@@ -160,6 +161,7 @@ pub(crate) async fn fetch_and_filter_records_batch(
 		.getm_records(ns_id, db_id, rids, version, cache_policy)
 		.await
 		.context("Failed to fetch records")?;
+	txn.record_scanned(records.iter().filter(|r| !r.data.is_none()).count());
 
 	let mut values = Vec::with_capacity(rids.len());
 	for record in records {
@@ -10,7 +10,7 @@ use super::pipeline::{
 use super::{FullTextScan, IndexScan, KnnScan};
 use crate::catalog::{DatabaseId, NamespaceId, Permission};
 use crate::err::Error;
-use crate::exec::index::access_path::{AccessPath, select_access_path};
+use crate::exec::index::access_path::{AccessPath, check_index_hint, select_access_path};
 use crate::exec::index::analysis::IndexAnalyzer;
 use crate::exec::operators::scan::pipeline::ScanPipeline;
 use crate::exec::permission::{
@@ -29,7 +29,7 @@ use crate::expr::order::Ordering;
 use crate::expr::with::With;
 use crate::expr::{Cond, ControlFlow, ControlFlowExt};
 use crate::iam::Action;
-use crate::idx::planner::ScanDirection;
+use crate::idx::planner::{ScanDirection, full_table_scan_warning};
 use crate::key::record;
 use crate::val::{TableName, Value};
 
@@ -603,6 +603,8 @@ async fn resolve_table_scan_stream(
 		}
 	};
 
+	check_index_hint(access_path.as_ref().unwrap_or(&AccessPath::TableScan), cfg.with.as_ref())?;
+
 	match access_path {
 		// B-tree index scan (single-column and compound).
 		// When the LIMIT was pushed into storage, the scan truncates rows
@@ -685,6 +687,9 @@ async fn resolve_table_scan_stream(
 		// Fall back to table KV scan (NOINDEX, BTree rejected by ordering
 		// check, etc.)
 		_ => {
+			if resolved_cond.is_some() && !matches!(&cfg.with, Some(With::NoIndex)) {
+				ctx.root().ctx.add_warning(full_table_scan_warning(&cfg.table_name));
+			}
 			let beg = record::prefix(cfg.ns_id, cfg.db_id, &cfg.table_name)?;
 			let end = record::suffix(cfg.ns_id, cfg.db_id, &cfg.table_name)?;
 			// Enable prefetching for full scans (no limit pushed)
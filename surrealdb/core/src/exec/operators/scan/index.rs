@@ -316,6 +316,9 @@ impl ExecOperator for IndexScan {
 		// Validate record user has access to this namespace/database
 		validate_record_user_access(&db_ctx)?;
 
+		// Count the statement as reading through an index
+		ctx.txn().record_index_used();
+
 		// Check if we need to enforce permissions
 		let check_perms = should_check_perms(&db_ctx, Action::View)?;
 
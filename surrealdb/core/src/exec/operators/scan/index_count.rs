@@ -143,6 +143,7 @@ impl ExecOperator for IndexCountScan {
 	fn execute(&self, ctx: &ExecutionContext) -> FlowResult<ValueBatchStream> {
 		let db_ctx = ctx.database()?.clone();
 		validate_record_user_access(&db_ctx)?;
+		ctx.txn().record_index_used();
 		let check_perms = should_check_perms(&db_ctx, Action::View)?;
 
 		let source_expr = Arc::clone(&self.source);
@@ -229,6 +229,7 @@ pub(crate) fn kv_scan_stream(
 			for (key, val) in entries {
 				batch.push(decode_record(&key, val)?);
 			}
+			txn.record_scanned(batch.len());
 			if !batch.is_empty() {
 				yield ValueBatch { values: batch };
 			}
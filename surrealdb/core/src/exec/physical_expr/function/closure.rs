@@ -124,8 +124,10 @@ impl PhysicalExpr for ClosureCallExec {
 				body,
 				captures,
 			} => {
-				// Create isolated execution context with captured variables
-				let mut isolated_ctx = ctx.exec_ctx.clone();
+				// Create isolated execution context with captured variables,
+				// consuming computation depth just as user-defined functions do
+				let mut isolated_ctx =
+					ctx.exec_ctx.dive(4).map_err(|e| ControlFlow::Err(anyhow::Error::new(e)))?;
 				for (name, value) in captures.clone() {
 					isolated_ctx = isolated_ctx.with_param(name, value);
 				}
@@ -66,7 +66,14 @@ impl PhysicalExpr for UserDefinedFunctionExec {
 		let auth_limit = crate::iam::AuthLimit::try_from(&func_def.auth_limit).map_err(|e| {
 			anyhow::anyhow!("Invalid auth limit on function '{}': {}", func_name, e)
 		})?;
-		let limited_ctx = ctx.exec_ctx.with_limited_auth(&auth_limit);
+		//    Each call also consumes computation depth so that unbounded recursion fails
+		//    cleanly instead of exhausting the stack. Calls are pretty heavy on the stack
+		//    in the streaming executor, so make the increase pretty heavy.
+		let limited_ctx = ctx
+			.exec_ctx
+			.with_limited_auth(&auth_limit)
+			.dive(4)
+			.map_err(|e| ControlFlow::Err(anyhow::Error::new(e)))?;
 		let ctx = EvalContext {
 			exec_ctx: &limited_ctx,
 			current_value: ctx.current_value,
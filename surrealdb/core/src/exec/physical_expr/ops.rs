@@ -48,8 +48,14 @@ impl PhysicalExpr for BinaryOp {
 			BinaryOperator::Add => operate::add(left, eval!(self.right))?,
 			BinaryOperator::Subtract => operate::sub(left, eval!(self.right))?,
 			BinaryOperator::Multiply => operate::mul(left, eval!(self.right))?,
-			BinaryOperator::Divide => operate::div(left, eval!(self.right))?,
-			BinaryOperator::Remainder => operate::rem(left, eval!(self.right))?,
+			BinaryOperator::Divide => {
+				let div_by_zero = ctx.exec_ctx.options().map(|o| o.div_by_zero).unwrap_or_default();
+				operate::div(left, eval!(self.right), div_by_zero)?
+			}
+			BinaryOperator::Remainder => {
+				let div_by_zero = ctx.exec_ctx.options().map(|o| o.div_by_zero).unwrap_or_default();
+				operate::rem(left, eval!(self.right), div_by_zero)?
+			}
 			BinaryOperator::Power => operate::pow(left, eval!(self.right))?,
 
 			BinaryOperator::Equal => operate::equal(&left, &eval!(self.right))?,
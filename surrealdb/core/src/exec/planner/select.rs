@@ -20,21 +20,24 @@ use surrealdb_types::ToSql;
 
 use super::Planner;
 use super::util::{
-	SELECT_ITERATION_PARAMS, all_value_sources, check_forbidden_group_by_params, derive_field_name,
-	extract_bruteforce_knn, extract_count_field_names, extract_matches_context,
-	extract_record_id_point_lookup, extract_version, fold_condition_expressions,
-	get_effective_limit_literal, has_knn_k_operator, has_knn_operator, has_top_level_or,
-	idiom_to_field_name, idiom_to_field_path, index_covers_ordering, is_count_all_eligible,
-	is_indexed_count_eligible, order_is_scan_compatible, resolve_condition_params,
-	resolve_param_value, resolve_projection_field_idioms, strip_fts_condition,
-	strip_index_conditions, strip_knn_from_condition,
+	SELECT_ITERATION_PARAMS, alias_to_field_path, all_value_sources,
+	check_forbidden_group_by_params, derive_field_name, extract_bruteforce_knn,
+	extract_count_field_names, extract_matches_context, extract_record_id_point_lookup,
+	extract_version, fold_condition_expressions, get_effective_limit_literal, has_knn_k_operator,
+	has_knn_operator, has_top_level_or, idiom_to_field_name, idiom_to_field_path,
+	index_covers_ordering, is_count_all_eligible, is_indexed_count_eligible,
+	order_is_scan_compatible, resolve_condition_params, resolve_param_value,
+	resolve_projection_field_idioms, strip_fts_condition, strip_index_conditions,
+	strip_knn_from_condition,
 };
 use crate::catalog::providers::{DatabaseProvider, NamespaceProvider, TableProvider};
 use crate::cnf::MAX_ORDER_LIMIT_PRIORITY_QUEUE_SIZE;
 use crate::err::Error;
 use crate::exec::expression_registry::{ComputePoint, ExpressionRegistry, resolve_order_by_alias};
 use crate::exec::field_path::FieldPath;
-use crate::exec::index::access_path::{AccessPath, BTreeAccess, select_access_path};
+use crate::exec::index::access_path::{
+	AccessPath, BTreeAccess, check_index_hint, select_access_path,
+};
 use crate::exec::index::analysis::IndexAnalyzer;
 #[cfg(all(storage, not(target_family = "wasm")))]
 use crate::exec::operators::ExternalSort;
@@ -49,6 +52,7 @@ use crate::exec::operators::{
 use crate::exec::{ExecOperator, OperatorMetrics};
 use crate::expr::field::{Field, Fields};
 use crate::expr::{Cond, Expr, Idiom, Literal};
+use crate::idx::planner::full_table_scan_warning;
 
 /// Configuration for the SELECT pipeline.
 ///
@@ -58,6 +62,7 @@ pub(crate) struct SelectPipelineConfig {
 	pub cond: Option<crate::expr::cond::Cond>,
 	pub split: Option<crate::expr::split::Splits>,
 	pub group: Option<crate::expr::group::Groups>,
+	pub having: Option<crate::expr::cond::Cond>,
 	pub order: Option<crate::expr::order::Ordering>,
 	pub limit: Option<crate::expr::limit::Limit>,
 	pub start: Option<crate::expr::start::Start>,
@@ -202,6 +207,7 @@ impl<'ctx> Planner<'ctx> {
 			cond,
 			split,
 			group,
+			having,
 			order,
 			limit,
 			start,
@@ -249,6 +255,14 @@ impl<'ctx> Planner<'ctx> {
 			(split_op, false)
 		};
 
+		// Filter the aggregated rows by any HAVING clause
+		let grouped = if let Some(having) = having {
+			let predicate = self.physical_expr(having.0).await?;
+			Arc::new(Filter::new(grouped, predicate)) as Arc<dyn ExecOperator>
+		} else {
+			grouped
+		};
+
 		// Shared expression registry for deduplication across sort and projection.
 		// Expressions computed for ORDER BY are reused by the projection step.
 		// Reserve the SELECT field names so that synthetic `_eN` names never
@@ -413,9 +427,15 @@ impl<'ctx> Planner<'ctx> {
 				for field in field_list {
 					if let Field::Single(selector) = field {
 						let field_selection = if let Some(alias) = &selector.alias {
-							let output_name = idiom_to_field_name(alias);
 							let expr = self.physical_expr(selector.expr).await?;
-							FieldSelection::with_alias(output_name, expr)
+							match alias_to_field_path(alias) {
+								Some(output_path) => {
+									FieldSelection::with_alias_path(output_path, expr)
+								}
+								None => {
+									FieldSelection::with_alias(idiom_to_field_name(alias), expr)
+								}
+							}
 						} else {
 							let output_name_or_path = match &selector.expr {
 								Expr::Idiom(idiom) => Ok(idiom_to_field_path(idiom)),
@@ -554,10 +574,15 @@ impl<'ctx> Planner<'ctx> {
 								// nested object construction, which SelectProject
 								// doesn't support. Fall back to the full Project
 								// operator which handles this via
-								// parse_output_path + set_field_on_object.
-								if output_name.contains('.')
-									&& !output_name.contains(['[', '(', ' '])
-								{
+								// set_field_on_object.
+								let nested = match alias_to_field_path(alias) {
+									Some(path) => path.0.len() > 1,
+									None => {
+										output_name.contains('.')
+											&& !output_name.contains(['[', '(', ' '])
+									}
+								};
+								if nested {
 									needs_fallback = true;
 									break;
 								}
@@ -1217,8 +1242,8 @@ impl<'ctx> Planner<'ctx> {
 		let explain = select.explain.take();
 		let plan = Box::pin(self.plan_select_core(select)).await?;
 		match explain {
-			Some(crate::expr::explain::Explain(full)) => {
-				if full {
+			Some(explain) => {
+				if explain.executes() {
 					Ok(Arc::new(AnalyzePlan {
 						plan,
 						format: crate::expr::ExplainFormat::Json,
@@ -1253,20 +1278,30 @@ impl<'ctx> Planner<'ctx> {
 			cond,
 			split,
 			group,
+			having,
 			order,
 			limit,
 			start,
 			fetch,
 			version,
 			timeout,
+			scan_timeout,
 			explain: _,
 			tempfiles,
 		} = select;
 
+		if !matches!(scan_timeout, Expr::Literal(Literal::None)) {
+			return Err(Error::PlannerUnimplemented(
+				"SCAN TIMEOUT is not supported in the streaming executor".to_string(),
+			));
+		}
+
 		let version = extract_version(version, self).await?;
 
 		// COUNT fast-path
-		if is_count_all_eligible(&fields, &group, &cond, &split, &order, &fetch, &omit, &what) {
+		if having.is_none()
+			&& is_count_all_eligible(&fields, &group, &cond, &split, &order, &fetch, &omit, &what)
+		{
 			use crate::exec::operators::CountScan;
 			let table_expr = self
 				.physical_expr(what.into_iter().next().expect("what verified non-empty"))
@@ -1295,8 +1330,10 @@ impl<'ctx> Planner<'ctx> {
 		// Indexed COUNT fast-path (COUNT with WHERE + matching COUNT index)
 		// Skip when WITH NOINDEX is specified — the user explicitly forbids
 		// index-assisted execution.
-		if is_indexed_count_eligible(&fields, &group, &cond, &split, &order, &fetch, &omit, &what)
-			&& !matches!(with, Some(crate::expr::with::With::NoIndex))
+		if having.is_none()
+			&& is_indexed_count_eligible(
+				&fields, &group, &cond, &split, &order, &fetch, &omit, &what,
+			) && !matches!(with, Some(crate::expr::with::With::NoIndex))
 		{
 			// Try COUNT index first, then B-tree index for key-only counting.
 			let has_count_idx = self.has_matching_count_index(&what, &cond).await;
@@ -1674,6 +1711,7 @@ impl<'ctx> Planner<'ctx> {
 			},
 			split,
 			group,
+			having,
 			order,
 			limit: if planned.limit_pushed {
 				None
@@ -1833,6 +1871,9 @@ impl<'ctx> Planner<'ctx> {
 
 			let resolved =
 				self.resolve_access_path(txn, ns, db, table_name, cond, order, with).await;
+			if let Ok(Some((access_path, _))) = &resolved {
+				check_index_hint(access_path, with)?;
+			}
 			if let Ok(Some((access_path, direction))) = resolved {
 				let table = table_name.clone();
 				let knn_ctx = self.ctx.get_knn_context().cloned();
@@ -1973,6 +2014,10 @@ impl<'ctx> Planner<'ctx> {
 						});
 					}
 					AccessPath::TableScan => {
+						if cond.is_some() && !matches!(with, Some(crate::expr::with::With::NoIndex))
+						{
+							self.ctx.add_warning(full_table_scan_warning(&table));
+						}
 						let filter_action = filter_action_for_predicate(&scan_predicate);
 						// TableScan can only provide ordering for `id ASC/DESC`.
 						// Push limit only when ORDER BY is compatible with the
@@ -336,6 +336,7 @@ impl<'ctx> Planner<'ctx> {
 				cond,
 				split,
 				group,
+				having: None,
 				order,
 				limit,
 				start,
@@ -1508,6 +1508,24 @@ pub(super) fn idiom_to_field_path(idiom: &crate::expr::idiom::Idiom) -> FieldPat
 	FieldPath(parts)
 }
 
+/// Extract the output path of a field alias for nested output construction.
+///
+/// Each field part of the alias is a level of nesting, so `AS x.y` builds
+/// `{ x: { y: .. } }`, while a quoted alias such as `` AS `x.y` `` remains a
+/// single field. Returns `None` for aliases containing any other parts.
+pub(super) fn alias_to_field_path(alias: &crate::expr::idiom::Idiom) -> Option<FieldPath> {
+	use crate::expr::part::Part;
+
+	alias
+		.iter()
+		.map(|part| match part {
+			Part::Field(name) => Some(FieldPathPart::Field(name.clone())),
+			_ => None,
+		})
+		.collect::<Option<Vec<_>>>()
+		.map(FieldPath)
+}
+
 // ============================================================================
 // COUNT() Fast-Path Detection
 // ============================================================================
@@ -1537,6 +1555,10 @@ pub(super) fn is_indexed_count_eligible(
 	if !fields.is_count_all_only() {
 		return false;
 	}
+	// Nested aliases require the full projection to build the output.
+	if has_nested_alias(fields) {
+		return false;
+	}
 	// Must have GROUP ALL.
 	let Some(groups) = group else {
 		return false;
@@ -1580,6 +1602,10 @@ pub(super) fn is_count_all_eligible(
 	if !fields.is_count_all_only() {
 		return false;
 	}
+	// Nested aliases require the full projection to build the output.
+	if has_nested_alias(fields) {
+		return false;
+	}
 	// Must have GROUP ALL (explicit `GROUP ALL` in the AST = Some(Groups(vec![]))).
 	let Some(groups) = group else {
 		return false;
@@ -1608,6 +1634,20 @@ pub(super) fn is_count_all_eligible(
 	)
 }
 
+/// Returns `true` if any field is aliased to a nested output path.
+fn has_nested_alias(fields: &Fields) -> bool {
+	let is_nested = |alias: &Option<crate::expr::idiom::Idiom>| {
+		alias.as_ref().and_then(alias_to_field_path).is_some_and(|path| path.0.len() > 1)
+	};
+	match fields {
+		Fields::Value(selector) => is_nested(&selector.alias),
+		Fields::Select(field_list) => field_list.iter().any(|f| match f {
+			Field::Single(selector) => is_nested(&selector.alias),
+			_ => false,
+		}),
+	}
+}
+
 /// Extract the output field names for a CountScan fast-path query.
 ///
 /// For each `count()` field in the SELECT list, this returns the alias name
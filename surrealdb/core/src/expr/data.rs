@@ -9,6 +9,7 @@ pub(crate) enum Data {
 	UnsetExpression(Vec<Idiom>),
 	PatchExpression(Expr),
 	MergeExpression(Expr),
+	MergeEachExpression(Expr),
 	ReplaceExpression(Expr),
 	ContentExpression(Expr),
 	SingleExpression(Expr),
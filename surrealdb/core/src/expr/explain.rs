@@ -1,7 +1,19 @@
 use surrealdb_types::{SqlFormat, ToSql};
 
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
-pub(crate) struct Explain(pub bool);
+pub(crate) enum Explain {
+	#[default]
+	Plan,
+	Full,
+	Analyze,
+}
+
+impl Explain {
+	/// Whether the statement is executed, rather than only planned
+	pub(crate) fn executes(&self) -> bool {
+		!matches!(self, Self::Plan)
+	}
+}
 
 impl ToSql for Explain {
 	fn fmt_sql(&self, f: &mut String, sql_fmt: SqlFormat) {
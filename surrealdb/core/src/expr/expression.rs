@@ -586,10 +586,12 @@ impl Expr {
 				fnc::operate::mul(left, stk.run(|stk| right.compute(stk, ctx, opt, doc)).await?)
 			}
 			BinaryOperator::Divide => {
-				fnc::operate::div(left, stk.run(|stk| right.compute(stk, ctx, opt, doc)).await?)
+				let right = stk.run(|stk| right.compute(stk, ctx, opt, doc)).await?;
+				fnc::operate::div(left, right, opt.div_by_zero)
 			}
 			BinaryOperator::Remainder => {
-				fnc::operate::rem(left, stk.run(|stk| right.compute(stk, ctx, opt, doc)).await?)
+				let right = stk.run(|stk| right.compute(stk, ctx, opt, doc)).await?;
+				fnc::operate::rem(left, right, opt.div_by_zero)
 			}
 			BinaryOperator::Power => {
 				fnc::operate::pow(left, stk.run(|stk| right.compute(stk, ctx, opt, doc)).await?)
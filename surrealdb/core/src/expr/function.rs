@@ -33,15 +33,19 @@ pub(crate) enum Function {
 impl Function {
 	/// Convert function call to a field name
 	pub(crate) fn to_idiom(&self) -> Idiom {
+		Idiom::field(self.name())
+	}
+
+	/// Returns the name of the function, as it is written when called
+	pub(crate) fn name(&self) -> String {
 		match self {
-			// Safety: "function" does not contain null bytes"
-			Self::Script(_) => Idiom::field("function".to_owned()),
-			Self::Normal(f) => Idiom::field(f.to_owned()),
-			Self::Custom(f) => Idiom::field(format!("fn::{f}")),
-			Self::Model(m) => Idiom::field(m.to_sql()),
+			Self::Script(_) => "function".to_owned(),
+			Self::Normal(f) => f.to_owned(),
+			Self::Custom(f) => format!("fn::{f}"),
+			Self::Model(m) => m.to_sql(),
 			Self::Module(m, s) => match s {
-				Some(s) => Idiom::field(format!("mod::{m}::{s}")),
-				None => Idiom::field(format!("mod::{m}")),
+				Some(s) => format!("mod::{m}::{s}"),
+				None => format!("mod::{m}"),
 			},
 			Self::Silo {
 				org,
@@ -51,10 +55,8 @@ impl Function {
 				patch,
 				sub,
 			} => match sub {
-				Some(s) => {
-					Idiom::field(format!("silo::{org}::{pkg}<{major}.{minor}.{patch}>::{s}"))
-				}
-				None => Idiom::field(format!("silo::{org}::{pkg}<{major}.{minor}.{patch}>")),
+				Some(s) => format!("silo::{org}::{pkg}<{major}.{minor}.{patch}>::{s}"),
+				None => format!("silo::{org}::{pkg}<{major}.{minor}.{patch}>"),
 			},
 		}
 	}
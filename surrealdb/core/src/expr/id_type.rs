@@ -0,0 +1,31 @@
+use revision::revisioned;
+
+use crate::expr::statements::info::InfoStructure;
+use crate::val::Value;
+
+/// The type of the ids which are generated for new records in a table
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub(crate) enum IdType {
+	/// A random 20 character string
+	#[default]
+	Rand,
+	/// A version 7 UUID
+	Uuid,
+	/// A ULID string
+	Ulid,
+	/// An increasing number, from a sequence bound to the table
+	Sequence,
+}
+
+impl InfoStructure for IdType {
+	fn structure(self) -> Value {
+		match self {
+			Self::Rand => "RAND",
+			Self::Uuid => "UUID",
+			Self::Ulid => "ULID",
+			Self::Sequence => "SEQUENCE",
+		}
+		.into()
+	}
+}
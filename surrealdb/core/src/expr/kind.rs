@@ -181,6 +181,15 @@ impl Kind {
 		}
 	}
 
+	/// Returns true if this type allows null values
+	pub(crate) fn can_be_null(&self) -> bool {
+		match self {
+			Kind::Null | Kind::Any => true,
+			Kind::Either(x) => x.iter().any(|x| x.can_be_null()),
+			_ => false,
+		}
+	}
+
 	/// Returns true if this type is a literal, or contains a literal
 	pub(crate) fn contains_literal(&self) -> bool {
 		match self {
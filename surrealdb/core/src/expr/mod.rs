@@ -24,6 +24,7 @@ pub(crate) mod field;
 pub(crate) mod filter;
 pub(crate) mod function;
 pub(crate) mod group;
+pub(crate) mod id_type;
 pub(crate) mod idiom;
 pub(crate) mod kind;
 pub(crate) mod language;
@@ -47,6 +48,8 @@ pub(crate) mod script;
 pub(crate) mod split;
 pub(crate) mod start;
 pub(crate) mod tokenizer;
+pub(crate) mod ttl;
+pub(crate) mod unknown_fields;
 pub(crate) mod user;
 pub(crate) mod view;
 pub(crate) mod with;
@@ -76,6 +79,7 @@ pub(crate) use self::field::{Field, Fields};
 pub(crate) use self::filter::Filter;
 pub(crate) use self::function::{Function, FunctionCall};
 pub(crate) use self::group::{Group, Groups};
+pub(crate) use self::id_type::IdType;
 pub(crate) use self::idiom::Idiom;
 pub(crate) use self::kind::{Kind, KindLiteral};
 pub(crate) use self::limit::Limit;
@@ -99,6 +103,8 @@ pub(crate) use self::split::{Split, Splits};
 pub(crate) use self::start::Start;
 pub(crate) use self::statements::{DefineAnalyzerStatement, SelectStatement, SleepStatement};
 pub(crate) use self::tokenizer::Tokenizer;
+pub(crate) use self::ttl::Ttl;
+pub(crate) use self::unknown_fields::UnknownFields;
 pub(crate) use self::view::View;
 pub(crate) use self::with::With;
 
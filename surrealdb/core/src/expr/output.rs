@@ -9,6 +9,7 @@ pub(crate) enum Output {
 	None,
 	Null,
 	Diff,
+	Changed,
 	After,
 	Before,
 	Fields(Fields),
@@ -21,6 +22,7 @@ impl ToSql for Output {
 			Self::None => f.push_str("NONE"),
 			Self::Null => f.push_str("NULL"),
 			Self::Diff => f.push_str("DIFF"),
+			Self::Changed => f.push_str("CHANGED"),
 			Self::After => f.push_str("AFTER"),
 			Self::Before => f.push_str("BEFORE"),
 			Self::Fields(v) => match v {
@@ -19,6 +19,7 @@ pub static AC: LazyLock<[Part; 1]> = LazyLock::new(|| [Part::Field(OBJ_PATH_ACCE
 pub static RD: LazyLock<[Part; 1]> = LazyLock::new(|| [Part::Field(OBJ_PATH_AUTH.to_owned())]);
 
 pub static OR: LazyLock<[Part; 1]> = LazyLock::new(|| [Part::Field("or".to_owned())]);
+pub static PR: LazyLock<[Part; 1]> = LazyLock::new(|| [Part::Field("pr".to_owned())]);
 
 pub static TK: LazyLock<[Part; 1]> = LazyLock::new(|| [Part::Field(OBJ_PATH_TOKEN.to_owned())]);
 
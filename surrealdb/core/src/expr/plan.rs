@@ -2,6 +2,7 @@ use crate::expr::Expr;
 use crate::expr::statements::{
 	AccessStatement, KillStatement, LiveStatement, OptionStatement, ShowStatement, UseStatement,
 };
+use crate::kvs::StatementType;
 
 #[derive(Clone, Debug)]
 pub(crate) struct LogicalPlan {
@@ -38,6 +39,45 @@ impl TopLevelExpr {
 			TopLevelExpr::Expr(expr) => expr.read_only(),
 		}
 	}
+
+	/// The type of the statement, used to label the statement metrics
+	pub(crate) fn statement_type(&self) -> StatementType {
+		match self {
+			TopLevelExpr::Begin => StatementType::Begin,
+			TopLevelExpr::Cancel => StatementType::Cancel,
+			TopLevelExpr::Commit => StatementType::Commit,
+			TopLevelExpr::Access(_) => StatementType::Access,
+			TopLevelExpr::Kill(_) => StatementType::Kill,
+			TopLevelExpr::Live(_) => StatementType::Live,
+			TopLevelExpr::Option(_) => StatementType::Option,
+			TopLevelExpr::Use(_) => StatementType::Use,
+			TopLevelExpr::Show(_) => StatementType::Show,
+			TopLevelExpr::Expr(expr) => match expr {
+				Expr::Select(_) => StatementType::Select,
+				Expr::Create(_) => StatementType::Create,
+				Expr::Update(_) => StatementType::Update,
+				Expr::Upsert(_) => StatementType::Upsert,
+				Expr::Delete(_) => StatementType::Delete,
+				Expr::Relate(_) => StatementType::Relate,
+				Expr::Insert(_) => StatementType::Insert,
+				Expr::Define(_) => StatementType::Define,
+				Expr::Remove(_) => StatementType::Remove,
+				Expr::Rebuild(_) => StatementType::Rebuild,
+				Expr::Alter(_) => StatementType::Alter,
+				Expr::Info(_) => StatementType::Info,
+				Expr::Foreach(_) => StatementType::Foreach,
+				Expr::Let(_) => StatementType::Let,
+				Expr::Sleep(_) => StatementType::Sleep,
+				Expr::IfElse(_) => StatementType::IfElse,
+				Expr::Return(_) => StatementType::Return,
+				Expr::Throw(_) => StatementType::Throw,
+				Expr::Explain {
+					..
+				} => StatementType::Explain,
+				_ => StatementType::Expression,
+			},
+		}
+	}
 }
 
 impl surrealdb_types::ToSql for TopLevelExpr {
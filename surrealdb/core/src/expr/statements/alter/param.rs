@@ -10,6 +10,7 @@ use crate::catalog::providers::DatabaseProvider;
 use crate::ctx::FrozenContext;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
+use crate::err::Error;
 use crate::expr::{Base, Expr, FlowResultExt};
 use crate::iam::{Action, ResourceKind};
 use crate::val::Value;
@@ -48,7 +49,15 @@ impl AlterParamStatement {
 		};
 
 		if let Some(ref v) = self.value {
-			pa.value = stk.run(|stk| v.compute(stk, ctx, opt, doc)).await.catch_return()?;
+			let value = stk.run(|stk| v.compute(stk, ctx, opt, doc)).await.catch_return()?;
+			// Ensure the value is of the kind the param was defined with
+			pa.value = match &pa.param_kind {
+				Some(kind) => value.coerce_to_kind(kind).map_err(|e| Error::SetCoerce {
+					name: self.name.clone(),
+					error: Box::new(e),
+				})?,
+				None => value,
+			};
 		}
 
 		match self.comment {
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use anyhow::{Result, bail};
+use futures::channel::oneshot::Receiver;
 use reblessive::tree::Stk;
 use surrealdb_types::ToSql;
 use uuid::Uuid;
@@ -13,7 +14,7 @@ use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
 use crate::expr::parameterize::{expr_to_ident, exprs_to_fields};
-use crate::expr::{Base, Expr, FlowResultExt, Literal, Part};
+use crate::expr::{Base, Cond, Expr, FlowResultExt, Literal, Part};
 use crate::iam::{Action, ResourceKind};
 use crate::val::{TableName, Value};
 
@@ -25,6 +26,7 @@ pub(crate) struct DefineIndexStatement {
 	pub cols: Vec<Expr>,
 	pub index: Index,
 	pub comment: Expr,
+	pub cond: Option<Cond>,
 	pub concurrently: bool,
 }
 
@@ -37,6 +39,7 @@ impl Default for DefineIndexStatement {
 			cols: Vec::new(),
 			index: Index::Idx,
 			comment: Expr::Literal(Literal::None),
+			cond: None,
 			concurrently: false,
 		}
 	}
@@ -85,6 +88,16 @@ impl DefineIndexStatement {
 			ctx.get_index_stores()
 				.index_removed(ctx.get_index_builder(), tb.namespace_id, tb.database_id, &tb, &ix)
 				.await?;
+			// Delete the data of any online rebuild of the index
+			if let Some(index_id) = ix.rebuilding {
+				txn.delp(&crate::key::index::all::new(
+					tb.namespace_id,
+					tb.database_id,
+					&tb.name,
+					index_id,
+				))
+				.await?;
+			}
 			ix.index_id
 		} else {
 			ctx.try_get_sequences()?
@@ -159,6 +172,8 @@ impl DefineIndexStatement {
 			index: self.index.clone(),
 			comment,
 			prepare_remove: false,
+			cond: self.cond.clone(),
+			rebuilding: None,
 		};
 		txn.put_tb_index(tb.namespace_id, tb.database_id, &tb.name, &index_def).await?;
 
@@ -186,7 +201,7 @@ impl DefineIndexStatement {
 		Ok(Value::None)
 	}
 }
-pub(in crate::expr::statements) async fn run_indexing(
+async fn run_indexing(
 	ctx: &FrozenContext,
 	opt: &Options,
 	tb: TableId,
@@ -198,6 +213,27 @@ pub(in crate::expr::statements) async fn run_indexing(
 		.ok_or_else(|| Error::unreachable("No Index Builder"))?
 		.build(ctx, opt.clone(), tb, ix, blocking)
 		.await?;
+	wait_for_indexing(rcv).await
+}
+
+/// Rebuilds an index online, replacing it with the given index once built.
+pub(in crate::expr::statements) async fn run_rebuilding(
+	ctx: &FrozenContext,
+	opt: &Options,
+	tb: TableId,
+	ix: Arc<IndexDefinition>,
+	new_ix: Arc<IndexDefinition>,
+	blocking: bool,
+) -> Result<()> {
+	let rcv = ctx
+		.get_index_builder()
+		.ok_or_else(|| Error::unreachable("No Index Builder"))?
+		.rebuild(ctx, opt.clone(), tb, ix, new_ix, blocking)
+		.await?;
+	wait_for_indexing(rcv).await
+}
+
+async fn wait_for_indexing(rcv: Option<Receiver<Result<()>>>) -> Result<()> {
 	if let Some(rcv) = rcv {
 		rcv.await.map_err(|_| Error::IndexingBuildingCancelled {
 			reason: "Channel shutdown".to_string(),
@@ -27,7 +27,7 @@ pub(crate) use event::DefineEventStatement;
 pub(crate) use field::{DefineDefault, DefineFieldStatement};
 pub(crate) use function::DefineFunctionStatement;
 pub(crate) use index::DefineIndexStatement;
-pub(in crate::expr::statements) use index::run_indexing;
+pub(in crate::expr::statements) use index::run_rebuilding;
 pub(crate) use model::DefineModelStatement;
 pub(crate) use module::DefineModuleStatement;
 pub(crate) use namespace::DefineNamespaceStatement;
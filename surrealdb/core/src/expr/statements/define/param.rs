@@ -8,7 +8,7 @@ use crate::ctx::FrozenContext;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
-use crate::expr::{Base, Expr, FlowResultExt as _};
+use crate::expr::{Base, Expr, FlowResultExt as _, Kind};
 use crate::iam::{Action, ResourceKind};
 use crate::val::Value;
 
@@ -16,6 +16,7 @@ use crate::val::Value;
 pub(crate) struct DefineParamStatement {
 	pub kind: DefineKind,
 	pub name: String,
+	pub param_kind: Option<Kind>,
 	pub value: Expr,
 	pub comment: Expr,
 	pub permissions: Permission,
@@ -35,6 +36,14 @@ impl DefineParamStatement {
 		opt.is_allowed(Action::Edit, ResourceKind::Parameter, &Base::Db)?;
 
 		let value = stk.run(|stk| self.value.compute(stk, ctx, opt, doc)).await.catch_return()?;
+		// Ensure the value is of the declared kind
+		let value = match &self.param_kind {
+			Some(kind) => value.coerce_to_kind(kind).map_err(|e| Error::SetCoerce {
+				name: self.name.clone(),
+				error: Box::new(e),
+			})?,
+			None => value,
+		};
 
 		// Fetch the transaction
 		let txn = ctx.tx();
@@ -74,6 +83,7 @@ impl DefineParamStatement {
 				name: self.name.clone(),
 				comment,
 				permissions: self.permissions.clone(),
+				param_kind: self.param_kind.clone(),
 			},
 		)
 		.await?;
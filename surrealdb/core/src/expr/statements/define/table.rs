@@ -24,7 +24,7 @@ use crate::expr::parameterize::expr_to_ident;
 use crate::expr::paths::{ID, IN, OUT};
 use crate::expr::{
 	Base, BinaryOperator, Cond, Expr, Field, Fields, FlowResultExt, Function, FunctionCall, Group,
-	Groups, Idiom, Kind, Literal, SelectStatement, View,
+	Groups, IdType, Idiom, Kind, Literal, SelectStatement, Ttl, UnknownFields, View,
 };
 use crate::iam::{Action, ResourceKind};
 use crate::key;
@@ -38,9 +38,12 @@ pub(crate) struct DefineTableStatement {
 	pub name: Expr,
 	pub drop: bool,
 	pub full: bool,
+	pub unknown_fields: UnknownFields,
 	pub view: Option<View>,
 	pub permissions: Permissions,
 	pub changefeed: Option<ChangeFeed>,
+	pub ttl: Option<Ttl>,
+	pub id_type: IdType,
 	pub comment: Expr,
 	pub table_type: TableType,
 }
@@ -53,9 +56,12 @@ impl Default for DefineTableStatement {
 			name: Expr::Literal(Literal::String(String::new())),
 			drop: false,
 			full: false,
+			unknown_fields: UnknownFields::Reject,
 			view: None,
 			permissions: Permissions::default(),
 			changefeed: None,
+			ttl: None,
+			id_type: IdType::Rand,
 			comment: Expr::Literal(Literal::None),
 			table_type: TableType::default(),
 		}
@@ -122,11 +128,14 @@ impl DefineTableStatement {
 			name: name.clone(),
 			drop: self.drop,
 			schemafull: self.full,
+			unknown_fields: self.unknown_fields.clone(),
 			table_type: self.table_type.clone(),
 			view: self.view.clone().map(|v| v.to_definition()).transpose()?,
 			permissions: self.permissions.clone(),
 			comment,
 			changefeed: self.changefeed,
+			ttl: self.ttl.clone(),
+			id_type: self.id_type.clone(),
 
 			cache_fields_ts: cache_ts,
 			cache_events_ts: cache_ts,
@@ -309,12 +318,14 @@ impl DefineTableStatement {
 			with: None,
 			split: None,
 			group: None,
+			having: None,
 			order: None,
 			limit: None,
 			start: None,
 			fetch: None,
 			version: Expr::Literal(Literal::None),
 			timeout: Expr::Literal(Literal::None),
+			scan_timeout: Expr::Literal(Literal::None),
 			explain: None,
 			tempfiles: false,
 		};
@@ -458,6 +469,9 @@ impl DefineTableStatement {
 				Aggregation::Accumulate(_) => {
 					fail!("Accumulate aggregation is not supported in materialized views")
 				}
+				Aggregation::ApproxDistinct(_) => {
+					fail!("ApproxDistinct aggregation is not supported in materialized views")
+				}
 			}
 		}
 
@@ -515,6 +529,9 @@ impl DefineTableStatement {
 					Aggregation::Accumulate(_) => {
 						fail!("Accumulate aggregation is not supported in materialized views")
 					}
+					Aggregation::ApproxDistinct(_) => {
+						fail!("ApproxDistinct aggregation is not supported in materialized views")
+					}
 				},
 			}));
 
@@ -559,12 +576,14 @@ impl DefineTableStatement {
 			only: false,
 			with: None,
 			split: None,
+			having: None,
 			order: None,
 			limit: None,
 			start: None,
 			fetch: None,
 			version: Expr::Literal(Literal::None),
 			timeout: Expr::Literal(Literal::None),
+			scan_timeout: Expr::Literal(Literal::None),
 			explain: None,
 			tempfiles: false,
 		};
@@ -729,6 +748,9 @@ impl DefineTableStatement {
 					Aggregation::Accumulate {
 						..
 					} => fail!("Accumulate aggregation is not supported in materialized views"),
+					Aggregation::ApproxDistinct {
+						..
+					} => fail!("ApproxDistinct aggregation is not supported in materialized views"),
 				}
 			}
 
@@ -5,6 +5,7 @@ use reblessive::tree::Stk;
 use surrealdb_types::{SqlFormat, ToSql};
 
 use crate::catalog::providers::{DatabaseProvider, NamespaceProvider, TableProvider};
+use crate::catalog::{DatabaseDefinition, NamespaceDefinition};
 use crate::ctx::{Context, FrozenContext};
 use crate::dbs::{Iterable, Iterator, Options, Statement};
 use crate::doc::{CursorDoc, NsDbTbCtx};
@@ -74,7 +75,6 @@ impl InsertStatement {
 			None => None,
 		};
 
-		let txn = ctx.tx();
 		// let ns = txn.expect_ns_by_name(opt.ns()?).await?;
 		// let db = txn.expect_db_by_name(opt.ns()?, opt.db()?).await?;
 		// let tb_def = txn.expect_tb_by_name(opt.ns()?, opt.db()?, &tb).await?;
@@ -119,22 +119,7 @@ impl InsertStatement {
 					// Specify the new table record id
 					let (tb, id) = extract_table_and_rid_key(&o, &tb)?;
 
-					doc_ctx = match doc_ctx {
-						Some(doc_ctx) if doc_ctx.tb.name == tb => Some(doc_ctx),
-						Some(_) | None => {
-							let tb_def =
-								txn.get_or_add_tb(Some(ctx), &ns.name, &db.name, &tb, None).await?;
-							let fields = txn
-								.all_tb_fields(ns.namespace_id, db.database_id, &tb, opt.version)
-								.await?;
-							Some(NsDbTbCtx {
-								ns: Arc::clone(&ns),
-								db: Arc::clone(&db),
-								tb: tb_def,
-								fields,
-							})
-						}
-					};
+					doc_ctx = Some(table_ctx(ctx, opt, &ns, &db, doc_ctx, &tb).await?);
 
 					// Pass the value to the iterator
 					iterator.ingest(iterable(
@@ -150,33 +135,14 @@ impl InsertStatement {
 			Data::SingleExpression(v) => {
 				let v = stk.run(|stk| v.compute(stk, ctx, opt, doc)).await.catch_return()?;
 				match v {
+					// An array of records, such as the output of a SELECT
+					// subquery, inserts each of the records
 					Value::Array(v) => {
 						for v in v {
 							// Specify the new table record id
 							let (tb, id) = extract_table_and_rid_key(&v, &tb)?;
 
-							doc_ctx = match doc_ctx {
-								Some(doc_ctx) if doc_ctx.tb.name == tb => Some(doc_ctx),
-								Some(_) | None => {
-									let tb_def = txn
-										.get_or_add_tb(Some(ctx), &ns.name, &db.name, &tb, None)
-										.await?;
-									let fields = txn
-										.all_tb_fields(
-											ns.namespace_id,
-											db.database_id,
-											&tb,
-											opt.version,
-										)
-										.await?;
-									Some(NsDbTbCtx {
-										ns: Arc::clone(&ns),
-										db: Arc::clone(&db),
-										tb: tb_def,
-										fields,
-									})
-								}
-							};
+							doc_ctx = Some(table_ctx(ctx, opt, &ns, &db, doc_ctx, &tb).await?);
 
 							// Pass the value to the iterator
 							iterator.ingest(iterable(
@@ -192,28 +158,7 @@ impl InsertStatement {
 						// Specify the new table record id
 						let (tb, id) = extract_table_and_rid_key(&v, &tb)?;
 
-						doc_ctx = match doc_ctx {
-							Some(doc_ctx) if doc_ctx.tb.name == tb => Some(doc_ctx),
-							Some(_) | None => {
-								let tb_def = txn
-									.get_or_add_tb(Some(ctx), &ns.name, &db.name, &tb, None)
-									.await?;
-								let fields = txn
-									.all_tb_fields(
-										ns.namespace_id,
-										db.database_id,
-										&tb,
-										opt.version,
-									)
-									.await?;
-								Some(NsDbTbCtx {
-									ns: Arc::clone(&ns),
-									db: Arc::clone(&db),
-									tb: tb_def,
-									fields,
-								})
-							}
-						};
+						doc_ctx = Some(table_ctx(ctx, opt, &ns, &db, doc_ctx, &tb).await?);
 
 						// Pass the value to the iterator
 						iterator.ingest(iterable(
@@ -258,6 +203,32 @@ impl ToSql for InsertStatement {
 	}
 }
 
+/// Returns the table context for the given table, reusing the previous
+/// context when consecutive records are inserted into the same table.
+async fn table_ctx(
+	ctx: &FrozenContext,
+	opt: &Options,
+	ns: &Arc<NamespaceDefinition>,
+	db: &Arc<DatabaseDefinition>,
+	prev: Option<NsDbTbCtx>,
+	tb: &TableName,
+) -> Result<NsDbTbCtx> {
+	if let Some(prev) = prev
+		&& prev.tb.name == *tb
+	{
+		return Ok(prev);
+	}
+	let txn = ctx.tx();
+	let tb_def = txn.get_or_add_tb(Some(ctx), &ns.name, &db.name, tb, None).await?;
+	let fields = txn.all_tb_fields(ns.namespace_id, db.database_id, tb, opt.version).await?;
+	Ok(NsDbTbCtx {
+		ns: Arc::clone(ns),
+		db: Arc::clone(db),
+		tb: tb_def,
+		fields,
+	})
+}
+
 fn iterable(
 	doc_ctx: NsDbTbCtx,
 	tb: TableName,
@@ -325,11 +296,10 @@ fn extract_table_and_rid_key(
 		Value::Array(id) => Some(id.into()),
 		// There is a UUID for the id field
 		Value::Uuid(id) => Some(id.into()),
-		// There is a record id defined
-		Value::RecordId(id) => {
-			// TODO: Perhaps check if the table in the RID matches the table we're inserting into.
-			Some(id.key)
-		}
+		// There is a record id defined, which may belong to another table
+		// when copying records with `INSERT INTO ... (SELECT ...)`, in which
+		// case the key is kept and the record is created in the target table
+		Value::RecordId(id) => Some(id.key),
 		// There is no record id field
 		Value::None => None,
 		// Any other value cannot be converted to a record id key
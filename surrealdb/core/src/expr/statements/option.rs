@@ -4,6 +4,7 @@ use surrealdb_types::{SqlFormat, ToSql};
 pub(crate) struct OptionStatement {
 	pub name: String,
 	pub what: bool,
+	pub value: Option<String>,
 }
 
 impl ToSql for OptionStatement {
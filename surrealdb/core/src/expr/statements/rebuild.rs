@@ -2,13 +2,14 @@ use anyhow::Result;
 use reblessive::tree::Stk;
 use surrealdb_types::{SqlFormat, ToSql};
 
+use crate::catalog::IndexDefinition;
 use crate::catalog::providers::TableProvider;
 use crate::ctx::FrozenContext;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
 use crate::expr::Base;
-use crate::expr::statements::define::run_indexing;
+use crate::expr::statements::define::run_rebuilding;
 use crate::iam::{Action, ResourceKind};
 use crate::val::{TableName, Value};
 
@@ -71,8 +72,16 @@ impl RebuildIndexStatement {
 		};
 		let tb = ctx.tx().expect_tb(ns, db, &self.table).await?;
 
-		// Rebuild the index
-		run_indexing(ctx, opt, tb.table_id, ix, !self.concurrently).await?;
+		// Rebuild the index under a new index id, so that queries are served
+		// from the existing index until the rebuilt index is ready
+		let index_id =
+			ctx.try_get_sequences()?.next_index_id(Some(ctx), ns, db, tb.name.clone()).await?;
+		let new_ix = IndexDefinition {
+			index_id,
+			rebuilding: None,
+			..ix.as_ref().clone()
+		};
+		run_rebuilding(ctx, opt, tb.table_id, ix, new_ix.into(), !self.concurrently).await?;
 		// Ok all good
 		Ok(Value::None)
 	}
@@ -64,6 +64,10 @@ impl RemoveIndexStatement {
 		};
 		// Get the table definition
 		let tb = txn.expect_tb(ns, db, &table_name).await?;
+		// Delete the data of any online rebuild of the index
+		if let Some(index_id) = ix.rebuilding {
+			txn.delp(&crate::key::index::all::new(ns, db, &table_name, index_id)).await?;
+		}
 		// Clear the index store cache
 		ctx.get_index_stores().index_removed(ctx.get_index_builder(), ns, db, &tb, &ix).await?;
 		// Delete the index data.
@@ -101,6 +101,10 @@ impl RemoveTableStatement {
 		} else {
 			txn.delp(&key).await?
 		};
+		// Remove the cached record id sequence
+		if let Some(seq) = ctx.get_sequences() {
+			seq.table_removed(ns, db, &name).await;
+		}
 		// Check if this is a foreign table
 		if let Some(view) = &tb.view {
 			let (ViewDefinition::Materialized {
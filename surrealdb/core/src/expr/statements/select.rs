@@ -43,12 +43,21 @@ pub(crate) struct SelectStatement {
 	/// This is used to produce the cartesian product of the values in split fields.
 	pub split: Option<Splits>,
 	pub group: Option<Groups>,
+	/// The HAVING clause.
+	///
+	/// This is used to filter the grouped results after aggregation.
+	pub having: Option<Cond>,
 	pub order: Option<Ordering>,
 	pub limit: Option<Limit>,
 	pub start: Option<Start>,
 	pub fetch: Option<Fetchs>,
 	pub version: Expr,
 	pub timeout: Expr,
+	/// The SCAN TIMEOUT clause.
+	///
+	/// This bounds the time spent iterating over records. Once it passes, the
+	/// iteration stops, and the records gathered so far are still processed.
+	pub scan_timeout: Expr,
 	pub explain: Option<Explain>,
 	pub tempfiles: bool,
 }
@@ -77,26 +77,39 @@ impl UpdateStatement {
 		// `$parent` must be visible while planning targets (e.g. `FROM $parent->edge`).
 		let prepare_ctx: Cow<'_, FrozenContext> = CursorDoc::with_parent_ctx(&ctx, doc);
 
-		// Loop over the update targets
-		for w in self.what.iter() {
+		// Each record in a MERGE EACH clause is a target
+		if let Some(Data::MergeEachExpression(changes)) = &self.data {
 			iterator
-				.prepare(stk, prepare_ctx.as_ref(), opt, doc, &mut planner, &stm_ctx, &doc_ctx, w)
+				.prepare_merge_each(
+					stk,
+					prepare_ctx.as_ref(),
+					opt,
+					doc,
+					&mut planner,
+					&stm_ctx,
+					&doc_ctx,
+					&self.what,
+					changes,
+				)
 				.await
-				.map_err(|e| {
-					if matches!(e.downcast_ref(), Some(Error::InvalidStatementTarget { .. })) {
-						let Ok(Error::InvalidStatementTarget {
-							value,
-						}) = e.downcast()
-						else {
-							unreachable!()
-						};
-						anyhow::Error::new(Error::UpdateStatement {
-							value,
-						})
-					} else {
-						e
-					}
-				})?;
+				.map_err(target_error)?;
+		} else {
+			// Loop over the update targets
+			for w in self.what.iter() {
+				iterator
+					.prepare(
+						stk,
+						prepare_ctx.as_ref(),
+						opt,
+						doc,
+						&mut planner,
+						&stm_ctx,
+						&doc_ctx,
+						w,
+					)
+					.await
+					.map_err(target_error)?;
+			}
 		}
 
 		CursorDoc::update_parent(prepare_ctx.as_ref(), None, async |ctx| {
@@ -125,6 +138,19 @@ impl UpdateStatement {
 	}
 }
 
+/// Converts an invalid target error into an UPDATE statement error
+fn target_error(e: anyhow::Error) -> anyhow::Error {
+	match e.downcast() {
+		Ok(Error::InvalidStatementTarget {
+			value,
+		}) => anyhow::Error::new(Error::UpdateStatement {
+			value,
+		}),
+		Ok(e) => anyhow::Error::new(e),
+		Err(e) => e,
+	}
+}
+
 impl ToSql for UpdateStatement {
 	fn fmt_sql(&self, f: &mut String, fmt: SqlFormat) {
 		let stmt: crate::sql::statements::update::UpdateStatement = self.clone().into();
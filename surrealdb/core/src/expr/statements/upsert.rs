@@ -76,26 +76,39 @@ impl UpsertStatement {
 
 		let prepare_ctx: Cow<'_, FrozenContext> = CursorDoc::with_parent_ctx(&ctx, doc);
 
-		// Loop over the upsert targets
-		for w in self.what.iter() {
+		// Each record in a MERGE EACH clause is a target
+		if let Some(Data::MergeEachExpression(changes)) = &self.data {
 			iterator
-				.prepare(stk, prepare_ctx.as_ref(), opt, doc, &mut planner, &stm_ctx, &doc_ctx, w)
+				.prepare_merge_each(
+					stk,
+					prepare_ctx.as_ref(),
+					opt,
+					doc,
+					&mut planner,
+					&stm_ctx,
+					&doc_ctx,
+					&self.what,
+					changes,
+				)
 				.await
-				.map_err(|e| {
-					if matches!(e.downcast_ref(), Some(Error::InvalidStatementTarget { .. })) {
-						let Ok(Error::InvalidStatementTarget {
-							value,
-						}) = e.downcast()
-						else {
-							unreachable!()
-						};
-						anyhow::Error::new(Error::UpsertStatement {
-							value,
-						})
-					} else {
-						e
-					}
-				})?;
+				.map_err(target_error)?;
+		} else {
+			// Loop over the upsert targets
+			for w in self.what.iter() {
+				iterator
+					.prepare(
+						stk,
+						prepare_ctx.as_ref(),
+						opt,
+						doc,
+						&mut planner,
+						&stm_ctx,
+						&doc_ctx,
+						w,
+					)
+					.await
+					.map_err(target_error)?;
+			}
 		}
 		CursorDoc::update_parent(prepare_ctx.as_ref(), None, async |ctx| {
 			// Attach the query planner to the context
@@ -128,6 +141,19 @@ impl UpsertStatement {
 	}
 }
 
+/// Converts an invalid target error into an UPSERT statement error
+fn target_error(e: anyhow::Error) -> anyhow::Error {
+	match e.downcast() {
+		Ok(Error::InvalidStatementTarget {
+			value,
+		}) => anyhow::Error::new(Error::UpsertStatement {
+			value,
+		}),
+		Ok(e) => anyhow::Error::new(e),
+		Err(e) => e,
+	}
+}
+
 impl ToSql for UpsertStatement {
 	fn fmt_sql(&self, f: &mut String, fmt: SqlFormat) {
 		let stmt: crate::sql::statements::upsert::UpsertStatement = self.clone().into();
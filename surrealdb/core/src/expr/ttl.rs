@@ -0,0 +1,27 @@
+use std::time;
+
+use revision::revisioned;
+
+use crate::expr::Idiom;
+use crate::expr::statements::info::InfoStructure;
+use crate::val::{Duration, Value};
+
+/// The time-to-live of the records in a table, relative to a datetime field.
+///
+/// Expired records are deleted by a background sweep, and remain visible to
+/// reads until they are deleted.
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub(crate) struct Ttl {
+	pub duration: time::Duration,
+	pub field: Idiom,
+}
+
+impl InfoStructure for Ttl {
+	fn structure(self) -> Value {
+		Value::from(map! {
+			"duration".to_string() => Duration(self.duration).into(),
+			"field".to_string() => self.field.to_raw_string().into(),
+		})
+	}
+}
@@ -0,0 +1,36 @@
+use revision::revisioned;
+
+use crate::expr::Idiom;
+use crate::expr::statements::info::InfoStructure;
+use crate::val::Value;
+
+/// What happens to the fields of a record which are not defined on a
+/// schemafull table
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub(crate) enum UnknownFields {
+	/// The record is rejected with an error
+	#[default]
+	Reject,
+	/// The fields are removed from the record
+	Ignore,
+	/// The fields are moved into an object stored in the given field
+	Store(Idiom),
+}
+
+impl InfoStructure for UnknownFields {
+	fn structure(self) -> Value {
+		match self {
+			Self::Reject => Value::from(map! {
+				"mode".to_string() => "REJECT".into(),
+			}),
+			Self::Ignore => Value::from(map! {
+				"mode".to_string() => "IGNORE".into(),
+			}),
+			Self::Store(field) => Value::from(map! {
+				"mode".to_string() => "STORE".into(),
+				"field".to_string() => field.to_raw_string().into(),
+			}),
+		}
+	}
+}
@@ -10,6 +10,13 @@ use crate::expr::statements::info::InfoStructure;
 use crate::expr::{Cond, Fields, Groups, Value};
 use crate::val::TableName;
 
+/// The `AS SELECT` clause of a table which is a view of other tables.
+///
+/// A materialized view is stored as records, which are updated within the
+/// same transaction as each write to a source table. Reading the view is
+/// therefore never stale: a transaction sees its own writes reflected in the
+/// view, and the view changes of a cancelled transaction are discarded along
+/// with the writes which caused them.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub(crate) struct View {
 	pub(crate) materialize: bool,
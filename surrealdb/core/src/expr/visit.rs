@@ -595,6 +595,9 @@ implement_visitor! {
 				this.visit_idiom(&g.0)?;
 			}
 		}
+		if let Some(h) = s.having.as_ref(){
+			this.visit_expr(&h.0)?;
+		}
 		if let Some(o) = s.order.as_ref(){
 			this.visit_ordering(o)?;
 		}
@@ -1185,6 +1188,9 @@ implement_visitor! {
 	}
 
 	fn visit_define_param(this, d: &DefineParamStatement){
+		if let Some(k) = d.param_kind.as_ref(){
+			this.visit_kind(k)?;
+		}
 		this.visit_expr(&d.value)?;
 		this.visit_expr(&d.comment)?;
 		this.visit_permission(&d.permissions)?;
@@ -1256,6 +1262,7 @@ implement_visitor! {
 			Output::None |
 				Output::Null |
 				Output::Diff |
+				Output::Changed |
 				Output::After |
 				Output::Before => {},
 			Output::Fields(fields) => {
@@ -1281,6 +1288,7 @@ implement_visitor! {
 			},
 			Data::PatchExpression(value) |
 				Data::MergeExpression(value) |
+				Data::MergeEachExpression(value) |
 				Data::ReplaceExpression(value) |
 				Data::ContentExpression(value) |
 				Data::SingleExpression(value) => {
@@ -2105,6 +2113,9 @@ implement_visitor_mut! {
 				this.visit_mut_idiom(&mut g.0)?;
 			}
 		}
+		if let Some(h) = s.having.as_mut(){
+			this.visit_mut_expr(&mut h.0)?;
+		}
 		if let Some(o) = s.order.as_mut(){
 			this.visit_mut_ordering(o)?;
 		}
@@ -2763,6 +2774,7 @@ implement_visitor_mut! {
 			Output::None |
 				Output::Null |
 				Output::Diff |
+				Output::Changed |
 				Output::After |
 				Output::Before => {},
 			Output::Fields(fields) => {
@@ -2788,6 +2800,7 @@ implement_visitor_mut! {
 			},
 			Data::PatchExpression(value) |
 				Data::MergeExpression(value) |
+				Data::MergeEachExpression(value) |
 				Data::ReplaceExpression(value) |
 				Data::ContentExpression(value) |
 				Data::SingleExpression(value) => {
@@ -1,10 +1,12 @@
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::mem::{self};
 use std::ops::Bound;
 
-use anyhow::{Result, ensure};
+use anyhow::{Result, bail, ensure};
 use rand::prelude::SliceRandom;
 use reblessive::tree::Stk;
+use surrealdb_types::ToSql;
 
 use super::args::{Optional, Rest};
 use crate::cnf::GENERATION_ALLOCATION_LIMIT;
@@ -12,11 +14,12 @@ use crate::ctx::FrozenContext;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
+use crate::expr::Idiom;
 use crate::val::array::{
 	Clump, Combine, Complement, Difference, Flatten, Intersect, Matches, Union, Uniq, Windows,
 };
 use crate::val::range::TypedRange;
-use crate::val::{Array, Closure, Value};
+use crate::val::{Array, Closure, Object, Value};
 
 /// Returns an error if an array of this length is too much to allocate.
 fn limit(name: &str, n: usize) -> Result<(), Error> {
@@ -201,6 +204,68 @@ pub fn distinct((array,): (Array,)) -> Result<Value> {
 	Ok(array.uniq().into())
 }
 
+/// Returns the operations which transform the first array into the second,
+/// keeping the values of a longest common subsequence of the arrays
+///
+/// Each operation is an object with an `op` of `keep`, `delete` or `insert`,
+/// the `value`, and an `index` which is the position of the value in the
+/// first array for `keep` and `delete`, or in the second array for `insert`.
+/// Values are compared by value, so objects and arrays are equal when their
+/// contents are equal. Deletions come before insertions at the same position.
+pub fn edit_script((a, b): (Array, Array)) -> Result<Value> {
+	// Values at the start or end of both arrays are always kept
+	let prefix = a.iter().zip(b.iter()).take_while(|(x, y)| x == y).count();
+	let suffix =
+		a[prefix..].iter().rev().zip(b[prefix..].iter().rev()).take_while(|(x, y)| x == y).count();
+	let x = &a[prefix..a.len() - suffix];
+	let y = &b[prefix..b.len() - suffix];
+	// The length of the longest common subsequence of each pair of suffixes
+	let width = y.len() + 1;
+	let cells = (x.len() + 1).saturating_mul(width);
+	limit("array::edit_script", mem::size_of::<usize>().saturating_mul(cells))?;
+	let mut lcs = vec![0usize; cells];
+	for i in (0..x.len()).rev() {
+		for j in (0..y.len()).rev() {
+			lcs[i * width + j] = if x[i] == y[j] {
+				lcs[(i + 1) * width + j + 1] + 1
+			} else {
+				lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+			};
+		}
+	}
+	let op = |op: &str, value: &Value, index: usize| {
+		Value::Object(Object(map! {
+			"op".to_string() => Value::from(op),
+			"value".to_string() => value.clone(),
+			"index".to_string() => Value::from(index as i64),
+		}))
+	};
+	let mut ops = Vec::with_capacity(a.len() + y.len());
+	for (i, v) in a[..prefix].iter().enumerate() {
+		ops.push(op("keep", v, i));
+	}
+	let (mut i, mut j) = (0, 0);
+	while i < x.len() || j < y.len() {
+		if i < x.len() && j < y.len() && x[i] == y[j] {
+			ops.push(op("keep", &x[i], prefix + i));
+			i += 1;
+			j += 1;
+		} else if i < x.len()
+			&& (j == y.len() || lcs[(i + 1) * width + j] >= lcs[i * width + j + 1])
+		{
+			ops.push(op("delete", &x[i], prefix + i));
+			i += 1;
+		} else {
+			ops.push(op("insert", &y[j], prefix + j));
+			j += 1;
+		}
+	}
+	for (i, v) in a.iter().enumerate().skip(a.len() - suffix) {
+		ops.push(op("keep", v, i));
+	}
+	Ok(Value::Array(Array(ops)))
+}
+
 pub fn fill(
 	(mut array, value, Optional(range_start), Optional(end)): (
 		Array,
@@ -596,10 +661,96 @@ pub fn max((array,): (Array,)) -> Result<Value> {
 	Ok(array.into_iter().max().unwrap_or_default())
 }
 
+/// Merges two arrays which are each sorted in ascending order into one sorted
+/// array, keeping the elements of the first array before equal elements of
+/// the second.
+///
+/// The arrays are not checked to be sorted. If they are not, the order of
+/// the merged elements is unspecified, but every element is still returned.
+pub fn merge_sorted((array, other): (Array, Array)) -> Result<Value> {
+	Ok(merge(array.0, other.0, |a, b| a <= b).into())
+}
+
+/// Merges two arrays which are each sorted in ascending order of the keys
+/// returned by the closure into one sorted array, keeping the elements of the
+/// first array before elements of the second with equal keys.
+///
+/// As with `merge_sorted`, the order is unspecified when the arrays are not
+/// sorted by the keys.
+pub async fn merge_sorted_by(
+	(stk, ctx, opt, doc): (&mut Stk, &FrozenContext, Option<&Options>, Option<&CursorDoc>),
+	(array, other, key): (Array, Array, Box<Closure>),
+) -> Result<Value> {
+	if let Some(opt) = opt {
+		let mut keyed = [Vec::with_capacity(array.len()), Vec::with_capacity(other.len())];
+		for (arr, out) in [array, other].into_iter().zip(keyed.iter_mut()) {
+			for arg in arr {
+				let k = key.invoke(stk, ctx, opt, doc, vec![arg.clone()]).await?;
+				out.push((k, arg));
+			}
+		}
+		let [array, other] = keyed;
+		let merged = merge(array, other, |(a, _), (b, _)| a <= b);
+		Ok(merged.into_iter().map(|(_, v)| v).collect::<Vec<_>>().into())
+	} else {
+		Ok(Value::None)
+	}
+}
+
+/// Merges two sequences in a single pass, taking from the first sequence
+/// while its next element is ordered before or equal to that of the second.
+fn merge<T>(a: Vec<T>, b: Vec<T>, le: impl Fn(&T, &T) -> bool) -> Vec<T> {
+	let mut res = Vec::with_capacity(a.len() + b.len());
+	let mut a = a.into_iter().peekable();
+	let mut b = b.into_iter().peekable();
+	loop {
+		let next = match (a.peek(), b.peek()) {
+			(Some(x), Some(y)) => {
+				if le(x, y) {
+					a.next()
+				} else {
+					b.next()
+				}
+			}
+			(Some(_), None) => a.next(),
+			(None, Some(_)) => b.next(),
+			(None, None) => break,
+		};
+		res.extend(next);
+	}
+	res
+}
+
 pub fn min((array,): (Array,)) -> Result<Value> {
 	Ok(array.into_iter().min().unwrap_or_default())
 }
 
+pub async fn partition(
+	(stk, ctx, opt, doc): (&mut Stk, &FrozenContext, Option<&Options>, Option<&CursorDoc>),
+	(array, check): (Array, Box<Closure>),
+) -> Result<Value> {
+	if let Some(opt) = opt {
+		let mut matching = Vec::new();
+		let mut rest = Vec::new();
+		for arg in array {
+			match check.invoke(stk, ctx, opt, doc, vec![arg.clone()]).await? {
+				Value::Bool(true) => matching.push(arg),
+				Value::Bool(false) => rest.push(arg),
+				v => bail!(Error::InvalidFunctionArguments {
+					name: "array::partition".to_owned(),
+					message: format!(
+						"The closure should return a boolean, but returned {}",
+						v.to_sql()
+					),
+				}),
+			}
+		}
+		Ok(Value::from(vec![Value::from(matching), Value::from(rest)]))
+	} else {
+		Ok(Value::None)
+	}
+}
+
 pub fn pop((mut array,): (Array,)) -> Result<Value> {
 	Ok(array.pop().unwrap_or(Value::None))
 }
@@ -892,6 +1043,71 @@ pub fn sort_natural_lexical(
 	}
 }
 
+/// Sorts an array of objects by the values at the given fields, like an
+/// `ORDER BY` clause. Each key is an object with a `field`, and a `dir` of
+/// either `asc` or `desc`, which defaults to `asc`. Later keys are only used
+/// to order elements with equal values for the earlier keys. Elements where a
+/// field is NONE are sorted after the other elements for that key, in either
+/// direction, and elements which are equal for every key keep their order.
+pub fn sort_by_keys((array, keys): (Array, Array)) -> Result<Value> {
+	let invalid = |message: String| Error::InvalidFunctionArguments {
+		name: "array::sort_by_keys".to_owned(),
+		message,
+	};
+	let keys = keys
+		.into_iter()
+		.map(|key| {
+			let Value::Object(mut key) = key else {
+				return Err(invalid(format!(
+					"The second argument must be an array of objects with a field and a direction, but found {}.",
+					key.to_sql()
+				)));
+			};
+			let idiom = match key.remove("field") {
+				Some(Value::String(field)) => {
+					crate::syn::idiom(&field).map(Idiom::from).map_err(|_| {
+						invalid(format!("Expected a field to sort by, but found '{field}'."))
+					})?
+				}
+				_ => return Err(invalid("Each key must have a field to sort by.".to_owned())),
+			};
+			let asc = match key.remove("dir") {
+				None => true,
+				Some(Value::String(dir)) if dir.eq_ignore_ascii_case("asc") => true,
+				Some(Value::String(dir)) if dir.eq_ignore_ascii_case("desc") => false,
+				Some(dir) => {
+					return Err(invalid(format!(
+						"Expected the direction to be 'asc' or 'desc', but found {}.",
+						dir.to_sql()
+					)));
+				}
+			};
+			Ok((idiom, asc))
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	let mut keyed: Vec<(Vec<Value>, Value)> = array
+		.into_iter()
+		.map(|val| (keys.iter().map(|(idiom, _)| val.pick(idiom)).collect(), val))
+		.collect();
+	// A stable sort keeps the order of elements which are equal for every key
+	keyed.sort_by(|(a, _), (b, _)| {
+		for ((a, b), (_, asc)) in a.iter().zip(b).zip(&keys) {
+			let ord = match (a.is_none(), b.is_none()) {
+				(true, true) => Ordering::Equal,
+				(true, false) => Ordering::Greater,
+				(false, true) => Ordering::Less,
+				(false, false) if *asc => a.cmp(b),
+				(false, false) => b.cmp(a),
+			};
+			if ord != Ordering::Equal {
+				return ord;
+			}
+		}
+		Ordering::Equal
+	});
+	Ok(keyed.into_iter().map(|(_, v)| v).collect::<Vec<_>>().into())
+}
+
 pub fn swap((mut array, from, to): (Array, i64, i64)) -> Result<Value> {
 	let min = 0;
 	let max = array.len();
@@ -927,14 +1143,44 @@ pub fn swap((mut array, from, to): (Array, i64, i64)) -> Result<Value> {
 	Ok(array.into())
 }
 
-pub fn transpose((array,): (Array,)) -> Result<Value> {
-	Ok(array.transpose().into())
+pub fn transpose((array, Optional(strict)): (Array, Optional<bool>)) -> Result<Value> {
+	if strict.unwrap_or(false) {
+		Ok(array.transpose_strict()?.into())
+	} else {
+		Ok(array.transpose().into())
+	}
 }
 
 pub fn union((array, other): (Array, Array)) -> Result<Value> {
 	Ok(array.union(other).into())
 }
 
+/// Returns the first element for each distinct combination of the values at
+/// the given fields. Fields which are missing from an element are NONE.
+pub fn unique_by((array, keys): (Array, Vec<String>)) -> Result<Value> {
+	let idioms = keys
+		.iter()
+		.map(|key| {
+			crate::syn::idiom(key).map(Idiom::from).map_err(|_| Error::InvalidFunctionArguments {
+				name: "array::unique_by".to_owned(),
+				message: format!(
+					"The second argument must be an array of fields, but found '{key}'."
+				),
+			})
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	#[expect(clippy::mutable_key_type)]
+	let mut seen = HashSet::with_capacity(array.len());
+	let mut res = Array::with_capacity(array.len());
+	for val in array {
+		let key: Vec<Value> = idioms.iter().map(|idiom| val.pick(idiom)).collect();
+		if seen.insert(key) {
+			res.push(val);
+		}
+	}
+	Ok(res.into())
+}
+
 pub fn windows((array, window_size): (Array, i64)) -> Result<Value> {
 	let window_size = window_size.max(0) as usize;
 	Ok(array.windows(window_size)?.into())
@@ -959,10 +1205,65 @@ pub mod sort {
 
 #[cfg(test)]
 mod tests {
-	use super::{at, first, join, last, slice};
+	use super::{at, edit_script, first, join, last, merge_sorted, slice, transpose};
 	use crate::fnc::args::Optional;
 	use crate::val::{Array, Value};
 
+	#[test]
+	fn array_merge_sorted() {
+		#[track_caller]
+		fn test(a: &[i64], b: &[i64], expected: &[i64]) {
+			let array = |v: &[i64]| -> Array {
+				v.iter().copied().map(Value::from).collect::<Vec<_>>().into()
+			};
+			assert_eq!(merge_sorted((array(a), array(b))).unwrap(), Value::from(array(expected)));
+		}
+
+		test(&[1, 3, 5], &[2, 4, 6], &[1, 2, 3, 4, 5, 6]);
+		test(&[1, 2], &[3, 4], &[1, 2, 3, 4]);
+		test(&[], &[1, 2], &[1, 2]);
+		test(&[1, 1], &[1], &[1, 1, 1]);
+		// Unsorted input keeps every element
+		test(&[3, 1], &[2], &[2, 3, 1]);
+	}
+
+	#[test]
+	fn array_edit_script() {
+		#[track_caller]
+		fn test(a: &str, b: &str, expected: &str) {
+			let array = |v: &str| -> Array {
+				v.chars().map(|c| Value::from(c.to_string())).collect::<Vec<_>>().into()
+			};
+			let Value::Array(ops) = edit_script((array(a), array(b))).unwrap() else {
+				panic!("expected an array");
+			};
+			// Render each operation as a character: ' ' to keep, '-' to delete, '+' to insert
+			let script: String = ops
+				.iter()
+				.map(|op| {
+					let Value::Object(op) = op else {
+						panic!("expected an object");
+					};
+					match op.get("op").cloned().map(Value::into_raw_string).as_deref() {
+						Some("keep") => ' ',
+						Some("delete") => '-',
+						Some("insert") => '+',
+						op => panic!("unexpected operation {op:?}"),
+					}
+				})
+				.collect();
+			assert_eq!(script, expected);
+		}
+
+		test("", "", "");
+		test("abc", "abc", "   ");
+		test("", "ab", "++");
+		test("ab", "", "--");
+		test("abc", "axc", " -+ ");
+		test("abcabba", "cbabac", "-- - +  +");
+		test("kitten", "sitting", "-+   -+ +");
+	}
+
 	#[test]
 	fn array_slice() {
 		#[track_caller]
@@ -1037,4 +1338,34 @@ mod tests {
 		test(vec!["hello", "world"].into(), -2, "hello".into());
 		test(vec!["hello", "world"].into(), -3, Value::None);
 	}
+
+	#[test]
+	fn array_transpose() {
+		fn array(rows: &[&[i64]]) -> Array {
+			rows.iter()
+				.map(|row| Value::from(row.iter().map(|n| Value::from(*n)).collect::<Vec<_>>()))
+				.collect::<Vec<_>>()
+				.into()
+		}
+
+		let strict = || Optional(Some(true));
+
+		let res = transpose((array(&[&[1, 2, 3], &[4, 5, 6]]), strict())).unwrap();
+		assert_eq!(res, Value::from(array(&[&[1, 4], &[2, 5], &[3, 6]])));
+		let res = transpose((array(&[&[1, 2, 3]]), strict())).unwrap();
+		assert_eq!(res, Value::from(array(&[&[1], &[2], &[3]])));
+		let res = transpose((Array::new(), strict())).unwrap();
+		assert_eq!(res, Value::from(Array::new()));
+		let err = transpose((array(&[&[1, 2, 3], &[4, 5]]), strict())).unwrap_err();
+		assert!(err.to_string().contains("All arrays must have the same length"), "{err}");
+		// Without the strict flag, ragged input is padded with NONE
+		let res = transpose((array(&[&[1, 2, 3], &[4, 5]]), Optional(None))).unwrap();
+		let expected: Array = vec![
+			Value::from(vec![Value::from(1), Value::from(4)]),
+			Value::from(vec![Value::from(2), Value::from(5)]),
+			Value::from(vec![Value::from(3), Value::None]),
+		]
+		.into();
+		assert_eq!(res, Value::from(expected));
+	}
 }
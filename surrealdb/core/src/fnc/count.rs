@@ -1,7 +1,8 @@
 use anyhow::Result;
 
 use super::args::Optional;
-use crate::val::Value;
+use crate::fnc::util::math::hyperloglog::HyperLogLog;
+use crate::val::{Array, Value};
 
 pub fn count((Optional(arg),): (Optional<Value>,)) -> Result<Value> {
 	Ok(arg
@@ -12,3 +13,14 @@ pub fn count((Optional(arg),): (Optional<Value>,)) -> Result<Value> {
 		})
 		.unwrap_or_else(|| 1.into()))
 }
+
+/// Estimates the number of distinct values in an array, with a standard
+/// error of about 2%. Within a GROUP BY statement this is an aggregate over
+/// the values of each group.
+pub fn approx_distinct((array,): (Array,)) -> Result<Value> {
+	let mut hll = HyperLogLog::default();
+	for v in array.iter() {
+		hll.insert(v);
+	}
+	Ok(hll.estimate().into())
+}
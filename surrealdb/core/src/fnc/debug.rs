@@ -0,0 +1,259 @@
+use anyhow::Result;
+use futures::StreamExt;
+use revision::SerializeRevisioned;
+
+use super::args::Optional;
+use crate::catalog::Record;
+use crate::ctx::FrozenContext;
+use crate::dbs::Options;
+use crate::err::Error;
+use crate::expr::Base;
+use crate::iam::{Action, ResourceKind};
+use crate::idx::planner::ScanDirection;
+use crate::key::record::{self, RecordKey};
+use crate::kvs::{KVValue, prefix_end};
+use crate::val::{Array, Object, TableName, Value};
+
+/// The number of key-value pairs returned by `debug::kv_scan` by default
+const DEFAULT_SCAN_LIMIT: u32 = 100;
+
+/// The maximum number of key-value pairs returned by `debug::kv_scan`
+const MAX_SCAN_LIMIT: u32 = 10_000;
+
+/// Returns the raw key-value pairs stored under a key prefix
+///
+/// The prefix is given as a hex encoded string, and each returned entry is
+/// an object with the hex encoded `key` and `value` bytes. At most 10,000
+/// entries are returned, regardless of the requested limit. This function is
+/// only available to root users, when the `debug` experimental capability is
+/// enabled.
+pub async fn kv_scan(
+	(ctx, opt): (&FrozenContext, Option<&Options>),
+	(prefix, Optional(limit)): (String, Optional<i64>),
+) -> Result<Value> {
+	let Some(opt) = opt else {
+		return Ok(Value::None);
+	};
+	// Raw keys span every namespace, so only root users may inspect them
+	opt.is_allowed(Action::Edit, ResourceKind::Any, &Base::Root)?;
+	// Decode the key prefix
+	let beg = hex::decode(&prefix).map_err(|_| Error::InvalidFunctionArguments {
+		name: "debug::kv_scan".to_owned(),
+		message: format!("The first argument must be a hex encoded key prefix, found '{prefix}'."),
+	})?;
+	// Check the number of entries to return
+	let limit = match limit {
+		None => DEFAULT_SCAN_LIMIT,
+		Some(limit) => u32::try_from(limit).ok().filter(|l| *l > 0).ok_or_else(|| {
+			Error::InvalidFunctionArguments {
+				name: "debug::kv_scan".to_owned(),
+				message: "The second argument must be a positive integer.".to_owned(),
+			}
+		})?,
+	}
+	.min(MAX_SCAN_LIMIT);
+	// Scan every key which starts with the prefix. A prefix without a
+	// successor covers the end of the keyspace, and every key starts with `/`
+	let end = prefix_end(&beg).unwrap_or_else(|| vec![0xff]);
+	let res = ctx.tx().scan(beg..end, limit, 0, None).await?;
+	// Output the raw entries as hex encoded strings
+	let res = res
+		.into_iter()
+		.map(|(k, v)| {
+			Value::Object(Object(map! {
+				"key".to_string() => Value::String(hex::encode(k)),
+				"value".to_string() => Value::String(hex::encode(v)),
+			}))
+		})
+		.collect::<Vec<_>>();
+	Ok(Value::Array(Array(res)))
+}
+
+/// Returns a checksum over every record stored in a table
+///
+/// Each record id and its data are hashed separately, and the hashes are
+/// combined with wrapping addition, so that the checksum does not depend on
+/// the order of the records. Tables with identical records have the same
+/// checksum, even in different datastores. This function scans the whole
+/// table, so it is only available when the `debug` experimental capability
+/// is enabled.
+pub async fn table_checksum(
+	(ctx, opt): (&FrozenContext, Option<&Options>),
+	(table,): (String,),
+) -> Result<Value> {
+	let Some(opt) = opt else {
+		return Ok(Value::None);
+	};
+	// The checksum covers every record, regardless of the table permissions
+	opt.is_allowed(Action::View, ResourceKind::Table, &Base::Db)?;
+	let (ns, db) = ctx.expect_ns_db_ids(opt).await?;
+	let tb = TableName::new(table);
+	let beg = record::prefix(ns, db, &tb)?;
+	let end = record::suffix(ns, db, &tb)?;
+	// Scan every record in the table
+	let txn = ctx.tx();
+	let stream = txn.stream_keys_vals(beg..end, None, None, 0, ScanDirection::Forward, true);
+	futures::pin_mut!(stream);
+	let mut sum = 0u128;
+	let mut count = 0;
+	while let Some(entries) = stream.next().await {
+		for (k, v) in entries? {
+			if ctx.is_done(Some(count)).await? {
+				return Ok(Value::None);
+			}
+			count += 1;
+			// Hash the record id and the record data
+			let key = RecordKey::decode_key(&k)?;
+			let val = Record::kv_decode_value(v)?;
+			let mut hasher = blake3::Hasher::new();
+			key.id.serialize_revisioned(&mut hasher)?;
+			val.data.serialize_revisioned(&mut hasher)?;
+			let hash = hasher.finalize();
+			let mut bytes = [0u8; 16];
+			bytes.copy_from_slice(&hash.as_bytes()[..16]);
+			sum = sum.wrapping_add(u128::from_be_bytes(bytes));
+		}
+	}
+	Ok(Value::String(format!("{sum:032x}")))
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::catalog::providers::CatalogProvider;
+	use crate::dbs::capabilities::{ExperimentalTarget, Targets};
+	use crate::dbs::{Capabilities, Session};
+	use crate::iam::{Level, Role};
+	use crate::key::{record, table};
+	use crate::kvs::LockType::Optimistic;
+	use crate::kvs::TransactionType::Write;
+	use crate::kvs::{Datastore, KVKey};
+	use crate::types::PublicValue;
+	use crate::val::{RecordIdKey, TableName};
+
+	async fn datastore() -> Datastore {
+		let caps = Capabilities::default()
+			.with_experimental(Targets::Some([ExperimentalTarget::Debug].into()));
+		Datastore::builder()
+			.with_capabilities(caps)
+			.with_auth(true)
+			.build_with_path("memory")
+			.await
+			.unwrap()
+	}
+
+	#[tokio::test]
+	async fn kv_scan_returns_record_keys() {
+		let ds = datastore().await;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let txn = ds.transaction(Write, Optimistic).await.unwrap();
+		let db = txn.ensure_ns_db(None, "test", "test").await.unwrap();
+		txn.commit().await.unwrap();
+		let (ns, db) = (db.namespace_id, db.database_id);
+		let mut res = ds
+			.execute("CREATE person:one SET name = 'One'; CREATE person:two;", &ses, None)
+			.await
+			.unwrap();
+		for res in res.drain(..) {
+			res.result.unwrap();
+		}
+		// Build the expected record keys
+		let tb = TableName::from("person");
+		let mut prefix = table::all::new(ns, db, &tb).encode_key().unwrap();
+		prefix.push(b'*');
+		let prefix = hex::encode(prefix);
+		let expected = ["one", "two"]
+			.map(|id| {
+				let key = record::new(ns, db, &tb, &RecordIdKey::from(id.to_owned()));
+				PublicValue::String(hex::encode(key.encode_key().unwrap()))
+			})
+			.to_vec();
+		// Scan the records in the table
+		let sql = format!("debug::kv_scan('{prefix}', 10).key");
+		let mut res = ds.execute(&sql, &ses, None).await.unwrap();
+		let val = res.remove(0).result.unwrap();
+		assert_eq!(val, PublicValue::Array(expected.into()));
+		// The limit is applied to the scan
+		let sql = format!("array::len(debug::kv_scan('{prefix}', 1))");
+		let mut res = ds.execute(&sql, &ses, None).await.unwrap();
+		assert_eq!(res.remove(0).result.unwrap(), PublicValue::from_t(1i64));
+	}
+
+	#[tokio::test]
+	async fn table_checksum_detects_changes() {
+		let sql = "
+			CREATE person:one SET name = 'One', tags = ['a', 'b'];
+			CREATE person:two SET name = 'Two', age = 42;
+			CREATE other:one SET name = 'One';
+		";
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let checksum = async |ds: &Datastore| {
+			let mut res = ds.execute("debug::table_checksum('person')", &ses, None).await.unwrap();
+			res.remove(0).result.unwrap()
+		};
+		// Create the same person records in a different order in two datastores
+		let one = datastore().await;
+		one.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None)
+			.await
+			.unwrap();
+		for res in one.execute(sql, &ses, None).await.unwrap() {
+			res.result.unwrap();
+		}
+		let two = datastore().await;
+		let sql = "
+			CREATE other:two;
+			CREATE person:two SET name = 'Two', age = 42;
+			CREATE person:one SET name = 'One', tags = ['a', 'b'];
+		";
+		two.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None)
+			.await
+			.unwrap();
+		for res in two.execute(sql, &ses, None).await.unwrap() {
+			res.result.unwrap();
+		}
+		// The checksums match across the datastores
+		let before = checksum(&one).await;
+		assert!(matches!(&before, PublicValue::String(s) if s.len() == 32), "{before:?}");
+		assert_eq!(before, checksum(&two).await);
+		// The checksum changes after an update
+		one.execute("UPDATE person:two SET age = 43", &ses, None).await.unwrap();
+		let after = checksum(&one).await;
+		assert_ne!(before, after);
+		// And matches again once both datastores have the same records
+		two.execute("UPDATE person:two SET age = 43", &ses, None).await.unwrap();
+		assert_eq!(after, checksum(&two).await);
+		// Moving a value to a different record changes the checksum
+		two.execute(
+			"DELETE person:one; CREATE person:three SET name = 'One', tags = ['a', 'b'];",
+			&ses,
+			None,
+		)
+		.await
+		.unwrap();
+		assert_ne!(after, checksum(&two).await);
+	}
+
+	#[tokio::test]
+	async fn kv_scan_is_gated() {
+		// The function is unavailable without the experimental capability
+		let ds = Datastore::new("memory").await.unwrap();
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let mut res = ds.execute("debug::kv_scan('')", &ses, None).await.unwrap();
+		let err = res.remove(0).result.unwrap_err();
+		assert!(err.to_string().contains("Experimental feature debug is not enabled"), "{err}");
+		let mut res = ds.execute("debug::table_checksum('person')", &ses, None).await.unwrap();
+		let err = res.remove(0).result.unwrap_err();
+		assert!(err.to_string().contains("Experimental feature debug is not enabled"), "{err}");
+		// The function is unavailable to non-root users
+		let ds = datastore().await;
+		let ses = Session::for_level(Level::Database("test".into(), "test".into()), Role::Owner)
+			.with_ns("test")
+			.with_db("test");
+		let mut res = ds.execute("debug::kv_scan('')", &ses, None).await.unwrap();
+		assert!(res.remove(0).result.is_err());
+		// Invalid prefixes are rejected
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let mut res = ds.execute("debug::kv_scan('xyz')", &ses, None).await.unwrap();
+		let err = res.remove(0).result.unwrap_err();
+		assert!(err.to_string().contains("hex encoded key prefix"), "{err}");
+	}
+}
@@ -41,6 +41,191 @@ pub mod base64 {
 	}
 }
 
+pub mod base32 {
+	use anyhow::{Result, bail};
+
+	use crate::err::Error;
+	use crate::fnc::args::Optional;
+	use crate::val::{Bytes, Value};
+
+	/// The RFC 4648 base32 alphabet
+	const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+	/// Encodes a `Bytes` value to a base32 string without padding.
+	pub fn encode((arg, Optional(padded)): (Bytes, Optional<bool>)) -> Result<Value> {
+		let padded = padded.unwrap_or_default();
+		let mut out = String::with_capacity(arg.len().div_ceil(5) * 8);
+		for chunk in arg.chunks(5) {
+			// Each group of 5 bytes is encoded as 8 characters
+			let mut buf = [0u8; 8];
+			buf[3..3 + chunk.len()].copy_from_slice(chunk);
+			let bits = u64::from_be_bytes(buf);
+			let chars = (chunk.len() * 8).div_ceil(5);
+			for i in 0..chars {
+				let idx = (bits >> (35 - i * 5)) & 0x1f;
+				out.push(ALPHABET[idx as usize] as char);
+			}
+			if padded {
+				out.extend(std::iter::repeat_n('=', 8 - chars));
+			}
+		}
+		Ok(Value::from(out))
+	}
+
+	/// Decodes a base32 string to a `Bytes` value. It accepts both padded and
+	/// non-padded base32 strings, in either upper or lower case.
+	pub fn decode((arg,): (String,)) -> Result<Value> {
+		let invalid = || Error::InvalidFunctionArguments {
+			name: "encoding::base32::decode".to_owned(),
+			message: "invalid base32".to_owned(),
+		};
+		let data = arg.trim_end_matches('=');
+		// Only these trailing group lengths can be produced by the encoder
+		if !matches!(data.len() % 8, 0 | 2 | 4 | 5 | 7) {
+			bail!(invalid());
+		}
+		let mut out = Vec::with_capacity(data.len() * 5 / 8);
+		let mut buffer = 0u32;
+		let mut bits = 0;
+		for c in data.bytes() {
+			let v = match c.to_ascii_uppercase() {
+				c @ b'A'..=b'Z' => c - b'A',
+				c @ b'2'..=b'7' => c - b'2' + 26,
+				_ => bail!(invalid()),
+			};
+			buffer = (buffer << 5) | v as u32;
+			bits += 5;
+			if bits >= 8 {
+				bits -= 8;
+				out.push((buffer >> bits) as u8);
+				buffer &= (1 << bits) - 1;
+			}
+		}
+		// Any trailing bits which do not form a byte must be zero
+		if buffer != 0 {
+			bail!(invalid());
+		}
+		Ok(Value::from(Bytes::from(out)))
+	}
+}
+
+pub mod base58 {
+	use anyhow::Result;
+
+	use super::base_x;
+	use crate::val::{Bytes, Value};
+
+	/// The Bitcoin base58 alphabet
+	const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+	/// Encodes a `Bytes` value to a base58 string.
+	pub fn encode((arg,): (Bytes,)) -> Result<Value> {
+		base_x::encode("encoding::base58::encode", ALPHABET, &arg)
+	}
+
+	/// Decodes a base58 string to a `Bytes` value.
+	pub fn decode((arg,): (String,)) -> Result<Value> {
+		base_x::decode("encoding::base58::decode", "base58", ALPHABET, &arg)
+	}
+}
+
+pub mod base62 {
+	use anyhow::Result;
+
+	use super::base_x;
+	use crate::val::{Bytes, Value};
+
+	/// The base62 alphabet, with digits before upper and lower case letters
+	const ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+	/// Encodes a `Bytes` value to a base62 string.
+	pub fn encode((arg,): (Bytes,)) -> Result<Value> {
+		base_x::encode("encoding::base62::encode", ALPHABET, &arg)
+	}
+
+	/// Decodes a base62 string to a `Bytes` value.
+	pub fn decode((arg,): (String,)) -> Result<Value> {
+		base_x::decode("encoding::base62::decode", "base62", ALPHABET, &arg)
+	}
+}
+
+/// The base58 and base62 encodings treat the input as a single big-endian
+/// number, with each leading zero byte represented by a leading zero digit.
+mod base_x {
+	use anyhow::{Result, ensure};
+
+	use crate::err::Error;
+	use crate::val::{Bytes, Value};
+
+	/// The maximum input length, as the conversion has quadratic complexity
+	const MAX_LENGTH: usize = 8192;
+
+	fn check_length(name: &str, len: usize) -> Result<()> {
+		ensure!(
+			len <= MAX_LENGTH,
+			Error::InvalidFunctionArguments {
+				name: name.to_owned(),
+				message: format!("The input must not exceed {MAX_LENGTH} bytes."),
+			}
+		);
+		Ok(())
+	}
+
+	pub(super) fn encode(name: &str, alphabet: &[u8], input: &[u8]) -> Result<Value> {
+		check_length(name, input.len())?;
+		let base = alphabet.len() as u32;
+		let zeros = input.iter().take_while(|b| **b == 0).count();
+		// The digits of the number, least significant first
+		let mut digits: Vec<u8> = Vec::with_capacity(input.len() * 2);
+		for &byte in &input[zeros..] {
+			let mut carry = byte as u32;
+			for d in digits.iter_mut() {
+				carry += (*d as u32) << 8;
+				*d = (carry % base) as u8;
+				carry /= base;
+			}
+			while carry > 0 {
+				digits.push((carry % base) as u8);
+				carry /= base;
+			}
+		}
+		let mut out = String::with_capacity(zeros + digits.len());
+		out.extend(std::iter::repeat_n(alphabet[0] as char, zeros));
+		out.extend(digits.iter().rev().map(|d| alphabet[*d as usize] as char));
+		Ok(Value::from(out))
+	}
+
+	pub(super) fn decode(name: &str, kind: &str, alphabet: &[u8], input: &str) -> Result<Value> {
+		check_length(name, input.len())?;
+		let base = alphabet.len() as u32;
+		let zeros = input.bytes().take_while(|c| *c == alphabet[0]).count();
+		// The bytes of the number, least significant first
+		let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+		for c in input.bytes().skip(zeros) {
+			let Some(digit) = alphabet.iter().position(|a| *a == c) else {
+				return Err(Error::InvalidFunctionArguments {
+					name: name.to_owned(),
+					message: format!("invalid {kind}"),
+				}
+				.into());
+			};
+			let mut carry = digit as u32;
+			for b in bytes.iter_mut() {
+				carry += (*b as u32) * base;
+				*b = carry as u8;
+				carry >>= 8;
+			}
+			while carry > 0 {
+				bytes.push(carry as u8);
+				carry >>= 8;
+			}
+		}
+		let mut out = vec![0; zeros];
+		out.extend(bytes.iter().rev());
+		Ok(Value::from(Bytes::from(out)))
+	}
+}
+
 pub mod cbor {
 	use anyhow::Result;
 
@@ -137,6 +322,105 @@ mod tests {
 		assert_eq!(result, Value::from(Bytes::from(b"hello".to_vec())));
 	}
 
+	#[test]
+	fn test_base32_known_vectors() {
+		// Test vectors from RFC 4648
+		let vectors = [
+			("", "", ""),
+			("f", "MY", "MY======"),
+			("fo", "MZXQ", "MZXQ===="),
+			("foo", "MZXW6", "MZXW6==="),
+			("foob", "MZXW6YQ", "MZXW6YQ="),
+			("fooba", "MZXW6YTB", "MZXW6YTB"),
+			("foobar", "MZXW6YTBOI", "MZXW6YTBOI======"),
+		];
+		for (input, unpadded, padded) in vectors {
+			let bytes = Bytes::from(input.as_bytes().to_vec());
+			let result = base32::encode((bytes.clone(), Optional(None))).unwrap();
+			assert_eq!(result, Value::from(unpadded));
+			let result = base32::encode((bytes.clone(), Optional(Some(true)))).unwrap();
+			assert_eq!(result, Value::from(padded));
+			// Both forms, in either case, decode to the input
+			for encoded in [unpadded.to_owned(), padded.to_owned(), unpadded.to_lowercase()] {
+				let result = base32::decode((encoded,)).unwrap();
+				assert_eq!(result, Value::from(bytes.clone()));
+			}
+		}
+	}
+
+	#[test]
+	fn test_base32_decode_invalid() {
+		assert!(base32::decode(("MZXW6YT1".to_string(),)).is_err());
+		assert!(base32::decode(("MZX".to_string(),)).is_err());
+		assert!(base32::decode(("MZ=XW6YTB".to_string(),)).is_err());
+		// Non-zero trailing bits are not a canonical encoding
+		assert!(base32::decode(("MY".to_string(),)).is_ok());
+		assert!(base32::decode(("MZ".to_string(),)).is_err());
+	}
+
+	#[test]
+	fn test_base58_known_vectors() {
+		let vectors: [(&[u8], &str); 5] = [
+			(b"", ""),
+			(b"hello world", "StV1DL6CwTryKyV"),
+			(b"Hello World!", "2NEpo7TZRRrLZSi2U"),
+			(&[0, 0, 1], "112"),
+			(&[0], "1"),
+		];
+		for (input, encoded) in vectors {
+			let bytes = Bytes::from(input.to_vec());
+			assert_eq!(base58::encode((bytes.clone(),)).unwrap(), Value::from(encoded));
+			assert_eq!(base58::decode((encoded.to_owned(),)).unwrap(), Value::from(bytes));
+		}
+		// Zero, upper case O, upper case I and lower case l are not in the alphabet
+		for input in ["0", "O", "I", "l", "StV1DL6C-wTryKyV"] {
+			assert!(base58::decode((input.to_owned(),)).is_err());
+		}
+	}
+
+	#[test]
+	fn test_base62_known_vectors() {
+		let vectors: [(&[u8], &str); 5] = [
+			(b"", ""),
+			(b"hello", "7tQLFHz"),
+			(b"hello world", "AAwf93rvy4aWQVw"),
+			(b"Hello World!", "T8dgcjRGkZ3aysdN"),
+			(&[0, 0, 1], "001"),
+		];
+		for (input, encoded) in vectors {
+			let bytes = Bytes::from(input.to_vec());
+			assert_eq!(base62::encode((bytes.clone(),)).unwrap(), Value::from(encoded));
+			assert_eq!(base62::decode((encoded.to_owned(),)).unwrap(), Value::from(bytes));
+		}
+		assert!(base62::decode(("hello-world".to_owned(),)).is_err());
+	}
+
+	#[test]
+	fn test_base_x_round_trip() {
+		let input: Vec<u8> = (0..=255).chain([0, 0, 0]).collect();
+		let bytes = Bytes::from(input);
+		let Value::String(encoded) = base58::encode((bytes.clone(),)).unwrap() else {
+			panic!("expected a string");
+		};
+		assert_eq!(base58::decode((encoded,)).unwrap(), Value::from(bytes.clone()));
+		let Value::String(encoded) = base62::encode((bytes.clone(),)).unwrap() else {
+			panic!("expected a string");
+		};
+		assert_eq!(base62::decode((encoded,)).unwrap(), Value::from(bytes.clone()));
+		let Value::String(encoded) = base32::encode((bytes.clone(), Optional(None))).unwrap()
+		else {
+			panic!("expected a string");
+		};
+		assert_eq!(base32::decode((encoded,)).unwrap(), Value::from(bytes));
+	}
+
+	#[test]
+	fn test_base_x_length_limit() {
+		let input = Bytes::from(vec![1u8; 8193]);
+		assert!(base58::encode((input,)).is_err());
+		assert!(base62::decode(("1".repeat(8193),)).is_err());
+	}
+
 	#[test]
 	fn test_json_encode_string() {
 		let input = Value::from("hello");
@@ -1,9 +1,10 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use geo::algorithm::centroid::Centroid;
 use geo::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
-use geo::{Bearing, Distance, Haversine};
+use geo::{Bearing, Distance, Haversine, Point};
 
-use crate::val::{Geometry, Value};
+use crate::err::Error;
+use crate::val::{Geometry, Number, Value};
 
 pub fn area((arg,): (Geometry,)) -> Result<Value> {
 	match arg {
@@ -38,7 +39,12 @@ pub fn bearing((v, w): (Geometry, Geometry)) -> Result<Value> {
 }
 
 pub fn centroid((arg,): (Geometry,)) -> Result<Value> {
-	let centroid = match arg {
+	Ok(centroid_of(arg).map(Into::into).unwrap_or(Value::None))
+}
+
+/// Returns the centroid of a geometry, or `None` if the geometry is empty
+fn centroid_of(arg: Geometry) -> Option<Point<f64>> {
+	match arg {
 		Geometry::Point(v) => Some(v.centroid()),
 		Geometry::Line(v) => v.centroid(),
 		Geometry::Polygon(v) => v.centroid(),
@@ -46,13 +52,34 @@ pub fn centroid((arg,): (Geometry,)) -> Result<Value> {
 		Geometry::MultiLine(v) => v.centroid(),
 		Geometry::MultiPolygon(v) => v.centroid(),
 		Geometry::Collection(v) => v.into_iter().collect::<geo::Geometry<f64>>().centroid(),
-	};
-	Ok(centroid.map(Into::into).unwrap_or(Value::None))
+	}
 }
 
+/// Returns the haversine distance in meters between two geometries.
+///
+/// Geometries which are not points are measured from their centroid, and
+/// empty geometries, which have no centroid, return NONE.
 pub fn distance((v, w): (Geometry, Geometry)) -> Result<Value> {
-	Ok(match (v, w) {
-		(Geometry::Point(v), Geometry::Point(w)) => Haversine.distance(v, w).into(),
+	Ok(match (centroid_of(v), centroid_of(w)) {
+		(Some(v), Some(w)) => Haversine.distance(v, w).into(),
+		_ => Value::None,
+	})
+}
+
+/// Returns whether a geometry is within a radius in meters of a center.
+///
+/// Geometries which are not points are measured from their centroid, in
+/// the same way as `geo::distance`.
+pub fn within((v, w, radius): (Geometry, Geometry, Number)) -> Result<Value> {
+	let radius = radius.to_float();
+	if radius.is_nan() || radius < 0.0 {
+		bail!(Error::InvalidFunctionArguments {
+			name: String::from("geo::within"),
+			message: String::from("The third argument must be a radius of 0 or more meters."),
+		});
+	}
+	Ok(match (centroid_of(v), centroid_of(w)) {
+		(Some(v), Some(w)) => (Haversine.distance(v, w) <= radius).into(),
 		_ => Value::None,
 	})
 }
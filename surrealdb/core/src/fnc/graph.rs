@@ -0,0 +1,235 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use anyhow::{Result, bail};
+use reblessive::tree::Stk;
+use surrealdb_types::ToSql;
+
+use crate::cnf::IDIOM_RECURSION_LIMIT;
+use crate::ctx::FrozenContext;
+use crate::dbs::Options;
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::expr::field::Selector;
+use crate::expr::statements::SelectStatement;
+use crate::expr::{Expr, Fields, Idiom, Literal};
+use crate::val::{Array, Object, RecordId, Set, TableName, Value};
+
+/// Expands a value by replacing record links with the records they point to
+///
+/// Record links are followed recursively, up to `depth` links away from the
+/// original value, where the depth is limited to the idiom recursion limit.
+/// Each record is only expanded once, so any links at the depth limit, or
+/// links to a record which has already been expanded, including links which
+/// point back to a record which is currently being expanded, are left as
+/// record ids.
+pub async fn expand(
+	(stk, ctx, opt, _doc): (&mut Stk, &FrozenContext, Option<&Options>, Option<&CursorDoc>),
+	(value, depth): (Value, i64),
+) -> Result<Value> {
+	let Some(opt) = opt else {
+		return Ok(Value::None);
+	};
+	let depth = u64::try_from(depth).map_err(|_| Error::InvalidFunctionArguments {
+		name: "graph::expand".to_owned(),
+		message: "The second argument must be a non-negative integer.".to_owned(),
+	})?;
+	let depth = depth.min(*IDIOM_RECURSION_LIMIT as u64);
+	let mut visited = HashSet::new();
+	expand_value(stk, ctx, opt, value, depth, &mut visited).await
+}
+
+async fn expand_value(
+	stk: &mut Stk,
+	ctx: &FrozenContext,
+	opt: &Options,
+	value: Value,
+	depth: u64,
+	visited: &mut HashSet<RecordId>,
+) -> Result<Value> {
+	match value {
+		Value::RecordId(rid) => {
+			// Leave the link in place at the depth limit, or when the record
+			// has already been expanded
+			if depth == 0 || !visited.insert(rid.clone()) {
+				return Ok(Value::RecordId(rid));
+			}
+			// Check if the query has been cancelled or has timed out
+			if let Some(reason) = ctx.done(true)? {
+				bail!(Error::from(reason));
+			}
+			yield_now!();
+			// Fetch the linked record
+			let mut record = Value::RecordId(rid);
+			stk.run(|stk| record.fetch(stk, ctx, opt, &[])).await?;
+			// Expand the links within the fetched record
+			stk.run(|stk| expand_value(stk, ctx, opt, record, depth - 1, visited)).await
+		}
+		Value::Object(obj) => {
+			let mut res = Object::default();
+			for (key, val) in obj {
+				let val = stk.run(|stk| expand_value(stk, ctx, opt, val, depth, visited)).await?;
+				res.insert(key, val);
+			}
+			Ok(Value::Object(res))
+		}
+		Value::Array(arr) => {
+			let mut res = Array::with_capacity(arr.len());
+			for val in arr {
+				res.push(stk.run(|stk| expand_value(stk, ctx, opt, val, depth, visited)).await?);
+			}
+			Ok(Value::Array(res))
+		}
+		Value::Set(set) => {
+			let mut res = Set::new();
+			for val in set {
+				res.insert(stk.run(|stk| expand_value(stk, ctx, opt, val, depth, visited)).await?);
+			}
+			Ok(Value::Set(res))
+		}
+		value => Ok(value),
+	}
+}
+
+/// Returns the records of a table in topological order
+///
+/// Each record is ordered before any records which it points to through the
+/// edge table. Records which are not connected to any others are included,
+/// and edges which do not connect two records of the table are ignored. When
+/// the edges contain a cycle, including an edge from a record to itself, an
+/// error listing the records in the cycle is returned.
+pub async fn topo_sort(
+	(stk, ctx, opt, _doc): (&mut Stk, &FrozenContext, Option<&Options>, Option<&CursorDoc>),
+	(table, edge): (String, String),
+) -> Result<Value> {
+	let Some(opt) = opt else {
+		return Ok(Value::None);
+	};
+	// Fetch the records, and the records which each edge connects
+	let ids =
+		select_value(stk, ctx, opt, &table, Expr::Idiom(Idiom::field("id".to_owned()))).await?;
+	let links = Expr::Literal(Literal::Array(vec![
+		Expr::Idiom(Idiom::field("in".to_owned())),
+		Expr::Idiom(Idiom::field("out".to_owned())),
+	]));
+	let links = select_value(stk, ctx, opt, &edge, links).await?;
+	// Count the incoming edges of each record
+	let mut incoming: BTreeMap<RecordId, usize> = BTreeMap::new();
+	for id in ids {
+		if let Value::RecordId(id) = id {
+			incoming.insert(id, 0);
+		}
+	}
+	let mut outgoing: BTreeMap<RecordId, Vec<RecordId>> = BTreeMap::new();
+	for link in links {
+		let Value::Array(Array(link)) = link else {
+			continue;
+		};
+		let [Value::RecordId(from), Value::RecordId(to)] = link.as_slice() else {
+			continue;
+		};
+		if !incoming.contains_key(from) {
+			continue;
+		}
+		let Some(count) = incoming.get_mut(to) else {
+			continue;
+		};
+		*count += 1;
+		outgoing.entry(from.clone()).or_default().push(to.clone());
+	}
+	// Repeatedly take the first record which has no remaining incoming edges
+	let mut ready: BTreeSet<RecordId> =
+		incoming.iter().filter(|(_, n)| **n == 0).map(|(id, _)| id.clone()).collect();
+	let mut sorted = Array::with_capacity(incoming.len());
+	while let Some(id) = ready.pop_first() {
+		for to in outgoing.get(&id).into_iter().flatten() {
+			if let Some(count) = incoming.get_mut(to) {
+				*count -= 1;
+				if *count == 0 {
+					ready.insert(to.clone());
+				}
+			}
+		}
+		incoming.remove(&id);
+		sorted.push(Value::RecordId(id));
+	}
+	// Any records left over are in, or follow on from, a cycle
+	if !incoming.is_empty() {
+		let cycle = find_cycle(&incoming, &outgoing);
+		bail!(Error::GraphCycle {
+			edge,
+			cycle: Value::from(cycle).to_sql(),
+		});
+	}
+	Ok(Value::Array(sorted))
+}
+
+/// Selects `VALUE expr` for every record in the specified table
+async fn select_value(
+	stk: &mut Stk,
+	ctx: &FrozenContext,
+	opt: &Options,
+	table: &str,
+	expr: Expr,
+) -> Result<Array> {
+	let stm = SelectStatement {
+		fields: Fields::Value(Box::new(Selector {
+			expr,
+			alias: None,
+		})),
+		what: vec![Expr::Table(TableName::from(table))],
+		only: false,
+		omit: vec![],
+		with: None,
+		cond: None,
+		split: None,
+		group: None,
+		having: None,
+		order: None,
+		limit: None,
+		start: None,
+		fetch: None,
+		version: Expr::Literal(Literal::None),
+		timeout: Expr::Literal(Literal::None),
+		scan_timeout: Expr::Literal(Literal::None),
+		explain: None,
+		tempfiles: false,
+	};
+	match stm.compute(stk, ctx, opt, None).await? {
+		Value::Array(arr) => Ok(arr),
+		_ => Ok(Array::new()),
+	}
+}
+
+/// Finds a cycle among the records which could not be sorted
+///
+/// Every remaining record has at least one incoming edge from another
+/// remaining record, so walking backwards along those edges must eventually
+/// revisit a record. The walk between the two visits is a cycle.
+fn find_cycle(
+	remaining: &BTreeMap<RecordId, usize>,
+	outgoing: &BTreeMap<RecordId, Vec<RecordId>>,
+) -> Vec<Value> {
+	let mut incoming: BTreeMap<&RecordId, &RecordId> = BTreeMap::new();
+	for (from, tos) in outgoing.iter().filter(|(from, _)| remaining.contains_key(*from)) {
+		for to in tos.iter().filter(|to| remaining.contains_key(*to)) {
+			incoming.entry(to).or_insert(from);
+		}
+	}
+	let mut path = Vec::new();
+	let mut next = remaining.keys().next();
+	while let Some(id) = next {
+		if let Some(pos) = path.iter().position(|x| *x == id) {
+			// The path was walked backwards, so reverse the cycle, and
+			// start it from its first record so that the error is stable
+			let mut cycle = path.split_off(pos);
+			cycle.reverse();
+			if let Some(first) = cycle.iter().enumerate().min_by_key(|(_, x)| **x).map(|(i, _)| i) {
+				cycle.rotate_left(first);
+			}
+			return cycle.into_iter().map(|x| Value::RecordId(x.clone())).collect();
+		}
+		path.push(id);
+		next = incoming.get(id).copied();
+	}
+	Vec::new()
+}
@@ -15,6 +15,16 @@ pub async fn get(_: &FrozenContext, (_, _): (Value, Optional<Value>)) -> Result<
 	anyhow::bail!(Error::HttpDisabled)
 }
 
+#[cfg(not(feature = "http"))]
+pub async fn get_with_meta(_: &FrozenContext, (_, _): (Value, Optional<Value>)) -> Result<Value> {
+	anyhow::bail!(Error::HttpDisabled)
+}
+
+#[cfg(not(feature = "http"))]
+pub async fn stream(_: &FrozenContext, (_, _): (Value, Optional<Value>)) -> Result<Value> {
+	anyhow::bail!(Error::HttpDisabled)
+}
+
 #[cfg(not(feature = "http"))]
 pub async fn put(
 	_: &FrozenContext,
@@ -44,6 +54,16 @@ pub async fn delete(_: &FrozenContext, (_, _): (Value, Optional<Value>)) -> Resu
 	anyhow::bail!(Error::HttpDisabled)
 }
 
+#[cfg(not(feature = "http"))]
+pub async fn options(_: &FrozenContext, (_, _): (Value, Optional<Value>)) -> Result<Value> {
+	anyhow::bail!(Error::HttpDisabled)
+}
+
+#[cfg(not(feature = "http"))]
+pub async fn trace(_: &FrozenContext, (_, _): (Value, Optional<Value>)) -> Result<Value> {
+	anyhow::bail!(Error::HttpDisabled)
+}
+
 #[cfg(feature = "http")]
 fn try_as_uri(fn_name: &str, value: Value) -> Result<String> {
 	match value {
@@ -93,6 +113,27 @@ pub async fn get(
 	crate::fnc::util::http::get(ctx, uri, opts).await
 }
 
+#[cfg(feature = "http")]
+pub async fn get_with_meta(
+	ctx: &FrozenContext,
+	(uri, Optional(opts)): (Value, Optional<Value>),
+) -> Result<Value> {
+	let uri = try_as_uri("http::get_with_meta", uri)?;
+	let opts =
+		try_as_opts("http::get_with_meta", "The second argument should be an object.", opts)?;
+	crate::fnc::util::http::get_with_meta(ctx, uri, opts).await
+}
+
+#[cfg(feature = "http")]
+pub async fn stream(
+	ctx: &FrozenContext,
+	(uri, Optional(opts)): (Value, Optional<Value>),
+) -> Result<Value> {
+	let uri = try_as_uri("http::stream", uri)?;
+	let opts = try_as_opts("http::stream", "The second argument should be an object.", opts)?;
+	crate::fnc::util::http::stream(ctx, uri, opts).await
+}
+
 #[cfg(feature = "http")]
 pub async fn put(
 	ctx: &FrozenContext,
@@ -132,3 +173,23 @@ pub async fn delete(
 	let opts = try_as_opts("http::delete", "The second argument should be an object.", opts)?;
 	crate::fnc::util::http::delete(ctx, uri, opts).await
 }
+
+#[cfg(feature = "http")]
+pub async fn options(
+	ctx: &FrozenContext,
+	(uri, Optional(opts)): (Value, Optional<Value>),
+) -> Result<Value> {
+	let uri = try_as_uri("http::options", uri)?;
+	let opts = try_as_opts("http::options", "The second argument should be an object.", opts)?;
+	crate::fnc::util::http::options(ctx, uri, opts).await
+}
+
+#[cfg(feature = "http")]
+pub async fn trace(
+	ctx: &FrozenContext,
+	(uri, Optional(opts)): (Value, Optional<Value>),
+) -> Result<Value> {
+	let uri = try_as_uri("http::trace", uri)?;
+	let opts = try_as_opts("http::trace", "The second argument should be an object.", opts)?;
+	crate::fnc::util::http::trace(ctx, uri, opts).await
+}
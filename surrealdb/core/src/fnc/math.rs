@@ -16,7 +16,7 @@ use crate::fnc::util::math::spread::Spread;
 use crate::fnc::util::math::top::Top;
 use crate::fnc::util::math::trimean::Trimean;
 use crate::val::number::Sort;
-use crate::val::{Number, TryPow, Value};
+use crate::val::{Array, Number, TryPow, Value};
 
 pub fn abs((arg,): (Number,)) -> Result<Value> {
 	let Some(x) = arg.checked_abs() else {
@@ -78,6 +78,33 @@ pub fn deg2rad((arg,): (Number,)) -> Result<Value> {
 	Ok(arg.deg2rad().into())
 }
 
+/// Returns the exponential moving average at each element of the array,
+/// starting from the first element, with the given smoothing factor.
+pub fn ema((array, alpha): (Vec<Number>, Number)) -> Result<Value> {
+	let alpha = alpha.to_float();
+	ensure!(
+		alpha > 0.0 && alpha <= 1.0,
+		Error::InvalidFunctionArguments {
+			name: String::from("math::ema"),
+			message: String::from(
+				"The second argument must be a number greater than 0 and at most 1."
+			),
+		}
+	);
+	let mut res = Vec::with_capacity(array.len());
+	let mut avg: Option<f64> = None;
+	for n in array {
+		let n = n.to_float();
+		let next = match avg {
+			Some(avg) => alpha * n + (1.0 - alpha) * avg,
+			None => n,
+		};
+		avg = Some(next);
+		res.push(Value::from(next));
+	}
+	Ok(res.into())
+}
+
 pub fn fixed((arg, p): (Number, i64)) -> Result<Value> {
 	ensure!(
 		p > 0,
@@ -155,10 +182,40 @@ pub fn mode((array,): (Vec<Number>,)) -> Result<Value> {
 	Ok(array.mode().into())
 }
 
+/// Returns the mean of each window of consecutive elements of the array.
+///
+/// An array with fewer elements than the window has no full window, and
+/// returns an empty array.
+pub fn moving_average((array, window): (Vec<Number>, i64)) -> Result<Value> {
+	ensure!(
+		window > 0,
+		Error::InvalidFunctionArguments {
+			name: String::from("math::moving_average"),
+			message: String::from("The second argument must be an integer greater than 0."),
+		}
+	);
+	let window = usize::try_from(window).unwrap_or(usize::MAX);
+	Ok(array
+		.windows(window)
+		.map(|w| util::math::mean(w).map(Value::Number))
+		.collect::<Result<Vec<_>>>()?
+		.into())
+}
+
 pub fn nearestrank((mut array, n): (Vec<Number>, Number)) -> Result<Value> {
 	Ok(array.sorted().nearestrank(n).into())
 }
 
+pub fn percent_rank((value, array): (Number, Vec<Number>)) -> Result<Value> {
+	// Only numbers strictly less than the value are counted, so the lowest
+	// value in the array has a rank of 0, and tied values share a rank
+	if array.is_empty() {
+		return Ok(Value::None);
+	}
+	let below = array.iter().filter(|x| **x < value).count();
+	Ok((below as f64 / array.len() as f64).into())
+}
+
 pub fn percentile((mut array, n): (Vec<Number>, Number)) -> Result<Value> {
 	Ok(array.sorted().percentile(n).into())
 }
@@ -210,6 +267,47 @@ pub fn tan((arg,): (Number,)) -> Result<Value> {
 	Ok(arg.tan().into())
 }
 
+/// Returns the average of a series of `{ time, value }` samples, where each
+/// value is weighted by how long it was held, until the time of the next
+/// sample. Samples are sorted by time first, so they can be in any order.
+///
+/// The last sample is held for no time, so when no time passes between the
+/// samples, such as when there is only one sample, the mean of the values is
+/// returned instead.
+pub fn time_weighted_avg((array,): (Array,)) -> Result<Value> {
+	let invalid = || Error::InvalidFunctionArguments {
+		name: String::from("math::time_weighted_avg"),
+		message: String::from(
+			"The argument must be an array of objects with a datetime `time` and a number `value`.",
+		),
+	};
+	let mut samples = Vec::with_capacity(array.len());
+	for v in array {
+		let Value::Object(mut v) = v else {
+			bail!(invalid());
+		};
+		let (Some(Value::Datetime(time)), Some(Value::Number(value))) =
+			(v.remove("time"), v.remove("value"))
+		else {
+			bail!(invalid());
+		};
+		samples.push((time, value.to_float()));
+	}
+	samples.sort_by(|a, b| a.0.cmp(&b.0));
+	let mut total = 0.0;
+	let mut weighted = 0.0;
+	for pair in samples.windows(2) {
+		let held = (pair[1].0.clone() - pair[0].0.clone()).nanos() as f64;
+		total += held;
+		weighted += held * pair[0].1;
+	}
+	if total == 0.0 {
+		let values = samples.into_iter().map(|(_, v)| Number::from(v)).collect();
+		return mean((values,));
+	}
+	Ok(Value::from(weighted / total))
+}
+
 pub fn top((array, c): (Vec<Number>, i64)) -> Result<Value> {
 	ensure!(
 		c > 0,
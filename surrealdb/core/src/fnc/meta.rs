@@ -0,0 +1,44 @@
+use anyhow::Result;
+
+use crate::ctx::FrozenContext;
+use crate::kvs::Transaction;
+use crate::val::{Object, Value};
+
+/// Returns information about the storage engine in use
+pub fn engine(ctx: &FrozenContext, _: ()) -> Result<Value> {
+	Ok(engine_info(&ctx.tx()))
+}
+
+/// Builds an object describing the storage engine behind a transaction
+pub(crate) fn engine_info(tx: &Transaction) -> Value {
+	Value::Object(Object(map! {
+		"name".to_string() => Value::from(tx.engine()),
+		"version".to_string() => tx.engine_version().map(Value::from).unwrap_or_default(),
+		"pessimistic_locking".to_string() => Value::Bool(tx.supports_pessimistic_locking()),
+		"savepoints".to_string() => Value::Bool(tx.supports_savepoints()),
+	}))
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::dbs::Session;
+	use crate::kvs::Datastore;
+	use crate::types::PublicValue;
+
+	#[tokio::test]
+	async fn engine_reports_memory_features() {
+		let ds = Datastore::new("memory").await.unwrap();
+		let ses = Session::owner();
+		let mut res = ds.execute("RETURN meta::engine();", &ses, None).await.unwrap();
+		let val = res.remove(0).result.unwrap();
+		let PublicValue::Object(obj) = val else {
+			panic!("expected an object, found {val:?}");
+		};
+		assert_eq!(obj.get("name"), Some(&PublicValue::String("memory".to_owned())));
+		let version = option_env!("SURREAL_ENGINE_VERSION_MEMORY")
+			.map_or(PublicValue::None, |v| PublicValue::String(v.to_owned()));
+		assert_eq!(obj.get("version"), Some(&version));
+		assert_eq!(obj.get("pessimistic_locking"), Some(&PublicValue::Bool(false)));
+		assert_eq!(obj.get("savepoints"), Some(&PublicValue::Bool(true)));
+	}
+}
@@ -16,16 +16,21 @@ pub mod array;
 pub mod bytes;
 pub mod count;
 pub mod crypto;
+pub mod debug;
 pub mod duration;
 pub mod encoding;
 pub mod file;
 pub mod geo;
+pub mod graph;
 pub mod http;
 pub mod math;
+pub mod meta;
 pub mod not;
+pub mod number;
 pub mod object;
 pub mod operate;
 pub mod parse;
+pub mod query;
 pub mod rand;
 pub mod record;
 pub mod schema;
@@ -36,6 +41,7 @@ pub mod session;
 pub mod set;
 pub mod sleep;
 pub mod string;
+pub mod table;
 pub mod time;
 pub mod r#type;
 pub mod util;
@@ -63,8 +69,12 @@ pub async fn run(
 		|| name.eq("array::includes")
 		|| name.eq("array::index_of")
 		|| name.eq("array::map")
+		|| name.eq("array::merge_sorted_by")
+		|| name.eq("array::partition")
 		|| name.eq("array::reduce")
 		|| name.eq("array::some")
+		|| name.eq("debug::kv_scan")
+		|| name.eq("debug::table_checksum")
 		|| name.eq("file::put")
 		|| name.eq("file::put_if_not_exists")
 		|| name.eq("file::get")
@@ -76,6 +86,8 @@ pub async fn run(
 		|| name.eq("file::rename")
 		|| name.eq("file::rename_if_not_exists")
 		|| name.eq("file::list")
+		|| name.eq("graph::expand")
+		|| name.eq("graph::topo_sort")
 		|| name.eq("record::exists")
 		|| name.eq("record::is_edge")
 		|| name.eq("set::all")
@@ -85,11 +97,13 @@ pub async fn run(
 		|| name.eq("set::fold")
 		|| name.eq("set::map")
 		|| name.eq("set::reduce")
+		|| name.eq("table::distinct_values")
 		|| name.eq("type::field")
 		|| name.eq("type::fields")
 		|| name.eq("value::diff")
 		|| name.eq("value::patch")
 		|| name.eq("sequence::nextval")
+		|| name.eq("session::info")
 		|| name.starts_with("api")
 		|| name.starts_with("http")
 		|| name.starts_with("search")
@@ -97,6 +111,7 @@ pub async fn run(
 		|| name.starts_with("crypto::bcrypt")
 		|| name.starts_with("crypto::pbkdf2")
 		|| name.starts_with("crypto::scrypt")
+		|| name.eq("schema::diff")
 		|| name.eq("schema::table::exists")
 	{
 		stk.run(|stk| asynchronous(stk, ctx, opt, doc, name, args)).await
@@ -170,6 +185,7 @@ pub fn synchronous(
 		"array::concat" => array::concat,
 		"array::difference" => array::difference,
 		"array::distinct" => array::distinct,
+		"array::edit_script" => array::edit_script,
 		"array::fill" => array::fill,
 		"array::first" => array::first,
 		"array::flatten" => array::flatten,
@@ -185,6 +201,7 @@ pub fn synchronous(
 		"array::logical_xor" => array::logical_xor,
 		"array::matches" => array::matches,
 		"array::max" => array::max,
+		"array::merge_sorted" => array::merge_sorted,
 		"array::min" => array::min,
 		"array::pop" => array::pop,
 		"array::prepend" => array::prepend,
@@ -200,9 +217,11 @@ pub fn synchronous(
 		"array::sort_natural" => array::sort_natural,
 		"array::sort_lexical" => array::sort_lexical,
 		"array::sort_natural_lexical" => array::sort_natural_lexical,
+		"array::sort_by_keys" => array::sort_by_keys,
 		"array::swap" => array::swap,
 		"array::transpose" => array::transpose,
 		"array::union" => array::union,
+		"array::unique_by" => array::unique_by,
 		"array::sort::asc" => array::sort::asc,
 		"array::sort::desc" => array::sort::desc,
 		"array::windows" => array::windows,
@@ -210,6 +229,7 @@ pub fn synchronous(
 		"bytes::len" => bytes::len,
 		//
 		"count" => count::count,
+		"count::approx_distinct" => count::approx_distinct,
 		//
 		"crypto::blake3" => crypto::blake3,
 		"crypto::joaat" => crypto::joaat,
@@ -239,6 +259,12 @@ pub fn synchronous(
 		exp(Files) "file::bucket" => file::bucket,
 		exp(Files) "file::key" => file::key,
 		//
+		"encoding::base32::decode" => encoding::base32::decode,
+		"encoding::base32::encode" => encoding::base32::encode,
+		"encoding::base58::decode" => encoding::base58::decode,
+		"encoding::base58::encode" => encoding::base58::encode,
+		"encoding::base62::decode" => encoding::base62::decode,
+		"encoding::base62::encode" => encoding::base62::encode,
 		"encoding::base64::decode" => encoding::base64::decode,
 		"encoding::base64::encode" => encoding::base64::encode,
 		"encoding::cbor::decode" => encoding::cbor::decode,
@@ -253,6 +279,7 @@ pub fn synchronous(
 		"geo::hash::decode" => geo::hash::decode,
 		"geo::hash::encode" => geo::hash::encode,
 		"geo::is_valid" => geo::is::valid,
+		"geo::within" => geo::within,
 		//
 		"math::abs" => math::abs,
 		"math::acos" => math::acos,
@@ -265,6 +292,7 @@ pub fn synchronous(
 		"math::cos" => math::cos,
 		"math::cot" => math::cot,
 		"math::deg2rad" => math::deg2rad,
+		"math::ema" => math::ema,
 		"math::fixed" => math::fixed,
 		"math::floor" => math::floor,
 		"math::interquartile" => math::interquartile,
@@ -280,7 +308,9 @@ pub fn synchronous(
 		"math::midhinge" => math::midhinge,
 		"math::min" => math::min,
 		"math::mode" => math::mode,
+		"math::moving_average" => math::moving_average,
 		"math::nearestrank" => math::nearestrank,
+		"math::percent_rank" => math::percent_rank,
 		"math::percentile" => math::percentile,
 		"math::pow" => math::pow,
 		"math::product" => math::product,
@@ -293,22 +323,30 @@ pub fn synchronous(
 		"math::stddev" => math::stddev,
 		"math::sum" => math::sum,
 		"math::tan" => math::tan,
+		"math::time_weighted_avg" => math::time_weighted_avg,
 		"math::top" => math::top,
 		"math::trimean" => math::trimean,
 		"math::variance" => math::variance,
 		//
+		"meta::engine" => meta::engine(ctx),
 		"meta::id" => record::id,
 		"meta::tb" => record::tb,
 		//
 		"not" => not::not,
 		//
+		"number::from_base" => number::from_base,
+		"number::to_base" => number::to_base,
+		//
+		"object::defaults" => object::defaults,
 		"object::entries" => object::entries,
 		"object::extend" => object::extend,
+		"object::flatten" => object::flatten,
 		"object::remove" => object::remove,
 		"object::from_entries" => object::from_entries,
 		"object::is_empty" => object::is_empty,
 		"object::keys" => object::keys,
 		"object::len" => object::len,
+		"object::unflatten" => object::unflatten,
 		"object::values" => object::values,
 		//
 		"parse::email::host" => parse::email::host,
@@ -321,6 +359,9 @@ pub fn synchronous(
 		"parse::url::query" => parse::url::query,
 		"parse::url::scheme" => parse::url::scheme,
 		//
+		"query::escape_ident" => query::escape_ident,
+		"query::escape_literal" => query::escape_literal,
+		//
 		"rand" => rand::rand,
 		"rand::bool" => rand::bool,
 		"rand::duration" => rand::duration,
@@ -330,12 +371,18 @@ pub fn synchronous(
 		"rand::int" => rand::int,
 		"rand::string" => rand::string,
 		"rand::time" => rand::time,
+		"rand::ulid::from_datetime" => rand::ulid::from_datetime,
 		"rand::ulid" => rand::ulid,
 		"rand::uuid::v4" => rand::uuid::v4,
+		"rand::uuid::v5" => rand::uuid::v5,
 		"rand::uuid::v7" => rand::uuid::v7,
 		"rand::uuid" => rand::uuid,
+		"rand::weighted" => rand::weighted,
+		"rand::weighted_many" => rand::weighted_many,
 		//
 		"record::id" => record::id,
+		"record::parse" => record::parse,
+		"record::range" => record::range,
 		"record::table" => record::tb,
 		"record::tb" => record::tb,
 		//
@@ -370,10 +417,15 @@ pub fn synchronous(
 		"string::concat" => string::concat,
 		"string::contains" => string::contains,
 		"string::ends_with" => string::ends_with,
+		"string::fuzzy_match" => string::fuzzy_match,
+		"string::fuzzy_score" => string::fuzzy_score,
 		"string::join" => string::join,
 		"string::len" => string::len,
 		"string::lowercase" => string::lowercase,
 		"string::matches" => string::matches,
+		"string::normalize_url" => string::normalize_url,
+		"string::parse_kv" => string::parse_kv,
+		"string::parse_template" => string::parse_template,
 		"string::repeat" => string::repeat,
 		"string::replace" => string::replace,
 		"string::reverse" => string::reverse,
@@ -385,6 +437,7 @@ pub fn synchronous(
 		"string::uppercase" => string::uppercase,
 		"string::words" => string::words,
 		//
+		"string::distance::damerau" => string::distance::damerau_levenshtein,
 		"string::distance::damerau_levenshtein" => string::distance::damerau_levenshtein,
 		"string::distance::hamming" => string::distance::hamming,
 		"string::distance::levenshtein" => string::distance::levenshtein,
@@ -430,12 +483,15 @@ pub fn synchronous(
 		"string::semver::set::minor" => string::semver::set::minor,
 		"string::semver::set::patch" => string::semver::set::patch,
 		//
+		"time::add_business_days" => time::add_business_days,
 		"time::ceil" => time::ceil,
 		"time::day" => time::day,
 		"time::floor" => time::floor,
 		"time::format" => time::format,
+		"time::format_zoned" => time::format_zoned,
 		"time::group" => time::group,
 		"time::hour" => time::hour,
+		"time::in_zone" => time::in_zone,
 		"time::max" => time::max,
 		"time::min" => time::min,
 		"time::minute" => time::minute,
@@ -477,7 +533,9 @@ pub fn synchronous(
 		exp(Files) "type::file" => r#type::file,
 		"type::float" => r#type::float,
 		"type::geometry" => r#type::geometry,
+		"type::id_from" => r#type::id_from,
 		"type::int" => r#type::int,
+		"type::is" => r#type::is_kind,
 		"type::number" => r#type::number,
 		"type::of" => r#type::type_of,
 		"type::point" => r#type::point,
@@ -514,6 +572,9 @@ pub fn synchronous(
 		"type::is_string" => r#type::is::string,
 		"type::is_uuid" => r#type::is::uuid,
 		//
+		"value::canonicalize" => value::canonicalize,
+		"value::deduplicate_refs" => value::deduplicate_refs,
+		//
 		"vector::add" => vector::add,
 		"vector::angle" => vector::angle,
 		"vector::cross" => vector::cross,
@@ -589,6 +650,8 @@ pub async fn asynchronous(
 		"array::includes" => array::any((stk, ctx, Some(opt), doc)).await,
 		"array::index_of" => array::find_index((stk, ctx, Some(opt), doc)).await,
 		"array::map" => array::map((stk, ctx, Some(opt), doc)).await,
+		"array::merge_sorted_by" => array::merge_sorted_by((stk, ctx, Some(opt), doc)).await,
+		"array::partition" => array::partition((stk, ctx, Some(opt), doc)).await,
 		"array::reduce" => array::reduce((stk, ctx, Some(opt), doc)).await,
 		"array::some" => array::any((stk, ctx, Some(opt), doc)).await,
 		//
@@ -601,6 +664,9 @@ pub async fn asynchronous(
 		"crypto::scrypt::compare" => (cpu_intensive) crypto::scrypt::cmp.await,
 		"crypto::scrypt::generate" => (cpu_intensive) crypto::scrypt::r#gen.await,
 		//
+		exp(Debug) "debug::kv_scan" => debug::kv_scan((ctx, Some(opt))).await,
+		exp(Debug) "debug::table_checksum" => debug::table_checksum((ctx, Some(opt))).await,
+		//
 		exp(Files) "file::put" => file::put((stk, ctx, opt, doc)).await,
 		exp(Files) "file::put_if_not_exists" => file::put_if_not_exists((stk, ctx, opt, doc)).await,
 		exp(Files) "file::get" => file::get((stk, ctx, opt, doc)).await,
@@ -613,12 +679,19 @@ pub async fn asynchronous(
 		exp(Files) "file::exists" => file::exists((stk, ctx, opt, doc)).await,
 		exp(Files) "file::list" => file::list((stk, ctx, opt, doc)).await,
 		//
+		"graph::expand" => graph::expand((stk, ctx, Some(opt), doc)).await,
+		"graph::topo_sort" => graph::topo_sort((stk, ctx, Some(opt), doc)).await,
+		//
 		"http::head" => http::head(ctx).await,
 		"http::get" => http::get(ctx).await,
+		"http::get_with_meta" => http::get_with_meta(ctx).await,
+		"http::stream" => http::stream(ctx).await,
 		"http::put" => http::put(ctx).await,
 		"http::post" =>  http::post(ctx).await,
 		"http::patch" => http::patch(ctx).await,
 		"http::delete" => http::delete(ctx).await,
+		"http::options" => http::options(ctx).await,
+		"http::trace" => http::trace(ctx).await,
 		//
 		"record::exists" => record::exists((stk, ctx, Some(opt), doc)).await,
 		"record::is_edge" => record::is::edge((stk, ctx, Some(opt), doc)).await,
@@ -642,11 +715,16 @@ pub async fn asynchronous(
 		//
 		"sequence::nextval" => sequence::nextval((ctx, opt)).await,
 		//
+		"table::distinct_values" => table::distinct_values((stk, ctx, Some(opt), doc)).await,
+		//
+		"session::info" => session::info((ctx, opt)),
+		//
 		"type::field" => r#type::field((stk, ctx, Some(opt), doc)).await,
 		"type::fields" => r#type::fields((stk, ctx, Some(opt), doc)).await,
 		//
 		"value::diff" => value::diff.await,
 		"value::patch" => value::patch.await,
+		"schema::diff" => schema::diff((ctx, Some(opt))).await,
 		"schema::table::exists" => schema::table::exists((ctx, Some(opt))).await,
 	)
 }
@@ -771,6 +849,7 @@ pub async fn idiom(
 				"concat" => array::concat,
 				"difference" => array::difference,
 				"distinct" => array::distinct,
+				"edit_script" => array::edit_script,
 				"every" => array::all((stk, ctx, Some(opt), doc)).await,
 				"fill" => array::fill,
 				"filter" => array::filter((stk, ctx, Some(opt), doc)).await,
@@ -795,7 +874,10 @@ pub async fn idiom(
 				"matches" => array::matches,
 				"map" => array::map((stk, ctx, Some(opt), doc)).await,
 				"max" => array::max,
+				"merge_sorted" => array::merge_sorted,
+				"merge_sorted_by" => array::merge_sorted_by((stk, ctx, Some(opt), doc)).await,
 				"min" => array::min,
+				"partition" => array::partition((stk, ctx, Some(opt), doc)).await,
 				"pop" => array::pop,
 				"prepend" => array::prepend,
 				"push" => array::push,
@@ -809,9 +891,11 @@ pub async fn idiom(
 				"sort_natural" => array::sort_natural,
 				"sort_lexical" => array::sort_lexical,
 				"sort_natural_lexical" => array::sort_natural_lexical,
+				"sort_by_keys" => array::sort_by_keys,
 				"swap" => array::swap,
 				"transpose" => array::transpose,
 				"union" => array::union,
+				"unique_by" => array::unique_by,
 				"sort_asc" => array::sort::asc,
 				"sort_desc" => array::sort::desc,
 				"windows" => array::windows,
@@ -1039,6 +1123,7 @@ pub async fn idiom(
 				"hash_decode" => geo::hash::decode,
 				"hash_encode" => geo::hash::encode,
 				"is_valid" => geo::is::valid,
+				"within" => geo::within,
 
 				"type_of" => r#type::type_of,
 				"is_array" => r#type::is::array,
@@ -1166,12 +1251,15 @@ pub async fn idiom(
 				args.clone(),
 				"no such method found for the object type",
 				//
+				"defaults" => object::defaults,
 				"entries" => object::entries,
 				"extend" => object::extend,
+				"flatten" => object::flatten,
 				"is_empty" => object::is_empty,
 				"keys" => object::keys,
 				"len" => object::len,
 				"remove" => object::remove,
+				"unflatten" => object::unflatten,
 				"values" => object::values,
 
 
@@ -1254,6 +1342,7 @@ pub async fn idiom(
 				"sign" => math::sign,
 				"sin" => math::sin,
 				"tan" => math::tan,
+				"to_base" => number::to_base,
 
 				"type_of" => r#type::type_of,
 				"is_array" => r#type::is::array,
@@ -1319,10 +1408,15 @@ pub async fn idiom(
 				"concat" => string::concat,
 				"contains" => string::contains,
 				"ends_with" => string::ends_with,
+				"fuzzy_match" => string::fuzzy_match,
+				"fuzzy_score" => string::fuzzy_score,
 				"join" => string::join,
 				"len" => string::len,
 				"lowercase" => string::lowercase,
 				"matches" => string::matches,
+				"normalize_url" => string::normalize_url,
+				"parse_kv" => string::parse_kv,
+				"parse_template" => string::parse_template,
 				"repeat" => string::repeat,
 				"replace" => string::replace,
 				"reverse" => string::reverse,
@@ -1428,12 +1522,15 @@ pub async fn idiom(
 				args.clone(),
 				"no such method found for the datetime type",
 				//
+				"add_business_days" => time::add_business_days,
 				"ceil" => time::ceil,
 				"day" => time::day,
 				"floor" => time::floor,
 				"format" => time::format,
+				"format_zoned" => time::format_zoned,
 				"group" => time::group,
 				"hour" => time::hour,
+				"in_zone" => time::in_zone,
 				"is_leap_year" => time::is::leap_year,
 				"micros" => time::micros,
 				"millis" => time::millis,
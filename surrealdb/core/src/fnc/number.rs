@@ -0,0 +1,47 @@
+use anyhow::Result;
+
+use crate::err::Error;
+use crate::val::Value;
+
+/// Checks that a base is within the supported range of 2 to 36
+fn radix(name: &str, base: i64) -> Result<u32> {
+	match u32::try_from(base) {
+		Ok(radix @ 2..=36) => Ok(radix),
+		_ => Err(anyhow::Error::new(Error::InvalidFunctionArguments {
+			name: name.to_owned(),
+			message: format!("The base must be an integer between 2 and 36, but found {base}."),
+		})),
+	}
+}
+
+/// Returns the digits of an integer in the given base, using lowercase
+/// letters for the digits above 9
+pub fn to_base((val, base): (i64, i64)) -> Result<Value> {
+	let radix = radix("number::to_base", base)?;
+	let mut digits = Vec::new();
+	let mut rest = val.unsigned_abs();
+	loop {
+		let digit = (rest % radix as u64) as u32;
+		digits.push(char::from_digit(digit, radix).expect("digit should be below the radix"));
+		rest /= radix as u64;
+		if rest == 0 {
+			break;
+		}
+	}
+	if val < 0 {
+		digits.push('-');
+	}
+	Ok(digits.into_iter().rev().collect::<String>().into())
+}
+
+/// Parses the digits of an integer in the given base, ignoring the case of
+/// any letters
+pub fn from_base((val, base): (String, i64)) -> Result<Value> {
+	let radix = radix("number::from_base", base)?;
+	i64::from_str_radix(&val, radix).map(Value::from).map_err(|_| {
+		anyhow::Error::new(Error::InvalidFunctionArguments {
+			name: "number::from_base".to_owned(),
+			message: format!("The string '{val}' is not a valid integer in base {base}."),
+		})
+	})
+}
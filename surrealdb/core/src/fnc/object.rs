@@ -62,6 +62,134 @@ pub fn from_entries((array,): (Array,)) -> Result<Value> {
 	Ok(Value::Object(Object(obj)))
 }
 
+/// Flattens a nested object into an object with dotted keys, where array
+/// elements are addressed using bracketed indices, such as `a[0].b`.
+pub fn flatten((object,): (Object,)) -> Result<Value> {
+	fn flatten_into(out: &mut BTreeMap<String, Value>, key: String, value: Value) {
+		match value {
+			Value::Object(v) if !v.is_empty() => {
+				for (k, v) in v.0 {
+					flatten_into(out, format!("{key}.{k}"), v);
+				}
+			}
+			Value::Array(v) if !v.is_empty() => {
+				for (i, v) in v.0.into_iter().enumerate() {
+					flatten_into(out, format!("{key}[{i}]"), v);
+				}
+			}
+			v => {
+				out.insert(key, v);
+			}
+		}
+	}
+
+	let mut out = BTreeMap::new();
+	for (k, v) in object.0 {
+		flatten_into(&mut out, k, v);
+	}
+	Ok(Value::Object(Object(out)))
+}
+
+/// A single part of a flattened object key
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum FlatKeyPart {
+	Field(String),
+	Index(usize),
+}
+
+/// Parses a flattened key into its parts. Keys which are not valid paths
+/// are treated as a single field.
+fn parse_flat_key(key: &str) -> Vec<FlatKeyPart> {
+	fn parse(key: &str) -> Option<Vec<FlatKeyPart>> {
+		let mut parts = Vec::new();
+		for part in key.split('.') {
+			let (name, mut rest) = part.split_at(part.find('[').unwrap_or(part.len()));
+			if name.is_empty() {
+				return None;
+			}
+			parts.push(FlatKeyPart::Field(name.to_owned()));
+			while !rest.is_empty() {
+				let end = rest.find(']')?;
+				let idx = rest.strip_prefix('[')?[..end - 1].parse().ok()?;
+				parts.push(FlatKeyPart::Index(idx));
+				rest = &rest[end + 1..];
+			}
+		}
+		Some(parts)
+	}
+	parse(key).unwrap_or_else(|| vec![FlatKeyPart::Field(key.to_owned())])
+}
+
+/// Reverses [`flatten`], turning an object with dotted keys into a nested object.
+pub fn unflatten((object,): (Object,)) -> Result<Value> {
+	fn collision(key: &str) -> anyhow::Error {
+		anyhow::Error::new(Error::InvalidFunctionArguments {
+			name: "object::unflatten".to_string(),
+			message: format!("The key '{key}' collides with another key"),
+		})
+	}
+
+	fn container(part: &FlatKeyPart) -> Value {
+		match part {
+			FlatKeyPart::Field(_) => Value::Object(Object::default()),
+			FlatKeyPart::Index(_) => Value::Array(Array::new()),
+		}
+	}
+
+	fn insert(target: &mut Value, parts: &[FlatKeyPart], value: Value, key: &str) -> Result<()> {
+		let Some((part, rest)) = parts.split_first() else {
+			return Ok(());
+		};
+		let child = match (target, part) {
+			(Value::Object(obj), FlatKeyPart::Field(name)) => {
+				if rest.is_empty() {
+					if obj.contains_key(name) {
+						return Err(collision(key));
+					}
+					obj.insert(name.clone(), value);
+					return Ok(());
+				}
+				obj.entry(name.clone()).or_insert_with(|| container(&rest[0]))
+			}
+			(Value::Array(arr), FlatKeyPart::Index(idx)) => {
+				if *idx > arr.len() {
+					bail!(Error::InvalidFunctionArguments {
+						name: "object::unflatten".to_string(),
+						message: format!("The key '{key}' skips an array index"),
+					});
+				}
+				if rest.is_empty() {
+					if *idx < arr.len() {
+						return Err(collision(key));
+					}
+					arr.push(value);
+					return Ok(());
+				}
+				if *idx == arr.len() {
+					arr.push(container(&rest[0]));
+				}
+				&mut arr[*idx]
+			}
+			_ => return Err(collision(key)),
+		};
+		insert(child, rest, value, key)
+	}
+
+	// Sort the keys by their parts, so that array indices are ordered numerically
+	let mut entries: Vec<_> =
+		object.0.into_iter().map(|(k, v)| (parse_flat_key(&k), k, v)).collect();
+	entries.sort_by(|a, b| a.0.cmp(&b.0));
+	let mut out = Value::Object(Object::default());
+	for (parts, key, value) in entries {
+		insert(&mut out, &parts, value, &key)?;
+	}
+	Ok(out)
+}
+
+pub fn defaults((object, defaults): (Object, Object)) -> Result<Value> {
+	Ok(Value::Object(object).coalesce_deep(&Value::Object(defaults)))
+}
+
 pub fn extend((mut object, other): (Object, Object)) -> Result<Value> {
 	object.0.extend(other.0);
 	Ok(Value::Object(object))
@@ -1,9 +1,11 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use reblessive::tree::Stk;
+use surrealdb_types::ToSql;
 
 use crate::ctx::FrozenContext;
-use crate::dbs::Options;
+use crate::dbs::{DivByZero, Options};
 use crate::doc::CursorDoc;
+use crate::err::Error;
 use crate::expr::Expr;
 use crate::idx::planner::executor::QueryExecutor;
 use crate::val::{RecordId, TryAdd, TryDiv, TryMul, TryNeg, TryPow, TryRem, TrySub, Value};
@@ -28,12 +30,26 @@ pub fn mul(a: Value, b: Value) -> Result<Value> {
 	a.try_mul(b)
 }
 
-pub fn div(a: Value, b: Value) -> Result<Value> {
-	Ok(a.try_div(b).unwrap_or(f64::NAN.into()))
+pub fn div(a: Value, b: Value, div_by_zero: DivByZero) -> Result<Value> {
+	match (a, b) {
+		(Value::Number(a), Value::Number(b)) if b.is_zero() => match div_by_zero {
+			DivByZero::Error => bail!(Error::TryDiv(a.to_sql(), b.to_sql())),
+			DivByZero::None => Ok(Value::None),
+			DivByZero::Infinity => Ok(Value::from(a.as_float() / b.as_float())),
+		},
+		(a, b) => Ok(a.try_div(b).unwrap_or(f64::NAN.into())),
+	}
 }
 
-pub fn rem(a: Value, b: Value) -> Result<Value> {
-	a.try_rem(b)
+pub fn rem(a: Value, b: Value, div_by_zero: DivByZero) -> Result<Value> {
+	match (a, b) {
+		(Value::Number(a), Value::Number(b)) if b.is_zero() => match div_by_zero {
+			DivByZero::Error => bail!(Error::TryRem(a.to_sql(), b.to_sql())),
+			DivByZero::None => Ok(Value::None),
+			DivByZero::Infinity => Ok(Value::from(a.as_float() % b.as_float())),
+		},
+		(a, b) => a.try_rem(b),
+	}
 }
 
 pub fn pow(a: Value, b: Value) -> Result<Value> {
@@ -234,7 +250,7 @@ mod tests {
 	fn div_int() {
 		let one = Value::from(5);
 		let two = Value::from(4);
-		let res = div(one, two);
+		let res = div(one, two, DivByZero::Error);
 		let out = res.unwrap();
 		assert_eq!(out, Value::from(1));
 	}
@@ -243,8 +259,51 @@ mod tests {
 	fn div_float() {
 		let one = Value::from(5.0);
 		let two = Value::from(4.0);
-		let res = div(one, two);
+		let res = div(one, two, DivByZero::Error);
 		let out = res.unwrap();
 		assert_eq!(out, Value::from(1.25_f64));
 	}
+
+	#[test]
+	fn div_by_zero() {
+		use rust_decimal::Decimal;
+
+		let zeros = [Value::from(0), Value::from(0.0), Value::from(Decimal::ZERO)];
+		for zero in zeros {
+			// Errors by default
+			assert!(div(Value::from(1), zero.clone(), DivByZero::Error).is_err());
+			assert!(rem(Value::from(1), zero.clone(), DivByZero::Error).is_err());
+			assert!(div(Value::from(1.5), zero.clone(), DivByZero::Error).is_err());
+			assert!(rem(Value::from(Decimal::ONE), zero.clone(), DivByZero::Error).is_err());
+			// Returns NONE
+			assert_eq!(div(Value::from(1), zero.clone(), DivByZero::None).unwrap(), Value::None);
+			assert_eq!(rem(Value::from(1.5), zero.clone(), DivByZero::None).unwrap(), Value::None);
+			assert_eq!(
+				div(Value::from(Decimal::ONE), zero.clone(), DivByZero::None).unwrap(),
+				Value::None
+			);
+			// Returns the floating point result
+			assert_eq!(
+				div(Value::from(1), zero.clone(), DivByZero::Infinity).unwrap(),
+				Value::from(f64::INFINITY)
+			);
+			assert_eq!(
+				div(Value::from(Decimal::NEGATIVE_ONE), zero.clone(), DivByZero::Infinity).unwrap(),
+				Value::from(f64::NEG_INFINITY)
+			);
+			let Value::Number(out) =
+				div(Value::from(0.0), zero.clone(), DivByZero::Infinity).unwrap()
+			else {
+				panic!("Expected a number");
+			};
+			assert!(out.is_nan());
+			let Value::Number(out) = rem(Value::from(1), zero, DivByZero::Infinity).unwrap() else {
+				panic!("Expected a number");
+			};
+			assert!(out.is_nan());
+		}
+		// Division of non-zero values is unaffected
+		assert_eq!(div(Value::from(5), Value::from(4), DivByZero::None).unwrap(), Value::from(1));
+		assert_eq!(rem(Value::from(5), Value::from(4), DivByZero::None).unwrap(), Value::from(1));
+	}
 }
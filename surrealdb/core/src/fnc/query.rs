@@ -0,0 +1,52 @@
+use anyhow::Result;
+use surrealdb_types::ToSql;
+
+use crate::fmt::EscapeIdent;
+use crate::val::Value;
+
+/// Returns the string escaped for use as an identifier within a query.
+pub fn escape_ident((ident,): (String,)) -> Result<Value> {
+	Ok(EscapeIdent(ident).to_sql().into())
+}
+
+/// Returns the value formatted as a literal for use within a query.
+pub fn escape_literal((value,): (Value,)) -> Result<Value> {
+	Ok(value.to_sql().into())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn ident(s: &str) -> Value {
+		escape_ident((s.to_string(),)).unwrap()
+	}
+
+	fn literal(v: impl Into<Value>) -> Value {
+		escape_literal((v.into(),)).unwrap()
+	}
+
+	#[test]
+	fn escape_ident_plain() {
+		assert_eq!(ident("person"), Value::from("person"));
+		assert_eq!(ident("first_name"), Value::from("first_name"));
+	}
+
+	#[test]
+	fn escape_ident_special() {
+		assert_eq!(ident(""), Value::from("``"));
+		assert_eq!(ident("1st"), Value::from("`1st`"));
+		assert_eq!(ident("first name"), Value::from("`first name`"));
+		assert_eq!(ident("a`b"), Value::from("`a\\`b`"));
+		assert_eq!(ident("⟨a⟩"), Value::from("`⟨a⟩`"));
+		assert_eq!(ident("SELECT"), Value::from("`SELECT`"));
+	}
+
+	#[test]
+	fn escape_literal_strings() {
+		assert_eq!(literal("hello"), Value::from("'hello'"));
+		assert_eq!(literal("it's"), Value::from("\"it's\""));
+		assert_eq!(literal("it's \"quoted\""), Value::from("\"it's \\\"quoted\\\"\""));
+		assert_eq!(literal("a\nb"), Value::from("'a\\nb'"));
+	}
+}
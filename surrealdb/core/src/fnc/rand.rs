@@ -1,15 +1,19 @@
+use std::sync::LazyLock;
+
+use ::ulid::{Generator, Ulid};
 use anyhow::{Result, bail, ensure};
 use chrono::{TimeZone, Utc};
+use parking_lot::Mutex;
 use rand::Rng;
-use rand::distributions::{Alphanumeric, DistString};
+use rand::distributions::{Alphanumeric, DistString, Distribution, WeightedIndex};
 use rand::prelude::IteratorRandom;
 use rand::seq::SliceRandom;
-use ulid::Ulid;
+use surrealdb_types::ToSql;
 
 use super::args::{Any, Args, Arity, FromArg, Optional};
-use crate::cnf::ID_CHARS;
+use crate::cnf::{GENERATION_ALLOCATION_LIMIT, ID_CHARS};
 use crate::err::Error;
-use crate::val::{Datetime, Duration, Number, Uuid, Value};
+use crate::val::{Array, Datetime, Duration, Number, Uuid, Value};
 
 pub fn rand(_: ()) -> Result<Value> {
 	Ok(rand::random::<f64>().into())
@@ -259,6 +263,129 @@ pub fn time((NoneOrRange(range),): (NoneOrRange<Value>,)) -> Result<Value> {
 	fail!("Expected a valid datetime, but were unable to generate one")
 }
 
+/// Returns one of the values of the items, chosen with a probability
+/// proportional to its weight
+pub fn weighted((items,): (Array,)) -> Result<Value> {
+	let (mut values, weights) = weighted_items("rand::weighted", items)?;
+	if values.is_empty() {
+		return Ok(Value::None);
+	}
+	let draws = weighted_draws("rand::weighted", &mut rand::thread_rng(), &weights, 1, true)?;
+	Ok(values.swap_remove(draws[0]))
+}
+
+/// Returns `n` values of the items, each chosen with a probability
+/// proportional to its weight, and with each item chosen at most once when
+/// drawing without replacement
+pub fn weighted_many((items, n, Optional(replace)): (Array, i64, Optional<bool>)) -> Result<Value> {
+	const NAME: &str = "rand::weighted_many";
+	let (values, weights) = weighted_items(NAME, items)?;
+	let replace = replace.unwrap_or(true);
+	let n = usize::try_from(n).map_err(|_| Error::InvalidFunctionArguments {
+		name: NAME.to_owned(),
+		message: "The second argument must be a non-negative integer.".to_owned(),
+	})?;
+	ensure!(
+		n.saturating_mul(std::mem::size_of::<Value>()) <= *GENERATION_ALLOCATION_LIMIT,
+		Error::InvalidFunctionArguments {
+			name: NAME.to_owned(),
+			message: format!("Output must not exceed {} bytes.", *GENERATION_ALLOCATION_LIMIT),
+		}
+	);
+	if n == 0 {
+		return Ok(Value::Array(Array::new()));
+	}
+	ensure!(
+		if replace {
+			!values.is_empty()
+		} else {
+			n <= values.len()
+		},
+		Error::InvalidFunctionArguments {
+			name: NAME.to_owned(),
+			message: format!(
+				"Unable to draw {n} values without replacement from {} items.",
+				values.len()
+			),
+		}
+	);
+	let draws = weighted_draws(NAME, &mut rand::thread_rng(), &weights, n, replace)?;
+	Ok(Value::Array(draws.into_iter().map(|i| values[i].clone()).collect()))
+}
+
+/// Reads the values and weights of the items passed to a weighted function,
+/// where each item is an object with a `value` and a positive `weight`
+fn weighted_items(name: &str, items: Array) -> Result<(Vec<Value>, Vec<f64>)> {
+	let mut values = Vec::with_capacity(items.len());
+	let mut weights = Vec::with_capacity(items.len());
+	for item in items {
+		let Value::Object(mut item) = item else {
+			bail!(Error::InvalidFunctionArguments {
+				name: name.to_owned(),
+				message: format!(
+					"Expected each item to be an object with a value and a weight, but found {}.",
+					item.to_sql()
+				),
+			});
+		};
+		let weight = match item.remove("weight") {
+			Some(Value::Number(weight)) if weight.to_float().is_finite() => weight.to_float(),
+			weight => bail!(Error::InvalidFunctionArguments {
+				name: name.to_owned(),
+				message: format!(
+					"Expected the weight of each item to be a number, but found {}.",
+					weight.unwrap_or_default().to_sql()
+				),
+			}),
+		};
+		ensure!(
+			weight > 0.0,
+			Error::InvalidFunctionArguments {
+				name: name.to_owned(),
+				message: format!(
+					"Expected the weight of each item to be a positive number, but found {weight}."
+				),
+			}
+		);
+		values.push(item.remove("value").unwrap_or_default());
+		weights.push(weight);
+	}
+	Ok((values, weights))
+}
+
+/// Draws `n` indexes of the weights, each with a probability proportional to
+/// its weight
+///
+/// When drawing without replacement each index is drawn at most once, so `n`
+/// must not be more than the number of weights.
+fn weighted_draws<R: Rng>(
+	name: &str,
+	rng: &mut R,
+	weights: &[f64],
+	n: usize,
+	replace: bool,
+) -> Result<Vec<usize>> {
+	let invalid = |e| Error::InvalidFunctionArguments {
+		name: name.to_owned(),
+		message: format!("Unable to draw from the weights: {e}."),
+	};
+	let mut dist = WeightedIndex::new(weights).map_err(invalid)?;
+	let mut draws = Vec::with_capacity(n);
+	for i in 0..n {
+		let index = dist.sample(rng);
+		draws.push(index);
+		// Remove the drawn item, unless this was the last draw
+		if !replace && i + 1 < n {
+			dist.update_weights(&[(index, &0.0)]).map_err(invalid)?;
+		}
+	}
+	Ok(draws)
+}
+
+/// Generates the ULIDs which are not created from a given datetime, so that
+/// ULIDs generated within the same millisecond still increase
+static ULID_GENERATOR: LazyLock<Mutex<Generator>> = LazyLock::new(|| Mutex::new(Generator::new()));
+
 pub fn ulid((Optional(timestamp),): (Optional<Datetime>,)) -> Result<Value> {
 	let ulid = match timestamp {
 		Some(timestamp) => {
@@ -275,7 +402,10 @@ pub fn ulid((Optional(timestamp),): (Optional<Datetime>,)) -> Result<Value> {
 
 			Ulid::from_datetime(timestamp.0.into())
 		}
-		None => Ulid::new(),
+		None => match ULID_GENERATOR.lock().generate() {
+			Ok(ulid) => ulid,
+			Err(_) => fail!("Expected to generate a ULID, but the random component overflowed"),
+		},
 	};
 
 	Ok(ulid.to_string().into())
@@ -302,10 +432,35 @@ pub fn uuid((Optional(timestamp),): (Optional<Datetime>,)) -> Result<Value> {
 	Ok(uuid.into())
 }
 
+pub mod ulid {
+	use ::ulid::Ulid;
+	use anyhow::Result;
+
+	use crate::val::{Datetime, Value};
+
+	pub fn from_datetime((timestamp,): (Datetime,)) -> Result<Value> {
+		#[cfg(target_family = "wasm")]
+		anyhow::ensure!(
+			timestamp.0 >= chrono::DateTime::UNIX_EPOCH,
+			crate::err::Error::InvalidFunctionArguments {
+				name: String::from("rand::ulid::from_datetime"),
+				message: format!(
+					"To generate a ULID from a datetime, it must be a time beyond UNIX epoch."
+				),
+			}
+		);
+
+		Ok(Ulid::from_datetime(timestamp.0.into()).to_string().into())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use std::thread;
 
+	use rand::SeedableRng;
+	use rand::rngs::StdRng;
+
 	use super::*;
 
 	#[test]
@@ -323,6 +478,34 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_rand_ulid_monotonic_concurrency() {
+		let mut handles = vec![];
+		for _ in 0..10 {
+			handles.push(thread::spawn(|| {
+				(0..1000)
+					.map(|_| ulid((Optional(None),)).unwrap().into_raw_string())
+					.collect::<Vec<_>>()
+			}));
+		}
+		let mut all = std::collections::HashSet::new();
+		for handle in handles {
+			let ulids = handle.join().unwrap();
+			// The ULIDs generated by each thread are in the order they were generated
+			assert!(ulids.is_sorted_by(|a, b| a < b));
+			all.extend(ulids);
+		}
+		assert_eq!(all.len(), 10_000);
+	}
+
+	#[test]
+	fn test_rand_ulid_from_datetime() {
+		let datetime = Datetime::from(Utc.timestamp_millis_opt(1_718_452_800_123).unwrap());
+		let res = ulid::from_datetime((datetime.clone(),)).unwrap().into_raw_string();
+		let ulid = Ulid::from_string(&res).unwrap();
+		assert_eq!(Datetime::from(chrono::DateTime::<Utc>::from(ulid.datetime())), datetime);
+	}
+
 	#[test]
 	fn test_rand_id_len_0() {
 		let res = id((Optional(Some(0)), Optional(Some(0)))).unwrap();
@@ -332,8 +515,10 @@ mod tests {
 
 pub mod uuid {
 
-	use anyhow::Result;
+	use anyhow::{Result, bail};
+	use sha1::{Digest, Sha1};
 
+	use crate::err::Error;
 	use crate::fnc::args::Optional;
 	use crate::val::{Datetime, Uuid, Value};
 
@@ -341,6 +526,40 @@ pub mod uuid {
 		Ok(Uuid::new_v4().into())
 	}
 
+	pub fn v5((namespace, name): (Value, String)) -> Result<Value> {
+		// Resolve the namespace from a UUID or a standard namespace name
+		let namespace = match namespace {
+			Value::Uuid(v) => v.0,
+			Value::String(v) => match v.to_ascii_lowercase().as_str() {
+				"dns" => uuid::Uuid::NAMESPACE_DNS,
+				"url" => uuid::Uuid::NAMESPACE_URL,
+				"oid" => uuid::Uuid::NAMESPACE_OID,
+				"x500" => uuid::Uuid::NAMESPACE_X500,
+				_ => uuid::Uuid::try_parse(&v).map_err(|_| Error::InvalidFunctionArguments {
+					name: String::from("rand::uuid::v5"),
+					message: format!(
+						"The first argument must be a UUID or one of 'dns', 'url', 'oid' or 'x500', found '{v}'."
+					),
+				})?,
+			},
+			v => bail!(Error::InvalidFunctionArguments {
+				name: String::from("rand::uuid::v5"),
+				message: format!(
+					"The first argument must be a UUID or one of 'dns', 'url', 'oid' or 'x500', found {}.",
+					v.kind_of()
+				),
+			}),
+		};
+		// Hash the namespace and name as specified in RFC 9562
+		let mut hasher = Sha1::new();
+		hasher.update(namespace.as_bytes());
+		hasher.update(name.as_bytes());
+		let hash = hasher.finalize();
+		let mut bytes = [0u8; 16];
+		bytes.copy_from_slice(&hash[..16]);
+		Ok(Uuid(uuid::Builder::from_sha1_bytes(bytes).into_uuid()).into())
+	}
+
 	pub fn v7((Optional(timestamp),): (Optional<Datetime>,)) -> Result<Value> {
 		let uuid = match timestamp {
 			Some(timestamp) => {
@@ -361,4 +580,37 @@ pub mod uuid {
 		};
 		Ok(uuid.into())
 	}
+
+	#[test]
+	fn test_rand_weighted_distribution() {
+		let weights = [1.0, 2.0, 7.0];
+		let mut rng = StdRng::seed_from_u64(42);
+		let draws = weighted_draws("rand::weighted", &mut rng, &weights, 100_000, true).unwrap();
+		let mut counts = [0usize; 3];
+		for i in draws {
+			counts[i] += 1;
+		}
+		// Each weight is drawn in proportion to its share of the total
+		for (count, weight) in counts.iter().zip(weights) {
+			let expected = weight / 10.0;
+			let actual = *count as f64 / 100_000.0;
+			assert!((actual - expected).abs() < 0.01, "expected {expected}, found {actual}");
+		}
+		// The same seed draws the same values
+		let a = weighted_draws("rand::weighted", &mut StdRng::seed_from_u64(7), &weights, 10, true);
+		let b = weighted_draws("rand::weighted", &mut StdRng::seed_from_u64(7), &weights, 10, true);
+		assert_eq!(a.unwrap(), b.unwrap());
+	}
+
+	#[test]
+	fn test_rand_weighted_without_replacement() {
+		let weights = [1.0, 1000.0, 1.0, 1.0];
+		let mut rng = StdRng::seed_from_u64(42);
+		for _ in 0..100 {
+			let mut draws =
+				weighted_draws("rand::weighted_many", &mut rng, &weights, 4, false).unwrap();
+			draws.sort();
+			assert_eq!(draws, vec![0, 1, 2, 3]);
+		}
+	}
 }
@@ -1,12 +1,15 @@
-use anyhow::Result;
+use std::ops::Bound;
+
+use anyhow::{Result, bail};
 use reblessive::tree::Stk;
 
 use crate::ctx::FrozenContext;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
+use crate::err::Error;
 use crate::expr::FlowResultExt as _;
 use crate::expr::paths::ID;
-use crate::val::{RecordId, Value};
+use crate::val::{Object, RecordId, RecordIdKey, RecordIdKeyRange, TableName, Value};
 
 pub async fn exists(
 	(stk, ctx, opt, doc): (&mut Stk, &FrozenContext, Option<&Options>, Option<&CursorDoc>),
@@ -28,6 +31,69 @@ pub fn tb((arg,): (RecordId,)) -> Result<Value> {
 	Ok(arg.table.into_string().into())
 }
 
+/// Parses a string as a record id, returning an object with its table and id
+pub fn parse((arg,): (String,)) -> Result<Value> {
+	let rid: RecordId = crate::syn::record_id(&arg)
+		.map_err(|_| Error::InvalidFunctionArguments {
+			name: "record::parse".to_owned(),
+			message: format!("The argument must be a record id, but found '{arg}'."),
+		})?
+		.into();
+	Ok(Value::Object(Object(map! {
+		"table".to_string() => Value::from(rid.table.into_string()),
+		"id".to_string() => rid.key.into_value(),
+	})))
+}
+
+/// Builds a range of record ids on a table, equivalent to `table:start..end`.
+/// Either bound can be NONE to leave that side of the range open.
+pub fn range((table, start, end): (Value, Value, Value)) -> Result<Value> {
+	let table = match table {
+		Value::Table(tb) => tb,
+		Value::String(tb) if !tb.is_empty() => TableName::new(tb),
+		v => bail!(Error::InvalidFunctionArguments {
+			name: "record::range".to_owned(),
+			message: format!(
+				"The first argument must be a table name, but found a {}.",
+				v.kind_of()
+			),
+		}),
+	};
+	// Both bounds must be keys of the same type to form a range
+	if !start.is_none() && !end.is_none() && start.kind_of() != end.kind_of() {
+		bail!(Error::InvalidFunctionArguments {
+			name: "record::range".to_owned(),
+			message: format!(
+				"The start and end of the range must be of the same type, but found a {} and a {}.",
+				start.kind_of(),
+				end.kind_of()
+			),
+		});
+	}
+	let range = RecordIdKeyRange {
+		start: range_bound(start)?.map_or(Bound::Unbounded, Bound::Included),
+		end: range_bound(end)?.map_or(Bound::Unbounded, Bound::Excluded),
+	};
+	Ok(Value::RecordId(RecordId::new(table, Box::new(range))))
+}
+
+/// Converts a bound of a `record::range` into a record id key
+fn range_bound(value: Value) -> Result<Option<RecordIdKey>> {
+	if value.is_none() {
+		return Ok(None);
+	}
+	let kind = value.kind_of();
+	match RecordIdKey::from_value(value) {
+		Some(RecordIdKey::Range(_)) | None => bail!(Error::InvalidFunctionArguments {
+			name: "record::range".to_owned(),
+			message: format!(
+				"The bounds of the range must be record id keys or NONE, but found a {kind}."
+			),
+		}),
+		Some(key) => Ok(Some(key)),
+	}
+}
+
 pub mod is {
 	use anyhow::Result;
 	use reblessive::tree::Stk;
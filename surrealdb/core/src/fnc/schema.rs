@@ -1,3 +1,99 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use surrealdb_types::ToSql;
+
+use crate::catalog::providers::TableProvider;
+use crate::ctx::FrozenContext;
+use crate::dbs::Options;
+use crate::expr::Base;
+use crate::iam::{Action, ResourceKind};
+use crate::val::{TableName, Value};
+
+/// The definitions of one kind on a table, keyed by name. Each entry holds
+/// the definition as it was written, and the definition as it would be
+/// written on the table which is being compared against.
+type Definitions = BTreeMap<String, (String, String)>;
+
+/// Returns the field, index, and event definitions which differ between two
+/// tables. Definitions only on the second table are added, definitions only
+/// on the first table are removed, and definitions with the same name which
+/// are defined differently are changed.
+pub async fn diff(
+	(ctx, opt): (&FrozenContext, Option<&Options>),
+	(a, b): (String, String),
+) -> Result<Value> {
+	let Some(opt) = opt else {
+		return Ok(Value::None);
+	};
+	opt.valid_for_db()?;
+	opt.is_allowed(Action::View, ResourceKind::Table, &Base::Db)?;
+	let (ns, db) = ctx.expect_ns_db_ids(opt).await?;
+	let txn = ctx.tx();
+	let a: TableName = a.into();
+	let b: TableName = b.into();
+	// Collect the definitions for both tables
+	let mut fields = (Definitions::new(), Definitions::new());
+	let mut indexes = (Definitions::new(), Definitions::new());
+	let mut events = (Definitions::new(), Definitions::new());
+	for (tb, fds, ixs, evs) in [
+		(&a, &mut fields.0, &mut indexes.0, &mut events.0),
+		(&b, &mut fields.1, &mut indexes.1, &mut events.1),
+	] {
+		for fd in txn.all_tb_fields(ns, db, tb, None).await?.iter() {
+			let mut cmp = fd.clone();
+			cmp.table = a.clone();
+			fds.insert(fd.name.to_sql(), (fd.to_sql(), cmp.to_sql()));
+		}
+		for ix in txn.all_tb_indexes(ns, db, tb, None).await?.iter() {
+			// Skip indexes which are being removed
+			if ix.prepare_remove {
+				continue;
+			}
+			let mut cmp = ix.clone();
+			cmp.table_name = a.clone();
+			ixs.insert(ix.name.clone(), (ix.to_sql(), cmp.to_sql()));
+		}
+		for ev in txn.all_tb_events(ns, db, tb, None).await?.iter() {
+			let mut cmp = ev.clone();
+			cmp.target_table = a.clone();
+			evs.insert(ev.name.clone(), (ev.to_sql(), cmp.to_sql()));
+		}
+	}
+	Ok(Value::from(map! {
+		"fields".to_string() => diff_definitions(fields.0, fields.1),
+		"indexes".to_string() => diff_definitions(indexes.0, indexes.1),
+		"events".to_string() => diff_definitions(events.0, events.1),
+	}))
+}
+
+fn diff_definitions(a: Definitions, b: Definitions) -> Value {
+	let mut added = Vec::new();
+	let mut removed = Vec::new();
+	let mut changed = Vec::new();
+	for (name, (sql, cmp)) in a.iter() {
+		match b.get(name) {
+			None => removed.push(Value::from(sql.clone())),
+			Some((other, other_cmp)) if other_cmp != cmp => changed.push(Value::from(map! {
+				"name".to_string() => Value::from(name.clone()),
+				"from".to_string() => Value::from(sql.clone()),
+				"to".to_string() => Value::from(other.clone()),
+			})),
+			Some(_) => {}
+		}
+	}
+	for (name, (sql, _)) in b {
+		if !a.contains_key(&name) {
+			added.push(Value::from(sql));
+		}
+	}
+	Value::from(map! {
+		"added".to_string() => Value::from(added),
+		"removed".to_string() => Value::from(removed),
+		"changed".to_string() => Value::from(changed),
+	})
+}
+
 pub mod table {
 	use anyhow::Result;
 
@@ -15,7 +15,7 @@ use crate::fnc::script::fetch::classes::{
 	Request, RequestInit, RequestRedirect, Response, ResponseInit, ResponseType,
 };
 use crate::fnc::script::modules::surrealdb::query::QueryContext;
-use crate::http::HttpClient;
+use crate::http::Redirects;
 
 #[js::function]
 pub async fn fetch<'js>(
@@ -48,43 +48,18 @@ pub async fn fetch<'js>(
 	let headers = headers.borrow();
 	let mut headers = headers.inner.clone();
 
-	let client = match js_req.init.request_redirect {
-		RequestRedirect::Follow => query_ctx.http_client(),
-		RequestRedirect::Error => {
-			let cap = query_ctx.get_capabilities();
-			Arc::new(
-				HttpClient::new_with_redirect_policy(
-					cap.allow_net.clone(),
-					cap.allow_net.clone(),
-					|attempt| attempt.error("unexpected redirect"),
-				)
-				.map_err(|e| {
-					Exception::throw_internal(
-						&ctx,
-						&format!("Could not initialize http client: {e}"),
-					)
-				})?,
-			)
-		}
-		RequestRedirect::Manual => {
-			let cap = query_ctx.get_capabilities();
-			Arc::new(
-				HttpClient::new_with_redirect_policy(
-					cap.allow_net.clone(),
-					cap.allow_net.clone(),
-					|attempt| attempt.stop(),
-				)
-				.map_err(|e| {
-					Exception::throw_internal(
-						&ctx,
-						&format!("Could not initialize http client: {e}"),
-					)
-				})?,
-			)
-		}
+	let redirects = match js_req.init.request_redirect {
+		RequestRedirect::Follow => Redirects::Follow,
+		RequestRedirect::Error => Redirects::Error,
+		RequestRedirect::Manual => Redirects::Manual,
 	};
-
-	let mut req_builder = client.request(js_req.init.method, url.clone());
+	// Reuse the clients of the query context, along with their connection pools
+	let mut req_builder = query_ctx
+		.http_client()
+		.request_with_redirects(redirects, js_req.init.method, url.clone())
+		.map_err(|e| {
+			Exception::throw_internal(&ctx, &format!("Could not initialize http client: {e}"))
+		})?;
 	// Set the body for the request.
 	if let Some(body) = js_req.init.body {
 		match body.data.replace(BodyData::Used) {
@@ -24,6 +24,7 @@ impl_module_def!(
 	"concat" => run,
 	"difference" => run,
 	"distinct" => run,
+	"edit_script" => run,
 	"every" => fut Async,
 	"fill" => run,
 	"filter" => fut Async,
@@ -49,7 +50,10 @@ impl_module_def!(
 	"map" => fut Async,
 	"matches" => run,
 	"max" => run,
+	"merge_sorted" => run,
+	"merge_sorted_by" => fut Async,
 	"min" => run,
+	"partition" => fut Async,
 	"pop" => run,
 	"push" => run,
 	"prepend" => run,
@@ -63,11 +67,13 @@ impl_module_def!(
 	"slice" => run,
 	"some" => fut Async,
 	"sort" => (sort::Package),
+	"sort_by_keys" => run,
 	"sort_lexical" => run,
 	"sort_natural" => run,
 	"sort_natural_lexical" => run,
 	"swap" => run,
 	"transpose" => run,
 	"union" => run,
+	"unique_by" => run,
 	"windows" => run
 );
@@ -13,5 +13,6 @@ impl_module_def!(
 	"centroid" => run,
 	"distance" => run,
 	"hash" => (hash::Package),
-	"is_valid" => run
+	"is_valid" => run,
+	"within" => run
 );
@@ -10,8 +10,12 @@ impl_module_def!(
 	"http",
 	"head" => fut Async,
 	"get" => fut Async,
+	"get_with_meta" => fut Async,
+	"stream" => fut Async,
 	"put" => fut Async,
 	"post" => fut Async,
 	"patch" => fut Async,
-	"delete" => fut Async
+	"delete" => fut Async,
+	"options" => fut Async,
+	"trace" => fut Async
 );
@@ -17,6 +17,7 @@ impl_module_def!(
 	"cos" => run,
 	"cot" => run,
 	"deg2rad" => run,
+	"ema" => run,
 	"fixed" => run,
 	"floor" => run,
 	"interquartile" => run,
@@ -32,7 +33,9 @@ impl_module_def!(
 	"midhinge" => run,
 	"min" => run,
 	"mode" => run,
+	"moving_average" => run,
 	"nearestrank" => run,
+	"percent_rank" => run,
 	"percentile" => run,
 	"pow" => run,
 	"product" => run,
@@ -45,6 +48,7 @@ impl_module_def!(
 	"stddev" => run,
 	"sum" => run,
 	"tan" => run,
+	"time_weighted_avg" => run,
 	"top" => run,
 	"trimean" => run,
 	"variance" => run
@@ -6,6 +6,7 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"record",
+	"engine" => run,
 	"id" => run,
 	"tb" => run
 );
@@ -11,15 +11,19 @@ mod api;
 mod array;
 mod bytes;
 mod crypto;
+mod debug;
 mod duration;
 mod encoding;
 mod file;
 mod geo;
+mod graph;
 mod http;
 mod math;
 mod meta;
+mod number;
 mod object;
 mod parse;
+mod query;
 mod rand;
 mod record;
 mod schema;
@@ -28,6 +32,7 @@ mod sequence;
 mod session;
 mod set;
 mod string;
+mod table;
 mod time;
 mod r#type;
 mod value;
@@ -43,16 +48,20 @@ impl_module_def!(
 	"bytes" => (bytes::Package),
 	"count" => run,
 	"crypto" => (crypto::Package),
+	"debug" => (debug::Package),
 	"duration" => (duration::Package),
 	"encoding" => (encoding::Package),
 	"file" => (file::Package),
 	"geo" => (geo::Package),
+	"graph" => (graph::Package),
 	"http" => (http::Package),
 	"math" => (math::Package),
 	"meta" => (meta::Package),
 	"not" => run,
+	"number" => (number::Package),
 	"object" => (object::Package),
 	"parse" => (parse::Package),
+	"query" => (query::Package),
 	"rand" => (rand::Package),
 	"record" => (record::Package),
 	"search" => (search::Package),
@@ -61,6 +70,7 @@ impl_module_def!(
 	"set" => (set::Package),
 	"sleep" => fut Async,
 	"string" => (string::Package),
+	"table" => (table::Package),
 	"time" => (time::Package),
 	"type" => (r#type::Package),
 	"value" => (value::Package),
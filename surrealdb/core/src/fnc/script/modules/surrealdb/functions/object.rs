@@ -6,12 +6,15 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"object",
+	"defaults" => run,
 	"entries" => run,
 	"extend" => run,
+	"flatten" => run,
 	"from_entries" => run,
 	"is_empty" => run,
 	"keys" => run,
 	"len" => run,
 	"remove" => run,
+	"unflatten" => run,
 	"values" => run
 );
@@ -4,6 +4,7 @@ use js::prelude::Rest;
 use super::run;
 use crate::val::Value;
 
+mod ulid;
 mod uuid;
 
 pub struct Package;
@@ -21,6 +22,8 @@ impl js::module::ModuleDef for Package {
 		decls.declare("time")?;
 		decls.declare("ulid")?;
 		decls.declare("uuid")?;
+		decls.declare("weighted")?;
+		decls.declare("weighted_many")?;
 		Ok(())
 	}
 	fn evaluate<'js>(ctx: &js::Ctx<'js>, exports: &js::module::Exports<'js>) -> js::Result<()> {
@@ -52,13 +55,20 @@ impl js::module::ModuleDef for Package {
 		let value = crate::fnc::script::modules::impl_module_def!(ctx, "rand", "time", run,);
 		exports.export("time", value.clone())?;
 		default.set("time", value)?;
-		let value = crate::fnc::script::modules::impl_module_def!(ctx, "rand", "ulid", run,);
+		let value = crate::fnc::script::modules::impl_module_def!(ctx, "rand", "ulid", (ulid::Package),);
 		exports.export("ulid", value.clone())?;
 		default.set("ulid", value)?;
 		let value =
 			crate::fnc::script::modules::impl_module_def!(ctx, "rand", "uuid", (uuid::Package),);
 		exports.export("uuid", value.clone())?;
 		default.set("uuid", value)?;
+		let value = crate::fnc::script::modules::impl_module_def!(ctx, "rand", "weighted", run,);
+		exports.export("weighted", value.clone())?;
+		default.set("weighted", value)?;
+		let value =
+			crate::fnc::script::modules::impl_module_def!(ctx, "rand", "weighted_many", run,);
+		exports.export("weighted_many", value.clone())?;
+		default.set("weighted_many", value)?;
 		exports.export("default", default)?;
 		Ok(())
 	}
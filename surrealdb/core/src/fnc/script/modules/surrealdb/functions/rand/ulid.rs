@@ -0,0 +1,27 @@
+use js::Ctx;
+use js::prelude::Rest;
+
+use super::super::run;
+use crate::val::Value;
+
+pub struct Package;
+
+impl js::module::ModuleDef for Package {
+	fn declare(decls: &js::module::Declarations) -> js::Result<()> {
+		decls.declare("default")?;
+		decls.declare("from_datetime")?;
+		Ok(())
+	}
+	fn evaluate<'js>(ctx: &js::Ctx<'js>, exports: &js::module::Exports<'js>) -> js::Result<()> {
+		let default = js::Function::new(ctx.clone(), |ctx: Ctx<'js>, args: Rest<Value>| {
+			run(ctx, "rand::ulid", args.0)
+		})?
+		.with_name("ulid")?;
+		let value =
+			crate::fnc::script::modules::impl_module_def!(ctx, "rand::ulid", "from_datetime", run,);
+		exports.export("from_datetime", value.clone())?;
+		default.set("from_datetime", value)?;
+		exports.export("default", default)?;
+		Ok(())
+	}
+}
@@ -10,6 +10,7 @@ impl js::module::ModuleDef for Package {
 	fn declare(decls: &js::module::Declarations) -> js::Result<()> {
 		decls.declare("default")?;
 		decls.declare("v4")?;
+		decls.declare("v5")?;
 		decls.declare("v7")?;
 		Ok(())
 	}
@@ -21,6 +22,9 @@ impl js::module::ModuleDef for Package {
 		let value = crate::fnc::script::modules::impl_module_def!(ctx, "rand::uuid", "v4", run,);
 		exports.export("v4", value.clone())?;
 		default.set("v4", value)?;
+		let value = crate::fnc::script::modules::impl_module_def!(ctx, "rand::uuid", "v5", run,);
+		exports.export("v5", value.clone())?;
+		default.set("v5", value)?;
 		let value = crate::fnc::script::modules::impl_module_def!(ctx, "rand::uuid", "v7", run,);
 		exports.export("v7", value.clone())?;
 		default.set("v7", value)?;
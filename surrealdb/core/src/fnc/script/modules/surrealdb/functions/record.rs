@@ -10,6 +10,8 @@ impl_module_def!(
 	"record",
 	"exists" => fut Async,
 	"id" => run,
+	"parse" => run,
+	"range" => run,
 	"table" => run,
 	"tb" => run,
 	"is_edge" => fut Async
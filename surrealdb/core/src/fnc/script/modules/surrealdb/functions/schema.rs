@@ -1,3 +1,6 @@
+use js::prelude::Async;
+
+use super::fut;
 use crate::fnc::script::modules::impl_module_def;
 
 mod table;
@@ -7,5 +10,6 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"string",
+	"diff" => fut Async,
 	"table" => (table::Package)
 );
@@ -8,6 +8,7 @@ impl_module_def!(
 	"session",
 	"db" => run,
 	"id" => run,
+	"info" => run,
 	"ip" => run,
 	"ns" => run,
 	"origin" => run,
@@ -16,11 +16,16 @@ impl_module_def!(
 	"contains" => run,
 	"distance" => (distance::Package),
 	"ends_with" => run,
+	"fuzzy_match" => run,
+	"fuzzy_score" => run,
 	"html" => (html::Package),
 	"join" => run,
 	"len" => run,
 	"lowercase" => run,
 	"matches" => run,
+	"normalize_url" => run,
+	"parse_kv" => run,
+	"parse_template" => run,
 	"repeat" => run,
 	"replace" => run,
 	"reverse" => run,
@@ -6,6 +6,7 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"string::distance",
+	"damerau" => run,
 	"damerau_levenshtein" => run,
 	"hamming" => run,
 	"levenshtein" => run,
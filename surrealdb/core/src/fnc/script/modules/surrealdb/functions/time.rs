@@ -6,12 +6,15 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"time",
+	"add_business_days" => run,
 	"ceil" => run,
 	"day" => run,
 	"floor" => run,
 	"format" => run,
+	"format_zoned" => run,
 	"group" => run,
 	"hour" => run,
+	"in_zone" => run,
 	"max" => run,
 	"min" => run,
 	"mins" => run,
@@ -18,7 +18,9 @@ impl_module_def!(
 	"fields" => fut Async,
 	"file" => run,
 	"float" => run,
+	"id_from" => run,
 	"int" => run,
+	"is" => run,
 	"number" => run,
 	"of" => run,
 	"point" => run,
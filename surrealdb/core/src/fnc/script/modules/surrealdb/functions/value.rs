@@ -1,6 +1,6 @@
 use js::prelude::Async;
 
-use super::fut;
+use super::{fut, run};
 use crate::fnc::script::modules::impl_module_def;
 
 pub struct Package;
@@ -8,6 +8,8 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"value",
+	"canonicalize" => run,
+	"deduplicate_refs" => run,
 	"diff" => fut Async,
 	"patch" => fut Async
 );
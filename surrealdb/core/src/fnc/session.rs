@@ -1,7 +1,9 @@
 use anyhow::Result;
 
 use crate::ctx::FrozenContext;
-use crate::expr::paths::{AC, DB, ID, IP, NS, OR, RD, TK};
+use crate::dbs::Options;
+use crate::expr::paths::{AC, DB, ID, IP, NS, OR, PR, RD, TK};
+use crate::iam::{Auth, Level};
 use crate::val::Value;
 
 pub fn ac(ctx: &FrozenContext, _: ()) -> Result<Value> {
@@ -16,6 +18,38 @@ pub fn id(ctx: &FrozenContext, _: ()) -> Result<Value> {
 	Ok(ctx.value("session").unwrap_or(&Value::None).pick(ID.as_ref()))
 }
 
+pub fn info((ctx, opt): (&FrozenContext, &Options), _: ()) -> Result<Value> {
+	Ok(info_object(ctx, &opt.auth))
+}
+
+/// Builds the object returned by `session::info()`, which describes the
+/// session and its connection without exposing any credentials.
+pub(crate) fn info_object(ctx: &FrozenContext, auth: &Auth) -> Value {
+	let session = ctx.value("session").unwrap_or(&Value::None);
+	let level = match auth.level() {
+		Level::No => Value::None,
+		Level::Root => Value::from("ROOT"),
+		Level::Namespace(_) => Value::from("NAMESPACE"),
+		Level::Database(_, _) => Value::from("DATABASE"),
+		Level::Record(_, _, _) => Value::from("RECORD"),
+	};
+	let role = match auth.max_role() {
+		Some(role) => Value::from(role.to_string().to_uppercase()),
+		None => Value::None,
+	};
+	Value::from(map! {
+		"ac".to_string() => session.pick(AC.as_ref()),
+		"db".to_string() => session.pick(DB.as_ref()),
+		"id".to_string() => session.pick(ID.as_ref()),
+		"ip".to_string() => session.pick(IP.as_ref()),
+		"level".to_string() => level,
+		"ns".to_string() => session.pick(NS.as_ref()),
+		"origin".to_string() => session.pick(OR.as_ref()),
+		"protocol".to_string() => session.pick(PR.as_ref()),
+		"role".to_string() => role,
+	})
+}
+
 pub fn ip(ctx: &FrozenContext, _: ()) -> Result<Value> {
 	Ok(ctx.value("session").unwrap_or(&Value::None).pick(IP.as_ref()))
 }
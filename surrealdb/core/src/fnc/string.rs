@@ -1,14 +1,15 @@
 use std::ops::Bound;
 
-use anyhow::{Result, ensure};
+use anyhow::{Result, bail, ensure};
 use surrealdb_types::ToSql;
+use url::Url;
 
 use super::args::{Any, Cast, Optional};
 use crate::cnf::{GENERATION_ALLOCATION_LIMIT, STRING_SIMILARITY_LIMIT};
 use crate::err::Error;
 use crate::fnc::util::string;
 use crate::val::range::TypedRange;
-use crate::val::{Regex, Value};
+use crate::val::{Object, Regex, Value};
 
 /// Returns `true` if a string of this length is too much to allocate.
 fn limit(name: &str, n: usize) -> Result<()> {
@@ -81,6 +82,32 @@ pub fn ends_with((val, chr): (String, String)) -> Result<Value> {
 	Ok(val.ends_with(&chr).into())
 }
 
+/// Checks whether two strings match, allowing for small differences.
+///
+/// The strings match when their [`fuzzy_score`] is at least the given
+/// threshold, which must be between 0.0 and 1.0.
+pub fn fuzzy_match((haystack, needle, threshold): (String, String, f64)) -> Result<Value> {
+	ensure!(
+		(0.0..=1.0).contains(&threshold),
+		Error::InvalidFunctionArguments {
+			name: "string::fuzzy_match".to_owned(),
+			message: "The threshold must be a number between 0 and 1.".to_owned(),
+		}
+	);
+	check_similarity_input_length("string::fuzzy_match", &haystack, &needle)?;
+	Ok((strsim::normalized_levenshtein(&haystack, &needle) >= threshold).into())
+}
+
+/// Calculates how closely two strings match.
+///
+/// The score is the Levenshtein distance between the strings, normalized by
+/// the length of the longer string and subtracted from 1.0. A score of 1.0
+/// means the strings are identical, and 0.0 means they share nothing.
+pub fn fuzzy_score((a, b): (String, String)) -> Result<Value> {
+	check_similarity_input_length("string::fuzzy_score", &a, &b)?;
+	Ok(strsim::normalized_levenshtein(&a, &b).into())
+}
+
 pub fn join(Any(args): Any) -> Result<Value> {
 	let mut args = args.into_iter().map(Value::into_raw_string);
 	let chr = args.next().ok_or_else(|| Error::InvalidFunctionArguments {
@@ -108,6 +135,70 @@ pub fn lowercase((string,): (String,)) -> Result<Value> {
 	Ok(string.to_lowercase().into())
 }
 
+/// Returns the URL in its normalized form, with a lowercase scheme and host,
+/// and without the default port for the scheme. Returns NONE when the string
+/// is not a valid URL.
+pub fn normalize_url((string,): (String,)) -> Result<Value> {
+	match Url::parse(&string) {
+		Ok(url) => Ok(String::from(url).into()),
+		Err(_) => Ok(Value::None),
+	}
+}
+
+/// Reads the `strict` option of the parsing functions, which makes malformed
+/// input an error instead of returning the fields parsed before it.
+fn parse_strict_option(name: &str, opts: Option<Object>) -> Result<bool> {
+	let mut strict = false;
+	for (k, v) in opts.into_iter().flat_map(|o| o.0) {
+		match (k.as_str(), v) {
+			("strict", Value::Bool(v)) => strict = v,
+			(k, v) => bail!(Error::InvalidFunctionArguments {
+				name: name.to_owned(),
+				message: format!(
+					"Unexpected option `{k}: {}`. Expected `strict` to be a boolean.",
+					v.to_sql()
+				),
+			}),
+		}
+	}
+	Ok(strict)
+}
+
+/// Parses a line of `key=value` pairs, such as a logfmt log line, into an
+/// object.
+pub fn parse_kv((line, Optional(opts)): (String, Optional<Object>)) -> Result<Value> {
+	let strict = parse_strict_option("string::parse_kv", opts)?;
+	let (res, err) = string::parse::kv(&line);
+	if let Some(message) = err.filter(|_| strict) {
+		bail!(Error::InvalidFunctionArguments {
+			name: "string::parse_kv".to_owned(),
+			message,
+		});
+	}
+	Ok(res.into())
+}
+
+/// Extracts the `{field}` placeholders of a template from a line into an
+/// object.
+pub fn parse_template(
+	(line, template, Optional(opts)): (String, String, Optional<Object>),
+) -> Result<Value> {
+	let strict = parse_strict_option("string::parse_template", opts)?;
+	let (res, err) = string::parse::template(&line, &template).map_err(|message| {
+		Error::InvalidFunctionArguments {
+			name: "string::parse_template".to_owned(),
+			message: format!("Invalid template: {message}"),
+		}
+	})?;
+	if let Some(message) = err.filter(|_| strict) {
+		bail!(Error::InvalidFunctionArguments {
+			name: "string::parse_template".to_owned(),
+			message,
+		});
+	}
+	Ok(res.into())
+}
+
 pub fn repeat((val, num): (String, i64)) -> Result<Value> {
 	//TODO: Deal with truncation of neg:
 	let num = num as usize;
@@ -292,7 +383,10 @@ pub mod distance {
 	use crate::err::Error;
 	use crate::val::Value;
 
-	/// Calculate the Damerau-Levenshtein distance between two strings.
+	/// Calculate the Damerau-Levenshtein distance between two strings. This
+	/// is the number of insertions, deletions, substitutions, and
+	/// transpositions of adjacent characters needed to turn one string into
+	/// the other, where characters are unicode code points.
 	///
 	/// Time complexity: O(n * m) where n and m are the string lengths.
 	/// Space complexity: O(n * m).
@@ -339,7 +433,9 @@ pub mod distance {
 		}
 	}
 
-	/// Calculate the Levenshtein distance between two strings.
+	/// Calculate the Levenshtein distance between two strings. This is the
+	/// number of insertions, deletions, and substitutions needed to turn one
+	/// string into the other, where characters are unicode code points.
 	///
 	/// Time complexity: O(n * m) where n and m are the string lengths.
 	/// Space complexity: O(min(n, m)).
@@ -730,9 +826,9 @@ pub mod semver {
 mod tests {
 	use surrealdb_types::ToSql;
 
-	use super::{matches, replace, slice};
+	use super::{matches, normalize_url, replace, slice};
 	use crate::fnc::args::{Cast, Optional};
-	use crate::val::Value;
+	use crate::val::{Object, Value};
 
 	#[test]
 	fn string_slice() {
@@ -798,6 +894,26 @@ mod tests {
 		test("foo bar", "bar", true);
 	}
 
+	#[test]
+	fn string_normalize_url() {
+		#[track_caller]
+		fn test(url: &str, expected: Value) {
+			assert_eq!(
+				normalize_url((url.to_string(),)).unwrap(),
+				expected,
+				"normalize_url({url})"
+			);
+		}
+
+		test("HTTP://Example.COM:80/a", "http://example.com/a".into());
+		test("https://example.com:443", "https://example.com/".into());
+		test("ws://example.com:80/socket", "ws://example.com/socket".into());
+		test("http://example.com:8080", "http://example.com:8080/".into());
+		test("https://example.com:80", "https://example.com:80/".into());
+		test("example.com", Value::None);
+		test("", Value::None);
+	}
+
 	#[test]
 	fn html_encode() {
 		let value = super::html::encode((String::from("<div>Hello world!</div>"),)).unwrap();
@@ -864,6 +980,24 @@ mod tests {
 		assert_eq!(value, Value::from("1.2.9"));
 	}
 
+	#[test]
+	fn fuzzy_match() {
+		let score = super::fuzzy_score(("surrealdb".into(), "surealdb".into())).unwrap();
+		assert_eq!(score, Value::from(1.0 - 1.0 / 9.0));
+		// Near-matches above the threshold
+		let value = super::fuzzy_match(("surrealdb".into(), "surealdb".into(), 0.8)).unwrap();
+		assert_eq!(value, Value::Bool(true));
+		// Near-matches below the threshold
+		let value = super::fuzzy_match(("surrealdb".into(), "surealdb".into(), 0.9)).unwrap();
+		assert_eq!(value, Value::Bool(false));
+		// Identical strings always match
+		let value = super::fuzzy_match(("".into(), "".into(), 1.0)).unwrap();
+		assert_eq!(value, Value::Bool(true));
+		// The threshold must be a ratio
+		assert!(super::fuzzy_match(("a".into(), "a".into(), 1.5)).is_err());
+		assert!(super::fuzzy_match(("a".into(), "a".into(), -0.1)).is_err());
+	}
+
 	#[test]
 	fn similarity_distance_length_limit() {
 		use crate::cnf::STRING_SIMILARITY_LIMIT;
@@ -900,4 +1034,70 @@ mod tests {
 		assert!(super::distance::levenshtein((at_limit_a.clone(), at_limit_b.clone())).is_ok());
 		assert!(super::similarity::jaro((at_limit_a, at_limit_b)).is_ok());
 	}
+
+	#[test]
+	fn string_parse_kv() {
+		#[track_caller]
+		fn test(line: &str, expected: &str) {
+			let res = super::parse_kv((line.to_owned(), Optional(None))).unwrap();
+			assert_eq!(res.to_sql(), expected);
+		}
+
+		test("", "{  }");
+		test("level=info code=200", "{ code: '200', level: 'info' }");
+		test("  level=info   code=200  ", "{ code: '200', level: 'info' }");
+		test(r#"msg="hello world" level=warn"#, "{ level: 'warn', msg: 'hello world' }");
+		test(r#"msg="say \"hi\" \\ bye""#, r#"{ msg: 'say "hi" \\ bye' }"#);
+		test(r#"empty= quoted="" debug"#, "{ debug: true, empty: '', quoted: '' }");
+		test("path=/a=b", "{ path: '/a=b' }");
+		// Malformed input returns the pairs before it
+		test(r#"level=info msg="unterminated"#, "{ level: 'info' }");
+		test("level=info =oops code=200", "{ level: 'info' }");
+		test(r#"a=1 b="x"y c=3"#, "{ a: '1' }");
+	}
+
+	#[test]
+	fn string_parse_kv_strict() {
+		let strict = || {
+			let mut opts = Object::default();
+			opts.insert("strict".to_owned(), Value::Bool(true));
+			Optional(Some(opts))
+		};
+		assert!(super::parse_kv((r#"a=1 b="x y""#.to_owned(), strict())).is_ok());
+		assert!(super::parse_kv((r#"a=1 b="x y"#.to_owned(), strict())).is_err());
+		assert!(super::parse_kv(("a=1 =2".to_owned(), strict())).is_err());
+		let mut opts = Object::default();
+		opts.insert("strict".to_owned(), Value::from("yes"));
+		assert!(super::parse_kv(("a=1".to_owned(), Optional(Some(opts)))).is_err());
+	}
+
+	#[test]
+	fn string_parse_template() {
+		#[track_caller]
+		fn test(line: &str, template: &str, expected: &str) {
+			let res = super::parse_template((line.to_owned(), template.to_owned(), Optional(None)))
+				.unwrap();
+			assert_eq!(res.to_sql(), expected);
+		}
+
+		test(
+			"127.0.0.1 - GET /index.html 200",
+			"{ip} - {method} {path} {status}",
+			"{ ip: '127.0.0.1', method: 'GET', path: '/index.html', status: '200' }",
+		);
+		test(
+			"[error] disk full.",
+			"[{level}] {message}.",
+			"{ level: 'error', message: 'disk full' }",
+		);
+		test("v1.2.3.", "v{version}.", "{ version: '1.2.3' }");
+		test("{a} = 1", "{{a}} = {value}", "{ value: '1' }");
+		// A line which does not match returns the fields before it
+		test("tobie 42 years", "{name} {age}.", "{ name: 'tobie' }");
+		test("GET /", "{method} {path} HTTP", "{ method: 'GET' }");
+		// Invalid templates are errors
+		assert!(super::parse_template(("a".into(), "{a}{b}".into(), Optional(None))).is_err());
+		assert!(super::parse_template(("a".into(), "{a".into(), Optional(None))).is_err());
+		assert!(super::parse_template(("a".into(), "{}".into(), Optional(None))).is_err());
+	}
 }
@@ -0,0 +1,141 @@
+use anyhow::Result;
+use reblessive::tree::Stk;
+
+use crate::catalog::Index;
+use crate::catalog::providers::TableProvider;
+use crate::ctx::FrozenContext;
+use crate::dbs::Options;
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::expr::field::Selector;
+use crate::expr::statements::SelectStatement;
+use crate::expr::{BinaryOperator, Cond, Expr, Fields, Group, Groups, Idiom, Limit, Literal};
+use crate::iam::Action;
+use crate::key;
+use crate::kvs::KVValue;
+use crate::val::{Array, RecordId, TableName, Value};
+
+/// Returns up to `limit` distinct values of a field on a table, in order
+///
+/// When the field is the first field of a standard or unique index, the
+/// values are read by walking the index, jumping over the entries of each
+/// value once it has been found. Otherwise, or when the values are subject to
+/// permissions, the table is scanned with the values grouped together. Records
+/// where the field is NONE are not included.
+pub async fn distinct_values(
+	(stk, ctx, opt, _doc): (&mut Stk, &FrozenContext, Option<&Options>, Option<&CursorDoc>),
+	(table, field, limit): (String, String, i64),
+) -> Result<Value> {
+	let Some(opt) = opt else {
+		return Ok(Value::None);
+	};
+	let limit = u32::try_from(limit).map_err(|_| Error::InvalidFunctionArguments {
+		name: "table::distinct_values".to_owned(),
+		message: "The third argument must be a non-negative integer.".to_owned(),
+	})?;
+	let idiom: Idiom = crate::syn::idiom(&field)
+		.map_err(|_| Error::InvalidFunctionArguments {
+			name: "table::distinct_values".to_owned(),
+			message: format!("The second argument must be a field, but found '{field}'."),
+		})?
+		.into();
+	let table = TableName::from(table);
+	// The index can only be used when permissions don't need to be checked
+	if !opt.check_perms(Action::View)?
+		&& let Some(values) = index_values(ctx, opt, &table, &idiom, limit).await?
+	{
+		return Ok(Value::Array(values));
+	}
+	scan_values(stk, ctx, opt, table, idiom, limit).await
+}
+
+/// Walks the first suitable index on the field, if there is one
+///
+/// Returns `None` when there is no suitable index, or when the field holds
+/// arrays, as the elements of arrays are indexed individually.
+async fn index_values(
+	ctx: &FrozenContext,
+	opt: &Options,
+	table: &TableName,
+	idiom: &Idiom,
+	limit: u32,
+) -> Result<Option<Array>> {
+	let Some((ns, db)) = ctx.try_ns_db_ids(opt).await? else {
+		return Ok(None);
+	};
+	let txn = ctx.tx();
+	let indexes = txn.all_tb_indexes(ns, db, table, None).await?;
+	let Some(ix) = indexes.iter().find(|ix| {
+		matches!(ix.index, Index::Idx | Index::Uniq)
+			&& !ix.prepare_remove
+			&& ix.cond.is_none()
+			&& ix.cols.first() == Some(idiom)
+	}) else {
+		return Ok(None);
+	};
+	let mut beg = key::index::Index::prefix_beg(ns, db, table, ix.index_id)?;
+	let end = key::index::Index::prefix_end(ns, db, table, ix.index_id)?;
+	let mut values = Array::new();
+	while values.len() < limit as usize {
+		// Find the first entry after the values found so far
+		let Some((_, val)) = txn.scan(beg..end.clone(), 1, 0, None).await?.into_iter().next()
+		else {
+			break;
+		};
+		// Read the value from the record, as the index key only holds the
+		// value in its normalised form
+		let rid = RecordId::kv_decode_value(val)?;
+		let record = txn.get_record(ns, db, table, &rid.key, None).await?;
+		let value = record.data.pick(idiom);
+		if let Value::Array(_) = value {
+			return Ok(None);
+		}
+		// Skip over every entry for this value
+		let fd = Array::from(vec![value.clone()]);
+		beg = key::index::Index::prefix_ids_composite_end(ns, db, table, ix.index_id, &fd)?;
+		if !value.is_none() {
+			values.push(value);
+		}
+	}
+	Ok(Some(values))
+}
+
+/// Selects the distinct values with `SELECT VALUE field FROM table WHERE
+/// field != NONE GROUP BY field LIMIT limit`
+async fn scan_values(
+	stk: &mut Stk,
+	ctx: &FrozenContext,
+	opt: &Options,
+	table: TableName,
+	idiom: Idiom,
+	limit: u32,
+) -> Result<Value> {
+	let stm = SelectStatement {
+		fields: Fields::Value(Box::new(Selector {
+			expr: Expr::Idiom(idiom.clone()),
+			alias: None,
+		})),
+		what: vec![Expr::Table(table)],
+		cond: Some(Cond(Expr::Binary {
+			left: Box::new(Expr::Idiom(idiom.clone())),
+			op: BinaryOperator::NotEqual,
+			right: Box::new(Expr::Literal(Literal::None)),
+		})),
+		group: Some(Groups(vec![Group(idiom)])),
+		limit: Some(Limit(Expr::Literal(Literal::Integer(limit.into())))),
+		only: false,
+		omit: vec![],
+		with: None,
+		split: None,
+		having: None,
+		order: None,
+		start: None,
+		fetch: None,
+		version: Expr::Literal(Literal::None),
+		timeout: Expr::Literal(Literal::None),
+		scan_timeout: Expr::Literal(Literal::None),
+		explain: None,
+		tempfiles: false,
+	};
+	stm.compute(stk, ctx, opt, None).await
+}
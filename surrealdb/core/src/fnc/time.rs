@@ -1,11 +1,60 @@
+use std::collections::HashSet;
+
 use anyhow::{Result, bail};
 use chrono::offset::TimeZone;
-use chrono::{DateTime, Datelike, DurationRound, Local, Timelike, Utc};
+use chrono::{
+	DateTime, Datelike, DurationRound, Local, SecondsFormat, TimeDelta, Timelike, Utc, Weekday,
+};
+use chrono_tz::Tz;
 
 use super::args::Optional;
 use crate::err::Error;
 use crate::val::{Datetime, Duration, Number, Value};
 
+/// Adds a number of business days to a datetime, keeping its time of day.
+///
+/// Saturdays, Sundays, and the dates of any of the holidays are skipped, with
+/// the dates compared in UTC. A negative number of days moves backwards.
+pub fn add_business_days(
+	(val, days, Optional(holidays)): (Datetime, i64, Optional<Vec<Datetime>>),
+) -> Result<Value> {
+	let out_of_range = || Error::InvalidFunctionArguments {
+		name: String::from("time::add_business_days"),
+		message: String::from("The resulting datetime is out of range."),
+	};
+	let holidays: HashSet<_> =
+		holidays.unwrap_or_default().into_iter().map(|h| h.date_naive()).collect();
+	let sign = days.signum();
+	// Every whole week holds exactly five weekdays, so jump over all but the
+	// last few business days at once, keeping at least one day to step so
+	// that the result always lands on a business day.
+	let weeks = days.unsigned_abs().saturating_sub(1) / 5;
+	let mut remaining = days.unsigned_abs() - weeks * 5;
+	let jump = i64::try_from(weeks).ok().and_then(|w| TimeDelta::try_weeks(w * sign));
+	let start = *val;
+	let mut res = jump.and_then(|d| start.checked_add_signed(d)).ok_or_else(out_of_range)?;
+	// Any holidays on weekdays within the jumped span still need skipping
+	let (from, to) = (start.date_naive(), res.date_naive());
+	remaining += holidays
+		.iter()
+		.filter(|h| !matches!(h.weekday(), Weekday::Sat | Weekday::Sun))
+		.filter(|h| match sign {
+			1 => from < **h && **h <= to,
+			_ => to <= **h && **h < from,
+		})
+		.count() as u64;
+	let step = TimeDelta::days(sign);
+	while remaining > 0 {
+		res = res.checked_add_signed(step).ok_or_else(out_of_range)?;
+		if !matches!(res.weekday(), Weekday::Sat | Weekday::Sun)
+			&& !holidays.contains(&res.date_naive())
+		{
+			remaining -= 1;
+		}
+	}
+	Ok(res.into())
+}
+
 pub fn ceil((val, duration): (Datetime, Duration)) -> Result<Value> {
 	match chrono::Duration::from_std(*duration) {
 		Ok(d) => {
@@ -86,6 +135,21 @@ pub fn format((val, format): (Datetime, String)) -> Result<Value> {
 	Ok(res.into())
 }
 
+/// Formats a datetime as its local time in an IANA timezone, such as
+/// `Europe/Berlin`.
+pub fn format_zoned((val, format, zone): (Datetime, String, String)) -> Result<Value> {
+	use std::fmt::Write;
+	let tz = parse_zone("time::format_zoned", &zone)?;
+	let mut res = String::new();
+	let Ok(()) = write!(&mut res, "{}", val.with_timezone(&tz).format(&format)) else {
+		bail!(Error::InvalidFunctionArguments {
+			name: "time::format_zoned".to_owned(),
+			message: format!("`{}` is not a valid time formatting string", format)
+		});
+	};
+	Ok(res.into())
+}
+
 pub fn group((val, group): (Datetime, String)) -> Result<Value> {
 	match group.as_str() {
 		"year" => Ok(Utc
@@ -141,6 +205,26 @@ pub fn hour((Optional(val),): (Optional<Datetime>,)) -> Result<Value> {
 	})
 }
 
+/// Returns the local time of a datetime in an IANA timezone, such as
+/// `Europe/Berlin`, with the UTC offset which applies at that instant.
+///
+/// Every instant has exactly one local time in a timezone, so the result is
+/// never ambiguous, including around daylight saving time transitions.
+pub fn in_zone((val, zone): (Datetime, String)) -> Result<Value> {
+	let tz = parse_zone("time::in_zone", &zone)?;
+	Ok(val.with_timezone(&tz).to_rfc3339_opts(SecondsFormat::AutoSi, false).into())
+}
+
+/// Parses the name of an IANA timezone
+fn parse_zone(name: &str, zone: &str) -> Result<Tz> {
+	zone.parse().map_err(|_| {
+		anyhow::Error::new(Error::InvalidFunctionArguments {
+			name: name.to_owned(),
+			message: format!("'{zone}' is not a valid IANA timezone name."),
+		})
+	})
+}
+
 pub fn max((array,): (Vec<Datetime>,)) -> Result<Value> {
 	Ok(match array.into_iter().max() {
 		Some(v) => v.into(),
@@ -1,6 +1,7 @@
 use anyhow::{Result, bail, ensure};
 use geo::Point;
 use reblessive::tree::Stk;
+use revision::SerializeRevisioned;
 use rust_decimal::Decimal;
 
 use super::args::Optional;
@@ -8,7 +9,7 @@ use crate::ctx::FrozenContext;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
-use crate::expr::{FlowResultExt as _, Idiom};
+use crate::expr::{FlowResultExt as _, Idiom, Kind};
 use crate::syn;
 use crate::val::{
 	Array, Bytes, Datetime, Duration, File, Geometry, Number, Range, RecordId, RecordIdKey,
@@ -124,6 +125,35 @@ pub fn string_lossy((val,): (Value,)) -> Result<Value> {
 	}
 }
 
+/// Returns a record id whose key is derived from a hash of the given value.
+pub fn id_from((table, val): (Value, Value)) -> Result<Value> {
+	let table = match table {
+		Value::Table(t) => t,
+		Value::String(t) if !t.is_empty() => TableName::new(t),
+		v => bail!(Error::TbInvalid {
+			value: v.into_raw_string(),
+		}),
+	};
+	// Hash the canonical serialisation of the value, so that the
+	// same value always produces the same record id key.
+	let mut hasher = blake3::Hasher::new();
+	val.serialize_revisioned(&mut hasher)?;
+	let hash = hasher.finalize();
+	let key = hash.to_hex()[..32].to_string();
+	Ok(Value::RecordId(RecordId {
+		table,
+		key: RecordIdKey::String(key),
+	}))
+}
+
+/// Returns whether the value can be coerced to the given kind, without
+/// converting the value.
+pub fn is_kind((val, kind): (Value, String)) -> Result<Value> {
+	// Parse the string as a Kind
+	let kind: Kind = syn::kind(&kind)?.into();
+	Ok(val.can_coerce_to_kind(&kind).into())
+}
+
 pub fn table((val,): (Value,)) -> Result<Value> {
 	let table_name = match val {
 		Value::Table(t) => t,
@@ -344,4 +374,42 @@ mod tests {
 			panic!("An empty record id part should result in an error");
 		}
 	}
+
+	#[test]
+	fn is_kind() {
+		let is = |v: Value, k: &str| super::is_kind((v, k.to_owned())).unwrap();
+		assert_eq!(is(1.into(), "int"), Value::Bool(true));
+		assert_eq!(is(1.0.into(), "int"), Value::Bool(true));
+		assert_eq!(is(1.5.into(), "int"), Value::Bool(false));
+		assert_eq!(is("1".into(), "int"), Value::Bool(false));
+		assert_eq!(is(Value::None, "int"), Value::Bool(false));
+		assert_eq!(is(Value::None, "option<int>"), Value::Bool(true));
+		assert_eq!(is(Value::Null, "option<int>"), Value::Bool(false));
+		assert_eq!(is("a".into(), "int | string"), Value::Bool(true));
+		assert_eq!(is(true.into(), "int | string"), Value::Bool(false));
+		// The value is left unchanged
+		let value = Value::from(1.0);
+		assert!(super::is_kind((value.clone(), "int".to_owned())).is_ok());
+		assert_eq!(value, Value::from(1.0));
+
+		let value = super::is_kind((1.into(), "not a kind".to_owned()));
+		assert!(value.is_err(), "An invalid kind should result in an error");
+	}
+
+	#[test]
+	fn id_from_is_deterministic() {
+		let id = |v: Value| super::id_from(("person".into(), v)).unwrap();
+		let a = id(Value::from(vec![Value::from(1), Value::from("a")]));
+		let b = id(Value::from(vec![Value::from(1), Value::from("a")]));
+		let c = id(Value::from(vec![Value::from(1), Value::from("b")]));
+		assert_eq!(a, b);
+		assert_ne!(a, c);
+		let Value::RecordId(rid) = a else {
+			panic!("Expected a record id");
+		};
+		assert_eq!(rid.table, "person");
+
+		let value = super::id_from(("".into(), "a".into()));
+		assert!(value.is_err(), "An empty table name should result in an error");
+	}
 }
@@ -1,19 +1,38 @@
+use std::io::Read;
+use std::time::Duration;
+
 use anyhow::{Context as _, Result, bail};
-use reqwest::header::CONTENT_TYPE;
+use bytes::Bytes;
+use futures::StreamExt;
+use reqwest::header::{AUTHORIZATION, CONTENT_ENCODING, CONTENT_TYPE};
+use reqwest::multipart::{Form, Part};
 use reqwest::{Method, RequestBuilder, Response};
+use surrealdb_types::ToSql;
 use url::Url;
 
+use crate::cnf::MAX_HTTP_ERROR_BODY_SIZE;
 use crate::ctx::FrozenContext;
 use crate::err::Error;
 use crate::sql::expression::convert_public_value_to_internal;
-use crate::types::{PublicBytes, PublicValue};
+use crate::types::{PublicBytes, PublicObject, PublicValue};
 use crate::val::{Object, Value};
 
 pub(crate) fn uri_is_valid(uri: &str) -> bool {
 	reqwest::Url::parse(uri).is_ok()
 }
 
-fn encode_body(req: RequestBuilder, body: PublicValue) -> Result<RequestBuilder> {
+/// The key of an object body which is sent as `multipart/form-data`
+const MULTIPART: &str = "__multipart";
+
+pub(crate) fn encode_body(req: RequestBuilder, body: PublicValue) -> Result<RequestBuilder> {
+	// An object with only a multipart field is sent as a form, with the
+	// boundary and content type set from the form
+	if let PublicValue::Object(v) = &body
+		&& v.len() == 1
+		&& let Some(PublicValue::Object(fields)) = v.get(MULTIPART)
+	{
+		return Ok(req.multipart(encode_multipart(fields)?));
+	}
 	let res = match body {
 		PublicValue::Bytes(v) => req.body(v.into_inner()),
 		PublicValue::String(v) => req.body(v),
@@ -25,64 +44,513 @@ fn encode_body(req: RequestBuilder, body: PublicValue) -> Result<RequestBuilder>
 	Ok(res)
 }
 
-async fn decode_response(res: Response) -> Result<PublicValue> {
-	match res.error_for_status() {
-		Ok(res) => match res.headers().get(CONTENT_TYPE) {
-			Some(mime) => match mime.to_str() {
-				Ok(v) if v.starts_with("application/json") => {
-					let txt = res.text().await.map_err(Error::from)?;
-					let json: serde_json::Value = serde_json::from_str(&txt)
-						.context("Failed to parse JSON response")
-						.map_err(|e| Error::Http(e.to_string()))?;
-					Ok(crate::rpc::format::json::json_to_value(json))
+/// Builds a multipart form, where strings are sent as text parts and bytes
+/// are sent as file parts named after their field
+fn encode_multipart(fields: &PublicObject) -> Result<Form> {
+	let mut form = Form::new();
+	for (name, value) in fields.iter() {
+		let name = name.clone();
+		form = match value {
+			PublicValue::String(v) => form.text(name, v.clone()),
+			PublicValue::Bytes(v) => {
+				let part = Part::bytes(v.to_vec())
+					.file_name(name.clone())
+					.mime_str("application/octet-stream")
+					.map_err(Error::from)?;
+				form.part(name, part)
+			}
+			v => bail!(Error::Http(format!(
+				"The multipart field '{name}' should be a string or bytes, but found a value of type '{}'",
+				v.kind().to_sql()
+			))),
+		};
+	}
+	Ok(form)
+}
+
+/// Reads the body of the response, decoding it as specified by the
+/// `Content-Encoding` header
+///
+/// Responses are usually decompressed by the client, which then removes the
+/// header. The header remains when the request specified its own
+/// `Accept-Encoding` header, so bodies with a supported encoding are decoded,
+/// and the request fails when such a body is not valid for its encoding.
+/// Bodies with any other encoding are returned as they were received.
+///
+/// When a maximum size is given, the request fails as soon as the body is
+/// known to be larger, before or while it is decoded.
+async fn read_body(res: Response, max: Option<usize>) -> Result<Bytes> {
+	let encoding = res
+		.headers()
+		.get(CONTENT_ENCODING)
+		.and_then(|v| v.to_str().ok())
+		.map(|v| v.trim().to_ascii_lowercase());
+	let bytes = match max {
+		Some(max) => read_limited(res, max).await?,
+		None => res.bytes().await.map_err(Error::from)?,
+	};
+	let Some(encoding) = encoding else {
+		return Ok(bytes);
+	};
+	match decompress_body(encoding, bytes.clone(), max).await? {
+		Some(decoded) => {
+			check_size(decoded.len() as u64, max)?;
+			Ok(Bytes::from(decoded))
+		}
+		None => Ok(bytes),
+	}
+}
+
+/// Reads the body of the response, failing once it is larger than the limit
+async fn read_limited(res: Response, max: usize) -> Result<Bytes> {
+	// Fail before reading when the size of the body is known up front
+	if let Some(len) = res.content_length() {
+		check_size(len, Some(max))?;
+	}
+	let mut bytes = Vec::new();
+	let mut stream = res.bytes_stream();
+	while let Some(chunk) = stream.next().await {
+		bytes.extend_from_slice(&chunk.map_err(Error::from)?);
+		check_size(bytes.len() as u64, Some(max))?;
+	}
+	Ok(Bytes::from(bytes))
+}
+
+fn check_size(len: u64, max: Option<usize>) -> Result<()> {
+	match max {
+		Some(max) if len > max as u64 => bail!(Error::Http(format!(
+			"The response body is larger than the maximum size of {max} bytes"
+		))),
+		_ => Ok(()),
+	}
+}
+
+/// Decompresses a body on a blocking thread, as decoding a large body can
+/// take a while
+#[cfg(not(target_family = "wasm"))]
+async fn decompress_body(
+	encoding: String,
+	bytes: Bytes,
+	max: Option<usize>,
+) -> Result<Option<Vec<u8>>> {
+	tokio::task::spawn_blocking(move || decompress(&encoding, &bytes, max))
+		.await
+		.context("Failed to decode the response body")?
+}
+
+/// Decompresses a body on the current thread, as there are no blocking
+/// threads in WASM
+#[cfg(target_family = "wasm")]
+async fn decompress_body(
+	encoding: String,
+	bytes: Bytes,
+	max: Option<usize>,
+) -> Result<Option<Vec<u8>>> {
+	decompress(&encoding, &bytes, max)
+}
+
+/// Decompresses a body with the specified encoding, returning `None` when
+/// the encoding is not supported, and an error when the body is not valid
+/// for the encoding.
+///
+/// When a maximum size is given, decoding stops one byte past it, so that
+/// a small body can not expand without bound, while the caller can still
+/// tell that the decoded body is larger than the maximum size.
+fn decompress(encoding: &str, bytes: &[u8], max: Option<usize>) -> Result<Option<Vec<u8>>> {
+	let limit = max.map_or(u64::MAX, |max| max as u64 + 1);
+	let mut decoded = Vec::new();
+	let res = match encoding {
+		"gzip" | "x-gzip" => {
+			flate2::read::MultiGzDecoder::new(bytes).take(limit).read_to_end(&mut decoded)
+		}
+		// Some servers send raw deflate data instead of the zlib format
+		"deflate" => flate2::read::ZlibDecoder::new(bytes)
+			.take(limit)
+			.read_to_end(&mut decoded)
+			.or_else(|_| {
+				decoded.clear();
+				flate2::read::DeflateDecoder::new(bytes).take(limit).read_to_end(&mut decoded)
+			}),
+		"br" => brotli::Decompressor::new(bytes, 4096).take(limit).read_to_end(&mut decoded),
+		_ => return Ok(None),
+	};
+	if let Err(e) = res {
+		bail!(Error::Http(format!("Failed to decode the {encoding} encoded response body: {e}")));
+	}
+	Ok(Some(decoded))
+}
+
+async fn decode_response(res: Response, max: Option<usize>) -> Result<PublicValue> {
+	decode_body(error_for_status(res).await?, max).await
+}
+
+/// Returns the response if its status is not an error, or otherwise an error
+/// with the status and the start of the body, which usually describes what
+/// went wrong
+pub(crate) async fn error_for_status(res: Response) -> Result<Response> {
+	let status = res.status();
+	if !status.is_client_error() && !status.is_server_error() {
+		return Ok(res);
+	}
+	let mut message =
+		format!("{} {}", status.as_u16(), status.canonical_reason().unwrap_or("Unknown"));
+	if let Some(body) = error_body(res).await {
+		message = format!("{message}: {body}");
+	}
+	bail!(Error::Http(message))
+}
+
+/// Reads up to `MAX_HTTP_ERROR_BODY_SIZE` bytes of the body of an error
+/// response, as specified by the `Content-Type` header. JSON bodies are
+/// returned in their compact form, and text bodies as they are. Other bodies,
+/// and bodies which can not be read, are left out.
+async fn error_body(res: Response) -> Option<String> {
+	let mime = res.headers().get(CONTENT_TYPE)?.to_str().ok()?.to_ascii_lowercase();
+	let json = mime.starts_with("application/json") || mime.contains("+json");
+	if !json && !mime.starts_with("text") {
+		return None;
+	}
+	let encoding = res
+		.headers()
+		.get(CONTENT_ENCODING)
+		.and_then(|v| v.to_str().ok())
+		.map(|v| v.trim().to_ascii_lowercase());
+	// Stop reading once the cap is reached, so a huge body is not downloaded
+	let max = *MAX_HTTP_ERROR_BODY_SIZE;
+	let mut bytes = Vec::new();
+	let mut stream = res.bytes_stream();
+	while bytes.len() < max {
+		match stream.next().await {
+			Some(Ok(chunk)) => bytes.extend_from_slice(&chunk),
+			Some(Err(_)) => return None,
+			None => break,
+		}
+	}
+	if let Some(encoding) = encoding {
+		match decompress(&encoding, &bytes, Some(max)) {
+			Ok(Some(decoded)) => bytes = decoded,
+			Ok(None) => {}
+			Err(_) => return None,
+		}
+	}
+	let body = match serde_json::from_slice::<serde_json::Value>(&bytes) {
+		Ok(value) if json => value.to_string(),
+		_ => String::from_utf8_lossy(&bytes).into_owned(),
+	};
+	let body = body.trim();
+	if body.is_empty() {
+		return None;
+	}
+	// Truncate the body at a character boundary within the cap
+	let mut end = body.len().min(max);
+	while !body.is_char_boundary(end) {
+		end -= 1;
+	}
+	Some(body[..end].to_owned())
+}
+
+/// Decodes the body of the response as specified by the `Content-Type` header,
+/// reading at most `max` bytes
+pub(crate) async fn decode_body(res: Response, max: Option<usize>) -> Result<PublicValue> {
+	match res.headers().get(CONTENT_TYPE) {
+		Some(mime) => match mime.to_str() {
+			Ok(v) if v.starts_with("application/json") => {
+				let bytes = read_body(res, max).await?;
+				let json: serde_json::Value = serde_json::from_slice(&bytes)
+					.context("Failed to parse JSON response")
+					.map_err(|e| Error::Http(e.to_string()))?;
+				Ok(crate::rpc::format::json::json_to_value(json))
+			}
+			Ok(v) if v.starts_with("application/octet-stream") => {
+				let bytes = read_body(res, max).await?;
+				Ok(PublicValue::Bytes(PublicBytes::from(bytes)))
+			}
+			// TRACE responses contain the request as received, as text
+			Ok(v) if v.starts_with("text") || v.starts_with("message/http") => {
+				let charset = charset(v);
+				let bytes = read_body(res, max).await?;
+				Ok(PublicValue::String(decode_text(charset.as_deref(), &bytes)))
+			}
+			_ => Ok(PublicValue::None),
+		},
+		_ => Ok(PublicValue::None),
+	}
+}
+
+/// Returns the `charset` parameter of a `Content-Type` header
+fn charset(mime: &str) -> Option<String> {
+	let mime = mime.parse::<mime::Mime>().ok()?;
+	mime.get_param(mime::CHARSET).map(|v| v.as_str().to_owned())
+}
+
+/// Decodes a text body with the specified charset, falling back to UTF-8
+/// when no charset is specified or the charset is not known, as
+/// `Response::text` does
+fn decode_text(charset: Option<&str>, bytes: &[u8]) -> String {
+	let encoding = charset
+		.and_then(|v| encoding_rs::Encoding::for_label(v.as_bytes()))
+		.unwrap_or(encoding_rs::UTF_8);
+	encoding.decode(bytes).0.into_owned()
+}
+
+/// Returns the headers of the response as an object, where the values of
+/// headers which appear more than once are collected into an array
+pub(crate) fn decode_headers(res: &Response) -> Object {
+	let mut headers = Object::default();
+	for (name, value) in res.headers() {
+		let value = Value::from(String::from_utf8_lossy(value.as_bytes()).into_owned());
+		match headers.get_mut(name.as_str()) {
+			Some(Value::Array(values)) => values.push(value),
+			Some(existing) => {
+				let first = std::mem::take(existing);
+				*existing = Value::Array(vec![first, value].into());
+			}
+			None => {
+				headers.insert(name.as_str().to_owned(), value);
+			}
+		}
+	}
+	headers
+}
+
+/// How a request is retried, as specified by the `retry` option
+#[derive(Debug)]
+struct Retry {
+	/// The maximum number of attempts, including the first
+	attempts: u32,
+	/// The delay before the first retry, which doubles for each later retry
+	backoff: Duration,
+	/// The response status codes which are retried
+	on: Vec<u16>,
+}
+
+impl Retry {
+	/// Parses the `retry` option of a request.
+	///
+	/// Only GET, HEAD, OPTIONS, and TRACE requests are retried, unless the
+	/// `idempotent` flag
+	/// is set to declare that repeating the request is safe.
+	fn parse(method: &Method, value: Value) -> Result<Retry> {
+		let invalid = |message: String| Error::InvalidFunctionArguments {
+			name: format!("http::{}", method.as_str().to_lowercase()),
+			message,
+		};
+		let Value::Object(opts) = value else {
+			bail!(invalid("The retry option should be an object.".to_owned()));
+		};
+		let mut retry = Retry {
+			attempts: 3,
+			backoff: Duration::from_millis(100),
+			on: vec![502, 503, 504],
+		};
+		let mut idempotent =
+			matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS | Method::TRACE);
+		for (k, v) in opts {
+			match k.as_str() {
+				"attempts" => {
+					retry.attempts = v
+						.coerce_to::<i64>()
+						.ok()
+						.and_then(|v| u32::try_from(v).ok())
+						.filter(|v| *v > 0)
+						.ok_or_else(|| {
+							invalid("The retry attempts should be a positive integer.".to_owned())
+						})?
 				}
-				Ok(v) if v.starts_with("application/octet-stream") => {
-					let bytes = res.bytes().await.map_err(Error::from)?;
-					Ok(PublicValue::Bytes(PublicBytes::from(bytes)))
+				"backoff" => {
+					retry.backoff = v
+						.coerce_to::<crate::val::Duration>()
+						.map_err(|_| invalid("The retry backoff should be a duration.".to_owned()))?
+						.0
 				}
-				Ok(v) if v.starts_with("text") => {
-					let txt = res.text().await.map_err(Error::from)?;
-					let val = PublicValue::String(txt);
-					Ok(val)
+				"on" => {
+					retry.on = v
+						.coerce_to::<Vec<i64>>()
+						.ok()
+						.and_then(|v| v.into_iter().map(|s| u16::try_from(s).ok()).collect())
+						.ok_or_else(|| {
+							invalid(
+								"The retry status codes should be an array of integers.".to_owned(),
+							)
+						})?
 				}
-				_ => Ok(PublicValue::None),
-			},
-			_ => Ok(PublicValue::None),
-		},
-		Err(err) => match err.status() {
-			Some(s) => bail!(Error::Http(format!(
-				"{} {}",
-				s.as_u16(),
-				s.canonical_reason().unwrap_or_default(),
-			))),
-			None => bail!(Error::Http(err.to_string())),
-		},
+				"idempotent" => {
+					idempotent = v.coerce_to::<bool>().map_err(|_| {
+						invalid("The retry idempotent flag should be a boolean.".to_owned())
+					})?
+				}
+				_ => bail!(invalid(format!("Unexpected retry option '{k}'."))),
+			}
+		}
+		if !idempotent {
+			retry.attempts = 1;
+		}
+		Ok(retry)
+	}
+
+	/// Checks whether the outcome of an attempt is a transient failure
+	fn is_retryable(&self, res: &Result<Response, reqwest::Error>) -> bool {
+		match res {
+			Ok(res) => self.on.contains(&res.status().as_u16()),
+			// Requests which could not be built will never succeed
+			Err(e) => !e.is_builder(),
+		}
+	}
+
+	/// Returns the delay before the given retry, starting from 1
+	fn delay(&self, retry: u32) -> Duration {
+		self.backoff.saturating_mul(2u32.saturating_pow(retry - 1))
 	}
 }
 
-async fn request(
+/// The credentials of the `auth` option of a request
+enum Auth {
+	Basic {
+		user: String,
+		pass: String,
+	},
+	Bearer(String),
+}
+
+/// Parses the `auth` option of a request.
+///
+/// Basic credentials are specified as `{ basic: { user, pass } }`, and bearer
+/// tokens as `{ bearer: token }`.
+fn parse_auth(method: &Method, value: Value) -> Result<Auth> {
+	let invalid = |message: &str| Error::InvalidFunctionArguments {
+		name: format!("http::{}", method.as_str().to_lowercase()),
+		message: message.to_owned(),
+	};
+	let Value::Object(mut auth) = value else {
+		bail!(invalid("The auth option should be an object."));
+	};
+	let res = match (auth.remove("basic"), auth.remove("bearer")) {
+		(Some(Value::Object(mut basic)), None) => {
+			let (Some(Value::String(user)), Some(Value::String(pass))) =
+				(basic.remove("user"), basic.remove("pass"))
+			else {
+				bail!(invalid("The basic auth option should have a string user and pass."));
+			};
+			if !basic.is_empty() {
+				bail!(invalid("The basic auth option should only have a user and pass."));
+			}
+			Auth::Basic {
+				user,
+				pass,
+			}
+		}
+		(Some(_), None) => {
+			bail!(invalid("The basic auth option should have a string user and pass."))
+		}
+		(None, Some(Value::String(token))) => Auth::Bearer(token),
+		(None, Some(_)) => bail!(invalid("The bearer auth option should be a string.")),
+		_ => bail!(invalid("The auth option should have either a basic or a bearer field.")),
+	};
+	if !auth.is_empty() {
+		bail!(invalid("The auth option should have either a basic or a bearer field."));
+	}
+	Ok(res)
+}
+
+/// Sets the `Authorization` header of the request from the `auth` option,
+/// which is removed from the options so that it is not sent as a header.
+/// The header is marked as sensitive, so that it is never logged.
+pub(crate) fn encode_auth(
+	req: RequestBuilder,
+	method: &Method,
+	opts: &mut Object,
+) -> Result<RequestBuilder> {
+	let Some(auth) = opts.remove("auth") else {
+		return Ok(req);
+	};
+	if opts.keys().any(|k| k.eq_ignore_ascii_case(AUTHORIZATION.as_str())) {
+		bail!(Error::InvalidFunctionArguments {
+			name: format!("http::{}", method.as_str().to_lowercase()),
+			message: "The auth option can not be used with an Authorization header.".to_owned(),
+		});
+	}
+	let req = match parse_auth(method, auth)? {
+		Auth::Basic {
+			user,
+			pass,
+		} => req.basic_auth(user, Some(pass)),
+		Auth::Bearer(token) => req.bearer_auth(token),
+	};
+	Ok(req)
+}
+
+/// Parses the `timeout` option of a request, which is removed from the
+/// options so that it is not sent as a header
+pub(crate) fn parse_timeout(method: &Method, opts: &mut Object) -> Result<Option<Duration>> {
+	let Some(value) = opts.remove("timeout") else {
+		return Ok(None);
+	};
+	let invalid = |message: &str| Error::InvalidFunctionArguments {
+		name: format!("http::{}", method.as_str().to_lowercase()),
+		message: message.to_owned(),
+	};
+	// Requests can not be given a timeout in WebAssembly
+	if cfg!(target_family = "wasm") {
+		bail!(invalid("The timeout option is not supported in WebAssembly."));
+	}
+	let timeout = value
+		.coerce_to::<crate::val::Duration>()
+		.map_err(|_| invalid("The timeout option should be a duration."))?;
+	Ok(Some(timeout.0))
+}
+
+/// Sends a single attempt of a request, within the timeout of the request
+/// and of the query
+async fn send(
+	ctx: &FrozenContext,
+	req: RequestBuilder,
+	timeout: Option<Duration>,
+) -> Result<Response, reqwest::Error> {
+	let timeout = match (timeout, ctx.timeout()) {
+		(Some(a), Some(b)) => Some(a.min(b)),
+		(a, b) => a.or(b),
+	};
+	match timeout {
+		#[cfg(not(target_family = "wasm"))]
+		Some(d) => req.timeout(d).send().await,
+		_ => req.send().await,
+	}
+}
+
+async fn sleep(delay: Duration) {
+	#[cfg(target_family = "wasm")]
+	wasmtimer::tokio::sleep(delay).await;
+	#[cfg(not(target_family = "wasm"))]
+	tokio::time::sleep(delay).await;
+}
+
+/// Sends a request, retrying any transient failures, and returns the response
+async fn send_request(
 	ctx: &FrozenContext,
 	method: Method,
 	uri: String,
 	body: Option<Value>,
 	opts: impl Into<Object>,
-) -> Result<Value> {
+) -> Result<Response> {
 	// Check if the URI is valid and allowed
 	let url = Url::parse(&uri).map_err(|_| Error::InvalidUrl(uri.clone()))?;
 	ctx.check_allowed_net(&url).await?;
 
+	// The retry and timeout options are not sent as headers
+	let mut opts: Object = opts.into();
+	let retry = opts.remove("retry").map(|v| Retry::parse(&method, v)).transpose()?;
+	let timeout = parse_timeout(&method, &mut opts)?;
 	let body = match body {
 		Some(v) => Some(crate::val::convert_value_to_public_value(v)?),
 		None => None,
 	};
 
-	let is_head = matches!(method, Method::HEAD);
-
 	let cli = ctx.http_client();
 	// Start a new HTTP request using the shared client
-	let mut req = cli.request(method.clone(), url);
+	let mut req = cli.request(method.clone(), url.clone());
+	req = encode_auth(req, &method, &mut opts)?;
 	// Add specified header values
-	for (k, v) in opts.into().iter() {
+	for (k, v) in opts.iter() {
 		req = req.header(k.as_str(), v.to_raw_string());
 	}
 
@@ -91,30 +559,64 @@ async fn request(
 		req = encode_body(req, b)?;
 	}
 
-	// Send the request and wait
-	let res = match ctx.timeout() {
-		#[cfg(not(target_family = "wasm"))]
-		Some(d) => req.timeout(d).send().await.map_err(Error::from)?,
-		_ => req.send().await.map_err(Error::from)?,
+	// Send the request and wait, retrying any transient failures
+	let mut attempt = 1;
+	let res = loop {
+		// Keep a copy of the request for the next attempt, if there is one
+		let next = match &retry {
+			Some(retry) if attempt < retry.attempts => req.try_clone(),
+			_ => None,
+		};
+		let res = send(ctx, req, timeout).await;
+		let (Some(retry), Some(next)) = (&retry, next) else {
+			break res.map_err(Error::from)?;
+		};
+		if !retry.is_retryable(&res) {
+			break res.map_err(Error::from)?;
+		}
+		// The retry has to fit within the timeout of the query
+		let delay = retry.delay(attempt);
+		if ctx.timeout().is_some_and(|t| t <= delay) {
+			break res.map_err(Error::from)?;
+		}
+		sleep(delay).await;
+		// Stop if the query was cancelled or timed out while waiting
+		if let Some(reason) = ctx.done(true)? {
+			bail!(Error::from(reason));
+		}
+		// The network target may no longer be allowed
+		ctx.check_allowed_net(&url).await?;
+		req = next;
+		attempt += 1;
 	};
+	Ok(res)
+}
 
-	if is_head {
-		// Check the response status
-		match res.error_for_status() {
-			Ok(_) => Ok(Value::None),
-			Err(err) => match err.status() {
-				Some(s) => bail!(Error::Http(format!(
-					"{} {}",
-					s.as_u16(),
-					s.canonical_reason().unwrap_or_default(),
-				))),
-				None => bail!(Error::Http(err.to_string())),
-			},
+async fn request(
+	ctx: &FrozenContext,
+	method: Method,
+	uri: String,
+	body: Option<Value>,
+	opts: impl Into<Object>,
+) -> Result<Value> {
+	let res = send_request(ctx, method.clone(), uri, body, opts).await?;
+	match method {
+		Method::HEAD => {
+			// Check the response status
+			error_for_status(res).await?;
+			Ok(Value::None)
+		}
+		Method::OPTIONS => {
+			// Receive the headers, which describe what the target allows
+			let res = error_for_status(res).await?;
+			Ok(Value::Object(decode_headers(&res)))
+		}
+		_ => {
+			// Receive the response as a value, within the maximum size
+			let max = ctx.get_capabilities().max_http_response_size();
+			let val = decode_response(res, max).await?;
+			Ok(convert_public_value_to_internal(val))
 		}
-	} else {
-		// Receive the response as a value
-		let val = decode_response(res).await?;
-		Ok(convert_public_value_to_internal(val))
 	}
 }
 
@@ -126,6 +628,165 @@ pub async fn get(ctx: &FrozenContext, uri: String, opts: impl Into<Object>) -> R
 	request(ctx, Method::GET, uri, None, opts).await
 }
 
+/// Sends an OPTIONS request, and returns the headers of the response, such
+/// as the `Allow` and `Access-Control-*` headers
+pub async fn options(ctx: &FrozenContext, uri: String, opts: impl Into<Object>) -> Result<Value> {
+	request(ctx, Method::OPTIONS, uri, None, opts).await
+}
+
+/// Sends a TRACE request, and returns the request as received by the target
+pub async fn trace(ctx: &FrozenContext, uri: String, opts: impl Into<Object>) -> Result<Value> {
+	request(ctx, Method::TRACE, uri, None, opts).await
+}
+
+/// Sends a GET request, and returns the status, headers, and body of the
+/// response, without failing when the status is not a success
+pub async fn get_with_meta(
+	ctx: &FrozenContext,
+	uri: String,
+	opts: impl Into<Object>,
+) -> Result<Value> {
+	let res = send_request(ctx, Method::GET, uri, None, opts).await?;
+	let status = Value::from(i64::from(res.status().as_u16()));
+	let headers = Value::Object(decode_headers(&res));
+	let max = ctx.get_capabilities().max_http_response_size();
+	let body = convert_public_value_to_internal(decode_body(res, max).await?);
+	Ok(Value::Object(Object(map! {
+		"status".to_string() => status,
+		"headers".to_string() => headers,
+		"body".to_string() => body,
+	})))
+}
+
+/// Sends a GET request, and parses each line of the response body as JSON,
+/// as in an NDJSON feed
+///
+/// The body is read a chunk at a time, and each line is parsed as soon as it
+/// has been received, so only the line being received is held as raw bytes.
+/// The parsed values are returned together as an array, so the whole feed is
+/// held in memory once parsed. The bytes received are limited by the maximum
+/// HTTP response size, and each line by `MAX_HTTP_STREAM_LINE_SIZE`. The
+/// query is checked for cancellation and timeouts before each chunk is read.
+/// Empty lines are skipped.
+#[cfg(not(target_family = "wasm"))]
+pub async fn stream(ctx: &FrozenContext, uri: String, opts: impl Into<Object>) -> Result<Value> {
+	let res = send_request(ctx, Method::GET, uri, None, opts).await?;
+	let res = error_for_status(res).await?;
+	let max = ctx.get_capabilities().max_http_response_size();
+	let mut lines = LineParser::new(*crate::cnf::MAX_HTTP_STREAM_LINE_SIZE);
+	// A body which the client did not decompress can only be split once it
+	// has been read in full
+	if res.headers().contains_key(CONTENT_ENCODING) {
+		let bytes = read_body(res, max).await?;
+		lines.push(&bytes)?;
+		return lines.finish();
+	}
+	// Fail before reading when the size of the body is known up front
+	if let Some(len) = res.content_length() {
+		check_size(len, max)?;
+	}
+	let mut received = 0u64;
+	let mut stream = res.bytes_stream();
+	loop {
+		if let Some(reason) = ctx.done(true)? {
+			bail!(Error::from(reason));
+		}
+		match stream.next().await {
+			Some(chunk) => {
+				let chunk = chunk.map_err(Error::from)?;
+				received += chunk.len() as u64;
+				check_size(received, max)?;
+				lines.push(&chunk)?;
+			}
+			None => return lines.finish(),
+		}
+	}
+}
+
+#[cfg(target_family = "wasm")]
+pub async fn stream(_: &FrozenContext, _: String, _: impl Into<Object>) -> Result<Value> {
+	bail!(Error::InvalidFunctionArguments {
+		name: "http::stream".to_owned(),
+		message: "The function is not supported in WebAssembly.".to_owned(),
+	})
+}
+
+/// Splits a body into lines as it is received, and parses each line as JSON
+#[cfg_attr(target_family = "wasm", expect(dead_code))]
+struct LineParser {
+	/// The start of a line which has not been received in full
+	partial: Vec<u8>,
+	/// The maximum number of bytes in a line
+	max_line: usize,
+	/// The number of lines which have been received
+	line: usize,
+	values: Vec<Value>,
+}
+
+#[cfg_attr(target_family = "wasm", expect(dead_code))]
+impl LineParser {
+	fn new(max_line: usize) -> Self {
+		Self {
+			partial: Vec::new(),
+			max_line,
+			line: 0,
+			values: Vec::new(),
+		}
+	}
+
+	/// Parses every line which is completed by the chunk
+	fn push(&mut self, chunk: &[u8]) -> Result<()> {
+		let mut rest = chunk;
+		while let Some(end) = rest.iter().position(|b| *b == b'\n') {
+			self.check_line(self.partial.len() + end)?;
+			if self.partial.is_empty() {
+				self.parse(&rest[..end])?;
+			} else {
+				self.partial.extend_from_slice(&rest[..end]);
+				let line = std::mem::take(&mut self.partial);
+				self.parse(&line)?;
+			}
+			rest = &rest[end + 1..];
+		}
+		self.check_line(self.partial.len() + rest.len())?;
+		self.partial.extend_from_slice(rest);
+		Ok(())
+	}
+
+	/// Parses the last line, which may not end with a newline
+	fn finish(mut self) -> Result<Value> {
+		let line = std::mem::take(&mut self.partial);
+		self.parse(&line)?;
+		Ok(Value::from(self.values))
+	}
+
+	/// Fails when the line being received is longer than the limit
+	fn check_line(&self, len: usize) -> Result<()> {
+		if len > self.max_line {
+			bail!(Error::Http(format!(
+				"Line {} of the response is larger than the maximum size of {} bytes",
+				self.line + 1,
+				self.max_line
+			)));
+		}
+		Ok(())
+	}
+
+	fn parse(&mut self, line: &[u8]) -> Result<()> {
+		self.line += 1;
+		let invalid = |e: &dyn std::fmt::Display| {
+			Error::Http(format!("Failed to parse line {} of the response: {e}", self.line))
+		};
+		let line = std::str::from_utf8(line).map_err(|e| invalid(&e))?.trim();
+		if line.is_empty() {
+			return Ok(());
+		}
+		let value = crate::syn::json(line).map_err(|e| invalid(&e))?;
+		self.values.push(convert_public_value_to_internal(value));
+		Ok(())
+	}
+}
+
 pub async fn put(
 	ctx: &FrozenContext,
 	uri: String,
@@ -156,3 +817,483 @@ pub async fn patch(
 pub async fn delete(ctx: &FrozenContext, uri: String, opts: impl Into<Object>) -> Result<Value> {
 	request(ctx, Method::DELETE, uri, None, opts).await
 }
+
+#[cfg(test)]
+mod tests {
+	use std::io::Write;
+
+	use wiremock::matchers::{header, method, path};
+	use wiremock::{Mock, MockServer, ResponseTemplate};
+
+	use crate::dbs::{Capabilities, Session};
+	use crate::kvs::Datastore;
+	use crate::types::{PublicNumber, PublicValue};
+
+	/// Mounts an endpoint which fails twice before succeeding
+	async fn flaky_server(verb: &str) -> MockServer {
+		let server = MockServer::start().await;
+		Mock::given(method(verb))
+			.and(path("/flaky"))
+			.respond_with(ResponseTemplate::new(503))
+			.up_to_n_times(2)
+			.mount(&server)
+			.await;
+		Mock::given(method(verb))
+			.and(path("/flaky"))
+			.respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+			.mount(&server)
+			.await;
+		server
+	}
+
+	/// Mounts an endpoint which responds with a JSON object, compressed with
+	/// the specified encoding, and labelled with the specified header
+	async fn compressed_server(header_encoding: &str, encoding: &str) -> MockServer {
+		let json = br#"{ "name": "Tobie", "tags": ["one", "two"] }"#;
+		let body = match encoding {
+			"gzip" => {
+				let mut enc = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+				enc.write_all(json).unwrap();
+				enc.finish().unwrap()
+			}
+			"deflate" => {
+				let mut enc = flate2::write::ZlibEncoder::new(Vec::new(), Default::default());
+				enc.write_all(json).unwrap();
+				enc.finish().unwrap()
+			}
+			"br" => {
+				let mut enc = brotli::CompressorWriter::new(Vec::new(), 4096, 5, 22);
+				enc.write_all(json).unwrap();
+				enc.into_inner()
+			}
+			_ => json.to_vec(),
+		};
+		let server = MockServer::start().await;
+		Mock::given(method("POST"))
+			.and(path("/compressed"))
+			.and(header("content-type", "application/json"))
+			.respond_with(
+				ResponseTemplate::new(200)
+					.insert_header("content-encoding", header_encoding)
+					.set_body_raw(body, "application/json"),
+			)
+			.mount(&server)
+			.await;
+		server
+	}
+
+	async fn run(sql: &str) -> Result<PublicValue, String> {
+		let ds = Datastore::builder()
+			.with_capabilities(Capabilities::all())
+			.build_with_path("memory")
+			.await
+			.unwrap();
+		let mut res = ds.execute(sql, &Session::owner(), None).await.unwrap();
+		res.remove(0).result.map_err(|e| e.to_string())
+	}
+
+	#[tokio::test]
+	async fn retry_transient_failures() {
+		let server = flaky_server("GET").await;
+		let sql = format!(
+			"http::get('{}/flaky', {{ retry: {{ attempts: 3, backoff: 1ms }} }})",
+			server.uri()
+		);
+		assert_eq!(run(&sql).await, Ok(PublicValue::String("ok".to_owned())));
+		// Without retries the first failure is returned
+		let server = flaky_server("GET").await;
+		let sql = format!("http::get('{}/flaky')", server.uri());
+		assert!(run(&sql).await.unwrap_err().contains("503 Service Unavailable"));
+	}
+
+	#[tokio::test]
+	async fn retry_only_idempotent_requests() {
+		// Requests which may not be idempotent are not retried by default
+		let server = flaky_server("POST").await;
+		let sql = format!(
+			"http::post('{}/flaky', 'body', {{ retry: {{ attempts: 3, backoff: 1ms }} }})",
+			server.uri()
+		);
+		assert!(run(&sql).await.unwrap_err().contains("503 Service Unavailable"));
+		// Unless they are declared to be idempotent
+		let server = flaky_server("POST").await;
+		let sql = format!(
+			"http::post('{}/flaky', 'body', {{ retry: {{ attempts: 3, backoff: 1ms, idempotent: true }} }})",
+			server.uri()
+		);
+		assert_eq!(run(&sql).await, Ok(PublicValue::String("ok".to_owned())));
+	}
+
+	#[tokio::test]
+	async fn retry_within_attempts_and_status_codes() {
+		// The attempts are exhausted before the request succeeds
+		let server = flaky_server("GET").await;
+		let sql = format!(
+			"http::get('{}/flaky', {{ retry: {{ attempts: 2, backoff: 1ms }} }})",
+			server.uri()
+		);
+		assert!(run(&sql).await.unwrap_err().contains("503 Service Unavailable"));
+		// Only the specified status codes are retried
+		let server = flaky_server("GET").await;
+		let sql = format!(
+			"http::get('{}/flaky', {{ retry: {{ attempts: 3, backoff: 1ms, on: [502] }} }})",
+			server.uri()
+		);
+		assert!(run(&sql).await.unwrap_err().contains("503 Service Unavailable"));
+		// Invalid options are rejected
+		let sql = format!("http::get('{}/flaky', {{ retry: {{ tries: 3 }} }})", server.uri());
+		assert!(run(&sql).await.unwrap_err().contains("Unexpected retry option 'tries'."));
+	}
+
+	#[tokio::test]
+	async fn decompress_responses() {
+		let expected = run("{ name: 'Tobie', tags: ['one', 'two'] }").await.unwrap();
+		assert!(matches!(expected, PublicValue::Object(_)));
+		for encoding in ["gzip", "deflate", "br"] {
+			let server = compressed_server(encoding, encoding).await;
+			// The response is decompressed by the client
+			let sql = format!("http::post('{}/compressed', {{ name: 'Tobie' }})", server.uri());
+			assert_eq!(run(&sql).await, Ok(expected.clone()), "{encoding}");
+			// Or by the function, when the request specifies the accepted encodings
+			let sql = format!(
+				"http::post('{}/compressed', {{ name: 'Tobie' }}, {{ 'accept-encoding': '{encoding}' }})",
+				server.uri()
+			);
+			assert_eq!(run(&sql).await, Ok(expected.clone()), "{encoding}");
+		}
+		// A body which is not valid for its encoding is rejected
+		let server = compressed_server("gzip", "identity").await;
+		let sql = format!(
+			"http::post('{}/compressed', {{ name: 'Tobie' }}, {{ 'accept-encoding': 'gzip' }})",
+			server.uri()
+		);
+		let err = run(&sql).await.unwrap_err();
+		assert!(err.contains("Failed to decode the gzip encoded response body"), "{err}");
+	}
+
+	#[test]
+	fn decode_text_with_charset() {
+		assert_eq!(super::charset("text/plain; charset=ISO-8859-1").as_deref(), Some("ISO-8859-1"));
+		assert_eq!(super::decode_text(Some("ISO-8859-1"), b"caf\xe9"), "caf\u{e9}");
+		// Text is read as UTF-8 without a known charset
+		assert_eq!(super::decode_text(None, "caf\u{e9}".as_bytes()), "caf\u{e9}");
+		assert_eq!(super::decode_text(Some("unknown"), "caf\u{e9}".as_bytes()), "caf\u{e9}");
+	}
+
+	#[tokio::test]
+	async fn get_with_meta() {
+		let server = MockServer::start().await;
+		Mock::given(method("GET"))
+			.and(path("/missing"))
+			.respond_with(
+				ResponseTemplate::new(404)
+					.insert_header("etag", "\"v1\"")
+					.append_header("link", "</one>")
+					.append_header("link", "</two>")
+					.set_body_raw(r#"{ "reason": "missing" }"#, "application/json"),
+			)
+			.mount(&server)
+			.await;
+		let sql = format!("http::get_with_meta('{}/missing')", server.uri());
+		let PublicValue::Object(res) = run(&sql).await.unwrap() else {
+			panic!("expected an object");
+		};
+		// The status is returned instead of an error
+		assert_eq!(res.get("status"), Some(&PublicValue::Number(PublicNumber::Int(404))));
+		let Some(PublicValue::Object(body)) = res.get("body") else {
+			panic!("expected the body to be an object");
+		};
+		assert_eq!(body.get("reason"), Some(&PublicValue::String("missing".to_owned())));
+		let Some(PublicValue::Object(headers)) = res.get("headers") else {
+			panic!("expected the headers to be an object");
+		};
+		assert_eq!(headers.get("etag"), Some(&PublicValue::String("\"v1\"".to_owned())));
+		assert_eq!(
+			headers.get("content-type"),
+			Some(&PublicValue::String("application/json".to_owned()))
+		);
+		// Headers with more than one value are collected into an array
+		let Some(PublicValue::Array(links)) = headers.get("link") else {
+			panic!("expected the link header to be an array");
+		};
+		assert_eq!(
+			**links,
+			vec![
+				PublicValue::String("</one>".to_owned()),
+				PublicValue::String("</two>".to_owned())
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn encode_multipart_body() {
+		let server = MockServer::start().await;
+		Mock::given(method("POST"))
+			.and(path("/upload"))
+			.respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+			.mount(&server)
+			.await;
+		let sql = format!(
+			"http::post('{}/upload', {{ __multipart: {{ field: 'value', file: <bytes> 'contents' }} }})",
+			server.uri()
+		);
+		assert_eq!(run(&sql).await, Ok(PublicValue::String("ok".to_owned())));
+		let requests = server.received_requests().await.unwrap();
+		let req = &requests[0];
+		// The content type specifies the boundary between the parts
+		let content_type = req.headers.get("content-type").unwrap().to_str().unwrap();
+		let boundary = content_type.strip_prefix("multipart/form-data; boundary=").unwrap();
+		let body = String::from_utf8(req.body.clone()).unwrap();
+		let text = format!(
+			"--{boundary}\r\nContent-Disposition: form-data; name=\"field\"\r\n\r\nvalue\r\n"
+		);
+		let file = format!(
+			"--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"file\"\r\nContent-Type: application/octet-stream\r\n\r\ncontents\r\n"
+		);
+		assert!(body.contains(&text), "{body}");
+		assert!(body.contains(&file), "{body}");
+		assert!(body.ends_with(&format!("--{boundary}--\r\n")), "{body}");
+		// Other values can not be sent as parts
+		let sql =
+			format!("http::post('{}/upload', {{ __multipart: {{ field: 1 }} }})", server.uri());
+		assert!(run(&sql).await.unwrap_err().contains(
+			"The multipart field 'field' should be a string or bytes, but found a value of type 'int'"
+		));
+	}
+
+	#[tokio::test]
+	async fn auth_headers() {
+		let server = MockServer::start().await;
+		Mock::given(method("GET"))
+			.and(path("/basic"))
+			.and(header("authorization", "Basic dG9iaWU6c2VjcmV0"))
+			.respond_with(ResponseTemplate::new(200).set_body_string("basic"))
+			.mount(&server)
+			.await;
+		Mock::given(method("GET"))
+			.and(path("/bearer"))
+			.and(header("authorization", "Bearer abc.def"))
+			.respond_with(ResponseTemplate::new(200).set_body_string("bearer"))
+			.mount(&server)
+			.await;
+		// Basic credentials are encoded as base64
+		let sql = format!(
+			"http::get('{}/basic', {{ auth: {{ basic: {{ user: 'tobie', pass: 'secret' }} }} }})",
+			server.uri()
+		);
+		assert_eq!(run(&sql).await, Ok(PublicValue::String("basic".to_owned())));
+		let sql =
+			format!("http::get('{}/bearer', {{ auth: {{ bearer: 'abc.def' }} }})", server.uri());
+		assert_eq!(run(&sql).await, Ok(PublicValue::String("bearer".to_owned())));
+		// The auth option can not be combined with an explicit header
+		let sql = format!(
+			"http::get('{}/bearer', {{ auth: {{ bearer: 'abc.def' }}, authorization: 'Bearer xyz' }})",
+			server.uri()
+		);
+		assert!(
+			run(&sql)
+				.await
+				.unwrap_err()
+				.contains("The auth option can not be used with an Authorization header.")
+		);
+		let sql = format!("http::get('{}/bearer', {{ auth: {{ bearer: 1 }} }})", server.uri());
+		assert!(
+			run(&sql).await.unwrap_err().contains("The bearer auth option should be a string.")
+		);
+	}
+
+	#[tokio::test]
+	async fn error_bodies() {
+		let server = MockServer::start().await;
+		Mock::given(method("POST"))
+			.and(path("/invalid"))
+			.respond_with(
+				ResponseTemplate::new(400)
+					.set_body_raw(r#"{ "error": "The name is invalid" }"#, "application/json"),
+			)
+			.mount(&server)
+			.await;
+		Mock::given(method("GET"))
+			.and(path("/huge"))
+			.respond_with(ResponseTemplate::new(500).set_body_string("x".repeat(100_000)))
+			.mount(&server)
+			.await;
+		Mock::given(method("HEAD"))
+			.and(path("/missing"))
+			.respond_with(ResponseTemplate::new(404))
+			.mount(&server)
+			.await;
+		// The JSON error message is included in the error
+		let sql = format!("http::post('{}/invalid', {{ name: '' }})", server.uri());
+		assert_eq!(
+			run(&sql).await.unwrap_err(),
+			"There was an error processing a remote HTTP request: 400 Bad Request: {\"error\":\"The name is invalid\"}"
+		);
+		// Large bodies are truncated
+		let sql = format!("http::get('{}/huge')", server.uri());
+		let err = run(&sql).await.unwrap_err();
+		assert!(err.contains("500 Internal Server Error: xxx"), "{err}");
+		assert!(err.len() < 4096 + 100, "{err}");
+		// Responses without a body only include the status
+		let sql = format!("http::head('{}/missing')", server.uri());
+		assert_eq!(
+			run(&sql).await.unwrap_err(),
+			"There was an error processing a remote HTTP request: 404 Not Found"
+		);
+	}
+
+	#[tokio::test]
+	async fn request_timeout() {
+		let server = MockServer::start().await;
+		Mock::given(method("GET"))
+			.and(path("/slow"))
+			.respond_with(
+				ResponseTemplate::new(200)
+					.set_body_string("ok")
+					.set_delay(std::time::Duration::from_millis(500)),
+			)
+			.mount(&server)
+			.await;
+		// The request fails once its own timeout has passed
+		let sql = format!("http::get('{}/slow', {{ timeout: 50ms }})", server.uri());
+		let err = run(&sql).await.unwrap_err();
+		assert!(err.starts_with("There was an error processing a remote HTTP request"), "{err}");
+		let sql = format!("http::get('{}/slow', {{ timeout: 5s }})", server.uri());
+		assert_eq!(run(&sql).await, Ok(PublicValue::String("ok".to_owned())));
+		// The timeout is not sent as a header
+		let requests = server.received_requests().await.unwrap();
+		assert!(requests.iter().all(|req| !req.headers.contains_key("timeout")));
+		let sql = format!("http::get('{}/slow', {{ timeout: 'soon' }})", server.uri());
+		assert!(run(&sql).await.unwrap_err().contains("The timeout option should be a duration."));
+	}
+
+	#[tokio::test]
+	async fn stream_lines() {
+		let server = MockServer::start().await;
+		Mock::given(method("GET"))
+			.and(path("/feed"))
+			.respond_with(ResponseTemplate::new(200).set_body_string(
+				"{\"id\":1,\"name\":\"Tobie\"}\r\n\n{\"id\":2,\"tags\":[\"one\"]}\n[3]",
+			))
+			.mount(&server)
+			.await;
+		Mock::given(method("GET"))
+			.and(path("/invalid"))
+			.respond_with(ResponseTemplate::new(200).set_body_string("{\"id\":1}\nnot json\n"))
+			.mount(&server)
+			.await;
+		// Each line is parsed, and empty lines are skipped
+		let expected =
+			run("[{ id: 1, name: 'Tobie' }, { id: 2, tags: ['one'] }, [3]]").await.unwrap();
+		let sql = format!("http::stream('{}/feed')", server.uri());
+		assert_eq!(run(&sql).await, Ok(expected));
+		// Lines which are not JSON are reported by their line number
+		let sql = format!("http::stream('{}/invalid')", server.uri());
+		let err = run(&sql).await.unwrap_err();
+		assert!(err.contains("Failed to parse line 2 of the response"), "{err}");
+	}
+
+	#[test]
+	fn stream_line_size() {
+		let mut lines = super::LineParser::new(8);
+		// Lines may be split across chunks, up to the limit
+		lines.push(b"[1, 2]\n[3,").unwrap();
+		lines.push(b" 4]\n").unwrap();
+		// Lines without a newline fail once they are longer than the limit
+		let err = lines.push(b"[5, 6, 7, 8]").unwrap_err();
+		assert_eq!(
+			err.to_string(),
+			"There was an error processing a remote HTTP request: Line 3 of the response is larger than the maximum size of 8 bytes"
+		);
+	}
+
+	#[tokio::test]
+	async fn max_response_size() {
+		let server = MockServer::start().await;
+		Mock::given(method("GET"))
+			.and(path("/large"))
+			.respond_with(ResponseTemplate::new(200).set_body_string("x".repeat(10 * 1024 * 1024)))
+			.mount(&server)
+			.await;
+		Mock::given(method("GET"))
+			.and(path("/small"))
+			.respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+			.mount(&server)
+			.await;
+		let mut enc = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+		enc.write_all(&vec![b'x'; 10 * 1024 * 1024]).unwrap();
+		Mock::given(method("GET"))
+			.and(path("/compressed"))
+			.respond_with(
+				ResponseTemplate::new(200)
+					.insert_header("content-encoding", "gzip")
+					.set_body_raw(enc.finish().unwrap(), "text/plain"),
+			)
+			.mount(&server)
+			.await;
+		let ds = Datastore::builder()
+			.with_capabilities(Capabilities::all().with_max_http_response_size(Some(1024 * 1024)))
+			.build_with_path("memory")
+			.await
+			.unwrap();
+		let run = async |sql: String| {
+			let mut res = ds.execute(&sql, &Session::owner(), None).await.unwrap();
+			res.remove(0).result.map_err(|e| e.to_string())
+		};
+		// Bodies larger than the limit are rejected
+		let err = run(format!("http::get('{}/large')", server.uri())).await.unwrap_err();
+		assert_eq!(
+			err,
+			"There was an error processing a remote HTTP request: The response body is larger than the maximum size of 1048576 bytes"
+		);
+		let sql = format!("http::get_with_meta('{}/large')", server.uri());
+		assert!(run(sql).await.unwrap_err().contains("maximum size"));
+		let sql = format!("http::stream('{}/large')", server.uri());
+		assert!(run(sql).await.unwrap_err().contains("maximum size"));
+		// Including bodies which are only larger once decompressed
+		let sql =
+			format!("http::get('{}/compressed', {{ 'accept-encoding': 'gzip' }})", server.uri());
+		assert!(run(sql).await.unwrap_err().contains("maximum size"));
+		// Smaller bodies are read as usual
+		let sql = format!("http::get('{}/small')", server.uri());
+		assert_eq!(run(sql).await, Ok(PublicValue::String("ok".to_owned())));
+	}
+
+	#[tokio::test]
+	async fn options_and_trace() {
+		let server = MockServer::start().await;
+		Mock::given(method("OPTIONS"))
+			.and(path("/resource"))
+			.respond_with(
+				ResponseTemplate::new(204)
+					.insert_header("allow", "GET, OPTIONS")
+					.insert_header("access-control-allow-origin", "*"),
+			)
+			.mount(&server)
+			.await;
+		Mock::given(method("TRACE"))
+			.and(path("/resource"))
+			.respond_with(
+				ResponseTemplate::new(200)
+					.set_body_raw("TRACE /resource HTTP/1.1\r\n\r\n", "message/http"),
+			)
+			.mount(&server)
+			.await;
+		// OPTIONS returns the headers of the response
+		let sql = format!("http::options('{}/resource')", server.uri());
+		let PublicValue::Object(headers) = run(&sql).await.unwrap() else {
+			panic!("expected the headers of the response");
+		};
+		assert_eq!(headers.get("allow"), Some(&PublicValue::String("GET, OPTIONS".to_owned())));
+		assert_eq!(
+			headers.get("access-control-allow-origin"),
+			Some(&PublicValue::String("*".to_owned()))
+		);
+		// TRACE returns the request as received by the target
+		let sql = format!("http::trace('{}/resource')", server.uri());
+		assert_eq!(
+			run(&sql).await,
+			Ok(PublicValue::String("TRACE /resource HTTP/1.1\r\n\r\n".to_owned()))
+		);
+	}
+}
@@ -0,0 +1,144 @@
+use std::hash::{Hash, Hasher};
+
+use crate::val::Value;
+
+/// The number of bits of the hash which select a register.
+const PRECISION: u32 = 12;
+
+/// The number of registers in a sketch.
+const REGISTERS: usize = 1 << PRECISION;
+
+/// A HyperLogLog sketch which estimates the number of distinct values added
+/// to it, using a fixed 4KiB of memory.
+///
+/// With 4096 registers the standard error of the estimate is about 2%.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HyperLogLog {
+	registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+	fn default() -> Self {
+		Self {
+			registers: vec![0; REGISTERS],
+		}
+	}
+}
+
+impl HyperLogLog {
+	/// Restores a sketch from its registers, as returned by `into_registers`.
+	pub fn from_registers(registers: Vec<u8>) -> Self {
+		if registers.len() == REGISTERS {
+			Self {
+				registers,
+			}
+		} else {
+			Self::default()
+		}
+	}
+
+	/// Returns the registers of the sketch, so that it can be stored.
+	pub fn into_registers(self) -> Vec<u8> {
+		self.registers
+	}
+
+	/// Adds a value to the sketch. `NONE` values are not counted.
+	pub fn insert(&mut self, value: &Value) {
+		if value.is_none() {
+			return;
+		}
+		let mut hasher = std::collections::hash_map::DefaultHasher::new();
+		value.hash(&mut hasher);
+		let hash = hasher.finish();
+		// The first bits select the register, and the rest give the rank
+		let idx = (hash >> (64 - PRECISION)) as usize;
+		let rank = ((hash << PRECISION) | (1 << (PRECISION - 1))).leading_zeros() as u8 + 1;
+		if self.registers[idx] < rank {
+			self.registers[idx] = rank;
+		}
+	}
+
+	/// Combines another sketch into this one, as if its values had been added.
+	pub fn merge(&mut self, other: &HyperLogLog) {
+		for (a, b) in self.registers.iter_mut().zip(other.registers.iter()) {
+			if *a < *b {
+				*a = *b;
+			}
+		}
+	}
+
+	/// Clears every value from the sketch.
+	pub fn clear(&mut self) {
+		self.registers.fill(0);
+	}
+
+	/// Returns the estimated number of distinct values added to the sketch.
+	pub fn estimate(&self) -> i64 {
+		let m = REGISTERS as f64;
+		let alpha = 0.7213 / (1.0 + 1.079 / m);
+		let mut sum = 0.0;
+		let mut zeros = 0;
+		for &r in &self.registers {
+			sum += 2f64.powi(-(r as i32));
+			if r == 0 {
+				zeros += 1;
+			}
+		}
+		let estimate = alpha * m * m / sum;
+		// Use linear counting for small cardinalities, where it is more accurate
+		let estimate = if estimate <= 2.5 * m && zeros > 0 {
+			m * (m / zeros as f64).ln()
+		} else {
+			estimate
+		};
+		estimate.round() as i64
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_hyperloglog_empty() {
+		let hll = HyperLogLog::default();
+		assert_eq!(hll.estimate(), 0);
+	}
+
+	#[test]
+	fn test_hyperloglog_duplicates() {
+		let mut hll = HyperLogLog::default();
+		for _ in 0..1000 {
+			hll.insert(&Value::from("one"));
+			hll.insert(&Value::from("two"));
+			hll.insert(&Value::None);
+		}
+		assert_eq!(hll.estimate(), 2);
+	}
+
+	#[test]
+	fn test_hyperloglog_high_cardinality() {
+		let mut hll = HyperLogLog::default();
+		let count = 200_000;
+		for i in 0..count {
+			hll.insert(&Value::from(i));
+			// Repeated values do not change the estimate
+			hll.insert(&Value::from(i / 2));
+		}
+		let error = (hll.estimate() - count).abs() as f64 / count as f64;
+		assert!(error < 0.05, "estimate {} is not within 5% of {count}", hll.estimate());
+	}
+
+	#[test]
+	fn test_hyperloglog_merge() {
+		let mut a = HyperLogLog::default();
+		let mut b = HyperLogLog::default();
+		for i in 0..50_000i64 {
+			a.insert(&Value::from(i));
+			b.insert(&Value::from(i + 25_000));
+		}
+		a.merge(&b);
+		let error = (a.estimate() - 75_000).abs() as f64 / 75_000.0;
+		assert!(error < 0.05, "estimate {} is not within 5% of 75000", a.estimate());
+	}
+}
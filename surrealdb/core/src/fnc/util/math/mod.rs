@@ -6,6 +6,7 @@ use rust_decimal::{Decimal, MathematicalOps};
 use crate::val::{Number, TryAdd, TryPow};
 
 pub mod bottom;
+pub mod hyperloglog;
 pub mod interquartile;
 pub mod median;
 pub mod midhinge;
@@ -0,0 +1,181 @@
+use crate::val::{Object, Value};
+
+/// Parses a logfmt-style line of `key=value` pairs separated by whitespace.
+///
+/// Values may be quoted to contain whitespace, with `\"` and `\\` escaping a
+/// quote and a backslash. A key without a value is parsed as `true`. Parsing
+/// stops at the first malformed pair, returning the pairs parsed before it
+/// along with a description of the problem.
+pub fn kv(s: &str) -> (Object, Option<String>) {
+	let mut res = Object::default();
+	let mut chars = s.char_indices().peekable();
+	loop {
+		// Skip the whitespace between pairs
+		while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+		let Some(&(start, _)) = chars.peek() else {
+			return (res, None);
+		};
+		// Parse the key
+		let mut end = s.len();
+		while let Some(&(i, c)) = chars.peek() {
+			if c.is_whitespace() || c == '=' || c == '"' {
+				end = i;
+				break;
+			}
+			chars.next();
+		}
+		let key = &s[start..end];
+		if key.is_empty() {
+			return (res, Some(format!("Expected a key at position {start}")));
+		}
+		// A key without a value is a flag
+		match chars.peek() {
+			None => {
+				res.insert(key.to_owned(), Value::Bool(true));
+				return (res, None);
+			}
+			Some((_, c)) if c.is_whitespace() => {
+				res.insert(key.to_owned(), Value::Bool(true));
+				continue;
+			}
+			Some((i, '"')) => {
+				return (res, Some(format!("Unexpected `\"` in key `{key}` at position {i}")));
+			}
+			_ => {
+				chars.next();
+			}
+		}
+		// Parse the value
+		let value = if chars.next_if(|(_, c)| *c == '"').is_some() {
+			let mut value = String::new();
+			let mut closed = false;
+			while let Some((_, c)) = chars.next() {
+				match c {
+					'"' => {
+						closed = true;
+						break;
+					}
+					'\\' => match chars.next() {
+						Some((_, c @ ('"' | '\\'))) => value.push(c),
+						Some((_, 'n')) => value.push('\n'),
+						Some((_, 't')) => value.push('\t'),
+						Some((_, c)) => {
+							value.push('\\');
+							value.push(c);
+						}
+						None => break,
+					},
+					c => value.push(c),
+				}
+			}
+			if !closed {
+				return (res, Some(format!("Unterminated quoted value for key `{key}`")));
+			}
+			if let Some((i, _)) = chars.next_if(|(_, c)| !c.is_whitespace()) {
+				return (
+					res,
+					Some(format!(
+						"Expected whitespace after the value of key `{key}` at position {i}"
+					)),
+				);
+			}
+			value
+		} else {
+			let mut value = String::new();
+			while let Some((_, c)) = chars.next_if(|(_, c)| !c.is_whitespace()) {
+				value.push(c);
+			}
+			value
+		};
+		res.insert(key.to_owned(), Value::from(value));
+	}
+}
+
+enum Segment<'a> {
+	Literal(String),
+	Field(&'a str),
+}
+
+/// Splits a template into its literal text and `{field}` placeholders, with
+/// `{{` and `}}` escaping a brace.
+fn segments(template: &str) -> Result<Vec<Segment<'_>>, String> {
+	let mut res = Vec::new();
+	let mut literal = String::new();
+	let mut chars = template.char_indices().peekable();
+	while let Some((i, c)) = chars.next() {
+		match c {
+			'{' if chars.next_if(|(_, c)| *c == '{').is_some() => literal.push('{'),
+			'}' if chars.next_if(|(_, c)| *c == '}').is_some() => literal.push('}'),
+			'{' => {
+				let Some(end) = template[i..].find('}').map(|x| x + i) else {
+					return Err(format!("Unclosed `{{` at position {i}"));
+				};
+				let name = &template[i + 1..end];
+				if name.is_empty() {
+					return Err(format!("Expected a field name at position {i}"));
+				}
+				if !literal.is_empty() {
+					res.push(Segment::Literal(std::mem::take(&mut literal)));
+				} else if let Some(Segment::Field(prev)) = res.last() {
+					return Err(format!(
+						"The fields `{prev}` and `{name}` must be separated by some text"
+					));
+				}
+				res.push(Segment::Field(name));
+				while chars.next_if(|(j, _)| *j <= end).is_some() {}
+			}
+			'}' => return Err(format!("Unexpected `}}` at position {i}")),
+			c => literal.push(c),
+		}
+	}
+	if !literal.is_empty() {
+		res.push(Segment::Literal(literal));
+	}
+	Ok(res)
+}
+
+/// Extracts the `{field}` placeholders of a template from a line.
+///
+/// Each field matches the text up to the next occurrence of the literal text
+/// which follows it in the template, or the rest of the line for the last
+/// field. Matching stops where the line differs from the template, returning
+/// the fields extracted before it along with a description of the problem.
+/// An invalid template is an error.
+pub fn template(s: &str, template: &str) -> Result<(Object, Option<String>), String> {
+	let segments = segments(template)?;
+	let mut res = Object::default();
+	let mut rest = s;
+	for (i, segment) in segments.iter().enumerate() {
+		match segment {
+			Segment::Literal(literal) => {
+				let Some(r) = rest.strip_prefix(literal.as_str()) else {
+					let pos = s.len() - rest.len();
+					return Ok((res, Some(format!("Expected `{literal}` at position {pos}"))));
+				};
+				rest = r;
+			}
+			Segment::Field(name) => {
+				let end = match segments.get(i + 1) {
+					// The last field matches the rest of the line
+					None => Some(rest.len()),
+					// A field before the final text matches up to that text at the end
+					Some(Segment::Literal(literal)) if i + 2 == segments.len() => {
+						rest.ends_with(literal.as_str()).then(|| rest.len() - literal.len())
+					}
+					Some(Segment::Literal(literal)) => rest.find(literal.as_str()),
+					Some(Segment::Field(_)) => None,
+				};
+				let Some(end) = end else {
+					return Ok((res, Some(format!("Could not find the end of field `{name}`"))));
+				};
+				res.insert((*name).to_owned(), Value::from(&rest[..end]));
+				rest = &rest[end..];
+			}
+		}
+	}
+	if !rest.is_empty() {
+		let pos = s.len() - rest.len();
+		return Ok((res, Some(format!("Unexpected text after the template at position {pos}"))));
+	}
+	Ok((res, None))
+}
@@ -1,11 +1,46 @@
+use std::collections::HashSet;
+
 use anyhow::Result;
 use reblessive::tree::Stk;
+use surrealdb_types::ToSql;
 
 use crate::ctx::FrozenContext;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::expr::Operation;
-use crate::val::{Closure, Value};
+use crate::fnc::args::Optional;
+use crate::val::{Array, Closure, Object, RecordId, Set, Value};
+
+/// Returns a copy of the value in a canonical form, so that values which are
+/// structurally equal are also identical
+///
+/// The keys of objects are always kept in sorted order, so objects are the
+/// same however their keys were ordered when they were written. Arrays are
+/// order-significant and are left as they are, unless `sort_arrays` is true,
+/// in which case the elements of every nested array are sorted.
+pub fn canonicalize((value, Optional(sort_arrays)): (Value, Optional<bool>)) -> Result<Value> {
+	Ok(canonicalize_value(value, sort_arrays.unwrap_or(false)))
+}
+
+fn canonicalize_value(value: Value, sort_arrays: bool) -> Value {
+	match value {
+		Value::Object(obj) => Value::Object(
+			obj.into_iter().map(|(k, v)| (k, canonicalize_value(v, sort_arrays))).collect(),
+		),
+		Value::Array(arr) => {
+			let mut res: Array =
+				arr.into_iter().map(|v| canonicalize_value(v, sort_arrays)).collect();
+			if sort_arrays {
+				res.sort_unstable();
+			}
+			Value::Array(res)
+		}
+		Value::Set(set) => {
+			Value::Set(set.into_iter().map(|v| canonicalize_value(v, sort_arrays)).collect())
+		}
+		value => value,
+	}
+}
 
 pub async fn chain(
 	(stk, ctx, opt, doc): (&mut Stk, &FrozenContext, Option<&Options>, Option<&CursorDoc>),
@@ -26,3 +61,66 @@ pub async fn patch((mut val, diff): (Value, Value)) -> Result<Value> {
 	val.patch(diff)?;
 	Ok(val)
 }
+
+/// Replaces repeated record references with `{ "$ref": "table:id" }` markers
+///
+/// The value is walked in order, and the first occurrence of each record is
+/// left in place. A record occurs either as a record link, or as an object
+/// whose `id` field is the record id, such as a record expanded with
+/// `graph::expand`. Every later occurrence of the same record is replaced by a
+/// marker, which breaks any cycles between expanded records.
+pub fn deduplicate_refs((value,): (Value,)) -> Result<Value> {
+	let mut seen = HashSet::new();
+	Ok(deduplicate_value(value, &mut seen))
+}
+
+fn deduplicate_value(value: Value, seen: &mut HashSet<RecordId>) -> Value {
+	match value {
+		Value::RecordId(rid) => {
+			if seen.contains(&rid) {
+				return ref_marker(&rid);
+			}
+			seen.insert(rid.clone());
+			Value::RecordId(rid)
+		}
+		Value::Object(obj) => {
+			if let Some(Value::RecordId(rid)) = obj.get("id") {
+				if seen.contains(rid) {
+					return ref_marker(rid);
+				}
+				seen.insert(rid.clone());
+			}
+			let mut res = Object::default();
+			for (key, val) in obj {
+				// The id of a record is not a reference to itself
+				let val = match val {
+					Value::RecordId(rid) if key == "id" => Value::RecordId(rid),
+					val => deduplicate_value(val, seen),
+				};
+				res.insert(key, val);
+			}
+			Value::Object(res)
+		}
+		Value::Array(arr) => {
+			let mut res = Array::with_capacity(arr.len());
+			for val in arr {
+				res.push(deduplicate_value(val, seen));
+			}
+			Value::Array(res)
+		}
+		Value::Set(set) => {
+			let mut res = Set::new();
+			for val in set {
+				res.insert(deduplicate_value(val, seen));
+			}
+			Value::Set(res)
+		}
+		value => value,
+	}
+}
+
+fn ref_marker(rid: &RecordId) -> Value {
+	Value::Object(Object(map! {
+		"$ref".to_string() => Value::from(rid.to_sql()),
+	}))
+}
@@ -241,11 +241,13 @@ fn select_all_from_record(rid: &RecordId, version: &Option<Datetime>) -> SelectS
 		only: true,
 		version: version_to_expr(version),
 		timeout: Expr::Literal(Literal::None),
+		scan_timeout: Expr::Literal(Literal::None),
 		omit: vec![],
 		with: None,
 		cond: None,
 		split: None,
 		group: None,
+		having: None,
 		order: None,
 		limit: None,
 		start: None,
@@ -274,11 +276,13 @@ fn select_field_from_record(
 		only: true,
 		version: version_to_expr(version),
 		timeout: Expr::Literal(Literal::None),
+		scan_timeout: Expr::Literal(Literal::None),
 		omit: vec![],
 		with: None,
 		cond: None,
 		split: None,
 		group: None,
+		having: None,
 		order: None,
 		limit: None,
 		start: None,
@@ -309,11 +313,13 @@ fn select_all_from_table(
 		start,
 		version: version_to_expr(version),
 		timeout: Expr::Literal(Literal::None),
+		scan_timeout: Expr::Literal(Literal::None),
 		omit: vec![],
 		only: false,
 		with: None,
 		split: None,
 		group: None,
+		having: None,
 		fetch: None,
 		explain: None,
 		tempfiles: false,
@@ -1,3 +1,6 @@
+#[cfg(not(target_family = "wasm"))]
+use std::sync::{Arc, OnceLock};
+
 use anyhow::Result;
 use http::Method;
 #[cfg(not(target_family = "wasm"))]
@@ -12,6 +15,27 @@ mod resolve;
 
 pub struct HttpClient {
 	client: Client,
+	/// The network targets which the client can connect to
+	#[cfg(not(target_family = "wasm"))]
+	filter: Arc<NetFilter>,
+	/// The client which fails on redirects, created when first used
+	#[cfg(not(target_family = "wasm"))]
+	error_client: OnceLock<Client>,
+	/// The client which returns redirects as responses, created when first used
+	#[cfg(not(target_family = "wasm"))]
+	manual_client: OnceLock<Client>,
+}
+
+/// How a request handles redirect responses
+#[cfg(not(target_family = "wasm"))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Redirects {
+	/// Follow redirects to allowed network targets
+	Follow,
+	/// Fail the request when a redirect is returned
+	Error,
+	/// Return the redirect as the response
+	Manual,
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -35,69 +59,45 @@ impl HttpClient {
 	where
 		F: Fn(Attempt) -> Action + Send + Sync + 'static,
 	{
-		use std::str::FromStr;
-		use std::sync::Arc;
-		use std::time::Duration;
-
-		use anyhow::Context as _;
-		use http::header::USER_AGENT;
-		use http::{HeaderMap, HeaderValue};
-		use reqwest::redirect::{Attempt, Policy};
-		use resolve::FilteringResolver;
-
-		use crate::cnf::SURREALDB_USER_AGENT;
-		use crate::dbs::capabilities::NetTarget;
-
 		let filter = Arc::new(NetFilter {
 			allow,
 			deny,
 		});
+		let client = build_client(filter.clone(), policy)?;
+		Ok(HttpClient {
+			client,
+			filter,
+			error_client: OnceLock::new(),
+			manual_client: OnceLock::new(),
+		})
+	}
 
-		let filter_clone = filter.clone();
-		let max_redirects = *crate::cnf::MAX_HTTP_REDIRECTS;
-		let redirect_function = move |attempt: Attempt| {
-			if attempt.previous().len() >= max_redirects {
-				return attempt.stop();
+	/// Creates a request which handles redirects as specified
+	///
+	/// The clients which do not follow redirects are created when first used,
+	/// and are then reused along with their connection pools.
+	#[cfg(not(target_family = "wasm"))]
+	pub fn request_with_redirects(
+		&self,
+		redirects: Redirects,
+		method: Method,
+		url: Url,
+	) -> Result<RequestBuilder> {
+		let (cell, policy): (_, fn(Attempt) -> Action) = match redirects {
+			Redirects::Follow => return Ok(self.request(method, url)),
+			Redirects::Error => {
+				(&self.error_client, |attempt| attempt.error("unexpected redirect"))
 			}
-
-			// Check domain name allowlist
-			let url = attempt.url();
-			let target = match NetTarget::from_str(url.host_str().unwrap_or(""))
-				.map_err(|e| crate::err::Error::InvalidUrl(format!("Invalid host: {}", e)))
-			{
-				Ok(x) => x,
-				Err(e) => return attempt.error(e),
-			};
-
-			if !filter_clone.allow.matches(&target) || filter_clone.deny.matches(&target) {
-				let url = url.to_string();
-				return attempt.error(crate::err::Error::NetTargetNotAllowed(url));
+			Redirects::Manual => (&self.manual_client, |attempt| attempt.stop()),
+		};
+		let client = match cell.get() {
+			Some(client) => client,
+			None => {
+				let client = build_client(self.filter.clone(), policy)?;
+				cell.get_or_init(|| client)
 			}
-
-			policy(attempt)
 		};
-
-		let value =
-			HeaderValue::from_str(&SURREALDB_USER_AGENT).context("Invalid user agent string")?;
-
-		let mut headers = HeaderMap::new();
-		headers.insert(USER_AGENT, value);
-
-		let client = Client::builder()
-			.pool_idle_timeout(Duration::from_secs(*crate::cnf::HTTP_IDLE_TIMEOUT_SECS))
-			.pool_max_idle_per_host(*crate::cnf::MAX_HTTP_IDLE_CONNECTIONS_PER_HOST)
-			.connect_timeout(Duration::from_secs(*crate::cnf::HTTP_CONNECT_TIMEOUT_SECS))
-			.tcp_keepalive(Some(Duration::from_secs(60)))
-			.http2_keep_alive_interval(Some(Duration::from_secs(30)))
-			.http2_keep_alive_timeout(Duration::from_secs(10))
-			.redirect(Policy::custom(redirect_function))
-			.dns_resolver(FilteringResolver::from_net_filter(filter))
-			.default_headers(headers)
-			.build()?;
-
-		Ok(HttpClient {
-			client,
-		})
+		Ok(client.request(method, url))
 	}
 
 	#[cfg(target_family = "wasm")]
@@ -114,3 +114,65 @@ impl HttpClient {
 		self.client.request(method, url)
 	}
 }
+
+/// Creates a client which only connects to the targets allowed by the filter
+#[cfg(not(target_family = "wasm"))]
+fn build_client<F>(filter: Arc<NetFilter>, policy: F) -> Result<Client>
+where
+	F: Fn(Attempt) -> Action + Send + Sync + 'static,
+{
+	use std::str::FromStr;
+	use std::time::Duration;
+
+	use anyhow::Context as _;
+	use http::header::USER_AGENT;
+	use http::{HeaderMap, HeaderValue};
+	use reqwest::redirect::Policy;
+	use resolve::FilteringResolver;
+
+	use crate::cnf::SURREALDB_USER_AGENT;
+
+	let filter_clone = filter.clone();
+	let max_redirects = *crate::cnf::MAX_HTTP_REDIRECTS;
+	let redirect_function = move |attempt: Attempt| {
+		if attempt.previous().len() >= max_redirects {
+			return attempt.stop();
+		}
+
+		// Check domain name allowlist
+		let url = attempt.url();
+		let target = match NetTarget::from_str(url.host_str().unwrap_or(""))
+			.map_err(|e| crate::err::Error::InvalidUrl(format!("Invalid host: {}", e)))
+		{
+			Ok(x) => x,
+			Err(e) => return attempt.error(e),
+		};
+
+		if !filter_clone.allow.matches(&target) || filter_clone.deny.matches(&target) {
+			let url = url.to_string();
+			return attempt.error(crate::err::Error::NetTargetNotAllowed(url));
+		}
+
+		policy(attempt)
+	};
+
+	let value =
+		HeaderValue::from_str(&SURREALDB_USER_AGENT).context("Invalid user agent string")?;
+
+	let mut headers = HeaderMap::new();
+	headers.insert(USER_AGENT, value);
+
+	let client = Client::builder()
+		.pool_idle_timeout(Duration::from_secs(*crate::cnf::HTTP_IDLE_TIMEOUT_SECS))
+		.pool_max_idle_per_host(*crate::cnf::MAX_HTTP_IDLE_CONNECTIONS_PER_HOST)
+		.connect_timeout(Duration::from_secs(*crate::cnf::HTTP_CONNECT_TIMEOUT_SECS))
+		.tcp_keepalive(Some(Duration::from_secs(60)))
+		.http2_keep_alive_interval(Some(Duration::from_secs(30)))
+		.http2_keep_alive_timeout(Duration::from_secs(10))
+		.redirect(Policy::custom(redirect_function))
+		.dns_resolver(FilteringResolver::from_net_filter(filter))
+		.default_headers(headers)
+		.build()?;
+
+	Ok(client)
+}
@@ -42,6 +42,14 @@ pub(crate) struct StatementContext<'a> {
 	is_perm: bool,
 }
 
+/// The warning raised when a conditional query on a table falls back to a
+/// full table scan because no index could be used.
+pub(crate) fn full_table_scan_warning(tb: &str) -> String {
+	format!(
+		"The query on table `{tb}` performed a full table scan as no index could be used for the WHERE condition"
+	)
+}
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) enum RecordStrategy {
 	Count,
@@ -346,6 +354,9 @@ impl QueryPlanner {
 				if let Some(reason) = reason {
 					self.fallbacks.push(reason);
 				}
+				if stm_ctx.cond.is_some() && !matches!(stm_ctx.with, Some(With::NoIndex)) {
+					stm_ctx.ctx.add_warning(full_table_scan_warning(t));
+				}
 				self.add(doc_ctx.clone(), t.clone(), None, exe, it, rs);
 				it.ingest(Iterable::Table(doc_ctx.clone(), t.clone(), rs, sc));
 				is_table_iterator = true;
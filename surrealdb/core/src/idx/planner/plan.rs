@@ -3,10 +3,11 @@ use std::collections::{BTreeMap, HashMap, HashSet};
 use std::hash::Hash;
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use surrealdb_types::ToSql;
 
 use crate::catalog::Index;
+use crate::err::Error;
 use crate::expr::operator::{MatchesOperator, NearestNeighbor};
 use crate::expr::with::With;
 use crate::expr::{BinaryOperator, Expr, Idiom};
@@ -184,6 +185,12 @@ impl PlanBuilder {
 		reason: Option<&str>,
 		granted_permission: GrantedPermission,
 	) -> Result<Plan> {
+		// A WITH INDEX clause forbids falling back to a table scan
+		if let Some(With::Index(ixs)) = ctx.with {
+			bail!(Error::IndexHintNotSatisfied {
+				names: ixs.join(", "),
+			});
+		}
 		// Evaluate the record strategy
 		let rs = ctx.check_record_strategy(false, granted_permission)?;
 		// Evaluate the scan direction
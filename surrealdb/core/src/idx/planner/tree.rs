@@ -368,7 +368,8 @@ impl<'a> TreeBuilder<'a> {
 		}
 		let mut irs = Vec::new();
 		for (idx, ix) in schema.indexes.iter().enumerate() {
-			if ix.prepare_remove {
+			// Partial indexes do not hold every document of the table
+			if ix.prepare_remove || ix.cond.is_some() {
 				continue;
 			}
 			if let Some(idiom_index) = ix.cols.iter().position(|p| p.eq(i)) {
@@ -59,7 +59,7 @@ impl IndexStores {
 		ix: &IndexDefinition,
 	) -> Result<()> {
 		if let Some(ib) = ib {
-			ib.remove_index(ns, db, &tb.name, ix.index_id).await?;
+			ib.remove_index(ns, db, &tb.name, ix).await?;
 		}
 		self.remove_index(ns, db, tb.table_id, ix).await
 	}
@@ -99,7 +99,7 @@ impl IndexStores {
 	) -> Result<()> {
 		for ix in tx.all_tb_indexes(ns, db, &tb.name, None).await?.iter() {
 			if let Some(ib) = ib {
-				ib.remove_index(ns, db, &tb.name, ix.index_id).await?;
+				ib.remove_index(ns, db, &tb.name, ix).await?;
 			}
 			self.remove_index(ns, db, tb.table_id, ix).await?;
 		}
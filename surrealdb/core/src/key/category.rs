@@ -59,6 +59,10 @@ pub enum Category {
 	TableIndexIdentifierBatch,
 	/// crate::key::table::is                /*{ns}*{db}*{tb}!is
 	TableIndexIdentifierState,
+	/// crate::key::table::rh                /*{ns}*{db}*{tb}!rh
+	TableRecordIdentifierBatch,
+	/// crate::key::table::rs                /*{ns}*{db}*{tb}!rs
+	TableRecordIdentifierState,
 	///
 	/// ------------------------------
 	///
@@ -124,6 +128,8 @@ pub enum Category {
 	IndexDefinition,
 	/// crate::key::table::lq                /*{ns}*{db}*{tb}!lq{lq}
 	TableLiveQuery,
+	/// crate::key::table::tt                /*{ns}*{db}*{tb}!tt
+	TableTtlSweep,
 	///
 	/// ------------------------------
 	///
@@ -253,6 +259,7 @@ impl Display for Category {
 			Self::TableView => "TableView",
 			Self::IndexDefinition => "IndexDefinition",
 			Self::TableLiveQuery => "TableLiveQuery",
+			Self::TableTtlSweep => "TableTtlSweep",
 			Self::IndexRoot => "IndexRoot",
 			Self::IndexTermDocList => "IndexTermDocList",
 			Self::IndexBTreeNode => "IndexBTreeNode",
@@ -289,6 +296,8 @@ impl Display for Category {
 			Self::EventQueue => "EventQueue",
 			Self::TableIndexIdentifierBatch => "TableIndexIdentifierBatch",
 			Self::TableIndexIdentifierState => "TableIndexIdentifierState",
+			Self::TableRecordIdentifierBatch => "TableRecordIdentifierBatch",
+			Self::TableRecordIdentifierState => "TableRecordIdentifierState",
 		};
 		write!(f, "{}", name)
 	}
@@ -71,6 +71,9 @@
 //! crate::key::table::ix                /*{ns}*{db}*{tb_name}!il{ix} -> ix_name
 //! crate::key::table::ix                /*{ns}*{db}*{tb_name}!ix{ix_name} -> IndexDefinition
 //! crate::key::table::lq                /*{ns}*{db}*{tb_name}!lq{lq}
+//! crate::key::table::rh                /*{ns}*{db}*{tb_name}!rh{start}
+//! crate::key::table::rs                /*{ns}*{db}*{tb_name}!rs{nid}
+//! crate::key::table::tt                /*{ns}*{db}*{tb_name}!tt
 //!
 //! crate::key::index::all               /*{ns}*{db}*{tb_name}+{ix}
 //! crate::key::index::bc                /*{ns}*{db}*{tb_name}+{ix}!bc{id}
@@ -6,3 +6,6 @@ pub mod ih;
 pub mod is;
 pub mod ix;
 pub mod lq;
+pub mod rh;
+pub mod rs;
+pub mod tt;
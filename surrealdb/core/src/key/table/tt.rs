@@ -0,0 +1,69 @@
+//! Stores the position of the last TTL sweep of a table
+use std::borrow::Cow;
+
+use storekey::{BorrowDecode, Encode};
+
+use crate::catalog::{DatabaseId, NamespaceId};
+use crate::key::category::{Categorise, Category};
+use crate::kvs::impl_kv_key_storekey;
+use crate::val::{RecordIdKey, TableName};
+
+/// Tt stores the id of the last record which was checked by the TTL sweeper,
+/// so that a sweep can resume from where the previous sweep stopped.
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Encode, BorrowDecode)]
+#[storekey(format = "()")]
+pub(crate) struct Tt<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: NamespaceId,
+	_b: u8,
+	pub db: DatabaseId,
+	_c: u8,
+	pub tb: Cow<'a, TableName>,
+	_d: u8,
+	_e: u8,
+	_f: u8,
+}
+
+impl_kv_key_storekey!(Tt<'_> => RecordIdKey);
+
+pub fn new(ns: NamespaceId, db: DatabaseId, tb: &TableName) -> Tt<'_> {
+	Tt::new(ns, db, tb)
+}
+
+impl Categorise for Tt<'_> {
+	fn categorise(&self) -> Category {
+		Category::TableTtlSweep
+	}
+}
+
+impl<'a> Tt<'a> {
+	pub fn new(ns: NamespaceId, db: DatabaseId, tb: &'a TableName) -> Self {
+		Self {
+			__: b'/',
+			_a: b'*',
+			ns,
+			_b: b'*',
+			db,
+			_c: b'*',
+			tb: Cow::Borrowed(tb),
+			_d: b'!',
+			_e: b't',
+			_f: b't',
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::kvs::KVKey;
+
+	#[test]
+	fn key() {
+		let tb = TableName::from("testtb");
+		let val = Tt::new(NamespaceId(1), DatabaseId(2), &tb);
+		let enc = Tt::encode_key(&val).unwrap();
+		assert_eq!(enc, b"/*\x00\x00\x00\x01*\x00\x00\x00\x02*testtb\0!tt");
+	}
+}
@@ -77,6 +77,33 @@ pub trait Transactable: requirements::TransactionRequirements {
 	/// Get the name of the transaction type.
 	fn kind(&self) -> &'static str;
 
+	/// Get the version of the datastore, if it is known.
+	fn version(&self) -> Option<&'static str> {
+		None
+	}
+
+	/// Check if the datastore supports transaction savepoints.
+	///
+	/// Datastores which support savepoints override this.
+	fn supports_savepoints(&self) -> bool {
+		false
+	}
+
+	/// Check if the datastore supports pessimistic locking.
+	///
+	/// Datastores which do not support pessimistic locking treat
+	/// pessimistic transactions as optimistic transactions.
+	fn supports_pessimistic_locking(&self) -> bool {
+		false
+	}
+
+	/// Check if the datastore supports versioned queries.
+	///
+	/// Datastores which keep historical versions of data override this.
+	fn supports_versioning(&self) -> bool {
+		false
+	}
+
 	/// Check if transaction is finished.
 	///
 	/// If the transaction has been cancelled or committed,
@@ -0,0 +1,129 @@
+//! Audit events for committed writes.
+//!
+//! When a datastore is built with an audit sink, every statement which
+//! writes to the datastore is recorded as an [`AuditEvent`] once its
+//! transaction commits. Statements within a `BEGIN ... COMMIT` block are sent
+//! when the block commits, and nothing is sent for transactions which are
+//! cancelled or fail to commit.
+//!
+//! Events are sent without waiting, so a full or closed sink never holds up a
+//! commit. Events which can not be delivered are dropped.
+//!
+//! The passwords of users and the keys of access methods are redacted from
+//! the statement text of an event.
+use std::collections::HashSet;
+use std::convert::Infallible;
+
+use async_channel::Sender;
+use chrono::Utc;
+use surrealdb_types::{Datetime, RecordId as PublicRecordId, ToSql};
+
+use crate::dbs::Options;
+use crate::expr::TopLevelExpr;
+use crate::expr::statements::alter::AlterUserStatement;
+use crate::expr::statements::{DefineAccessStatement, DefineUserStatement};
+use crate::expr::visit::{MutVisitor, VisitMut};
+use crate::val::RecordId;
+
+/// The text which replaces a secret in the statement of an audit event.
+const REDACTED: &str = "[REDACTED]";
+
+/// A structured record of a statement whose writes were committed.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct AuditEvent {
+	/// The time at which the writes were committed.
+	pub time: Datetime,
+	/// The id of the authenticated user or record, empty when anonymous.
+	pub actor: String,
+	/// The level at which the session is authenticated.
+	pub level: String,
+	/// The namespace selected by the session, if any.
+	pub ns: Option<String>,
+	/// The database selected by the session, if any.
+	pub db: Option<String>,
+	/// The text of the statement, with any passwords or keys redacted.
+	pub statement: String,
+	/// The tables which had records written, in the order they were written.
+	pub tables: Vec<String>,
+	/// The records which were created, updated, or deleted.
+	pub records: Vec<PublicRecordId>,
+}
+
+impl AuditEvent {
+	/// Create an event for a statement run with the given options, which
+	/// wrote the given records.
+	pub(crate) fn new(opt: &Options, statement: String, written: Vec<RecordId>) -> Self {
+		let mut tables: Vec<String> = Vec::new();
+		let mut records = Vec::with_capacity(written.len());
+		let mut seen_tables = HashSet::new();
+		let mut seen_records = HashSet::with_capacity(written.len());
+		for rid in written {
+			if !seen_tables.contains(rid.table.as_str()) {
+				seen_tables.insert(rid.table.as_str().to_owned());
+				tables.push(rid.table.as_str().to_owned());
+			}
+			if let Ok(rid) = PublicRecordId::try_from(rid)
+				&& seen_records.insert(rid.clone())
+			{
+				records.push(rid);
+			}
+		}
+		AuditEvent {
+			time: Datetime::from(Utc::now()),
+			actor: opt.auth.id().to_owned(),
+			level: opt.auth.level().to_string(),
+			ns: opt.ns.as_deref().map(str::to_owned),
+			db: opt.db.as_deref().map(str::to_owned),
+			statement,
+			tables,
+			records,
+		}
+	}
+}
+
+/// Formats a statement for an audit event, with the secrets of any users or
+/// access methods which it defines or alters redacted.
+pub(crate) fn statement(plan: &TopLevelExpr) -> String {
+	let mut plan = plan.clone();
+	let Ok(()) = Redactor.visit_mut_top_level_expr(&mut plan);
+	plan.to_sql()
+}
+
+/// Redacts the passwords of users and the keys of access methods.
+struct Redactor;
+
+impl MutVisitor for Redactor {
+	type Error = Infallible;
+
+	fn visit_mut_define_user(&mut self, d: &mut DefineUserStatement) -> Result<(), Self::Error> {
+		d.hash = REDACTED.to_owned();
+		d.visit_mut(self)
+	}
+
+	fn visit_mut_alter_user(&mut self, a: &mut AlterUserStatement) -> Result<(), Self::Error> {
+		if let Some(hash) = a.hash.as_mut() {
+			*hash = REDACTED.to_owned();
+		}
+		a.visit_mut(self)
+	}
+
+	fn visit_mut_define_access(
+		&mut self,
+		d: &mut DefineAccessStatement,
+	) -> Result<(), Self::Error> {
+		*d = d.clone().redact();
+		d.visit_mut(self)
+	}
+}
+
+/// Sends the events of a committed transaction to the audit sink.
+pub(crate) fn send(sink: &Sender<AuditEvent>, events: Vec<AuditEvent>) {
+	let time = Datetime::from(Utc::now());
+	for mut event in events {
+		event.time = time;
+		if let Err(e) = sink.try_send(event) {
+			warn!("Dropped an audit event which could not be delivered: {e}");
+		}
+	}
+}
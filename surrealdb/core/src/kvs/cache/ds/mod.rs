@@ -3,6 +3,8 @@ mod key;
 mod lookup;
 mod weight;
 
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use anyhow::Result;
 pub(crate) use entry::Entry;
 pub(crate) use lookup::Lookup;
@@ -16,6 +18,10 @@ pub(crate) type Cache = quick_cache::sync::Cache<key::Key, Entry, weight::Weight
 pub struct DatastoreCache {
 	/// Store the cache entries
 	cache: Cache,
+	/// The number of lookups which found an entry
+	hits: AtomicU64,
+	/// The number of lookups which found no entry
+	misses: AtomicU64,
 }
 
 impl DatastoreCache {
@@ -28,12 +34,24 @@ impl DatastoreCache {
 		);
 		Self {
 			cache,
+			hits: AtomicU64::new(0),
+			misses: AtomicU64::new(0),
 		}
 	}
 
 	/// Fetches an item from the datastore cache
 	pub(crate) fn get(&self, lookup: &Lookup) -> Option<Entry> {
-		self.cache.get(lookup)
+		let entry = self.cache.get(lookup);
+		match entry {
+			Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+			None => self.misses.fetch_add(1, Ordering::Relaxed),
+		};
+		entry
+	}
+
+	/// Returns the number of lookups which found and did not find an entry
+	pub(crate) fn lookups(&self) -> (u64, u64) {
+		(self.hits.load(Ordering::Relaxed), self.misses.load(Ordering::Relaxed))
 	}
 
 	/// Inserts an item into the datastore cache
@@ -16,8 +16,9 @@ use std::time::Duration;
 use anyhow::bail;
 use anyhow::{Context as _, Result, ensure};
 use async_channel::Sender;
-use bytes::{Bytes, BytesMut};
-use futures::{Future, Stream};
+use bytes::{Buf, Bytes, BytesMut};
+use chrono::Utc;
+use futures::{Future, Stream, StreamExt};
 use rand::{Rng, thread_rng};
 use reblessive::TreeStack;
 use surrealdb_types::{AuthError, Error as TypesError, SurrealValue, object};
@@ -32,10 +33,11 @@ use tracing::{debug, instrument, trace, warn};
 use uuid::Uuid;
 
 use super::api::Transactable;
+use super::snapshot::Snapshot;
 use super::tr::Transactor;
 use super::tx::Transaction;
 use super::version::MajorVersion;
-use super::{Key, Val, export};
+use super::{Key, Val, export, import};
 use crate::api::err::ApiError;
 use crate::api::invocation::process_api_request;
 use crate::api::request::ApiRequest;
@@ -45,9 +47,9 @@ use crate::catalog::providers::{
 	ApiProvider, CatalogProvider, DatabaseProvider, NamespaceProvider, NodeProvider, TableProvider,
 	UserProvider,
 };
-use crate::catalog::{ApiDefinition, Index, NodeLiveQuery, SubscriptionDefinition};
-use crate::cnf::NORMAL_FETCH_SIZE;
+use crate::catalog::{ApiDefinition, Index, NodeLiveQuery, Record, SubscriptionDefinition};
 use crate::cnf::dynamic::DynamicConfiguration;
+use crate::cnf::{NORMAL_FETCH_SIZE, TTL_SWEEP_SCAN_LIMIT};
 use crate::ctx::Context;
 #[cfg(feature = "jwks")]
 use crate::dbs::capabilities::NetTarget;
@@ -55,7 +57,10 @@ use crate::dbs::capabilities::{
 	ArbitraryQueryTarget, ExperimentalTarget, MethodTarget, RouteTarget,
 };
 use crate::dbs::node::{Node, Timestamp};
-use crate::dbs::{Capabilities, Executor, Options, QueryResult, QueryResultBuilder, Session};
+use crate::dbs::{
+	Capabilities, Executor, ExplainPlan, NewPlannerStrategy, Options, QueryResult,
+	QueryResultBuilder, Session,
+};
 use crate::doc::AsyncEventRecord;
 use crate::err::Error;
 use crate::expr::model::get_model_path;
@@ -72,22 +77,25 @@ use crate::idx::trees::store::IndexStores;
 use crate::key::root::ic::IndexCompactionKey;
 use crate::kvs::LockType::*;
 use crate::kvs::TransactionType::*;
+use crate::kvs::audit::AuditEvent;
 use crate::kvs::cache::ds::DatastoreCache;
 use crate::kvs::clock::SystemClock;
 use crate::kvs::ds::requirements::{
 	TransactionBuilderFactoryRequirements, TransactionBuilderRequirements,
 };
 use crate::kvs::index::IndexBuilder;
+use crate::kvs::result_cache::ResultCache;
 use crate::kvs::sequences::Sequences;
 use crate::kvs::slowlog::SlowLog;
+use crate::kvs::stats::{self, ConflictCounters, ConflictStats, OtherCounters, QueryCounters};
 use crate::kvs::tasklease::{LeaseHandler, TaskLeaseType};
-use crate::kvs::{KVValue, LockType, TransactionType};
-use crate::sql::Ast;
+use crate::kvs::{KVKey, KVValue, LockType, TransactionType};
+use crate::sql::{Ast, Explain as SqlExplain, Expr as SqlExpr, TopLevelExpr as SqlTopLevelExpr};
 #[cfg(feature = "surrealism")]
 use crate::surrealism::cache::SurrealismCache;
 use crate::syn::parser::{ParserSettings, StatementStream};
-use crate::types::{PublicNotification, PublicValue, PublicVariables};
-use crate::val::convert_value_to_public_value;
+use crate::types::{PublicDatetime, PublicNotification, PublicValue, PublicVariables};
+use crate::val::{Datetime, RecordId, TableName, Value, convert_value_to_public_value};
 use crate::{CommunityComposer, syn};
 
 mod builder;
@@ -113,10 +121,19 @@ pub struct Datastore {
 	/// The maximum duration timeout for running multiple statements in a
 	/// transaction.
 	transaction_timeout: Option<Duration>,
+	/// The number of times a transaction is run again when its commit fails
+	/// due to a conflict with a concurrent transaction.
+	transaction_retries: u32,
+	/// The maximum duration a pessimistic transaction waits to acquire a lock.
+	lock_timeout: Option<Duration>,
 	/// The security and feature capabilities for this datastore.
 	capabilities: Arc<Capabilities>,
 	// Whether this datastore enables live query notifications to subscribers.
 	notification_channel: Option<Sender<PublicNotification>>,
+	// The sink receiving an audit event for every committed write, if any.
+	audit_sink: Option<Sender<AuditEvent>>,
+	// The cache of the results of read-only statements, if any.
+	result_cache: Option<Arc<ResultCache>>,
 	// The index store cache
 	index_stores: IndexStores,
 	// The cross transaction cache
@@ -145,6 +162,10 @@ pub struct Datastore {
 	// Http client used to make requests.
 	#[cfg(feature = "http")]
 	http_client: Arc<HttpClient>,
+	// Cumulative transaction commit and conflict counters
+	conflict_counters: Arc<ConflictCounters>,
+	// Cumulative statement counters, and the open transactions
+	query_counters: Arc<QueryCounters>,
 }
 
 /// Represents a collection of metrics for a specific datastore flavor.
@@ -214,6 +235,7 @@ impl TransactionFactory {
 			self.async_event_trigger.clone(),
 			Transactor {
 				inner,
+				lock_timeout: None,
 			},
 		))
 	}
@@ -227,6 +249,11 @@ impl TransactionFactory {
 	fn collect_u64_metric(&self, metric: &str) -> Option<u64> {
 		self.builder.collect_u64_metric(metric)
 	}
+
+	/// The period for which historical versions are retained, if limited.
+	pub(crate) fn version_retention(&self) -> Option<Duration> {
+		self.builder.version_retention()
+	}
 }
 
 #[cfg_attr(target_family = "wasm", async_trait::async_trait(?Send))]
@@ -269,6 +296,14 @@ pub trait TransactionBuilder: TransactionBuilderRequirements {
 	///
 	/// - `metric`: The name of the metric to collect.
 	fn collect_u64_metric(&self, metric: &str) -> Option<u64>;
+
+	/// The period for which historical versions are retained, if limited.
+	///
+	/// Versioned reads older than this period are rejected, as the data they
+	/// refer to may already have been garbage collected.
+	fn version_retention(&self) -> Option<Duration> {
+		None
+	}
 }
 
 #[cfg_attr(target_family = "wasm", async_trait::async_trait(?Send))]
@@ -546,6 +581,20 @@ impl TransactionBuilder for DatastoreFlavor {
 		}
 	}
 
+	/// The period for which historical versions are retained, if limited.
+	fn version_retention(&self) -> Option<Duration> {
+		match self {
+			#[cfg(feature = "kv-mem")]
+			Self::Mem(v) => v.version_retention(),
+			#[cfg(feature = "kv-rocksdb")]
+			Self::RocksDB(v) => v.version_retention(),
+			#[cfg(feature = "kv-surrealkv")]
+			Self::SurrealKV(v) => v.version_retention(),
+			#[allow(unreachable_patterns)]
+			_ => None,
+		}
+	}
+
 	async fn shutdown(&self) -> Result<()> {
 		match self {
 			#[cfg(feature = "kv-mem")]
@@ -659,8 +708,12 @@ impl Datastore {
 			dynamic_configuration: DynamicConfiguration::default(),
 			slow_log: self.slow_log,
 			transaction_timeout: self.transaction_timeout,
+			transaction_retries: self.transaction_retries,
+			lock_timeout: self.lock_timeout,
 			capabilities: self.capabilities.clone(),
 			notification_channel: self.notification_channel,
+			audit_sink: self.audit_sink,
+			result_cache: self.result_cache,
 			index_stores: Default::default(),
 			index_builder: IndexBuilder::new(self.transaction_factory.clone()),
 			#[cfg(feature = "jwks")]
@@ -678,6 +731,8 @@ impl Datastore {
 			async_event_trigger: self.async_event_trigger,
 			#[cfg(feature = "http")]
 			http_client: self.http_client,
+			conflict_counters: self.conflict_counters,
+			query_counters: self.query_counters,
 		}
 	}
 
@@ -698,6 +753,85 @@ impl Datastore {
 		self.transaction_timeout
 	}
 
+	/// Set the maximum duration a pessimistic transaction waits to acquire a
+	/// lock which is held by another transaction
+	///
+	/// This only applies to storage engines which support pessimistic
+	/// locking, as other engines detect conflicts when committing instead.
+	pub fn with_lock_timeout(mut self, duration: Option<Duration>) -> Self {
+		self.lock_timeout = duration;
+		self
+	}
+
+	/// Set the number of times a transaction is run again when its commit
+	/// fails due to a conflict with a concurrent transaction
+	///
+	/// The statements of a conflicting transaction are run again from the
+	/// start, so this should only be enabled when queries are idempotent.
+	/// Side effects outside of the transaction, such as requests made with the
+	/// `http::*` functions, are not rolled back and happen again on each retry.
+	pub fn with_transaction_retries(mut self, retries: u32) -> Self {
+		self.transaction_retries = retries;
+		self
+	}
+
+	/// Get the number of times a conflicting transaction is run again
+	pub(crate) fn transaction_retries(&self) -> u32 {
+		self.transaction_retries
+	}
+
+	/// Get the sink receiving audit events for committed writes, if any
+	pub(crate) fn audit_sink(&self) -> Option<&Sender<AuditEvent>> {
+		self.audit_sink.as_ref()
+	}
+
+	/// Get the cache of the results of read-only statements, if any
+	pub(crate) fn result_cache(&self) -> Option<&ResultCache> {
+		self.result_cache.as_deref()
+	}
+
+	/// Get the cumulative transaction commit statistics since startup.
+	///
+	/// Counts the successful commits, the commits which failed due to a
+	/// transaction conflict, and the number of conflicted operations which
+	/// were retried.
+	pub fn conflict_stats(&self) -> ConflictStats {
+		self.conflict_counters.snapshot()
+	}
+
+	/// Get the counters used to record transaction commit outcomes.
+	pub(crate) fn conflict_counters(&self) -> &ConflictCounters {
+		&self.conflict_counters
+	}
+
+	/// Get the counters used to record the statements executed.
+	pub(crate) fn query_counters(&self) -> &QueryCounters {
+		&self.query_counters
+	}
+
+	/// Get the datastore counters in the Prometheus text exposition format.
+	///
+	/// Includes the statements executed and the statements which failed,
+	/// labelled by statement type, the open transactions, the transaction
+	/// commit and conflict counters, the datastore cache hits and misses, the
+	/// number of slow queries logged, and the result cache hits and misses.
+	pub fn metrics_prometheus(&self) -> String {
+		let (cache_hits, cache_misses) = self.cache.lookups();
+		let (result_cache_hits, result_cache_misses) =
+			self.result_cache.as_ref().map(|c| c.lookups()).unwrap_or_default();
+		stats::render_prometheus(
+			&self.query_counters,
+			OtherCounters {
+				conflicts: self.conflict_counters.snapshot(),
+				cache_hits,
+				cache_misses,
+				slow_queries: self.slow_log.as_ref().map(SlowLog::logged).unwrap_or_default(),
+				result_cache_hits,
+				result_cache_misses,
+			},
+		)
+	}
+
 	#[cfg(storage)]
 	/// Set a temporary directory for ordering of large result sets
 	pub fn with_temporary_directory(mut self, path: Option<PathBuf>) -> Self {
@@ -779,7 +913,7 @@ impl Datastore {
 	#[instrument(err, level = "trace", target = "surrealdb::core::kvs::ds", skip_all)]
 	pub async fn check_version(&self) -> Result<(MajorVersion, bool)> {
 		// Retry because concurrent instances may conflict when writing the version key
-		let (version, is_new) = Self::retry("Check version", || self.get_version()).await?;
+		let (version, is_new) = self.retry("Check version", || self.get_version()).await?;
 		// Check we are running the latest version
 		if !version.is_latest() {
 			bail!(Error::OutdatedStorageVersion {
@@ -849,7 +983,7 @@ impl Datastore {
 	#[instrument(err, level = "trace", target = "surrealdb::core::kvs::ds", skip_all)]
 	pub async fn initialise_credentials(&self, user: &str, pass: &str) -> Result<()> {
 		// Retry because concurrent instances may conflict when creating the root user
-		Self::retry("Initialise credentials", || self.initialise_credentials_attempt(user, pass))
+		self.retry("Initialise credentials", || self.initialise_credentials_attempt(user, pass))
 			.await
 	}
 
@@ -1117,11 +1251,11 @@ impl Datastore {
 		// Each bootstrap step is retried independently, because concurrent instances
 		// writing to the same cluster metadata keys may cause transaction conflicts.
 		// Insert this node in the cluster
-		Self::retry("Insert node", || self.insert_node()).await?;
+		self.retry("Insert node", || self.insert_node()).await?;
 		// Mark inactive nodes as archived
-		Self::retry("Expire nodes", || self.expire_nodes()).await?;
+		self.retry("Expire nodes", || self.expire_nodes()).await?;
 		// Remove archived nodes
-		Self::retry("Remove nodes", || self.remove_nodes()).await?;
+		self.retry("Remove nodes", || self.remove_nodes()).await?;
 		// Everything ok
 		Ok(())
 	}
@@ -1142,7 +1276,7 @@ impl Datastore {
 	/// budget, so total wall-clock time never significantly exceeds the
 	/// global timeout. If no attempt succeeds within the budget, an error
 	/// is returned.
-	async fn retry<F, Fut, R>(task: &str, func: F) -> Result<R>
+	async fn retry<F, Fut, R>(&self, task: &str, func: F) -> Result<R>
 	where
 		F: Fn() -> Fut,
 		Fut: Future<Output = Result<R>>,
@@ -1183,6 +1317,7 @@ impl Datastore {
 			}
 			let tempo = Duration::from_secs(thread_rng().gen_range(0..10)).min(remaining);
 			sleep(tempo).await;
+			self.conflict_counters.record_retry();
 			attempt += 1;
 		}
 		if let Some(e) = last_error {
@@ -1569,6 +1704,175 @@ impl Datastore {
 		Ok(())
 	}
 
+	// --------------------------------------------------
+	// TTL functions
+	// --------------------------------------------------
+
+	/// Purges expired records from tables which have a TTL as a background task.
+	///
+	/// This method uses a distributed task lease mechanism to coordinate which
+	/// node sweeps the tables. Once a batch starts it runs to completion even
+	/// if the lease expires, so brief overlap is possible.
+	///
+	/// # Arguments
+	/// * `interval` - The interval between sweeps, to calculate the lease duration
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::ds", skip(self))]
+	pub async fn ttl_process(&self, interval: &Duration) -> Result<()> {
+		// Output function invocation details to logs
+		trace!(target: TARGET, "Attempting TTL sweep");
+		// Create a new lease handler
+		let lh = LeaseHandler::new(
+			self.sequences.clone(),
+			self.id,
+			self.transaction_factory.clone(),
+			TaskLeaseType::TtlSweep,
+			*interval * 2,
+		)?;
+		// If we don't get the lease, another node is handling this task
+		if !lh.has_lease().await? {
+			return Ok(());
+		}
+		// Output function invocation details to logs
+		trace!(target: TARGET, "Running TTL sweep");
+		// Perform the sweep
+		self.ttl_sweep_at(Some(&lh), Utc::now(), *TTL_SWEEP_SCAN_LIMIT).await?;
+		// Everything ok
+		Ok(())
+	}
+
+	/// Deletes the records whose TTL field is older than the table TTL,
+	/// relative to the given time, and returns the number of records deleted.
+	///
+	/// Each table is scanned in batches, starting from the position which is
+	/// stored at the end of the previous batch. At most `limit` records are
+	/// checked in each table, so a large table is swept over several calls,
+	/// each resuming where the previous one stopped. Records are deleted with
+	/// a regular DELETE statement, so that indexes, events and references are
+	/// kept up to date.
+	///
+	/// Expired records are not hidden from reads, and remain visible until
+	/// they are deleted by a sweep.
+	pub(crate) async fn ttl_sweep_at(
+		&self,
+		lh: Option<&LeaseHandler>,
+		now: chrono::DateTime<Utc>,
+		limit: u32,
+	) -> Result<usize> {
+		// Find all of the tables which have a TTL
+		let txn = self.transaction(Read, Optimistic).await?;
+		let mut tables = Vec::new();
+		for ns in catch!(txn, txn.all_ns(None).await).iter() {
+			for db in catch!(txn, txn.all_db(ns.namespace_id, None).await).iter() {
+				for tb in
+					catch!(txn, txn.all_tb(ns.namespace_id, db.database_id, None).await).iter()
+				{
+					if tb.ttl.is_some() {
+						tables.push((ns.name.clone(), db.name.clone(), tb.clone()));
+					}
+				}
+			}
+		}
+		txn.cancel().await?;
+		// Expired records are checked again when they are deleted
+		let ast = syn::parse_with_capabilities(
+			"DELETE $ids WHERE type::field($field) < $cutoff RETURN id",
+			&self.capabilities,
+		)?;
+		let limit = limit.max(1);
+		let mut deleted = 0;
+		for (ns, db, tb) in tables {
+			let Some(ttl) = &tb.ttl else {
+				continue;
+			};
+			let cutoff = match chrono::Duration::from_std(ttl.duration) {
+				Ok(d) => now.checked_sub_signed(d).unwrap_or(chrono::DateTime::<Utc>::MIN_UTC),
+				Err(_) => chrono::DateTime::<Utc>::MIN_UTC,
+			};
+			let sess = Session::owner().with_ns(&ns).with_db(&db);
+			let key = crate::key::table::tt::new(tb.namespace_id, tb.database_id, &tb.name);
+			let end = crate::key::record::suffix(tb.namespace_id, tb.database_id, &tb.name)?;
+			let mut scanned = 0;
+			loop {
+				// Fetch the next batch of records, after the last swept record
+				let batch = (*NORMAL_FETCH_SIZE).min(limit - scanned).max(1);
+				let txn = self.transaction(Read, Optimistic).await?;
+				let beg = match catch!(txn, txn.get(&key, None).await) {
+					Some(id) => {
+						let mut beg = catch!(
+							txn,
+							crate::key::record::new(tb.namespace_id, tb.database_id, &tb.name, &id)
+								.encode_key()
+						);
+						beg.push(0x00);
+						beg
+					}
+					None => catch!(
+						txn,
+						crate::key::record::prefix(tb.namespace_id, tb.database_id, &tb.name)
+					),
+				};
+				let res = catch!(txn, txn.scan(beg..end.clone(), batch, 0, None).await);
+				txn.cancel().await?;
+				// Find the expired records in this batch
+				let complete = res.len() < batch as usize;
+				scanned = scanned.saturating_add(res.len() as u32);
+				let mut ids = Vec::new();
+				let mut last = None;
+				for (k, v) in res {
+					let rk = crate::key::record::RecordKey::decode_key(&k)?;
+					let record = Record::kv_decode_value(v)?;
+					if let Value::Datetime(dt) = record.data.pick(&ttl.field)
+						&& dt.0 < cutoff
+					{
+						ids.push(Value::RecordId(RecordId {
+							table: tb.name.clone(),
+							key: rk.id.clone(),
+						}));
+					}
+					last = Some(rk.id);
+				}
+				// Delete the expired records, counting the records which the
+				// DELETE removed, as some may have changed since the scan
+				if !ids.is_empty() {
+					let vars = PublicVariables::from(map! {
+						"ids".to_string() => convert_value_to_public_value(Value::from(ids))?,
+						"field".to_string() => PublicValue::String(ttl.field.to_raw_string()),
+						"cutoff".to_string() => PublicValue::Datetime(PublicDatetime::from(cutoff)),
+					});
+					let res = self
+						.process(ast.clone(), &sess, Some(vars))
+						.await
+						.map_err(|e| anyhow::anyhow!(e))?;
+					for res in res {
+						if let PublicValue::Array(v) = res.result.map_err(|e| anyhow::anyhow!(e))? {
+							deleted += v.len();
+						}
+					}
+				}
+				// Store the position of the sweep, or reset it once the table is complete
+				let txn = self.transaction(Write, Optimistic).await?;
+				match last {
+					Some(id) if !complete => catch!(txn, txn.set(&key, &id).await),
+					_ => catch!(txn, txn.del(&key).await),
+				}
+				catch!(txn, txn.commit().await);
+				// The next sweep resumes from the stored position
+				if complete || scanned >= limit {
+					break;
+				}
+				// Possibly renew the lease
+				if let Some(lh) = lh
+					&& !lh.try_maintain_lease().await?
+				{
+					return Ok(deleted);
+				}
+				// Yield execution
+				yield_now!();
+			}
+		}
+		Ok(deleted)
+	}
+
 	// --------------------------------------------------
 	// Indexing functions
 	// --------------------------------------------------
@@ -1835,7 +2139,58 @@ impl Datastore {
 	/// }
 	/// ```
 	pub async fn transaction(&self, write: TransactionType, lock: LockType) -> Result<Transaction> {
-		self.transaction_factory.transaction(write, lock, self.sequences.clone()).await
+		let tx = self.transaction_factory.transaction(write, lock, self.sequences.clone()).await?;
+		let mut tx = tx.with_active(self.query_counters.track_transaction());
+		// Limit how long pessimistic transactions wait to acquire locks
+		if let (Pessimistic, Some(timeout)) = (lock, self.lock_timeout) {
+			tx = tx.with_lock_timeout(timeout);
+		}
+		// Invalidate the cached results which read the tables written to
+		if let Some(cache) = &self.result_cache {
+			tx = tx.with_result_cache(cache.clone());
+		}
+		// Track the written records when they are needed for audit events
+		if self.audit_sink.is_some() {
+			return Ok(tx.with_written_records());
+		}
+		Ok(tx)
+	}
+
+	/// Open a read-only view of the datastore as of the specified timestamp.
+	///
+	/// Every statement executed against the returned [`Snapshot`] reads data
+	/// as if it specified `VERSION` with this timestamp. This requires a
+	/// datastore with versioning enabled, and timestamps which are older than
+	/// the configured version retention period are rejected.
+	pub async fn snapshot_at(&self, at: PublicDatetime) -> Result<Snapshot<'_>> {
+		let at = Datetime::from(at);
+		// Ensure the data at this timestamp has not been garbage collected
+		if let Some(retention) = self.transaction_factory.version_retention() {
+			let oldest = Utc::now() - retention;
+			ensure!(
+				at.0 >= oldest,
+				Error::Kvs(super::Error::TimestampInvalid(format!(
+					"{at} is outside of the version retention period of {}",
+					crate::val::Duration::from(retention)
+				)))
+			);
+		}
+		// Open a read-only transaction for the snapshot
+		let tx = self.transaction(Read, Optimistic).await?;
+		// Ensure the datastore keeps historical versions of the data
+		if !tx.supports_versioning() {
+			let _ = tx.cancel().await;
+			bail!(Error::Kvs(super::Error::UnsupportedVersionedQueries));
+		}
+		// Convert the timestamp to a version stamp
+		let version = match at.to_version_stamp(tx.timestamp_impl().as_ref()) {
+			Ok(v) => v,
+			Err(e) => {
+				let _ = tx.cancel().await;
+				return Err(e);
+			}
+		};
+		Ok(Snapshot::new(self, Arc::new(tx), version))
 	}
 
 	pub(crate) fn sequences(&self) -> &Sequences {
@@ -1894,6 +2249,8 @@ impl Datastore {
 		sess: &Session,
 		vars: Option<PublicVariables>,
 	) -> std::result::Result<Vec<QueryResult>, TypesError> {
+		// Check the length of the query before parsing it
+		self.check_query_length(txt).map_err(crate::err::into_types_error)?;
 		// Parse the SQL query text
 		let ast = syn::parse_with_capabilities(txt, &self.capabilities)
 			.map_err(|e| TypesError::validation(e.to_string(), None))?;
@@ -1901,6 +2258,89 @@ impl Datastore {
 		self.process(ast, sess, vars).await
 	}
 
+	/// Parse an SQL SELECT statement, and return the plan for executing it
+	///
+	/// The statement is run with `EXPLAIN`, so no records are returned. When
+	/// the statement already ends with `EXPLAIN FULL`, the plan also includes
+	/// the number of records which were fetched.
+	///
+	/// ```rust,no_run
+	/// use anyhow::Error;
+	/// use surrealdb_core::kvs::Datastore;
+	/// use surrealdb_core::dbs::Session;
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(),Error> {
+	///     let ds = Datastore::new("memory").await?;
+	///     let ses = Session::owner().with_ns("test").with_db("test");
+	///     let plan = ds.explain("SELECT * FROM person WHERE age > 18", &ses, None).await?;
+	///     println!("{:?}", plan.indexes());
+	///     Ok(())
+	/// }
+	/// ```
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
+	pub async fn explain(
+		&self,
+		txt: &str,
+		sess: &Session,
+		vars: Option<PublicVariables>,
+	) -> std::result::Result<ExplainPlan, TypesError> {
+		// Check the length of the query before parsing it
+		self.check_query_length(txt).map_err(crate::err::into_types_error)?;
+		// Parse the SQL query text
+		let mut ast = syn::parse_with_capabilities(txt, &self.capabilities)
+			.map_err(|e| TypesError::validation(e.to_string(), None))?;
+		// Only a single SELECT statement can be explained
+		let [SqlTopLevelExpr::Expr(SqlExpr::Select(stm))] = ast.expressions.as_mut_slice() else {
+			return Err(TypesError::validation(
+				"Expected a single SELECT statement to explain".to_string(),
+				None,
+			));
+		};
+		stm.explain.get_or_insert(SqlExplain::Plan);
+		// The plan is read from the output of the compute executor
+		let sess = sess.clone().new_planner_strategy(NewPlannerStrategy::ComputeOnly);
+		let mut res = self.process(ast, &sess, vars).await?;
+		let res = res.pop().ok_or_else(|| TypesError::internal("Expected a result".to_string()))?;
+		ExplainPlan::try_from(res.result?)
+	}
+
+	/// Execute a query, yielding the result of each statement as it completes
+	///
+	/// Unlike [`Datastore::execute`], which waits for every statement to
+	/// finish before returning, the returned stream yields the result of each
+	/// top-level statement as soon as it is available. The results of a
+	/// `BEGIN` / `COMMIT` block are yielded once the whole block has finished.
+	/// If the query can not be run at all, a single error is yielded instead.
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
+	pub fn execute_stream_responses<'a>(
+		&'a self,
+		txt: &'a str,
+		sess: &'a Session,
+		vars: Option<PublicVariables>,
+	) -> impl Stream<Item = std::result::Result<QueryResult, TypesError>> + 'a {
+		let (send, recv) = async_channel::unbounded();
+		let exec = async move {
+			// Check the length of the query before parsing it
+			self.check_query_length(txt).map_err(crate::err::into_types_error)?;
+			// Parse the SQL query text
+			let ast = syn::parse_with_capabilities(txt, &self.capabilities)
+				.map_err(|e| TypesError::validation(e.to_string(), None))?;
+			// Check the number of statements in the query
+			self.check_statement_limit(&ast).map_err(crate::err::into_types_error)?;
+			// Process the AST, sending results as they complete
+			self.process_plan_with_sender(ast.into(), sess, vars, Some(send)).await.map(|_| ())
+		};
+		// Only yield the outcome of the execution if it failed. The channel
+		// is closed once the execution has finished and all results are sent
+		let exec = futures::stream::once(exec)
+			.filter_map(|res| futures::future::ready(res.err().map(Err)));
+		// Always yield completed results before continuing the execution
+		futures::stream::select_with_strategy(recv.map(Ok), exec, |_: &mut ()| {
+			futures::stream::PollNext::Left
+		})
+	}
+
 	/// Execute a query with an existing transaction
 	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
 	pub async fn execute_with_transaction(
@@ -1910,6 +2350,8 @@ impl Datastore {
 		vars: Option<PublicVariables>,
 		tx: Arc<Transaction>,
 	) -> std::result::Result<Vec<QueryResult>, TypesError> {
+		// Check the length of the query before parsing it
+		self.check_query_length(txt).map_err(crate::err::into_types_error)?;
 		// Parse the SQL query text
 		let ast = syn::parse_with_capabilities(txt, &self.capabilities)
 			.map_err(|e| TypesError::validation(e.to_string(), None))?;
@@ -1926,6 +2368,21 @@ impl Datastore {
 		vars: Option<PublicVariables>,
 		tx: Arc<Transaction>,
 	) -> std::result::Result<Vec<QueryResult>, TypesError> {
+		self.process_in_transaction(ast, sess, vars, tx, None).await
+	}
+
+	/// Process an AST with an existing transaction, optionally reading all
+	/// data as of the specified version stamp
+	pub(crate) async fn process_in_transaction(
+		&self,
+		ast: Ast,
+		sess: &Session,
+		vars: Option<PublicVariables>,
+		tx: Arc<Transaction>,
+		version: Option<u64>,
+	) -> std::result::Result<Vec<QueryResult>, TypesError> {
+		// Check the number of statements in the query
+		self.check_statement_limit(&ast).map_err(crate::err::into_types_error)?;
 		// Check if the session has expired
 		if sess.expired() {
 			return Err(TypesError::not_allowed(
@@ -1947,7 +2404,7 @@ impl Datastore {
 		}
 
 		// Create a new query options
-		let opt = self.setup_options(sess);
+		let opt = self.setup_options(sess).with_version(version);
 
 		// Create a default context
 		let mut ctx = self.setup_ctx().map_err(|e| {
@@ -2088,15 +2545,58 @@ impl Datastore {
 		sess: &Session,
 		vars: Option<PublicVariables>,
 	) -> std::result::Result<Vec<QueryResult>, TypesError> {
+		// Check the number of statements in the query
+		self.check_statement_limit(&ast).map_err(crate::err::into_types_error)?;
 		//TODO: Insert planner here.
 		self.process_plan(ast.into(), sess, vars).await
 	}
 
+	/// Ensure that the text of a query is not longer than allowed
+	pub(crate) fn check_query_length(&self, txt: &str) -> Result<(), Error> {
+		if let Some(limit) = self.capabilities.max_query_length() {
+			let length = txt.len();
+			if length > limit {
+				return Err(Error::QueryLengthLimitExceeded {
+					length,
+					limit,
+				});
+			}
+		}
+		Ok(())
+	}
+
+	/// Ensure that a query does not contain more statements than allowed
+	fn check_statement_limit(&self, ast: &Ast) -> Result<(), Error> {
+		if let Some(limit) = self.capabilities.max_statements() {
+			let count = ast.expressions.len();
+			if count > limit {
+				return Err(Error::QueryStatementLimitExceeded {
+					count,
+					limit,
+				});
+			}
+		}
+		Ok(())
+	}
+
 	pub(crate) async fn process_plan(
 		&self,
 		plan: LogicalPlan,
 		sess: &Session,
 		vars: Option<PublicVariables>,
+	) -> Result<Vec<QueryResult>, TypesError> {
+		self.process_plan_with_sender(plan, sess, vars, None).await
+	}
+
+	/// Process a logical plan, sending the results to the channel as each
+	/// top-level statement completes when a sender is specified. Any results
+	/// are then sent to the channel instead of being returned.
+	async fn process_plan_with_sender(
+		&self,
+		plan: LogicalPlan,
+		sess: &Session,
+		vars: Option<PublicVariables>,
+		sender: Option<Sender<QueryResult>>,
 	) -> Result<Vec<QueryResult>, TypesError> {
 		// Check if the session has expired
 		if sess.expired() {
@@ -2138,7 +2638,13 @@ impl Datastore {
 		}
 
 		// Process all statements
-		Executor::execute_plan(self, ctx.freeze(), opt, plan).await.map_err(|e| {
+		let res = match sender {
+			Some(sender) => Executor::execute_plan_streaming(self, ctx.freeze(), opt, plan, sender)
+				.await
+				.map(|_| Vec::new()),
+			None => Executor::execute_plan(self, ctx.freeze(), opt, plan).await,
+		};
+		res.map_err(|e| {
 			e.downcast::<Error>()
 				.map(crate::err::into_types_error)
 				.unwrap_or_else(|e| TypesError::internal(e.to_string()))
@@ -2227,6 +2733,113 @@ impl Datastore {
 		self.execute_import(sess, None, stream).await
 	}
 
+	/// Performs a streaming import of records from CSV into a table
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
+	pub async fn import_csv<S>(
+		&self,
+		stream: S,
+		table: &str,
+		cfg: import::CsvConfig,
+		sess: &Session,
+	) -> Result<import::CsvImportResult>
+	where
+		S: Stream<Item = Result<Bytes>>,
+	{
+		// Check if the session has expired
+		ensure!(!sess.expired(), Error::ExpiredSession);
+		// Check that a table has been specified
+		ensure!(
+			!table.is_empty(),
+			Error::TbInvalid {
+				value: table.to_owned(),
+			}
+		);
+		// Retrieve the provided NS and DB
+		let (ns, db) = crate::iam::check::check_ns_db(sess)?;
+		// Fetch the defined fields, so that cells can be cast to the field kinds
+		let txn = self.transaction(Read, Optimistic).await?;
+		let fields = match txn.get_db_by_name(&ns, &db, None).await? {
+			Some(db) => {
+				let tb = TableName::new(table.to_owned());
+				txn.all_tb_fields(db.namespace_id, db.database_id, &tb, None).await?
+			}
+			None => Arc::default(),
+		};
+		txn.cancel().await?;
+		let mut importer = import::CsvImporter::new(cfg, &fields);
+		// Each batch of records is inserted with a single statement, which
+		// depends on the columns, so is parsed once the first batch is ready
+		let mut ast = None;
+		let mut buffer = BytesMut::new();
+		let mut stream = pin!(stream);
+		let mut complete = false;
+		loop {
+			// Process all of the complete rows in the buffer
+			while !buffer.is_empty() {
+				let parsed =
+					import::parse_row(&buffer, importer.delimiter(), importer.quote(), complete);
+				let len = match &parsed {
+					import::CsvParsed::Incomplete => break,
+					import::CsvParsed::Row(_, len) | import::CsvParsed::Malformed(_, len) => *len,
+				};
+				importer.process(parsed)?;
+				buffer.advance(len);
+				if let Some(batch) = importer.full_batch() {
+					let ast = match &ast {
+						Some(ast) => ast,
+						None => ast.insert(syn::parse_with_capabilities(
+							&importer.insert_statement(),
+							&self.capabilities,
+						)?),
+					};
+					importer.result.imported +=
+						self.import_csv_batch(ast, table, batch, sess).await?;
+				}
+			}
+			if complete {
+				break;
+			}
+			// Read the next chunk of the file
+			match stream.next().await {
+				Some(bytes) => buffer.extend_from_slice(&bytes?),
+				None => complete = true,
+			}
+		}
+		// Insert any remaining records
+		if let Some(batch) = importer.last_batch() {
+			let ast = match &ast {
+				Some(ast) => ast,
+				None => ast.insert(syn::parse_with_capabilities(
+					&importer.insert_statement(),
+					&self.capabilities,
+				)?),
+			};
+			importer.result.imported += self.import_csv_batch(ast, table, batch, sess).await?;
+		}
+		Ok(importer.result)
+	}
+
+	/// Inserts a batch of records for a CSV import
+	async fn import_csv_batch(
+		&self,
+		ast: &Ast,
+		table: &str,
+		batch: Vec<Value>,
+		sess: &Session,
+	) -> Result<usize> {
+		let count = batch.len();
+		let vars = PublicVariables::from(map! {
+			"table".to_string() => PublicValue::String(table.to_owned()),
+			"data".to_string() => convert_value_to_public_value(Value::from(batch))?,
+		});
+		let res =
+			self.process(ast.clone(), sess, Some(vars)).await.map_err(|e| anyhow::anyhow!(e))?;
+		for res in res {
+			res.result.map_err(|e| anyhow::anyhow!(e))?;
+		}
+		Ok(count)
+	}
+
 	/// Performs a full database export as SQL
 	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
 	pub async fn export(
@@ -2283,6 +2896,11 @@ impl Datastore {
 			.with_live(sess.live())
 			.with_auth(sess.au.clone())
 			.with_auth_enabled(self.auth_enabled)
+			.with_return_version(sess.return_version)
+			.with_commit_summary(sess.commit_summary)
+			.with_metrics(sess.metrics)
+			.with_max_output_depth(sess.max_output_depth, sess.output_depth_policy)
+			.with_default_limit(sess.default_limit)
 	}
 
 	pub fn setup_ctx(&self) -> Result<Context> {
@@ -2506,9 +3124,150 @@ impl Datastore {
 #[cfg(test)]
 mod test {
 	use super::*;
+	use crate::dbs::NullCoercion;
 	use crate::iam::verify::verify_root_creds;
-	use crate::types::{PublicValue, PublicVariables};
-	use crate::val::TableName;
+	use crate::types::{PublicRecordId, PublicValue, PublicVariables};
+	use crate::val::RecordIdKey;
+
+	#[tokio::test]
+	async fn test_audit() {
+		let (send, recv) = crate::channel::bounded(16);
+		let ds = Datastore::builder().with_audit(send).build_with_path("memory").await.unwrap();
+		let ses = Session::owner().with_ns("test").with_db("test");
+		// A committed write emits an event with the records it wrote
+		let res = ds.execute("CREATE person:tobie SET name = 'Tobie'", &ses, None).await.unwrap();
+		assert!(res[0].result.is_ok());
+		let event = recv.try_recv().unwrap();
+		assert_eq!(event.statement, "CREATE person:tobie SET name = 'Tobie'");
+		assert_eq!(event.tables, ["person"]);
+		assert_eq!(event.records, [PublicRecordId::new("person", "tobie")]);
+		assert_eq!(event.ns.as_deref(), Some("test"));
+		assert_eq!(event.db.as_deref(), Some("test"));
+		// Reads do not emit events
+		ds.execute("SELECT * FROM person", &ses, None).await.unwrap();
+		assert!(recv.try_recv().is_err());
+		// Writes which are rolled back do not emit events
+		let res = ds.execute("BEGIN; CREATE person:jaime; CANCEL;", &ses, None).await.unwrap();
+		assert!(res.iter().all(|r| r.result.is_ok()));
+		assert!(recv.try_recv().is_err());
+		let res = ds.execute("CREATE person:tobie", &ses, None).await.unwrap();
+		assert!(res[0].result.is_err());
+		assert!(recv.try_recv().is_err());
+		// Each write in a committed transaction emits an event
+		let res = ds
+			.execute(
+				"BEGIN; CREATE person:jaime; UPDATE person:tobie SET name = 'Tobie'; COMMIT;",
+				&ses,
+				None,
+			)
+			.await
+			.unwrap();
+		assert!(res.iter().all(|r| r.result.is_ok()));
+		let event = recv.try_recv().unwrap();
+		assert_eq!(event.records, [PublicRecordId::new("person", "jaime")]);
+		let event = recv.try_recv().unwrap();
+		assert_eq!(event.records, [PublicRecordId::new("person", "tobie")]);
+		assert!(recv.try_recv().is_err());
+		// Records and tables are only listed once
+		let res = ds
+			.execute("UPDATE person:tobie, person:jaime, person:tobie SET age = 1", &ses, None)
+			.await
+			.unwrap();
+		assert!(res[0].result.is_ok());
+		let event = recv.try_recv().unwrap();
+		assert_eq!(event.tables, ["person"]);
+		assert_eq!(
+			event.records,
+			[PublicRecordId::new("person", "tobie"), PublicRecordId::new("person", "jaime")]
+		);
+		// Secrets are redacted from the statement text
+		let res = ds
+			.execute("DEFINE USER audited ON ROOT PASSWORD 'hunter2' ROLES VIEWER", &ses, None)
+			.await
+			.unwrap();
+		assert!(res[0].result.is_ok());
+		let event = recv.try_recv().unwrap();
+		assert!(event.statement.contains("PASSHASH '[REDACTED]'"), "{}", event.statement);
+		assert!(!event.statement.contains("argon2"), "{}", event.statement);
+		let res = ds
+			.execute(
+				"DEFINE ACCESS audited ON DATABASE TYPE JWT ALGORITHM HS512 KEY 'hunter2'",
+				&ses,
+				None,
+			)
+			.await
+			.unwrap();
+		assert!(res[0].result.is_ok());
+		let event = recv.try_recv().unwrap();
+		assert!(!event.statement.contains("hunter2"), "{}", event.statement);
+		assert!(event.statement.contains("[REDACTED]"), "{}", event.statement);
+	}
+
+	#[tokio::test]
+	async fn test_slow_log_hook() {
+		let slow = Arc::new(parking_lot::Mutex::new(Vec::new()));
+		let captured = slow.clone();
+		let ds = Datastore::builder()
+			.with_slow_log(Duration::ZERO, vec![], vec![])
+			.with_slow_log_hook(move |elapsed, query| {
+				captured.lock().push((elapsed, query.to_owned()));
+			})
+			.build_with_path("memory")
+			.await
+			.unwrap();
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let res = ds
+			.execute("CREATE person:tobie;\n\tSELECT *\n\tFROM person;", &ses, None)
+			.await
+			.unwrap();
+		assert!(res.iter().all(|r| r.result.is_ok()));
+		// Each statement is passed to the hook, on a single line
+		let slow = slow.lock();
+		let queries: Vec<_> = slow.iter().map(|(_, q)| q.as_str()).collect();
+		assert_eq!(queries, ["CREATE person:tobie", "SELECT * FROM person"]);
+	}
+
+	#[tokio::test]
+	async fn test_explain() {
+		let ds = Datastore::new("memory").await.unwrap();
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let res = ds
+			.execute(
+				"DEFINE INDEX idx_age ON person FIELDS age; CREATE person:1 SET age = 20;",
+				&ses,
+				None,
+			)
+			.await
+			.unwrap();
+		assert!(res.into_iter().all(|r| r.result.is_ok()));
+		// A filtered query on the indexed field iterates the index
+		let plan = ds.explain("SELECT * FROM person WHERE age = 20", &ses, None).await.unwrap();
+		let steps: Vec<_> = plan.steps.iter().map(|s| s.operation.as_str()).collect();
+		assert_eq!(steps, ["Iterate Index", "Collector", "RecordStrategy"]);
+		let iter = plan.iterators().next().unwrap();
+		assert_eq!(iter.table(), Some("person"));
+		assert_eq!(iter.index(), Some("idx_age"));
+		assert_eq!(plan.indexes(), ["idx_age"]);
+		assert_eq!(plan.collector(), Some("Memory"));
+		assert_eq!(plan.record_strategy(), Some("KeysAndValues"));
+		// EXPLAIN FULL also returns the number of fetched records
+		let plan = ds
+			.explain("SELECT * FROM person WHERE age = 20 EXPLAIN FULL", &ses, None)
+			.await
+			.unwrap();
+		let fetch = plan.steps.iter().find(|s| s.operation == "Fetch").unwrap();
+		assert_eq!(fetch.detail.get("count"), Some(&PublicValue::from_t(1i64)));
+		// Other fields are read by scanning the table
+		let plan =
+			ds.explain("SELECT * FROM person WHERE name = 'Tobie'", &ses, None).await.unwrap();
+		assert!(plan.indexes().is_empty());
+		assert_eq!(plan.iterators().next().unwrap().operation, "Iterate Table");
+		// Only SELECT statements can be explained
+		assert!(ds.explain("CREATE person:2", &ses, None).await.is_err());
+		assert!(
+			ds.explain("SELECT * FROM person; SELECT * FROM person", &ses, None).await.is_err()
+		);
+	}
 
 	#[tokio::test]
 	async fn test_setup_superuser() {
@@ -2550,6 +3309,477 @@ mod test {
 		}
 	}
 
+	#[tokio::test]
+	async fn import_csv_casts_fields_and_skips_malformed_rows() -> Result<()> {
+		let ds = Datastore::new("memory").await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		ds.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None).await?;
+		ds.execute(
+			"DEFINE TABLE person SCHEMAFULL;
+			DEFINE FIELD name ON person TYPE string;
+			DEFINE FIELD age ON person TYPE int;
+			DEFINE FIELD active ON person TYPE option<bool>;",
+			&ses,
+			None,
+		)
+		.await?;
+		let csv = "id,name,age,active\n\
+			tobie,\"Morgan, Tobie\",35,true\n\
+			jaime,Jaime,thirty,false\n\
+			\"broken\"row,x,1,true\n\
+			\n\
+			john,\"John \"\"JD\"\" Doe\",40,\n\
+			jane,Jane,28\n";
+		// Split the file into small chunks, so that rows span several chunks
+		let chunks: Vec<Result<Bytes>> =
+			csv.as_bytes().chunks(7).map(|c| Ok(Bytes::copy_from_slice(c))).collect();
+		let cfg = import::CsvConfig {
+			batch_size: 1,
+			on_error: import::CsvErrorPolicy::Skip,
+			..Default::default()
+		};
+		let res = ds.import_csv(futures::stream::iter(chunks), "person", cfg, &ses).await?;
+		assert_eq!(res.imported, 2);
+		assert_eq!(res.errors.iter().map(|e| e.row).collect::<Vec<_>>(), vec![3, 4, 6]);
+		let mut res = ds
+			.execute("SELECT VALUE [name, age, active] FROM person ORDER BY id", &ses, None)
+			.await?;
+		let val = res.remove(0).result?;
+		let exp = PublicValue::from_t(vec![
+			PublicValue::from_t(vec![
+				PublicValue::from_t("John \"JD\" Doe".to_string()),
+				PublicValue::from_t(40i64),
+				PublicValue::None,
+			]),
+			PublicValue::from_t(vec![
+				PublicValue::from_t("Morgan, Tobie".to_string()),
+				PublicValue::from_t(35i64),
+				PublicValue::from_t(true),
+			]),
+		]);
+		assert_eq!(val, exp);
+		// Malformed rows abort the import by default
+		let chunks: Vec<Result<Bytes>> = vec![Ok(Bytes::from_static(b"id,age\nbob,old\n"))];
+		let cfg = import::CsvConfig::default();
+		let err = ds.import_csv(futures::stream::iter(chunks), "person", cfg, &ses).await;
+		assert!(matches!(
+			err.unwrap_err().downcast_ref::<Error>(),
+			Some(Error::InvalidCsvRow {
+				row: 2,
+				..
+			})
+		));
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn import_csv_deduplicates_records() -> Result<()> {
+		let ds = Datastore::new("memory").await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		ds.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None).await?;
+		let csv = "id,name\n\
+			tobie,Tobie\n\
+			jaime,Jaime\n\
+			tobie,Tobie Morgan\n\
+			john,John\n\
+			jaime,Jaime Morgan\n";
+		let import = |table, policy| {
+			let chunks: Vec<Result<Bytes>> = vec![Ok(Bytes::from_static(csv.as_bytes()))];
+			let cfg = import::CsvConfig {
+				batch_size: 2,
+				dedup: Some(import::CsvDedupConfig {
+					policy,
+					..Default::default()
+				}),
+				..Default::default()
+			};
+			ds.import_csv(futures::stream::iter(chunks), table, cfg, &ses)
+		};
+		// Duplicates of earlier records are skipped
+		let res = import("person", import::CsvDuplicatePolicy::Skip).await?;
+		assert_eq!(res.imported, 3);
+		assert_eq!(res.duplicates, 2);
+		let mut res = ds.execute("SELECT VALUE name FROM person ORDER BY id", &ses, None).await?;
+		assert_eq!(res.remove(0).result?, syn::value("['Jaime', 'John', 'Tobie']")?);
+		// Duplicates of earlier records replace the earlier records
+		let res = import("account", import::CsvDuplicatePolicy::Overwrite).await?;
+		assert_eq!(res.duplicates, 2);
+		let mut res = ds.execute("SELECT VALUE name FROM account ORDER BY id", &ses, None).await?;
+		assert_eq!(res.remove(0).result?, syn::value("['Jaime Morgan', 'John', 'Tobie Morgan']")?);
+		// Re-running an import does not create duplicates of stored records
+		ds.execute("UPDATE person:john SET name = 'Johnny'", &ses, None).await?;
+		import("person", import::CsvDuplicatePolicy::Skip).await?;
+		let mut res = ds.execute("SELECT VALUE name FROM person ORDER BY id", &ses, None).await?;
+		assert_eq!(res.remove(0).result?, syn::value("['Jaime', 'Johnny', 'Tobie']")?);
+		// Duplicates are detected by the configured key columns
+		let chunks: Vec<Result<Bytes>> =
+			vec![Ok(Bytes::from_static(b"email,name\na@b.c,A\nd@e.f,D\na@b.c,B\n"))];
+		let cfg = import::CsvConfig {
+			dedup: Some(import::CsvDedupConfig {
+				key: vec!["email".to_string()],
+				..Default::default()
+			}),
+			..Default::default()
+		};
+		let res = ds.import_csv(futures::stream::iter(chunks), "contact", cfg, &ses).await?;
+		assert_eq!((res.imported, res.duplicates), (2, 1));
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn ttl_sweep_purges_expired_records() -> Result<()> {
+		let ds = Datastore::new("memory").await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		ds.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None).await?;
+		ds.execute(
+			"DEFINE TABLE session TTL 1h ON created_at;
+			CREATE session:one SET created_at = time::now();
+			CREATE session:two SET created_at = time::now() + 2h;
+			CREATE session:three;",
+			&ses,
+			None,
+		)
+		.await?;
+		// Nothing has expired yet
+		assert_eq!(ds.ttl_sweep_at(None, Utc::now(), 100).await?, 0);
+		// Advance the time past the TTL of the first record
+		let now = Utc::now() + chrono::Duration::minutes(90);
+		assert_eq!(ds.ttl_sweep_at(None, now, 100).await?, 1);
+		let mut res = ds.execute("SELECT VALUE id FROM session ORDER BY id", &ses, None).await?;
+		assert_eq!(res.remove(0).result?, syn::value("[session:three, session:two]")?);
+		// The sweep position is reset once the whole table has been swept
+		let txn = ds.transaction(Read, Optimistic).await?;
+		let db = txn.expect_db_by_name("test", "test").await?;
+		let tb = TableName::from("session");
+		let key = crate::key::table::tt::new(db.namespace_id, db.database_id, &tb);
+		assert!(txn.get(&key, None).await?.is_none());
+		txn.cancel().await?;
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn ttl_sweep_resumes_from_the_last_position() -> Result<()> {
+		let ds = Datastore::new("memory").await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		ds.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None).await?;
+		ds.execute(
+			"DEFINE TABLE session TTL 1h ON created_at;
+			FOR $i IN [0, 1, 2, 3, 4] { CREATE type::record('session', $i) SET created_at = time::now() };",
+			&ses,
+			None,
+		)
+		.await?;
+		let now = Utc::now() + chrono::Duration::minutes(90);
+		let txn = ds.transaction(Read, Optimistic).await?;
+		let db = txn.expect_db_by_name("test", "test").await?;
+		txn.cancel().await?;
+		let tb = TableName::from("session");
+		let key = crate::key::table::tt::new(db.namespace_id, db.database_id, &tb);
+		// Each sweep only checks a limited number of records
+		assert_eq!(ds.ttl_sweep_at(None, now, 2).await?, 2);
+		let txn = ds.transaction(Read, Optimistic).await?;
+		assert_eq!(txn.get(&key, None).await?, Some(RecordIdKey::Number(1)));
+		txn.cancel().await?;
+		assert_eq!(ds.ttl_sweep_at(None, now, 2).await?, 2);
+		assert_eq!(ds.ttl_sweep_at(None, now, 2).await?, 1);
+		// The sweep position is reset once the whole table has been swept
+		let txn = ds.transaction(Read, Optimistic).await?;
+		assert!(txn.get(&key, None).await?.is_none());
+		txn.cancel().await?;
+		let mut res = ds.execute("SELECT VALUE id FROM session", &ses, None).await?;
+		assert_eq!(res.remove(0).result?, syn::value("[]")?);
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn conflict_stats_count_conflicting_commits() -> Result<()> {
+		let ds = Datastore::new("memory").await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		ds.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None).await?;
+		ds.execute("CREATE test:one SET value = 0", &ses, None).await?.remove(0).result?;
+		let before = ds.conflict_stats();
+		// Both writers update the same record before either commits
+		let sql = "BEGIN; UPDATE test:one SET value += 1; SLEEP 50ms; COMMIT;";
+		let (one, two) = tokio::join!(ds.execute(sql, &ses, None), ds.execute(sql, &ses, None));
+		let failed =
+			[one?, two?].into_iter().filter(|res| res.iter().any(|r| r.result.is_err())).count();
+		assert_eq!(failed, 1);
+		let after = ds.conflict_stats();
+		assert_eq!(after.commits, before.commits + 1);
+		assert_eq!(after.conflicts, before.conflicts + 1);
+		assert_eq!(after.retries, before.retries);
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn transaction_retries_rerun_conflicting_blocks() -> Result<()> {
+		let ds = Datastore::new("memory").await?.with_transaction_retries(2);
+		let ses = Session::owner().with_ns("test").with_db("test");
+		ds.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None).await?;
+		ds.execute("CREATE test:one SET value = 0", &ses, None).await?.remove(0).result?;
+		let before = ds.conflict_stats();
+		// Both writers update the same record before either commits, so one
+		// conflicts and is run again once the other has committed
+		let sql = "BEGIN; UPDATE test:one SET value += 1; SLEEP 50ms; COMMIT;";
+		let (one, two) = tokio::join!(ds.execute(sql, &ses, None), ds.execute(sql, &ses, None));
+		for res in [one?, two?] {
+			assert_eq!(res.len(), 4);
+			assert!(res.iter().all(|r| r.result.is_ok()));
+		}
+		let after = ds.conflict_stats();
+		assert_eq!(after.commits, before.commits + 2);
+		assert_eq!(after.conflicts, before.conflicts + 1);
+		assert_eq!(after.retries, before.retries + 1);
+		let mut res = ds.execute("SELECT VALUE value FROM ONLY test:one", &ses, None).await?;
+		assert_eq!(res.remove(0).result?, syn::value("2")?);
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn metrics_prometheus_count_statements() -> Result<()> {
+		let ds = Datastore::new("memory").await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		ds.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None).await?;
+		ds.execute("CREATE test:one; CREATE test:one; SELECT * FROM test;", &ses, None).await?;
+		let metrics = ds.metrics_prometheus();
+		// Statements are counted by type
+		assert!(metrics.contains("# TYPE surrealdb_statements_total counter\n"));
+		assert!(metrics.contains("surrealdb_statements_total{type=\"create\"} 2\n"));
+		assert!(metrics.contains("surrealdb_statements_total{type=\"select\"} 1\n"));
+		assert!(metrics.contains("surrealdb_statement_errors_total{type=\"create\"} 1\n"));
+		assert!(!metrics.contains("surrealdb_statement_errors_total{type=\"select\"}"));
+		// The other counters are always included
+		for name in [
+			"surrealdb_transaction_commits_total",
+			"surrealdb_transaction_conflicts_total",
+			"surrealdb_transaction_retries_total",
+			"surrealdb_cache_hits_total",
+			"surrealdb_cache_misses_total",
+			"surrealdb_slow_queries_total",
+		] {
+			assert!(metrics.contains(&format!("# TYPE {name} counter\n")), "missing {name}");
+		}
+		// Open transactions are counted until they are dropped
+		assert!(metrics.contains("surrealdb_transactions_active 0\n"));
+		let txn = ds.transaction(Read, Optimistic).await?;
+		assert!(ds.metrics_prometheus().contains("surrealdb_transactions_active 1\n"));
+		txn.cancel().await?;
+		drop(txn);
+		assert!(ds.metrics_prometheus().contains("surrealdb_transactions_active 0\n"));
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn result_cache_invalidated_by_writes() -> Result<()> {
+		let ds = Datastore::builder().with_result_cache(100).build_with_path("memory").await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		ds.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None).await?;
+		ds.execute("CREATE person:one SET name = 'Tobie'", &ses, None).await?.remove(0).result?;
+		let cache = ds.result_cache().unwrap();
+		let sql = "SELECT * FROM person";
+		// The first run scans the table, and caches the result
+		let res = ds.execute(sql, &ses, None).await?.remove(0).result?;
+		assert_eq!(res, syn::value("[{ id: person:one, name: 'Tobie' }]")?);
+		assert_eq!(cache.lookups(), (0, 1));
+		// The next run returns the cached result without scanning the table
+		let res = ds.execute(sql, &ses, None).await?.remove(0).result?;
+		assert_eq!(res, syn::value("[{ id: person:one, name: 'Tobie' }]")?);
+		assert_eq!(cache.lookups(), (1, 1));
+		// Writes to other tables leave the result cached
+		ds.execute("CREATE post:one", &ses, None).await?.remove(0).result?;
+		ds.execute(sql, &ses, None).await?.remove(0).result?;
+		assert_eq!(cache.lookups(), (2, 1));
+		// Writes to the table invalidate the result
+		ds.execute("CREATE person:two SET name = 'Jaime'", &ses, None).await?.remove(0).result?;
+		let res = ds.execute(sql, &ses, None).await?.remove(0).result?;
+		let expected = "[{ id: person:one, name: 'Tobie' }, { id: person:two, name: 'Jaime' }]";
+		assert_eq!(res, syn::value(expected)?);
+		assert_eq!(cache.lookups(), (2, 2));
+		ds.execute(sql, &ses, None).await?.remove(0).result?;
+		assert_eq!(cache.lookups(), (3, 2));
+		// Writes in a cancelled transaction leave the result cached
+		ds.execute("BEGIN; CREATE person:three; CANCEL;", &ses, None).await?;
+		ds.execute(sql, &ses, None).await?.remove(0).result?;
+		assert_eq!(cache.lookups(), (4, 2));
+		// Schema changes invalidate every result
+		ds.execute("DEFINE FIELD age ON post", &ses, None).await?.remove(0).result?;
+		ds.execute(sql, &ses, None).await?.remove(0).result?;
+		assert_eq!(cache.lookups(), (4, 3));
+		// Statements which are not deterministic are not cached
+		ds.execute("SELECT *, rand() AS r FROM person", &ses, None).await?.remove(0).result?;
+		ds.execute("SELECT *, rand() AS r FROM person", &ses, None).await?.remove(0).result?;
+		assert_eq!(cache.lookups(), (4, 3));
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn result_cache_follows_links_and_options() -> Result<()> {
+		let ds = Datastore::builder().with_result_cache(100).build_with_path("memory").await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		ds.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None).await?;
+		ds.execute("CREATE person:one SET name = 'Tobie'", &ses, None).await?.remove(0).result?;
+		ds.execute("CREATE post:one SET author = person:one", &ses, None).await?.remove(0).result?;
+		let cache = ds.result_cache().unwrap();
+		let sql = "SELECT VALUE type::field('author.name') FROM post";
+		let res = ds.execute(sql, &ses, None).await?.remove(0).result?;
+		assert_eq!(res, syn::value("['Tobie']")?);
+		ds.execute(sql, &ses, None).await?.remove(0).result?;
+		assert_eq!(cache.lookups(), (1, 1));
+		// Writes to the linked table invalidate the result
+		ds.execute("UPDATE person:one SET name = 'Jaime'", &ses, None).await?.remove(0).result?;
+		let res = ds.execute(sql, &ses, None).await?.remove(0).result?;
+		assert_eq!(res, syn::value("['Jaime']")?);
+		assert_eq!(cache.lookups(), (1, 2));
+		// Results are not shared between different division by zero options
+		let res = ds
+			.execute("OPTION DIV_BY_ZERO = NONE; SELECT VALUE 1 / 0 FROM post;", &ses, None)
+			.await?
+			.remove(1)
+			.result?;
+		assert_eq!(res, syn::value("[NONE]")?);
+		assert_eq!(cache.lookups(), (1, 3));
+		let res = ds.execute("SELECT VALUE 1 / 0 FROM post", &ses, None).await?.remove(0).result;
+		assert!(res.is_err());
+		assert_eq!(cache.lookups(), (1, 4));
+		// Results are not shared between different null coercion policies
+		let sql = "SELECT * FROM person";
+		ds.execute(sql, &ses, None).await?.remove(0).result?;
+		let ses = ses.with_null_coercion(NullCoercion::Interchangeable);
+		ds.execute(sql, &ses, None).await?.remove(0).result?;
+		assert_eq!(cache.lookups(), (1, 6));
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn snapshot_reads_data_as_of_timestamp() -> Result<()> {
+		let ds = Datastore::new("memory").await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		ds.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None).await?;
+		ds.execute("CREATE test:one SET value = 1", &ses, None).await?.remove(0).result?;
+		let at = PublicDatetime::now();
+		ds.execute("UPDATE test:one SET value = 2", &ses, None).await?.remove(0).result?;
+		// Every statement in the snapshot sees the original value
+		let snapshot = ds.snapshot_at(at).await?;
+		let mut res =
+			snapshot.execute("SELECT * FROM test; SELECT * FROM test:one;", &ses, None).await?;
+		let expected = syn::value("[{ id: test:one, value: 1 }]")?;
+		assert_eq!(res.remove(0).result?, expected);
+		assert_eq!(res.remove(0).result?, expected);
+		// Writes are rejected by the read-only transaction
+		let mut res = snapshot.execute("UPDATE test:one SET value = 3", &ses, None).await?;
+		assert!(res.remove(0).result.is_err());
+		snapshot.cancel().await?;
+		// The datastore itself still sees the latest value
+		let mut res = ds.execute("SELECT * FROM test:one", &ses, None).await?;
+		assert_eq!(res.remove(0).result?, syn::value("[{ id: test:one, value: 2 }]")?);
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn snapshot_rejects_timestamps_outside_retention() -> Result<()> {
+		let ds = Datastore::new("memory?retention=1h").await?;
+		let at = PublicDatetime::from(Utc::now() - Duration::from_secs(2 * 60 * 60));
+		let err = ds.snapshot_at(at).await.err().expect("snapshot should be rejected");
+		assert!(err.to_string().contains("version retention period"), "{err}");
+		assert!(ds.snapshot_at(PublicDatetime::now()).await.is_ok());
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn statement_limit_rejects_large_queries() -> Result<()> {
+		let ds = Datastore::builder()
+			.with_capabilities(Capabilities::default().with_max_statements(Some(3)))
+			.build_with_path("memory")
+			.await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		// Queries at the limit are executed
+		let res = ds.execute("RETURN 1; RETURN 2; RETURN 3;", &ses, None).await?;
+		assert_eq!(res.len(), 3);
+		// Queries over the limit are rejected before execution
+		let err = ds
+			.execute("RETURN 1; RETURN 2; RETURN 3; RETURN 4;", &ses, None)
+			.await
+			.expect_err("query should be rejected");
+		assert_eq!(
+			err.message(),
+			"The query contains 4 statements, exceeding the maximum of 3 statements per query"
+		);
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn query_length_limit_rejects_large_queries() -> Result<()> {
+		let ds = Datastore::builder()
+			.with_capabilities(Capabilities::default().with_max_query_length(Some(16)))
+			.build_with_path("memory")
+			.await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		// Queries at the limit are executed
+		let res = ds.execute("RETURN 12345678;", &ses, None).await?;
+		assert_eq!(res.len(), 1);
+		// Queries over the limit are rejected before parsing
+		let err = ds
+			.execute("RETURN 123456789;", &ses, None)
+			.await
+			.expect_err("query should be rejected");
+		assert_eq!(
+			err.message(),
+			"The query is 17 bytes long, exceeding the maximum of 16 bytes per query"
+		);
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn query_length_limit_applies_to_snapshots() -> Result<()> {
+		let ds = Datastore::builder()
+			.with_capabilities(Capabilities::default().with_max_query_length(Some(16)))
+			.build_with_path("memory")
+			.await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let snapshot = ds.snapshot_at(PublicDatetime::now()).await?;
+		// Queries at the limit are executed
+		let res = snapshot.execute("RETURN 12345678;", &ses, None).await?;
+		assert_eq!(res.len(), 1);
+		// Queries over the limit are rejected before parsing
+		let err = snapshot
+			.execute("RETURN 123456789;", &ses, None)
+			.await
+			.expect_err("query should be rejected");
+		assert_eq!(
+			err.message(),
+			"The query is 17 bytes long, exceeding the maximum of 16 bytes per query"
+		);
+		snapshot.cancel().await?;
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn execute_stream_responses_yields_results_as_they_complete() -> Result<()> {
+		let ds = Datastore::new("memory").await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		ds.execute("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test;", &ses, None).await?;
+		ds.execute("DEFINE TABLE t", &ses, None).await?.remove(0).result?;
+		let start = web_time::Instant::now();
+		let mut stream = pin!(ds.execute_stream_responses("SLEEP 300ms; CREATE t:1;", &ses, None));
+		// The first result is yielded before the next statement runs
+		let first = stream.next().await.expect("a first result")?;
+		assert!(first.result.is_ok());
+		assert!(start.elapsed() >= Duration::from_millis(300));
+		let mut res = ds.execute("SELECT * FROM t:1", &ses, None).await?;
+		assert_eq!(res.remove(0).result?, PublicValue::Array(Default::default()));
+		// The remaining results follow once complete
+		let rest = stream.collect::<Vec<_>>().await;
+		assert_eq!(rest.len(), 1);
+		let mut res = ds.execute("SELECT * FROM t:1", &ses, None).await?;
+		assert_ne!(res.remove(0).result?, PublicValue::Array(Default::default()));
+		// Queries which fail to parse yield a single error
+		let res =
+			ds.execute_stream_responses("SELEC * FROM t", &ses, None).collect::<Vec<_>>().await;
+		assert_eq!(res.len(), 1);
+		assert!(res[0].is_err());
+		Ok(())
+	}
+
 	#[tokio::test]
 	pub async fn very_deep_query() -> Result<()> {
 		use reblessive::{Stack, Stk};
@@ -22,10 +22,13 @@ use crate::http::HttpClient;
 #[cfg(feature = "jwks")]
 use crate::iam::jwks::JwksCache;
 use crate::idx::trees::store::IndexStores;
+use crate::kvs::audit::AuditEvent;
 use crate::kvs::cache::ds::DatastoreCache;
 use crate::kvs::index::IndexBuilder;
+use crate::kvs::result_cache::ResultCache;
 use crate::kvs::sequences::Sequences;
-use crate::kvs::slowlog::SlowLog;
+use crate::kvs::slowlog::{SlowLog, SlowQueryHook};
+use crate::kvs::stats::{ConflictCounters, QueryCounters};
 use crate::kvs::{Datastore, TransactionBuilder, TransactionBuilderFactory, TransactionFactory};
 #[cfg(feature = "surrealism")]
 use crate::surrealism::cache::SurrealismCache;
@@ -36,9 +39,14 @@ pub struct Builder {
 	capabilities: Capabilities,
 	shutdown: CancellationToken,
 	notify_channel: Option<Sender<PublicNotification>>,
+	audit_sink: Option<Sender<AuditEvent>>,
+	result_cache: Option<usize>,
 	id: Option<Uuid>,
 	slow_log: Option<SlowLog>,
+	slow_log_hook: Option<SlowQueryHook>,
 	transaction_timeout: Option<Duration>,
+	transaction_retries: u32,
+	lock_timeout: Option<Duration>,
 	query_timeout: Option<Duration>,
 	temporary_directory: Option<Arc<PathBuf>>,
 	authenticate: bool,
@@ -58,9 +66,14 @@ impl Builder {
 			capabilities: Default::default(),
 			shutdown: CancellationToken::new(),
 			notify_channel: None,
+			audit_sink: None,
+			result_cache: None,
 			id: None,
 			slow_log: None,
+			slow_log_hook: None,
 			transaction_timeout: None,
+			transaction_retries: 0,
+			lock_timeout: None,
 			query_timeout: None,
 			temporary_directory: None,
 			authenticate: false,
@@ -86,12 +99,51 @@ impl Builder {
 		self
 	}
 
+	/// Adds a channel for receiving an audit event for every committed write
+	///
+	/// Events are sent without waiting, and are dropped when the channel is
+	/// full or closed, so that the sink never holds up a commit.
+	pub fn with_audit(mut self, channel: Sender<AuditEvent>) -> Self {
+		self.audit_sink = Some(channel);
+		self
+	}
+
+	/// Caches the results of up to `capacity` read-only statements
+	///
+	/// A cached result is returned when the same deterministic `SELECT`
+	/// statement is run again by the same session with the same parameters,
+	/// as long as none of the tables which it reads have been written to.
+	///
+	/// The cache only sees the writes made through this datastore, so it is
+	/// disabled for storage engines which are shared between several nodes.
+	pub fn with_result_cache(mut self, capacity: usize) -> Self {
+		self.result_cache = Some(capacity);
+		self
+	}
+
 	/// Sets the transaction timeout for this datastore
 	pub fn with_transaction_timeout(mut self, timeout: Option<Duration>) -> Self {
 		self.transaction_timeout = timeout;
 		self
 	}
 
+	/// Sets the number of times a transaction is run again when its commit
+	/// fails due to a conflict with a concurrent transaction
+	///
+	/// Side effects outside of the transaction, such as requests made with the
+	/// `http::*` functions, are not rolled back and happen again on each retry.
+	pub fn with_transaction_retries(mut self, retries: u32) -> Self {
+		self.transaction_retries = retries;
+		self
+	}
+
+	/// Sets the maximum duration a pessimistic transaction waits to acquire a
+	/// lock which is held by another transaction
+	pub fn with_lock_timeout(mut self, timeout: Option<Duration>) -> Self {
+		self.lock_timeout = timeout;
+		self
+	}
+
 	/// Sets the transaction timeout for this datastore
 	pub fn with_query_timeout(mut self, timeout: Option<Duration>) -> Self {
 		self.query_timeout = timeout;
@@ -129,6 +181,19 @@ impl Builder {
 		self
 	}
 
+	/// Set a callback which is invoked for each slow statement
+	///
+	/// The callback receives the time which the statement took and the text
+	/// of the statement, and is called instead of logging the statement. It
+	/// only takes effect when a slow log is configured with `with_slow_log`.
+	pub fn with_slow_log_hook(
+		mut self,
+		hook: impl Fn(Duration, &str) + Send + Sync + 'static,
+	) -> Self {
+		self.slow_log_hook = Some(Arc::new(hook));
+		self
+	}
+
 	pub fn with_temporary_directory<P: AsRef<Path>>(mut self, directory: Option<P>) -> Self {
 		self.temporary_directory = directory.map(|x| Arc::new(x.as_ref().to_path_buf()));
 		self
@@ -159,6 +224,20 @@ impl Builder {
 		builder: Box<dyn TransactionBuilder>,
 		buckets: BucketsManager,
 	) -> Result<Datastore> {
+		// The result cache is only invalidated by the writes of this node
+		let result_cache = match self.result_cache {
+			Some(capacity) => {
+				let (tx, local) = builder.new_transaction(false, false).await?;
+				tx.cancel().await?;
+				if local {
+					Some(Arc::new(ResultCache::new(capacity)))
+				} else {
+					warn!("The result cache is not supported by distributed storage engines");
+					None
+				}
+			}
+			None => None,
+		};
 		let async_event_trigger = Arc::new(Notify::new());
 		let tf = TransactionFactory::new(async_event_trigger.clone(), builder);
 		let id = self.id.unwrap_or_else(Uuid::new_v4);
@@ -176,9 +255,16 @@ impl Builder {
 			transaction_factory: tf.clone(),
 			auth_enabled: self.authenticate,
 			dynamic_configuration,
-			slow_log: self.slow_log,
+			slow_log: match self.slow_log_hook {
+				Some(hook) => self.slow_log.map(|s| s.with_hook(hook)),
+				None => self.slow_log,
+			},
 			transaction_timeout: self.transaction_timeout,
+			transaction_retries: self.transaction_retries,
+			lock_timeout: self.lock_timeout,
 			notification_channel: self.notify_channel,
+			audit_sink: self.audit_sink,
+			result_cache,
 			capabilities,
 			index_stores: IndexStores::default(),
 			index_builder: IndexBuilder::new(tf.clone()),
@@ -196,6 +282,8 @@ impl Builder {
 			lazy_surrealism: self.lazy_surrealism,
 			#[cfg(feature = "http")]
 			http_client,
+			conflict_counters: Arc::new(ConflictCounters::default()),
+			query_counters: Arc::new(QueryCounters::default()),
 		})
 	}
 }
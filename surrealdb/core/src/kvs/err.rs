@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use thiserror::Error;
 
 /// Result type for KVS (Key-Value Store) layer operations
@@ -56,6 +58,11 @@ pub enum Error {
 	#[error("The key being inserted already exists")]
 	TransactionKeyAlreadyExists,
 
+	/// A pessimistic transaction waited too long for a lock held by another
+	/// transaction
+	#[error("The transaction timed out after {0:?} waiting to acquire a lock")]
+	LockTimeout(Duration),
+
 	/// The underlying datastore does not support versioned queries
 	#[error("The underlying datastore does not support versioned queries")]
 	UnsupportedVersionedQueries,
@@ -0,0 +1,457 @@
+use std::collections::{HashMap, HashSet};
+use std::mem;
+
+use surrealdb_types::ToSql;
+
+use crate::catalog::FieldDefinition;
+use crate::cnf::IMPORT_BATCH_SIZE;
+use crate::expr::Kind;
+use crate::fmt::EscapeIdent;
+use crate::val::{Object, Value};
+
+/// How rows which can not be parsed or coerced are handled during a CSV import
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CsvErrorPolicy {
+	/// Stop the import and return the error
+	#[default]
+	Abort,
+	/// Skip the row and record the error in the import result
+	Skip,
+}
+
+/// How a record with the same key as an earlier record is handled during a
+/// CSV import
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CsvDuplicatePolicy {
+	/// Keep the earlier record, and skip the duplicate
+	#[default]
+	Skip,
+	/// Replace the fields of the earlier record with those of the duplicate
+	Overwrite,
+}
+
+/// The deduplication of records during a CSV import
+///
+/// Duplicates are detected against the records seen earlier in the import,
+/// and against the records already stored in the table, so re-running an
+/// import which was interrupted part way through does not create duplicates.
+/// Records already stored are matched by their id, or through a unique index
+/// on the key columns.
+#[derive(Clone, Debug)]
+pub struct CsvDedupConfig {
+	/// The columns which identify a record. When empty, records are
+	/// identified by the `id` column.
+	pub key: Vec<String>,
+	/// How a duplicate record is handled
+	pub policy: CsvDuplicatePolicy,
+	/// The maximum number of keys remembered from earlier batches. Once
+	/// reached, the remembered keys are forgotten, and duplicates of records
+	/// from earlier batches are only detected by the datastore.
+	pub max_tracked: usize,
+}
+
+impl Default for CsvDedupConfig {
+	fn default() -> CsvDedupConfig {
+		CsvDedupConfig {
+			key: Vec::new(),
+			policy: CsvDuplicatePolicy::default(),
+			max_tracked: 1_000_000,
+		}
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct CsvConfig {
+	/// The byte which separates fields within a row
+	pub delimiter: u8,
+	/// The byte which is used to quote fields
+	pub quote: u8,
+	/// Whether the first row of the file is a header row
+	pub header: bool,
+	/// The field names to use for each column. When not specified the
+	/// header row is used, and when specified any header row is skipped.
+	pub columns: Option<Vec<String>>,
+	/// The maximum number of records inserted in a single batch
+	pub batch_size: usize,
+	/// How rows which can not be parsed or coerced are handled
+	pub on_error: CsvErrorPolicy,
+	/// Whether duplicate records are detected, and how they are handled
+	pub dedup: Option<CsvDedupConfig>,
+}
+
+impl Default for CsvConfig {
+	fn default() -> CsvConfig {
+		CsvConfig {
+			delimiter: b',',
+			quote: b'"',
+			header: true,
+			columns: None,
+			batch_size: *IMPORT_BATCH_SIZE as usize,
+			on_error: CsvErrorPolicy::default(),
+			dedup: None,
+		}
+	}
+}
+
+/// A row which was skipped during a CSV import
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CsvRowError {
+	/// The 1-based position of the row in the file, including any header row
+	pub row: usize,
+	/// The reason the row was skipped
+	pub message: String,
+}
+
+/// The outcome of a CSV import
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CsvImportResult {
+	/// The number of records which were inserted. When deduplicating, this
+	/// includes records which matched a record already stored in the table.
+	pub imported: usize,
+	/// The rows which were skipped, when using [`CsvErrorPolicy::Skip`]
+	pub errors: Vec<CsvRowError>,
+	/// The number of rows which duplicated an earlier row in the file
+	pub duplicates: usize,
+}
+
+/// The outcome of parsing a single row from the start of a buffer
+#[derive(Debug, PartialEq)]
+pub(crate) enum CsvParsed {
+	/// More data is needed before the row can be parsed
+	Incomplete,
+	/// A row was parsed, consuming the given number of bytes
+	Row(Vec<String>, usize),
+	/// A malformed row was found, consuming the given number of bytes
+	Malformed(String, usize),
+}
+
+/// Parses a single row from the start of the buffer.
+///
+/// When `complete` is false, and the row may continue past the end of the
+/// buffer, [`CsvParsed::Incomplete`] is returned so that more data can be read.
+/// Empty lines are returned as a row with no fields.
+pub(crate) fn parse_row(buf: &[u8], delimiter: u8, quote: u8, complete: bool) -> CsvParsed {
+	let mut fields = Vec::new();
+	let mut field = Vec::new();
+	// Whether we are within a quoted field
+	let mut quoted = false;
+	// Whether the current field was a closed quoted field
+	let mut closed = false;
+	let mut i = 0;
+	while i < buf.len() {
+		let b = buf[i];
+		if quoted {
+			if b == quote {
+				match buf.get(i + 1) {
+					// An escaped quote within a quoted field
+					Some(&n) if n == quote => {
+						field.push(quote);
+						i += 1;
+					}
+					// The quote may be followed by another quote
+					None if !complete => return CsvParsed::Incomplete,
+					_ => {
+						quoted = false;
+						closed = true;
+					}
+				}
+			} else {
+				field.push(b);
+			}
+		} else if b == delimiter {
+			fields.push(mem::take(&mut field));
+			closed = false;
+		} else if b == b'\n' || b == b'\r' {
+			let mut end = i + 1;
+			if b == b'\r' {
+				match buf.get(end) {
+					Some(b'\n') => end += 1,
+					None if !complete => return CsvParsed::Incomplete,
+					_ => {}
+				}
+			}
+			// Empty lines contain no fields at all
+			if !fields.is_empty() || !field.is_empty() || closed {
+				fields.push(field);
+			}
+			return finish_row(fields, end);
+		} else if closed {
+			return match buf[i..].iter().position(|&b| b == b'\n') {
+				Some(pos) => CsvParsed::Malformed(
+					"unexpected character after a closing quote".to_string(),
+					i + pos + 1,
+				),
+				None if !complete => CsvParsed::Incomplete,
+				None => CsvParsed::Malformed(
+					"unexpected character after a closing quote".to_string(),
+					buf.len(),
+				),
+			};
+		} else if b == quote && field.is_empty() {
+			quoted = true;
+		} else {
+			field.push(b);
+		}
+		i += 1;
+	}
+	// The row may continue in the next chunk
+	if !complete {
+		return CsvParsed::Incomplete;
+	}
+	if quoted {
+		return CsvParsed::Malformed("unterminated quoted field".to_string(), buf.len());
+	}
+	if !fields.is_empty() || !field.is_empty() || closed {
+		fields.push(field);
+	}
+	finish_row(fields, buf.len())
+}
+
+fn finish_row(fields: Vec<Vec<u8>>, end: usize) -> CsvParsed {
+	match fields.into_iter().map(String::from_utf8).collect() {
+		Ok(fields) => CsvParsed::Row(fields, end),
+		Err(_) => CsvParsed::Malformed("invalid UTF-8 in row".to_string(), end),
+	}
+}
+
+/// Converts parsed CSV rows into records, and collects them into batches
+pub(crate) struct CsvImporter {
+	cfg: CsvConfig,
+	/// The field names of each column, once known
+	columns: Option<Vec<String>>,
+	/// The defined kinds of the fields in the table
+	kinds: HashMap<String, Kind>,
+	/// The number of rows processed so far
+	row: usize,
+	/// The records waiting to be inserted
+	batch: Vec<Value>,
+	/// The keys of the records from earlier batches, when deduplicating
+	seen: HashSet<Vec<String>>,
+	/// The keys of the records waiting to be inserted, and their position in
+	/// the batch, when deduplicating
+	pending: HashMap<Vec<String>, usize>,
+	/// The outcome of the import so far
+	pub(crate) result: CsvImportResult,
+}
+
+impl CsvImporter {
+	pub(crate) fn new(cfg: CsvConfig, fields: &[FieldDefinition]) -> Self {
+		let kinds = fields
+			.iter()
+			.filter_map(|fd| Some((fd.name.to_raw_string(), fd.field_kind.clone()?)))
+			.collect();
+		Self {
+			columns: cfg.columns.clone(),
+			cfg,
+			kinds,
+			row: 0,
+			batch: Vec::new(),
+			seen: HashSet::new(),
+			pending: HashMap::new(),
+			result: CsvImportResult::default(),
+		}
+	}
+
+	pub(crate) fn delimiter(&self) -> u8 {
+		self.cfg.delimiter
+	}
+
+	pub(crate) fn quote(&self) -> u8 {
+		self.cfg.quote
+	}
+
+	/// Returns the statement which inserts each batch of records into
+	/// `$table` from `$data`
+	pub(crate) fn insert_statement(&self) -> String {
+		let Some(dedup) = &self.cfg.dedup else {
+			return "INSERT INTO $table $data RETURN NONE".to_string();
+		};
+		// Set every column but the id when a record already exists
+		let columns = self.columns.iter().flatten().filter(|c| c.as_str() != "id");
+		let updates = columns
+			.map(|c| {
+				let c = EscapeIdent(c).to_sql();
+				format!("{c} = $input.{c}")
+			})
+			.collect::<Vec<_>>();
+		match dedup.policy {
+			CsvDuplicatePolicy::Overwrite if !updates.is_empty() => format!(
+				"INSERT INTO $table $data ON DUPLICATE KEY UPDATE {} RETURN NONE",
+				updates.join(", ")
+			),
+			_ => "INSERT IGNORE INTO $table $data RETURN NONE".to_string(),
+		}
+	}
+
+	/// Processes the outcome of parsing a single row
+	pub(crate) fn process(&mut self, parsed: CsvParsed) -> Result<(), crate::err::Error> {
+		let res = match parsed {
+			CsvParsed::Incomplete => return Ok(()),
+			// Skip over any empty lines
+			CsvParsed::Row(fields, _) if fields.is_empty() => return Ok(()),
+			CsvParsed::Row(fields, _) => {
+				self.row += 1;
+				self.process_row(fields)
+			}
+			CsvParsed::Malformed(message, _) => {
+				self.row += 1;
+				Err(message)
+			}
+		};
+		match res {
+			Ok(Some((record, key))) => self.push(record, key),
+			Ok(None) => {}
+			Err(message) => match self.cfg.on_error {
+				CsvErrorPolicy::Abort => {
+					return Err(crate::err::Error::InvalidCsvRow {
+						row: self.row,
+						message,
+					});
+				}
+				CsvErrorPolicy::Skip => self.result.errors.push(CsvRowError {
+					row: self.row,
+					message,
+				}),
+			},
+		}
+		Ok(())
+	}
+
+	/// Adds a record to the batch, handling any duplicate of an earlier record
+	fn push(&mut self, record: Value, key: Option<Vec<String>>) {
+		let (Some(dedup), Some(key)) = (&self.cfg.dedup, key) else {
+			self.batch.push(record);
+			return;
+		};
+		if let Some(&pos) = self.pending.get(&key) {
+			// The earlier record has not been inserted yet
+			self.result.duplicates += 1;
+			if dedup.policy == CsvDuplicatePolicy::Overwrite {
+				self.batch[pos] = record;
+			}
+			return;
+		}
+		if self.seen.contains(&key) {
+			self.result.duplicates += 1;
+			if dedup.policy == CsvDuplicatePolicy::Skip {
+				return;
+			}
+		}
+		self.pending.insert(key, self.batch.len());
+		self.batch.push(record);
+	}
+
+	/// Returns the values of the key columns, when deduplicating and the row
+	/// has a value for each of them
+	fn key(&self, columns: &[String], fields: &[String]) -> Option<Vec<String>> {
+		let dedup = self.cfg.dedup.as_ref()?;
+		let id = ["id".to_string()];
+		let key = if dedup.key.is_empty() {
+			&id[..]
+		} else {
+			&dedup.key[..]
+		};
+		key.iter()
+			.map(|k| {
+				let pos = columns.iter().position(|c| c == k)?;
+				Some(fields[pos].clone()).filter(|v| !v.is_empty())
+			})
+			.collect()
+	}
+
+	/// Remembers the keys of the batch which is about to be inserted
+	fn flush_keys(&mut self) {
+		let Some(dedup) = &self.cfg.dedup else {
+			return;
+		};
+		// The datastore detects duplicates of records it already stores
+		if self.seen.len() + self.pending.len() > dedup.max_tracked {
+			self.seen.clear();
+		}
+		self.seen.extend(self.pending.drain().map(|(k, _)| k));
+	}
+
+	fn process_row(
+		&mut self,
+		fields: Vec<String>,
+	) -> Result<Option<(Value, Option<Vec<String>>)>, String> {
+		// The first row is a header row
+		if self.row == 1 && self.cfg.header {
+			if self.columns.is_none() {
+				self.columns = Some(fields);
+			}
+			return Ok(None);
+		}
+		let Some(columns) = &self.columns else {
+			return Err("no header row or column names were specified".to_string());
+		};
+		if fields.len() != columns.len() {
+			return Err(format!("expected {} fields but found {}", columns.len(), fields.len()));
+		}
+		let key = self.key(columns, &fields);
+		let mut record = Object::default();
+		for (column, cell) in columns.iter().zip(fields) {
+			let value = match self.kinds.get(column) {
+				// Leave empty optional fields unset
+				Some(kind) if cell.is_empty() && kind.can_be_none() => continue,
+				Some(kind) => Value::from(cell)
+					.cast_to_kind(kind)
+					.map_err(|e| format!("invalid value for field `{column}`: {e}"))?,
+				None => Value::from(cell),
+			};
+			record.insert(column.clone(), value);
+		}
+		Ok(Some((Value::Object(record), key)))
+	}
+
+	/// Returns the pending records once a full batch has been collected
+	pub(crate) fn full_batch(&mut self) -> Option<Vec<Value>> {
+		if self.batch.len() >= self.cfg.batch_size.max(1) {
+			self.flush_keys();
+			Some(mem::take(&mut self.batch))
+		} else {
+			None
+		}
+	}
+
+	/// Returns any remaining records at the end of the import
+	pub(crate) fn last_batch(&mut self) -> Option<Vec<Value>> {
+		if self.batch.is_empty() {
+			None
+		} else {
+			self.flush_keys();
+			Some(mem::take(&mut self.batch))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn row(fields: &[&str], len: usize) -> CsvParsed {
+		CsvParsed::Row(fields.iter().map(|x| x.to_string()).collect(), len)
+	}
+
+	#[test]
+	fn parse_csv_rows() {
+		assert_eq!(parse_row(b"a,b,c\nd", b',', b'"', false), row(&["a", "b", "c"], 6));
+		assert_eq!(
+			parse_row(b"a;\"b;\"\"c\"\"\"\r\n", b';', b'"', true),
+			row(&["a", "b;\"c\""], 13)
+		);
+		assert_eq!(
+			parse_row(b"\"multi\nline\",x", b',', b'"', true),
+			row(&["multi\nline", "x"], 14)
+		);
+		assert_eq!(parse_row(b"\n", b',', b'"', true), row(&[], 1));
+		assert_eq!(parse_row(b",", b',', b'"', true), row(&["", ""], 1));
+		// Rows which may continue in the next chunk
+		assert_eq!(parse_row(b"a,b", b',', b'"', false), CsvParsed::Incomplete);
+		assert_eq!(parse_row(b"a,\"b\nc", b',', b'"', false), CsvParsed::Incomplete);
+		assert_eq!(parse_row(b"a,\"b\"", b',', b'"', false), CsvParsed::Incomplete);
+		// Malformed rows consume up to the end of the line
+		assert!(matches!(parse_row(b"\"a\"b,c\nd", b',', b'"', true), CsvParsed::Malformed(_, 7)));
+		assert!(matches!(parse_row(b"a,\"b", b',', b'"', true), CsvParsed::Malformed(_, 4)));
+	}
+}
@@ -4,7 +4,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
-use anyhow::{Result, ensure};
+use anyhow::{Result, bail, ensure};
 use futures::channel::oneshot::{Receiver, Sender, channel};
 use reblessive::TreeStack;
 use revision::revisioned;
@@ -14,13 +14,15 @@ use serde::{Deserialize, Serialize};
 use tokio::spawn;
 use tokio::sync::{Mutex, RwLock};
 use tokio::time::sleep;
+use uuid::Uuid;
 #[cfg(target_family = "wasm")]
 use wasm_bindgen_futures::spawn_local as spawn;
 use web_time::Instant;
 
 use crate::catalog::providers::TableProvider;
 use crate::catalog::{
-	DatabaseDefinition, DatabaseId, IndexDefinition, IndexId, NamespaceId, Record, TableId,
+	DatabaseDefinition, DatabaseId, IndexDefinition, IndexId, NamespaceId, Record, TableDefinition,
+	TableId,
 };
 use crate::cnf::INDEXING_BATCH_SIZE;
 use crate::ctx::{Context, FrozenContext};
@@ -33,6 +35,7 @@ use crate::key::index::ig::IndexAppending;
 use crate::key::record;
 use crate::kvs::LockType::Optimistic;
 use crate::kvs::ds::TransactionFactory;
+use crate::kvs::stats::is_conflict;
 use crate::kvs::{KVValue, Key, Transaction, TransactionType, Val, impl_kv_value_revisioned};
 use crate::mem::ALLOC;
 use crate::val::{Object, RecordId, RecordIdKey, TableName, Value};
@@ -81,16 +84,6 @@ impl BuildingStatus {
 	}
 }
 
-fn is_retryable_transaction_conflict(err: &anyhow::Error) -> bool {
-	if let Some(kvs_err) = err.downcast_ref::<crate::kvs::Error>() {
-		return kvs_err.is_retryable();
-	}
-	matches!(
-		err.downcast_ref::<Error>(),
-		Some(Error::Kvs(kvs_err)) if kvs_err.is_retryable()
-	)
-}
-
 impl From<BuildingStatus> for Value {
 	fn from(st: BuildingStatus) -> Self {
 		let mut o = Object::default();
@@ -185,9 +178,11 @@ impl IndexBuilder {
 		tb: TableId,
 		ix: Arc<IndexDefinition>,
 		ix_key: SharedIndexKey,
+		replaces: Option<Arc<IndexDefinition>>,
 		sdr: Option<Sender<Result<()>>>,
 	) -> Result<IndexBuilding> {
-		let building = Arc::new(Building::new(ctx, self.tf.clone(), opt, tb, ix, ix_key)?);
+		let building =
+			Arc::new(Building::new(ctx, self.tf.clone(), opt, tb, ix, ix_key, replaces)?);
 		let b = building.clone();
 		spawn(async move {
 			let guard = BuildingFinishGuard(b.clone());
@@ -195,6 +190,14 @@ impl IndexBuilder {
 			if let Err(err) = &r {
 				b.set_status(BuildingStatus::Error(err.to_string())).await;
 			}
+			// Discard an online rebuild which failed. An aborted rebuild is
+			// cleaned up by the statement which removed or redefined the index.
+			if b.replaces.is_some()
+				&& r.is_err()
+				&& let Err(err) = b.discard().await
+			{
+				warn!("Failed to discard the rebuild of index {}: {err}", b.ix.name);
+			}
 			drop(guard);
 			if let Some(s) = sdr
 				&& s.send(r).is_err()
@@ -215,6 +218,100 @@ impl IndexBuilder {
 	) -> Result<Option<Receiver<Result<()>>>> {
 		ix.expect_not_prepare_remove()?;
 		let (ns, db) = ctx.expect_ns_db_ids(&opt).await?;
+		self.start(ctx, opt, ns, db, tb, ix, None, blocking).await
+	}
+
+	/// Rebuilds an index online.
+	///
+	/// The replacement index is built under a new index id, while queries
+	/// are still served from the existing index, and document changes are
+	/// applied to both. The rebuild is recorded in the index definition, so
+	/// that document changes made on any node are applied to both indexes.
+	/// Once the replacement index is ready, the index definition is switched
+	/// over to it, and the previous index data is removed. If the rebuild
+	/// fails, the replacement index data is removed instead.
+	pub(crate) async fn rebuild(
+		&self,
+		ctx: &FrozenContext,
+		opt: Options,
+		tb: TableId,
+		ix: Arc<IndexDefinition>,
+		new_ix: Arc<IndexDefinition>,
+		blocking: bool,
+	) -> Result<Option<Receiver<Result<()>>>> {
+		ix.expect_not_prepare_remove()?;
+		let (ns, db) = ctx.expect_ns_db_ids(&opt).await?;
+		{
+			// Only one build of an index can run at a time
+			let indexes = self.indexes.read().await;
+			let running = |id: IndexId| {
+				indexes
+					.get(&IndexKey::new(ns, db, &ix.table_name, id))
+					.is_some_and(|b| !b.is_finished())
+			};
+			ensure!(
+				!running(ix.index_id) && !ix.rebuilding.is_some_and(running),
+				Error::IndexAlreadyBuilding {
+					name: ix.name.clone(),
+				}
+			);
+		}
+		// Document changes are now also applied to the replacement index
+		let tx = self
+			.tf
+			.transaction(TransactionType::Write, Optimistic, ctx.try_get_sequences()?.clone())
+			.await?;
+		catch!(tx, Self::mark_rebuilding_with_tx(&tx, &opt, ns, db, &ix, new_ix.index_id).await);
+		catch!(tx, tx.commit().await);
+		if let Some(cache) = ctx.get_cache() {
+			cache.clear_tb(ns, db, &ix.table_name);
+		}
+		self.start(ctx, opt, ns, db, tb, new_ix, Some(ix), blocking).await
+	}
+
+	/// Records in the index definition that the index is being rebuilt under
+	/// the given index id.
+	async fn mark_rebuilding_with_tx(
+		tx: &Transaction,
+		opt: &Options,
+		ns: NamespaceId,
+		db: DatabaseId,
+		ix: &IndexDefinition,
+		index_id: IndexId,
+	) -> Result<()> {
+		let tb = &ix.table_name;
+		// Check the index has not been removed or redefined in the meantime.
+		let current = tx.get_tb_index(ns, db, tb, &ix.name, None).await?;
+		let Some(current) = current.filter(|x| x.index_id == ix.index_id) else {
+			bail!(Error::IxNotFound {
+				name: ix.name.clone(),
+			});
+		};
+		current.expect_not_prepare_remove()?;
+		// Remove the data of any previous rebuild which did not complete
+		if let Some(stale) = current.rebuilding {
+			tx.delp(&crate::key::index::all::new(ns, db, tb, stale)).await?;
+		}
+		let ix = IndexDefinition {
+			rebuilding: Some(index_id),
+			..current.as_ref().clone()
+		};
+		put_index_with_tx(tx, opt, ns, db, &ix).await?;
+		Ok(())
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	async fn start(
+		&self,
+		ctx: &FrozenContext,
+		opt: Options,
+		ns: NamespaceId,
+		db: DatabaseId,
+		tb: TableId,
+		ix: Arc<IndexDefinition>,
+		replaces: Option<Arc<IndexDefinition>>,
+		blocking: bool,
+	) -> Result<Option<Receiver<Result<()>>>> {
 		let key = Arc::new(IndexKey::new(ns, db, &ix.table_name, ix.index_id));
 		let (rcv, sdr) = if blocking {
 			let (s, r) = channel();
@@ -231,12 +328,12 @@ impl IndexBuilder {
 						name: ix.name.clone(),
 					}
 				);
-				let ib = self.start_building(ctx, opt, tb, ix, key, sdr)?;
+				let ib = self.start_building(ctx, opt, tb, ix, key, replaces, sdr)?;
 				e.insert(ib);
 			}
 			Entry::Vacant(e) => {
 				// No index is currently building, we can start building it
-				let ib = self.start_building(ctx, opt, tb, ix, key, sdr)?;
+				let ib = self.start_building(ctx, opt, tb, ix, key, replaces, sdr)?;
 				e.insert(ib);
 			}
 		};
@@ -265,7 +362,9 @@ impl IndexBuilder {
 		db: DatabaseId,
 		ix: &IndexDefinition,
 	) -> BuildingStatus {
-		let key = IndexKey::new(ns, db, &ix.table_name, ix.index_id);
+		// During an online rebuild, report the progress of the replacement index
+		let id = ix.rebuilding.unwrap_or(ix.index_id);
+		let key = IndexKey::new(ns, db, &ix.table_name, id);
 		if let Some(b) = self.indexes.read().await.get(&key) {
 			b.status.read().await.clone()
 		} else {
@@ -278,16 +377,44 @@ impl IndexBuilder {
 		ns: NamespaceId,
 		db: DatabaseId,
 		tb: &TableName,
-		ix: IndexId,
+		ix: &IndexDefinition,
 	) -> Result<()> {
-		let key = IndexKey::new(ns, db, tb, ix);
-		if let Some(b) = self.indexes.write().await.remove(&key) {
-			b.abort();
+		let mut indexes = self.indexes.write().await;
+		// Abort the build of the index, and of any online rebuild of it
+		for id in std::iter::once(ix.index_id).chain(ix.rebuilding) {
+			if let Some(b) = indexes.remove(&IndexKey::new(ns, db, tb, id)) {
+				b.abort();
+			}
 		}
 		Ok(())
 	}
 }
 
+/// Stores an index definition, and refreshes the table cache for indexes.
+async fn put_index_with_tx(
+	tx: &Transaction,
+	opt: &Options,
+	ns: NamespaceId,
+	db: DatabaseId,
+	ix: &IndexDefinition,
+) -> Result<Arc<TableDefinition>> {
+	tx.put_tb_index(ns, db, &ix.table_name, ix).await?;
+	let tb = tx.expect_tb(ns, db, &ix.table_name).await?;
+	let (ns, db) = opt.ns_db()?;
+	let tb = tx
+		.put_tb(
+			ns,
+			db,
+			&TableDefinition {
+				cache_indexes_ts: Uuid::now_v7(),
+				..tb.as_ref().clone()
+			},
+		)
+		.await?;
+	tx.clear_cache();
+	Ok(tb)
+}
+
 #[revisioned(revision = 1)]
 #[derive(Debug, PartialEq)]
 pub(crate) struct Appending {
@@ -438,6 +565,8 @@ struct Building {
 	ix: Arc<IndexDefinition>,
 	/// Index key (namespace/db/table/index ids).
 	ix_key: SharedIndexKey,
+	/// The index which is replaced once built, when rebuilding online.
+	replaces: Option<Arc<IndexDefinition>>,
 	/// Current build status.
 	status: Arc<RwLock<BuildingStatus>>,
 	/// Queue of records awaiting indexing.
@@ -462,6 +591,7 @@ impl Building {
 		tb: TableId,
 		ix: Arc<IndexDefinition>,
 		ix_key: SharedIndexKey,
+		replaces: Option<Arc<IndexDefinition>>,
 	) -> Result<Self> {
 		let ikb = IndexKeyBase::new(ix_key.ns, ix_key.db, ix.table_name.clone(), ix.index_id);
 		Ok(Self {
@@ -472,6 +602,7 @@ impl Building {
 			tf,
 			ix,
 			ix_key,
+			replaces,
 			status: Arc::new(RwLock::new(BuildingStatus::Started)),
 			queue: Default::default(),
 			clean_queue: Default::default(),
@@ -707,6 +838,86 @@ impl Building {
 			&mut last_prepare_remove_check,
 		)
 		.await?;
+		// Switch over from the index this index replaces.
+		if let Some(prev) = &self.replaces
+			&& !self.is_aborted().await
+		{
+			self.replace(prev).await?;
+		}
+		Ok(())
+	}
+
+	/// Points the index definition at this index, and removes the data of
+	/// the index which was served while this index was being built.
+	async fn replace(&self, prev: &IndexDefinition) -> Result<()> {
+		let (ns, db) = (self.ix_key.ns, self.ix_key.db);
+		let ctx = self.new_write_tx_ctx().await?;
+		let tx = ctx.tx();
+		let Some(tb) = catch!(tx, self.replace_with_tx(&tx, prev).await) else {
+			tx.cancel().await?;
+			return Ok(());
+		};
+		catch!(tx, tx.commit().await);
+		// Clear the cache
+		if let Some(cache) = self.ctx.get_cache() {
+			cache.clear_tb(ns, db, &tb.name);
+		}
+		// Clear the index store cache of the previous index
+		self.ctx.get_index_stores().index_removed(None, ns, db, &tb, prev).await
+	}
+
+	async fn replace_with_tx(
+		&self,
+		tx: &Transaction,
+		prev: &IndexDefinition,
+	) -> Result<Option<Arc<TableDefinition>>> {
+		let (ns, db) = (self.ix_key.ns, self.ix_key.db);
+		let tb = &self.ix.table_name;
+		// Check the index has not been removed, redefined, or rebuilt again in
+		// the meantime.
+		let ix = tx.get_tb_index(ns, db, tb, &self.ix.name, None).await?;
+		let Some(ix) =
+			ix.filter(|ix| ix.index_id == prev.index_id && ix.rebuilding == Some(self.ix.index_id))
+		else {
+			// If so, the rebuilt index data is not needed anymore.
+			tx.delp(&crate::key::index::all::new(ns, db, tb, self.ix.index_id)).await?;
+			return Ok(None);
+		};
+		ix.expect_not_prepare_remove()?;
+		// Switch the definition over to the rebuilt index
+		let ix = IndexDefinition {
+			index_id: self.ix.index_id,
+			rebuilding: None,
+			..ix.as_ref().clone()
+		};
+		// Remove the previous index data
+		tx.delp(&crate::key::index::all::new(ns, db, tb, prev.index_id)).await?;
+		put_index_with_tx(tx, &self.opt, ns, db, &ix).await.map(Some)
+	}
+
+	/// Removes the data of this index after an online rebuild failed, and
+	/// clears the rebuild from the definition of the served index.
+	async fn discard(&self) -> Result<()> {
+		let (ns, db) = (self.ix_key.ns, self.ix_key.db);
+		let tb = &self.ix.table_name;
+		let ctx = self.new_write_tx_ctx().await?;
+		let tx = ctx.tx();
+		let ix = catch!(tx, tx.get_tb_index(ns, db, tb, &self.ix.name, None).await);
+		if let Some(ix) =
+			ix.filter(|ix| ix.rebuilding == Some(self.ix.index_id) && !ix.prepare_remove)
+		{
+			let ix = IndexDefinition {
+				rebuilding: None,
+				..ix.as_ref().clone()
+			};
+			catch!(tx, put_index_with_tx(&tx, &self.opt, ns, db, &ix).await);
+		}
+		catch!(tx, tx.delp(&crate::key::index::all::new(ns, db, tb, self.ix.index_id)).await);
+		catch!(tx, tx.commit().await);
+		// Clear the cache
+		if let Some(cache) = self.ctx.get_cache() {
+			cache.clear_tb(ns, db, tb);
+		}
 		Ok(())
 	}
 
@@ -773,7 +984,7 @@ impl Building {
 				let indexed = match self.index_appending_range(&ctx, &tx, keys, updates_count).await
 				{
 					Ok(indexed) => indexed,
-					Err(err) if is_retryable_transaction_conflict(&err) => {
+					Err(err) if is_conflict(&err) => {
 						let _ = tx.cancel().await;
 						*updates_count = saved_updates_count;
 						warn!("{}: transient conflict in appending range, retrying", self.ix.name);
@@ -813,7 +1024,7 @@ impl Building {
 						})
 						.await;
 					}
-					Err(err) if is_retryable_transaction_conflict(&err) => {
+					Err(err) if is_conflict(&err) => {
 						let _ = tx.cancel().await;
 						*updates_count = saved_updates_count;
 						warn!("{}: transient conflict on commit, retrying", self.ix.name);
@@ -980,7 +1191,7 @@ impl Building {
 		let res = tx.commit().await;
 		match res {
 			Ok(()) => Ok(()),
-			Err(err) if is_retryable_transaction_conflict(&err) => {
+			Err(err) if is_conflict(&err) => {
 				let _ = tx.cancel().await;
 				warn!(
 					"{}: transient conflict while cleaning legacy primary appending entry; continuing",
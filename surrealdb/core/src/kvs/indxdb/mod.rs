@@ -65,6 +65,16 @@ impl Transactable for Transaction {
 		"indxdb"
 	}
 
+	/// Get the version of the engine
+	fn version(&self) -> Option<&'static str> {
+		option_env!("SURREAL_ENGINE_VERSION_INDXDB")
+	}
+
+	/// Check if savepoints are supported
+	fn supports_savepoints(&self) -> bool {
+		true
+	}
+
 	/// Check if closed
 	fn closed(&self) -> bool {
 		self.done.load(Ordering::Relaxed)
@@ -22,6 +22,8 @@ use crate::kvs::{Key, Val};
 
 pub struct Datastore {
 	db: Database,
+	/// The period for which historical versions are retained
+	retention: Option<Duration>,
 }
 
 pub struct Transaction {
@@ -74,17 +76,24 @@ impl Datastore {
 		#[cfg(target_family = "wasm")]
 		let db = Database::new_with_options(opts);
 		// Configure GC retention if a retention period is specified
-		let db = if config.retention_ns > 0 {
-			db.with_gc_history(Duration::from_nanos(config.retention_ns))
-		} else {
-			db
+		let retention =
+			(config.retention_ns > 0).then(|| Duration::from_nanos(config.retention_ns));
+		let db = match retention {
+			Some(retention) => db.with_gc_history(retention),
+			None => db,
 		};
 		// Return the new datastore
 		Ok(Datastore {
 			db,
+			retention,
 		})
 	}
 
+	/// The period for which historical versions are retained, if limited
+	pub(crate) fn version_retention(&self) -> Option<Duration> {
+		self.retention
+	}
+
 	/// Shutdown the database
 	pub(crate) async fn shutdown(&self) -> Result<()> {
 		// Nothing to do here
@@ -111,6 +120,21 @@ impl Transactable for Transaction {
 		"memory"
 	}
 
+	/// Get the version of the engine
+	fn version(&self) -> Option<&'static str> {
+		option_env!("SURREAL_ENGINE_VERSION_MEMORY")
+	}
+
+	/// Check if savepoints are supported
+	fn supports_savepoints(&self) -> bool {
+		true
+	}
+
+	/// Check if versioned queries are supported
+	fn supports_versioning(&self) -> bool {
+		true
+	}
+
 	/// Check if closed
 	fn closed(&self) -> bool {
 		self.done.load(Ordering::Relaxed)
@@ -16,6 +16,7 @@
 
 pub mod config;
 pub mod export;
+pub mod import;
 
 mod api;
 mod batch;
@@ -25,6 +26,8 @@ mod err;
 mod into;
 mod key;
 mod scanner;
+mod snapshot;
+mod stats;
 mod threadpool;
 mod timestamp;
 mod tr;
@@ -40,14 +43,17 @@ mod tikv;
 #[cfg(test)]
 mod tests;
 
+pub(crate) mod audit;
 pub(crate) mod cache;
 pub(crate) mod index;
+pub(crate) mod result_cache;
 pub(crate) mod sequences;
 pub(crate) mod slowlog;
 pub(crate) mod tasklease;
 pub(crate) mod version;
 
 pub use api::{ScanLimit, Transactable};
+pub use audit::AuditEvent;
 pub(crate) use ds::TransactionFactory;
 pub use ds::requirements::{TransactionBuilderFactoryRequirements, TransactionBuilderRequirements};
 pub use ds::{
@@ -57,6 +63,10 @@ pub use err::{Error, Result};
 pub use into::IntoBytes;
 pub(crate) use key::{KVKey, KVValue, impl_kv_key_storekey, impl_kv_value_revisioned};
 pub use scanner::{Direction, Scanner};
+pub use slowlog::SlowQueryHook;
+pub use snapshot::Snapshot;
+pub use stats::ConflictStats;
+pub(crate) use stats::{StatementType, is_conflict};
 pub use timestamp::{
 	BoxTimeStamp, BoxTimeStampImpl, HlcTimeStamp, HlcTimeStampImpl, IncTimeStampImpl,
 	MAX_TIMESTAMP_BYTES, TimeStamp, TimeStampImpl,
@@ -64,6 +74,7 @@ pub use timestamp::{
 pub use tr::{LockType, TransactionType, Transactor};
 pub(crate) use tx::CachePolicy;
 pub use tx::Transaction;
+pub(crate) use util::prefix_end;
 
 /// The key part of a key-value pair. An alias for [`Vec<u8>`].
 pub type Key = Vec<u8>;
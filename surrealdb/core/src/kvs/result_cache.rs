@@ -0,0 +1,840 @@
+//! A cache of the results of read-only queries.
+//!
+//! When a datastore is built with a result cache, the results of read-only
+//! `SELECT` statements are cached, keyed by the statement, the values of the
+//! parameters which it uses, the session which ran it, and the options which
+//! change its result, such as `OPTION DIV_BY_ZERO`. Each result is
+//! stored with the write versions of the tables which the statement reads,
+//! and is only returned while none of those tables have been written to.
+//!
+//! A table version is increased whenever a transaction which wrote records to
+//! that table commits. Statements which may read tables which can not be
+//! determined from the statement, such as by following record links or graph
+//! edges, depend on the records of every table. Statements which change
+//! anything other than records, such as a schema definition, invalidate every
+//! cached result.
+//!
+//! Only deterministic statements are cached, so statements calling functions
+//! such as `rand()` or `time::now()`, or custom functions, are always run.
+//! Builtin functions are only cached when they are known to be deterministic.
+//! Values which are computed when a record is read, such as `COMPUTED`
+//! fields, are not checked for the tables which they read.
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use parking_lot::Mutex;
+use surrealdb_types::ToSql;
+
+use crate::ctx::FrozenContext;
+use crate::dbs::{DivByZero, NullCoercion, Options};
+use crate::expr::visit::{Visit, Visitor};
+use crate::expr::{Expr, Function, Idiom, Literal, Param, Part, SelectStatement, TopLevelExpr};
+use crate::val::{TableName, Value};
+
+/// The key identifying a cached result.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Key {
+	/// The session which ran the statement, as results depend on permissions
+	actor: String,
+	level: String,
+	ns: Option<String>,
+	db: Option<String>,
+	/// The text of the statement
+	statement: String,
+	/// The values of the parameters which the statement uses
+	params: String,
+	/// The LIMIT applied to statements without one
+	default_limit: Option<u32>,
+	/// The result of a division by zero, which may be an error
+	div_by_zero: DivByZero,
+	/// How NONE and NULL are coerced into one another
+	null_coercion: NullCoercion,
+}
+
+/// A cached result, with the versions of its dependencies when it was run.
+struct Entry {
+	versions: Vec<u64>,
+	value: Value,
+	truncated: bool,
+}
+
+/// The data which a statement reads.
+enum Dependencies {
+	/// The records of the listed tables
+	Tables(BTreeSet<String>),
+	/// The records of any table
+	All,
+}
+
+/// A statement which can be cached, and the versions of its dependencies
+/// from before it was run.
+pub(crate) struct CacheableStatement {
+	key: Key,
+	versions: Vec<u64>,
+}
+
+pub(crate) struct ResultCache {
+	/// The cached results
+	results: quick_cache::sync::Cache<Key, Arc<Entry>>,
+	/// The write version of each table which has been written to
+	tables: Mutex<HashMap<String, u64>>,
+	/// Increased whenever records in any table are written
+	records_version: AtomicU64,
+	/// Increased whenever anything other than records is written
+	schema_version: AtomicU64,
+	/// The number of lookups which returned a cached result
+	hits: AtomicU64,
+	/// The number of lookups which found no result, or an outdated result
+	misses: AtomicU64,
+}
+
+impl ResultCache {
+	/// Create a result cache holding up to `capacity` results.
+	pub(crate) fn new(capacity: usize) -> Self {
+		Self {
+			results: quick_cache::sync::Cache::new(capacity),
+			tables: Mutex::new(HashMap::new()),
+			records_version: AtomicU64::new(0),
+			schema_version: AtomicU64::new(0),
+			hits: AtomicU64::new(0),
+			misses: AtomicU64::new(0),
+		}
+	}
+
+	/// Returns the statement if its result can be cached, along with the
+	/// current versions of the data which it reads.
+	pub(crate) fn cacheable(
+		&self,
+		ctx: &FrozenContext,
+		opt: &Options,
+		stmt: &TopLevelExpr,
+	) -> Option<CacheableStatement> {
+		let TopLevelExpr::Expr(expr @ Expr::Select(_)) = stmt else {
+			return None;
+		};
+		let mut visitor = DependencyVisitor::default();
+		expr.visit(&mut visitor).ok()?;
+		// Include the value of each parameter, as the statement depends on them
+		let mut params = String::new();
+		for name in &visitor.params {
+			// Parameters which are not set may be defined by the statement
+			// itself, or depend on the document which is being processed
+			let value = ctx.value(name)?.to_sql();
+			params.push_str(&format!("${name}={value};"));
+		}
+		let dependencies = if visitor.follows_links {
+			Dependencies::All
+		} else {
+			Dependencies::Tables(visitor.tables)
+		};
+		Some(CacheableStatement {
+			key: Key {
+				actor: opt.auth.id().to_owned(),
+				level: opt.auth.level().to_string(),
+				ns: opt.ns.as_deref().map(str::to_owned),
+				db: opt.db.as_deref().map(str::to_owned),
+				statement: stmt.to_sql(),
+				params,
+				default_limit: opt.default_limit,
+				div_by_zero: opt.div_by_zero,
+				null_coercion: ctx.null_coercion(),
+			},
+			versions: self.versions(&dependencies),
+		})
+	}
+
+	/// Returns the current versions of the dependencies.
+	fn versions(&self, dependencies: &Dependencies) -> Vec<u64> {
+		let mut versions = vec![self.schema_version.load(Ordering::Acquire)];
+		match dependencies {
+			Dependencies::All => versions.push(self.records_version.load(Ordering::Acquire)),
+			Dependencies::Tables(tables) => {
+				let versions_by_table = self.tables.lock();
+				versions
+					.extend(tables.iter().map(|t| versions_by_table.get(t).copied().unwrap_or(0)));
+			}
+		}
+		versions
+	}
+
+	/// Returns the cached result of the statement, and whether it was
+	/// truncated, if none of the data it reads has been written since.
+	pub(crate) fn get(&self, stmt: &CacheableStatement) -> Option<(Value, bool)> {
+		match self.results.get(&stmt.key) {
+			Some(entry) if entry.versions == stmt.versions => {
+				self.hits.fetch_add(1, Ordering::Relaxed);
+				Some((entry.value.clone(), entry.truncated))
+			}
+			_ => {
+				self.misses.fetch_add(1, Ordering::Relaxed);
+				None
+			}
+		}
+	}
+
+	/// Caches the result of the statement.
+	///
+	/// The versions were taken before the statement was run, so a result is
+	/// never used if a write committed while the statement was running.
+	pub(crate) fn insert(&self, stmt: CacheableStatement, value: Value, truncated: bool) {
+		let entry = Entry {
+			versions: stmt.versions,
+			value,
+			truncated,
+		};
+		self.results.insert(stmt.key, Arc::new(entry));
+	}
+
+	/// Invalidates the results which read the tables which had records
+	/// written by a committed transaction.
+	pub(crate) fn records_written<'a>(&self, tables: impl IntoIterator<Item = &'a TableName>) {
+		let mut versions = self.tables.lock();
+		let mut written = false;
+		for table in tables {
+			*versions.entry(table.as_str().to_owned()).or_default() += 1;
+			written = true;
+		}
+		if written {
+			self.records_version.fetch_add(1, Ordering::Release);
+		}
+	}
+
+	/// Invalidates every cached result, after something other than records
+	/// was written.
+	pub(crate) fn schema_written(&self) {
+		self.schema_version.fetch_add(1, Ordering::Release);
+	}
+
+	/// Returns the number of lookups which returned a cached result, and the
+	/// number of lookups which did not.
+	pub(crate) fn lookups(&self) -> (u64, u64) {
+		(self.hits.load(Ordering::Relaxed), self.misses.load(Ordering::Relaxed))
+	}
+}
+
+/// Checks whether a write statement can only have written records, in which
+/// case the table versions are enough to invalidate the cached results.
+pub(crate) fn writes_only_records(stmt: &TopLevelExpr) -> bool {
+	matches!(
+		stmt,
+		TopLevelExpr::Expr(
+			Expr::Create(_)
+				| Expr::Update(_)
+				| Expr::Upsert(_)
+				| Expr::Delete(_)
+				| Expr::Relate(_)
+				| Expr::Insert(_)
+		)
+	)
+}
+
+/// Collects the tables and parameters a statement reads, and fails if the
+/// statement is not deterministic or not read-only.
+#[derive(Default)]
+struct DependencyVisitor {
+	tables: BTreeSet<String>,
+	params: BTreeSet<String>,
+	follows_links: bool,
+}
+
+impl Visitor for DependencyVisitor {
+	type Error = ();
+
+	fn visit_expr(&mut self, expr: &Expr) -> Result<(), Self::Error> {
+		match expr {
+			Expr::Table(table) => {
+				self.tables.insert(table.as_str().to_owned());
+			}
+			Expr::Create(_)
+			| Expr::Update(_)
+			| Expr::Upsert(_)
+			| Expr::Delete(_)
+			| Expr::Relate(_)
+			| Expr::Insert(_)
+			| Expr::Define(_)
+			| Expr::Remove(_)
+			| Expr::Rebuild(_)
+			| Expr::Alter(_)
+			| Expr::Info(_)
+			| Expr::Let(_)
+			| Expr::Sleep(_)
+			| Expr::Mock(_)
+			| Expr::Explain {
+				..
+			} => return Err(()),
+			_ => {}
+		}
+		expr.visit(self)
+	}
+
+	fn visit_literal(&mut self, literal: &Literal) -> Result<(), Self::Error> {
+		if let Literal::RecordId(rid) = literal {
+			self.tables.insert(rid.table.as_str().to_owned());
+		}
+		literal.visit(self)
+	}
+
+	fn visit_select(&mut self, stmt: &SelectStatement) -> Result<(), Self::Error> {
+		// Historical reads are not cached
+		if !matches!(stmt.version, Expr::Literal(Literal::None)) {
+			return Err(());
+		}
+		// Tables which are selected from through parameters or subqueries,
+		// and fetched records, are only known when the statement is run
+		if stmt.fetch.is_some()
+			|| stmt
+				.what
+				.iter()
+				.any(|w| !matches!(w, Expr::Table(_) | Expr::Literal(Literal::RecordId(_))))
+		{
+			self.follows_links = true;
+		}
+		stmt.visit(self)
+	}
+
+	fn visit_param(&mut self, param: &Param) -> Result<(), Self::Error> {
+		self.params.insert(param.as_str().to_owned());
+		Ok(())
+	}
+
+	fn visit_idiom(&mut self, idiom: &Idiom) -> Result<(), Self::Error> {
+		// Any part after the first may follow a record link
+		if idiom.0.len() > 1 {
+			self.follows_links = true;
+		}
+		idiom.visit(self)
+	}
+
+	fn visit_part(&mut self, part: &Part) -> Result<(), Self::Error> {
+		if let Part::Lookup(_) | Part::Recurse(..) | Part::Method(..) = part {
+			self.follows_links = true;
+		}
+		part.visit(self)
+	}
+
+	fn visit_function(&mut self, function: &Function) -> Result<(), Self::Error> {
+		let Function::Normal(name) = function else {
+			return Err(());
+		};
+		match function_kind(name) {
+			FunctionKind::Pure => Ok(()),
+			FunctionKind::ReadsRecords => {
+				self.follows_links = true;
+				Ok(())
+			}
+			FunctionKind::Uncacheable => Err(()),
+		}
+	}
+}
+
+enum FunctionKind {
+	/// Always returns the same result for the same arguments
+	Pure,
+	/// Reads records, from tables which are not known until it is run
+	ReadsRecords,
+	/// Depends on something other than its arguments and the stored records
+	Uncacheable,
+}
+
+fn function_kind(name: &str) -> FunctionKind {
+	match name {
+		"record::exists"
+		| "record::is_edge"
+		| "graph::expand"
+		| "graph::topo_sort"
+		| "table::distinct_values"
+		| "type::field"
+		| "type::fields" => FunctionKind::ReadsRecords,
+		name if DETERMINISTIC_FUNCTIONS.binary_search(&name).is_ok() => FunctionKind::Pure,
+		_ => FunctionKind::Uncacheable,
+	}
+}
+
+/// The builtin functions which always return the same result for the same
+/// arguments, sorted by name. Functions which are not listed, including any
+/// functions which are added later, are never cached.
+const DETERMINISTIC_FUNCTIONS: &[&str] = &[
+	"array::add",
+	"array::all",
+	"array::any",
+	"array::append",
+	"array::at",
+	"array::boolean_and",
+	"array::boolean_not",
+	"array::boolean_or",
+	"array::boolean_xor",
+	"array::clump",
+	"array::combine",
+	"array::complement",
+	"array::concat",
+	"array::difference",
+	"array::distinct",
+	"array::edit_script",
+	"array::every",
+	"array::fill",
+	"array::filter",
+	"array::filter_index",
+	"array::find",
+	"array::find_index",
+	"array::first",
+	"array::flatten",
+	"array::fold",
+	"array::group",
+	"array::includes",
+	"array::index_of",
+	"array::insert",
+	"array::intersect",
+	"array::is_empty",
+	"array::join",
+	"array::last",
+	"array::len",
+	"array::logical_and",
+	"array::logical_or",
+	"array::logical_xor",
+	"array::map",
+	"array::matches",
+	"array::max",
+	"array::merge_sorted",
+	"array::merge_sorted_by",
+	"array::min",
+	"array::partition",
+	"array::pop",
+	"array::prepend",
+	"array::push",
+	"array::range",
+	"array::reduce",
+	"array::remove",
+	"array::repeat",
+	"array::reverse",
+	"array::sequence",
+	"array::slice",
+	"array::some",
+	"array::sort",
+	"array::sort::asc",
+	"array::sort::desc",
+	"array::sort_by_keys",
+	"array::sort_lexical",
+	"array::sort_natural",
+	"array::sort_natural_lexical",
+	"array::swap",
+	"array::transpose",
+	"array::union",
+	"array::unique_by",
+	"array::windows",
+	"bytes::len",
+	"count",
+	"count::approx_distinct",
+	"crypto::argon2::compare",
+	"crypto::bcrypt::compare",
+	"crypto::blake3",
+	"crypto::joaat",
+	"crypto::md5",
+	"crypto::pbkdf2::compare",
+	"crypto::scrypt::compare",
+	"crypto::sha1",
+	"crypto::sha256",
+	"crypto::sha512",
+	"duration::days",
+	"duration::from::days",
+	"duration::from::hours",
+	"duration::from::micros",
+	"duration::from::millis",
+	"duration::from::mins",
+	"duration::from::nanos",
+	"duration::from::secs",
+	"duration::from::weeks",
+	"duration::from_days",
+	"duration::from_hours",
+	"duration::from_micros",
+	"duration::from_millis",
+	"duration::from_mins",
+	"duration::from_nanos",
+	"duration::from_secs",
+	"duration::from_weeks",
+	"duration::hours",
+	"duration::micros",
+	"duration::millis",
+	"duration::mins",
+	"duration::nanos",
+	"duration::secs",
+	"duration::set_day",
+	"duration::set_hour",
+	"duration::set_minute",
+	"duration::set_month",
+	"duration::set_nanosecond",
+	"duration::set_second",
+	"duration::set_year",
+	"duration::weeks",
+	"duration::years",
+	"encoding::base32::decode",
+	"encoding::base32::encode",
+	"encoding::base58::decode",
+	"encoding::base58::encode",
+	"encoding::base62::decode",
+	"encoding::base62::encode",
+	"encoding::base64::decode",
+	"encoding::base64::encode",
+	"encoding::cbor::decode",
+	"encoding::cbor::encode",
+	"encoding::json::decode",
+	"encoding::json::encode",
+	"geo::area",
+	"geo::bearing",
+	"geo::centroid",
+	"geo::distance",
+	"geo::hash::decode",
+	"geo::hash::encode",
+	"geo::is_valid",
+	"geo::within",
+	"math::abs",
+	"math::acos",
+	"math::acot",
+	"math::asin",
+	"math::atan",
+	"math::bottom",
+	"math::ceil",
+	"math::clamp",
+	"math::cos",
+	"math::cot",
+	"math::deg2rad",
+	"math::ema",
+	"math::fixed",
+	"math::floor",
+	"math::interquartile",
+	"math::lerp",
+	"math::lerpangle",
+	"math::ln",
+	"math::log",
+	"math::log10",
+	"math::log2",
+	"math::max",
+	"math::mean",
+	"math::median",
+	"math::midhinge",
+	"math::min",
+	"math::mode",
+	"math::moving_average",
+	"math::nearestrank",
+	"math::percent_rank",
+	"math::percentile",
+	"math::pow",
+	"math::product",
+	"math::rad2deg",
+	"math::round",
+	"math::sign",
+	"math::sin",
+	"math::spread",
+	"math::sqrt",
+	"math::stddev",
+	"math::sum",
+	"math::tan",
+	"math::time_weighted_avg",
+	"math::top",
+	"math::trimean",
+	"math::variance",
+	"meta::id",
+	"meta::tb",
+	"not",
+	"number::from_base",
+	"number::to_base",
+	"object::defaults",
+	"object::entries",
+	"object::extend",
+	"object::flatten",
+	"object::from_entries",
+	"object::is_empty",
+	"object::keys",
+	"object::len",
+	"object::matches",
+	"object::remove",
+	"object::unflatten",
+	"object::values",
+	"parse::email::host",
+	"parse::email::user",
+	"parse::url::domain",
+	"parse::url::fragment",
+	"parse::url::host",
+	"parse::url::path",
+	"parse::url::port",
+	"parse::url::query",
+	"parse::url::scheme",
+	"record::id",
+	"record::parse",
+	"record::range",
+	"record::table",
+	"record::tb",
+	"set::add",
+	"set::all",
+	"set::any",
+	"set::at",
+	"set::complement",
+	"set::contains",
+	"set::difference",
+	"set::filter",
+	"set::find",
+	"set::first",
+	"set::flatten",
+	"set::fold",
+	"set::intersect",
+	"set::is_empty",
+	"set::join",
+	"set::last",
+	"set::len",
+	"set::map",
+	"set::max",
+	"set::min",
+	"set::reduce",
+	"set::remove",
+	"set::slice",
+	"set::union",
+	"string::capitalize",
+	"string::concat",
+	"string::contains",
+	"string::distance::damerau",
+	"string::distance::damerau_levenshtein",
+	"string::distance::hamming",
+	"string::distance::levenshtein",
+	"string::distance::normalized_damerau_levenshtein",
+	"string::distance::normalized_levenshtein",
+	"string::distance::osa",
+	"string::distance::osa_distance",
+	"string::ends_with",
+	"string::fuzzy_match",
+	"string::fuzzy_score",
+	"string::html::encode",
+	"string::html::sanitize",
+	"string::is::alpha",
+	"string::is::alphanum",
+	"string::is::ascii",
+	"string::is::datetime",
+	"string::is::domain",
+	"string::is::email",
+	"string::is::hexadecimal",
+	"string::is::ip",
+	"string::is::ipv4",
+	"string::is::ipv6",
+	"string::is::latitude",
+	"string::is::longitude",
+	"string::is::numeric",
+	"string::is::record",
+	"string::is::semver",
+	"string::is::ulid",
+	"string::is::url",
+	"string::is::uuid",
+	"string::is_alpha",
+	"string::is_alphanum",
+	"string::is_ascii",
+	"string::is_datetime",
+	"string::is_domain",
+	"string::is_email",
+	"string::is_hexadecimal",
+	"string::is_ip",
+	"string::is_ipv4",
+	"string::is_ipv6",
+	"string::is_latitude",
+	"string::is_longitude",
+	"string::is_numeric",
+	"string::is_record",
+	"string::is_semver",
+	"string::is_ulid",
+	"string::is_url",
+	"string::is_uuid",
+	"string::join",
+	"string::len",
+	"string::lowercase",
+	"string::matches",
+	"string::normalize_url",
+	"string::parse_kv",
+	"string::parse_template",
+	"string::repeat",
+	"string::replace",
+	"string::reverse",
+	"string::semver::compare",
+	"string::semver::inc::major",
+	"string::semver::inc::minor",
+	"string::semver::inc::patch",
+	"string::semver::major",
+	"string::semver::minor",
+	"string::semver::patch",
+	"string::semver::set::major",
+	"string::semver::set::minor",
+	"string::semver::set::patch",
+	"string::similarity::fuzzy",
+	"string::similarity::jaro",
+	"string::similarity::jaro_winkler",
+	"string::similarity::smithwaterman",
+	"string::similarity::sorensen_dice",
+	"string::slice",
+	"string::slug",
+	"string::split",
+	"string::starts_with",
+	"string::trim",
+	"string::uppercase",
+	"string::words",
+	"time::add_business_days",
+	"time::ceil",
+	"time::day",
+	"time::floor",
+	"time::format",
+	"time::format_zoned",
+	"time::from::micros",
+	"time::from::millis",
+	"time::from::nanos",
+	"time::from::secs",
+	"time::from::ulid",
+	"time::from::unix",
+	"time::from::uuid",
+	"time::from_micros",
+	"time::from_millis",
+	"time::from_nanos",
+	"time::from_secs",
+	"time::from_ulid",
+	"time::from_unix",
+	"time::from_uuid",
+	"time::group",
+	"time::hour",
+	"time::in_zone",
+	"time::is::leap_year",
+	"time::is_leap_year",
+	"time::max",
+	"time::micros",
+	"time::millis",
+	"time::min",
+	"time::minute",
+	"time::month",
+	"time::nano",
+	"time::round",
+	"time::second",
+	"time::set_day",
+	"time::set_hour",
+	"time::set_minute",
+	"time::set_month",
+	"time::set_nanosecond",
+	"time::set_second",
+	"time::set_year",
+	"time::unix",
+	"time::wday",
+	"time::week",
+	"time::yday",
+	"time::year",
+	"type::array",
+	"type::bool",
+	"type::bytes",
+	"type::datetime",
+	"type::decimal",
+	"type::duration",
+	"type::float",
+	"type::geometry",
+	"type::id_from",
+	"type::int",
+	"type::is",
+	"type::is::array",
+	"type::is::bool",
+	"type::is::bytes",
+	"type::is::collection",
+	"type::is::datetime",
+	"type::is::decimal",
+	"type::is::duration",
+	"type::is::float",
+	"type::is::geometry",
+	"type::is::int",
+	"type::is::line",
+	"type::is::multiline",
+	"type::is::multipoint",
+	"type::is::multipolygon",
+	"type::is::none",
+	"type::is::null",
+	"type::is::number",
+	"type::is::object",
+	"type::is::point",
+	"type::is::polygon",
+	"type::is::range",
+	"type::is::record",
+	"type::is::string",
+	"type::is::uuid",
+	"type::is_array",
+	"type::is_bool",
+	"type::is_bytes",
+	"type::is_collection",
+	"type::is_datetime",
+	"type::is_decimal",
+	"type::is_duration",
+	"type::is_float",
+	"type::is_geometry",
+	"type::is_int",
+	"type::is_line",
+	"type::is_multiline",
+	"type::is_multipoint",
+	"type::is_multipolygon",
+	"type::is_none",
+	"type::is_null",
+	"type::is_number",
+	"type::is_object",
+	"type::is_point",
+	"type::is_polygon",
+	"type::is_range",
+	"type::is_record",
+	"type::is_set",
+	"type::is_string",
+	"type::is_uuid",
+	"type::number",
+	"type::of",
+	"type::point",
+	"type::range",
+	"type::record",
+	"type::set",
+	"type::string",
+	"type::string_lossy",
+	"type::table",
+	"type::thing",
+	"type::uuid",
+	"value::canonicalize",
+	"value::deduplicate_refs",
+	"value::diff",
+	"value::patch",
+	"vector::add",
+	"vector::angle",
+	"vector::cross",
+	"vector::distance::chebyshev",
+	"vector::distance::euclidean",
+	"vector::distance::hamming",
+	"vector::distance::mahalanobis",
+	"vector::distance::manhattan",
+	"vector::distance::minkowski",
+	"vector::divide",
+	"vector::dot",
+	"vector::magnitude",
+	"vector::multiply",
+	"vector::normalize",
+	"vector::project",
+	"vector::scale",
+	"vector::similarity::cosine",
+	"vector::similarity::jaccard",
+	"vector::similarity::pearson",
+	"vector::similarity::spearman",
+	"vector::subtract",
+];
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn deterministic_functions_are_sorted() {
+		assert!(DETERMINISTIC_FUNCTIONS.is_sorted());
+	}
+
+	#[test]
+	fn function_kinds() {
+		assert!(matches!(function_kind("string::len"), FunctionKind::Pure));
+		assert!(matches!(function_kind("count"), FunctionKind::Pure));
+		assert!(matches!(function_kind("record::exists"), FunctionKind::ReadsRecords));
+		assert!(matches!(function_kind("type::field"), FunctionKind::ReadsRecords));
+		for name in
+			["array::shuffle", "type::file", "time::now", "crypto::bcrypt::generate", "rand"]
+		{
+			assert!(matches!(function_kind(name), FunctionKind::Uncacheable), "{name}");
+		}
+	}
+}
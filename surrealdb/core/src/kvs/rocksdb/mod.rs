@@ -12,6 +12,7 @@ use std::ops::Range;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::time::Duration;
 
 use background_flusher::BackgroundFlusher;
 use commit_coordinator::CommitCoordinator;
@@ -42,6 +43,8 @@ pub struct Datastore {
 	db: Pin<Arc<OptimisticTransactionDB>>,
 	/// Whether user-defined timestamps (versioning) are enabled
 	versioned: bool,
+	/// The period for which historical versions are retained, if limited
+	retention: Option<Duration>,
 	/// Memory manager for managing memory usage
 	memory_manager: Arc<MemoryManager>,
 	/// Disk space manager for monitoring space usage and enforcing space limits
@@ -296,6 +299,8 @@ impl Datastore {
 		Ok(Datastore {
 			db,
 			versioned: config.versioned,
+			retention: (config.versioned && config.retention_ns > 0)
+				.then(|| Duration::from_nanos(config.retention_ns)),
 			memory_manager,
 			disk_space_manager,
 			background_flusher,
@@ -348,6 +353,11 @@ impl Datastore {
 		})
 	}
 
+	/// The period for which historical versions are retained, if limited
+	pub(crate) fn version_retention(&self) -> Option<Duration> {
+		self.retention
+	}
+
 	/// Shutdown the database
 	pub(crate) async fn shutdown(&self) -> Result<()> {
 		// Wait for the garbage collector to finish
@@ -503,6 +513,21 @@ impl Transactable for Transaction {
 		"rocksdb"
 	}
 
+	/// Get the version of the engine
+	fn version(&self) -> Option<&'static str> {
+		option_env!("SURREAL_ENGINE_VERSION_ROCKSDB")
+	}
+
+	/// Check if savepoints are supported
+	fn supports_savepoints(&self) -> bool {
+		true
+	}
+
+	/// Check if versioned queries are supported
+	fn supports_versioning(&self) -> bool {
+		self.versioned
+	}
+
 	/// Check if closed
 	fn closed(&self) -> bool {
 		self.done.load(Ordering::Relaxed)
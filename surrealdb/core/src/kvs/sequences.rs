@@ -50,6 +50,8 @@ use crate::key::sequence::ba::Ba;
 use crate::key::sequence::st::St;
 use crate::key::table::ih::IndexIdGeneratorBatchKey;
 use crate::key::table::is::IndexIdGeneratorStateKey;
+use crate::key::table::rh::RecordIdGeneratorBatchKey;
+use crate::key::table::rs::RecordIdGeneratorStateKey;
 use crate::kvs::ds::TransactionFactory;
 use crate::kvs::{KVKey, LockType, Transaction, TransactionType, impl_kv_value_revisioned};
 use crate::val::TableName;
@@ -86,6 +88,8 @@ enum SequenceDomain {
 	TablesIds(NamespaceId, DatabaseId),
 	/// A sequence generating IDs for indexes
 	IndexIds(NamespaceId, DatabaseId, TableName),
+	/// A sequence generating the IDs of new records in a table
+	RecordIds(NamespaceId, DatabaseId, TableName),
 }
 
 impl SequenceDomain {
@@ -113,6 +117,10 @@ impl SequenceDomain {
 		Self::IndexIds(ns, db, tb)
 	}
 
+	pub(crate) fn new_record_ids(ns: NamespaceId, db: DatabaseId, tb: TableName) -> Self {
+		Self::RecordIds(ns, db, tb)
+	}
+
 	fn new_batch_range_keys(&self) -> Result<Range<Vec<u8>>> {
 		match self {
 			Self::UserName(ns, db, sq) => Prefix::new_ba_range(*ns, *db, sq),
@@ -121,6 +129,7 @@ impl SequenceDomain {
 			Self::DatabasesIds(ns) => DatabaseIdGeneratorBatchKey::range(*ns),
 			Self::TablesIds(ns, db) => TableIdGeneratorBatchKey::range(*ns, *db),
 			Self::IndexIds(ns, db, tb) => IndexIdGeneratorBatchKey::range(*ns, *db, tb),
+			Self::RecordIds(ns, db, tb) => RecordIdGeneratorBatchKey::range(*ns, *db, tb),
 		}
 	}
 
@@ -134,6 +143,9 @@ impl SequenceDomain {
 			Self::IndexIds(ns, db, tb) => {
 				IndexIdGeneratorBatchKey::new(*ns, *db, tb, start).encode_key()
 			}
+			Self::RecordIds(ns, db, tb) => {
+				RecordIdGeneratorBatchKey::new(*ns, *db, tb, start).encode_key()
+			}
 		}
 	}
 
@@ -147,6 +159,9 @@ impl SequenceDomain {
 			Self::IndexIds(ns, db, tb) => {
 				IndexIdGeneratorStateKey::new(*ns, *db, tb, nid).encode_key()
 			}
+			Self::RecordIds(ns, db, tb) => {
+				RecordIdGeneratorStateKey::new(*ns, *db, tb, nid).encode_key()
+			}
 		}
 	}
 }
@@ -240,6 +255,14 @@ impl Sequences {
 		self.sequences.write().await.remove(&key);
 	}
 
+	/// Removes the record id sequence of a table from the cache.
+	///
+	/// This method is called when a table is deleted to clean up its cached state.
+	pub(crate) async fn table_removed(&self, ns: NamespaceId, db: DatabaseId, tb: &TableName) {
+		let key = SequenceDomain::new_record_ids(ns, db, tb.clone());
+		self.sequences.write().await.remove(&key);
+	}
+
 	/// Core internal method for retrieving the next value from a sequence.
 	///
 	/// This method coordinates sequence loading, caching, and value generation.
@@ -352,6 +375,27 @@ impl Sequences {
 		Ok(IndexId(id as u32))
 	}
 
+	/// Generates the next id for a new record in a table.
+	///
+	/// # Arguments
+	/// * `ctx` - Optional mutable context for transaction operations
+	/// * `ns` - The namespace ID
+	/// * `db` - The database ID
+	/// * `tb` - The table name to generate the record ID within
+	///
+	/// # Returns
+	/// A new unique record ID for the given table
+	pub(crate) async fn next_record_id(
+		&self,
+		ctx: Option<&Context>,
+		ns: NamespaceId,
+		db: DatabaseId,
+		tb: TableName,
+	) -> Result<i64> {
+		let domain = Arc::new(SequenceDomain::new_record_ids(ns, db, tb));
+		self.next_val(ctx, domain, 0, 100, None).await
+	}
+
 	/// Generates the next value for a user-defined sequence.
 	///
 	/// # Arguments
@@ -13,10 +13,13 @@
 //!   - If `param_allow` is non-empty, only those parameter names are included.
 //! - For readability, both the SQL statement and parameter values are rendered to SQL and
 //!   whitespace is collapsed so the entire log fits on one line.
+//! - When a [`SlowQueryHook`] is set, it is called with each slow statement instead of the
+//!   statement being logged.
 //!
 //! Note: Values considered "nullish" are not logged.
 use std::fmt::Write;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
 use surrealdb_types::ToSql;
@@ -26,6 +29,10 @@ use crate::catalog::{Permission, Permissions};
 use crate::ctx::FrozenContext;
 use crate::expr::visit::{Visit, Visitor};
 
+/// A callback which is invoked with the time taken and the text of each slow
+/// statement, in place of logging the statement.
+pub type SlowQueryHook = Arc<dyn Fn(Duration, &str) + Send + Sync>;
+
 #[derive(Clone)]
 /// Configuration and logic for slow query logging.
 ///
@@ -43,6 +50,8 @@ struct Inner {
 	duration: Duration,
 	param_allow: Vec<String>,
 	param_deny: Vec<String>,
+	logged: AtomicU64,
+	hook: Option<SlowQueryHook>,
 }
 
 pub(crate) struct ParamVisitor<'a> {
@@ -104,9 +113,28 @@ impl SlowLog {
 			duration,
 			param_allow,
 			param_deny,
+			logged: AtomicU64::new(0),
+			hook: None,
+		}))
+	}
+
+	/// Returns this configuration with a callback which is invoked for each
+	/// slow statement, instead of the statement being logged.
+	pub(super) fn with_hook(self, hook: SlowQueryHook) -> Self {
+		Self(Arc::new(Inner {
+			duration: self.0.duration,
+			param_allow: self.0.param_allow.clone(),
+			param_deny: self.0.param_deny.clone(),
+			logged: AtomicU64::new(self.logged()),
+			hook: Some(hook),
 		}))
 	}
 
+	/// Returns the number of statements which have been logged as slow.
+	pub(crate) fn logged(&self) -> u64 {
+		self.0.logged.load(Ordering::Relaxed)
+	}
+
 	/// Returns true if the parameter with the given name should be logged according
 	/// to the current allow/deny configuration.
 	#[inline]
@@ -124,7 +152,7 @@ impl SlowLog {
 	}
 
 	/// Check whether the supplied statement should be slow-logged and emit a
-	/// log line if the threshold is exceeded.
+	/// log line, or call the hook, if the threshold is exceeded.
 	///
 	/// This function:
 	/// - Computes elapsed time since `start` and returns early if under the threshold.
@@ -143,12 +171,18 @@ impl SlowLog {
 		if elapsed < self.0.duration {
 			return;
 		}
+		self.0.logged.fetch_add(1, Ordering::Relaxed);
 
+		// Ensure the query is logged on a single line by collapsing whitespace
+		let query = stm.to_sql().split_whitespace().collect::<Vec<_>>().join(" ");
+		// Pass the statement to the hook instead of logging it
+		if let Some(hook) = &self.0.hook {
+			hook(elapsed, &query);
+			return;
+		}
 		// Extract params
 		let params = self.extract_params(ctx, stm);
-		// Ensure the query is logged on a single line by collapsing whitespace
-		let stm = stm.to_sql().split_whitespace().collect::<Vec<_>>().join(" ");
-		warn!("Slow query detected - time: {elapsed:#?} - query: {stm} - params: [ {params} ]");
+		warn!("Slow query detected - time: {elapsed:#?} - query: {query} - params: [ {params} ]");
 	}
 
 	fn extract_params<S: SlowLogVisit + ToSql>(&self, ctx: &FrozenContext, stm: &S) -> String {
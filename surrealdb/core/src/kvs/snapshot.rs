@@ -0,0 +1,82 @@
+//! Read-only views of the datastore as of a point in time.
+//!
+//! A [`Snapshot`] is created with [`Datastore::snapshot_at`], and applies the
+//! semantics of `SELECT ... VERSION $ts` to every statement it executes, so
+//! that a multi-statement read observes one consistent version of the data.
+//!
+//! [`Datastore::snapshot_at`]: crate::kvs::Datastore::snapshot_at
+use std::sync::Arc;
+
+use anyhow::Result;
+use surrealdb_types::Error as TypesError;
+use tracing::instrument;
+
+use crate::dbs::{QueryResult, Session};
+use crate::kvs::{Datastore, Transaction};
+use crate::syn;
+use crate::types::PublicVariables;
+
+/// A read-only transaction view of the datastore as of a timestamp.
+pub struct Snapshot<'a> {
+	/// The datastore which this snapshot reads from
+	ds: &'a Datastore,
+	/// The read-only transaction used for all statements
+	tx: Arc<Transaction>,
+	/// The version stamp at which all data is read
+	version: u64,
+}
+
+impl<'a> Snapshot<'a> {
+	pub(crate) fn new(ds: &'a Datastore, tx: Arc<Transaction>, version: u64) -> Self {
+		Self {
+			ds,
+			tx,
+			version,
+		}
+	}
+
+	/// The version stamp at which this snapshot reads data
+	pub fn version(&self) -> u64 {
+		self.version
+	}
+
+	/// Parse and execute an SQL query against this snapshot
+	///
+	/// ```rust,no_run
+	/// use anyhow::Error;
+	/// use surrealdb_core::kvs::Datastore;
+	/// use surrealdb_core::dbs::Session;
+	/// use surrealdb_types::Datetime;
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(),Error> {
+	///     let ds = Datastore::new("memory").await?;
+	///     let ses = Session::owner().with_ns("test").with_db("test");
+	///     let snapshot = ds.snapshot_at(Datetime::now()).await?;
+	///     let ast = "SELECT * FROM person; SELECT * FROM company;";
+	///     let res = snapshot.execute(ast, &ses, None).await?;
+	///     snapshot.cancel().await?;
+	///     Ok(())
+	/// }
+	/// ```
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::snapshot", skip_all)]
+	pub async fn execute(
+		&self,
+		txt: &str,
+		sess: &Session,
+		vars: Option<PublicVariables>,
+	) -> std::result::Result<Vec<QueryResult>, TypesError> {
+		// Check the length of the query before parsing it
+		self.ds.check_query_length(txt).map_err(crate::err::into_types_error)?;
+		// Parse the SQL query text
+		let ast = syn::parse_with_capabilities(txt, self.ds.get_capabilities())
+			.map_err(|e| TypesError::validation(e.to_string(), None))?;
+		// Process the AST at the snapshot version
+		self.ds.process_in_transaction(ast, sess, vars, self.tx.clone(), Some(self.version)).await
+	}
+
+	/// Close the snapshot, releasing the underlying transaction
+	pub async fn cancel(self) -> Result<()> {
+		self.tx.cancel().await
+	}
+}
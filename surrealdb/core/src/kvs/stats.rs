@@ -0,0 +1,387 @@
+//! Cumulative transaction commit and query statistics.
+//!
+//! The executor records the outcome of every statement-level and
+//! `BEGIN ... COMMIT` transaction commit, so that contention hotspots can be
+//! diagnosed at runtime through [`Datastore::conflict_stats`]. It also counts
+//! the statements executed by type, which are exposed along with the other
+//! datastore counters through [`Datastore::metrics_prometheus`].
+//!
+//! [`Datastore::conflict_stats`]: crate::kvs::Datastore::conflict_stats
+//! [`Datastore::metrics_prometheus`]: crate::kvs::Datastore::metrics_prometheus
+use std::fmt::Write;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use crate::dbs::QueryMetrics;
+
+/// A point-in-time snapshot of the transaction commit counters.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ConflictStats {
+	/// The number of transactions which committed successfully.
+	pub commits: u64,
+	/// The number of transactions which failed to commit due to a conflict.
+	pub conflicts: u64,
+	/// The number of times a conflicted operation was retried.
+	pub retries: u64,
+}
+
+/// The atomic counters backing [`ConflictStats`].
+#[derive(Debug, Default)]
+pub(crate) struct ConflictCounters {
+	commits: AtomicU64,
+	conflicts: AtomicU64,
+	retries: AtomicU64,
+}
+
+impl ConflictCounters {
+	/// Record the outcome of a transaction commit.
+	pub(crate) fn record_commit(&self, res: &anyhow::Result<()>) {
+		match res {
+			Ok(_) => {
+				self.commits.fetch_add(1, Ordering::Relaxed);
+			}
+			Err(e) if is_conflict(e) => {
+				self.conflicts.fetch_add(1, Ordering::Relaxed);
+			}
+			Err(_) => {}
+		}
+	}
+
+	/// Record that a conflicted operation is being retried.
+	pub(crate) fn record_retry(&self) {
+		self.retries.fetch_add(1, Ordering::Relaxed);
+	}
+
+	/// Take a snapshot of the current counter values.
+	pub(crate) fn snapshot(&self) -> ConflictStats {
+		ConflictStats {
+			commits: self.commits.load(Ordering::Relaxed),
+			conflicts: self.conflicts.load(Ordering::Relaxed),
+			retries: self.retries.load(Ordering::Relaxed),
+		}
+	}
+}
+
+/// Check whether an error was caused by a retryable transaction conflict.
+pub(crate) fn is_conflict(err: &anyhow::Error) -> bool {
+	if let Some(e) = err.downcast_ref::<crate::kvs::Error>() {
+		return e.is_retryable();
+	}
+	matches!(
+		err.downcast_ref::<crate::err::Error>(),
+		Some(crate::err::Error::Kvs(e)) if e.is_retryable()
+	)
+}
+
+/// The type of a statement, used to label the statement metrics.
+///
+/// Each type indexes a fixed slot of the [`QueryCounters`], so recording a
+/// statement never needs to take a lock.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum StatementType {
+	Access,
+	Alter,
+	Begin,
+	Cancel,
+	Commit,
+	Create,
+	Define,
+	Delete,
+	Explain,
+	Expression,
+	Foreach,
+	IfElse,
+	Info,
+	Insert,
+	Kill,
+	Let,
+	Live,
+	Option,
+	Rebuild,
+	Relate,
+	Remove,
+	Return,
+	Select,
+	Show,
+	Sleep,
+	Throw,
+	Update,
+	Upsert,
+	Use,
+}
+
+impl StatementType {
+	/// Every statement type, in the order of their labels
+	const ALL: [StatementType; 29] = [
+		Self::Access,
+		Self::Alter,
+		Self::Begin,
+		Self::Cancel,
+		Self::Commit,
+		Self::Create,
+		Self::Define,
+		Self::Delete,
+		Self::Explain,
+		Self::Expression,
+		Self::Foreach,
+		Self::IfElse,
+		Self::Info,
+		Self::Insert,
+		Self::Kill,
+		Self::Let,
+		Self::Live,
+		Self::Option,
+		Self::Rebuild,
+		Self::Relate,
+		Self::Remove,
+		Self::Return,
+		Self::Select,
+		Self::Show,
+		Self::Sleep,
+		Self::Throw,
+		Self::Update,
+		Self::Upsert,
+		Self::Use,
+	];
+
+	/// The label of the statement type in the metrics
+	fn as_str(&self) -> &'static str {
+		match self {
+			Self::Access => "access",
+			Self::Alter => "alter",
+			Self::Begin => "begin",
+			Self::Cancel => "cancel",
+			Self::Commit => "commit",
+			Self::Create => "create",
+			Self::Define => "define",
+			Self::Delete => "delete",
+			Self::Explain => "explain",
+			Self::Expression => "expression",
+			Self::Foreach => "for",
+			Self::IfElse => "if",
+			Self::Info => "info",
+			Self::Insert => "insert",
+			Self::Kill => "kill",
+			Self::Let => "let",
+			Self::Live => "live",
+			Self::Option => "option",
+			Self::Rebuild => "rebuild",
+			Self::Relate => "relate",
+			Self::Remove => "remove",
+			Self::Return => "return",
+			Self::Select => "select",
+			Self::Show => "show",
+			Self::Sleep => "sleep",
+			Self::Throw => "throw",
+			Self::Update => "update",
+			Self::Upsert => "upsert",
+			Self::Use => "use",
+		}
+	}
+}
+
+/// The atomic counters of the statements of a type which were executed,
+/// and which failed.
+#[derive(Debug, Default)]
+struct StatementCounters {
+	executed: AtomicU64,
+	errors: AtomicU64,
+}
+
+/// The counters for the statements executed, and the open transactions.
+#[derive(Debug, Default)]
+pub(crate) struct QueryCounters {
+	statements: [StatementCounters; StatementType::ALL.len()],
+	active_transactions: Arc<AtomicU64>,
+}
+
+impl QueryCounters {
+	/// Record that a statement of the given type was executed.
+	pub(crate) fn record_statement(&self, kind: StatementType, ok: bool) {
+		let counters = &self.statements[kind as usize];
+		counters.executed.fetch_add(1, Ordering::Relaxed);
+		if !ok {
+			counters.errors.fetch_add(1, Ordering::Relaxed);
+		}
+	}
+
+	/// Count a transaction as open until the returned guard is dropped.
+	pub(crate) fn track_transaction(&self) -> ActiveTransaction {
+		self.active_transactions.fetch_add(1, Ordering::Relaxed);
+		ActiveTransaction(self.active_transactions.clone())
+	}
+}
+
+/// Counts a transaction as open for as long as it is held.
+#[derive(Debug)]
+pub(crate) struct ActiveTransaction(Arc<AtomicU64>);
+
+impl Drop for ActiveTransaction {
+	fn drop(&mut self) {
+		self.0.fetch_sub(1, Ordering::Relaxed);
+	}
+}
+
+/// The counters of the reads made by a transaction, for the metrics of the
+/// statements run in it.
+#[derive(Debug, Default)]
+pub(crate) struct ReadCounters {
+	records: AtomicU64,
+	bytes: AtomicU64,
+	index_used: AtomicBool,
+}
+
+impl ReadCounters {
+	/// Record that records were read from the datastore.
+	pub(crate) fn records_scanned(&self, count: usize) {
+		self.records.fetch_add(count as u64, Ordering::Relaxed);
+	}
+
+	/// Record that bytes were read from the datastore.
+	pub(crate) fn bytes_read(&self, count: usize) {
+		self.bytes.fetch_add(count as u64, Ordering::Relaxed);
+	}
+
+	/// Record that records were read through an index.
+	pub(crate) fn index_used(&self) {
+		self.index_used.store(true, Ordering::Relaxed);
+	}
+
+	/// Take the counts since this was last called, resetting the counters.
+	pub(crate) fn take(&self) -> QueryMetrics {
+		QueryMetrics {
+			records_scanned: self.records.swap(0, Ordering::Relaxed),
+			records_returned: 0,
+			index_used: self.index_used.swap(false, Ordering::Relaxed),
+			bytes_read: self.bytes.swap(0, Ordering::Relaxed),
+		}
+	}
+}
+
+/// The counters of a datastore which are not held by [`QueryCounters`].
+pub(crate) struct OtherCounters {
+	pub(crate) conflicts: ConflictStats,
+	pub(crate) cache_hits: u64,
+	pub(crate) cache_misses: u64,
+	pub(crate) slow_queries: u64,
+	pub(crate) result_cache_hits: u64,
+	pub(crate) result_cache_misses: u64,
+}
+
+/// Renders the counters in the Prometheus text exposition format.
+pub(crate) fn render_prometheus(queries: &QueryCounters, other: OtherCounters) -> String {
+	// Only the statement types which have been executed are rendered
+	let statements: Vec<_> = StatementType::ALL
+		.iter()
+		.map(|kind| {
+			let counters = &queries.statements[*kind as usize];
+			let executed = counters.executed.load(Ordering::Relaxed);
+			let errors = counters.errors.load(Ordering::Relaxed);
+			(kind.as_str(), executed, errors)
+		})
+		.filter(|(_, executed, _)| *executed > 0)
+		.collect();
+	let mut out = String::new();
+	write_metric(
+		&mut out,
+		"surrealdb_statements_total",
+		"counter",
+		"The number of statements executed.",
+		statements.iter().map(|(kind, executed, _)| (Some(*kind), *executed)),
+	);
+	write_metric(
+		&mut out,
+		"surrealdb_statement_errors_total",
+		"counter",
+		"The number of statements which returned an error.",
+		statements
+			.iter()
+			.filter(|(_, _, errors)| *errors > 0)
+			.map(|(kind, _, errors)| (Some(*kind), *errors)),
+	);
+	write_metric(
+		&mut out,
+		"surrealdb_transactions_active",
+		"gauge",
+		"The number of transactions which are currently open.",
+		[(None, queries.active_transactions.load(Ordering::Relaxed))],
+	);
+	write_metric(
+		&mut out,
+		"surrealdb_transaction_commits_total",
+		"counter",
+		"The number of transactions which committed successfully.",
+		[(None, other.conflicts.commits)],
+	);
+	write_metric(
+		&mut out,
+		"surrealdb_transaction_conflicts_total",
+		"counter",
+		"The number of transactions which failed to commit due to a conflict.",
+		[(None, other.conflicts.conflicts)],
+	);
+	write_metric(
+		&mut out,
+		"surrealdb_transaction_retries_total",
+		"counter",
+		"The number of times a conflicted operation was retried.",
+		[(None, other.conflicts.retries)],
+	);
+	write_metric(
+		&mut out,
+		"surrealdb_cache_hits_total",
+		"counter",
+		"The number of lookups which were found in the datastore cache.",
+		[(None, other.cache_hits)],
+	);
+	write_metric(
+		&mut out,
+		"surrealdb_cache_misses_total",
+		"counter",
+		"The number of lookups which were not found in the datastore cache.",
+		[(None, other.cache_misses)],
+	);
+	write_metric(
+		&mut out,
+		"surrealdb_slow_queries_total",
+		"counter",
+		"The number of statements which were logged as slow queries.",
+		[(None, other.slow_queries)],
+	);
+	write_metric(
+		&mut out,
+		"surrealdb_result_cache_hits_total",
+		"counter",
+		"The number of statements which returned a cached result.",
+		[(None, other.result_cache_hits)],
+	);
+	write_metric(
+		&mut out,
+		"surrealdb_result_cache_misses_total",
+		"counter",
+		"The number of cacheable statements which had no valid cached result.",
+		[(None, other.result_cache_misses)],
+	);
+	out
+}
+
+/// Writes the help and type lines of a metric, followed by its samples,
+/// which are labelled with the statement type when one is given.
+fn write_metric(
+	out: &mut String,
+	name: &str,
+	kind: &str,
+	help: &str,
+	samples: impl IntoIterator<Item = (Option<&'static str>, u64)>,
+) {
+	// Writing into a string cannot fail
+	let _ = writeln!(out, "# HELP {name} {help}");
+	let _ = writeln!(out, "# TYPE {name} {kind}");
+	for (label, value) in samples {
+		let _ = match label {
+			Some(label) => writeln!(out, "{name}{{type=\"{label}\"}} {value}"),
+			None => writeln!(out, "{name} {value}"),
+		};
+	}
+}
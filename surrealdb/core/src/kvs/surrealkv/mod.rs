@@ -34,6 +34,8 @@ pub struct Datastore {
 	db: Tree,
 	/// Whether the datastore supports transaction versioning
 	versioned: bool,
+	/// The period for which historical versions are retained, if limited
+	retention: Option<Duration>,
 	/// Commit coordinator for batching transaction commits when sync=every
 	commit_coordinator: Option<Arc<CommitCoordinator>>,
 	/// Background flusher for periodically flushing WAL when sync=<interval>
@@ -120,11 +122,18 @@ impl Datastore {
 		Ok(Datastore {
 			db,
 			versioned: config.versioned,
+			retention: (config.versioned && config.retention_ns > 0)
+				.then(|| Duration::from_nanos(config.retention_ns)),
 			commit_coordinator,
 			background_flusher,
 		})
 	}
 
+	/// The period for which historical versions are retained, if limited
+	pub(crate) fn version_retention(&self) -> Option<Duration> {
+		self.retention
+	}
+
 	/// Shutdown the database
 	pub(crate) async fn shutdown(&self) -> Result<()> {
 		// Wait for the background flusher to finish
@@ -175,6 +184,21 @@ impl Transactable for Transaction {
 		"surrealkv"
 	}
 
+	/// Get the version of the engine
+	fn version(&self) -> Option<&'static str> {
+		option_env!("SURREAL_ENGINE_VERSION_SURREALKV")
+	}
+
+	/// Check if savepoints are supported
+	fn supports_savepoints(&self) -> bool {
+		true
+	}
+
+	/// Check if versioned queries are supported
+	fn supports_versioning(&self) -> bool {
+		self.versioned
+	}
+
 	/// Check if closed
 	fn closed(&self) -> bool {
 		self.done.load(Ordering::Relaxed)
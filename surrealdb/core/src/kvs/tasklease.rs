@@ -27,6 +27,8 @@ pub(crate) enum TaskLeaseType {
 	IndexCompaction,
 	/// Event processing
 	EventProcessing,
+	/// Task for purging expired records from tables with a TTL
+	TtlSweep,
 }
 
 /// Represents a distributed task lease stored in the datastore.
@@ -0,0 +1,45 @@
+#![cfg(feature = "kv-tikv")]
+
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use super::CreateDs;
+use crate::kvs::LockType::*;
+use crate::kvs::TransactionType::*;
+
+pub async fn lock_timeout(new_ds: impl CreateDs) {
+	// Create a new datastore
+	let node_id = Uuid::parse_str("4f4c1b0e-5b2a-4d3f-9c6e-7a1d2e3f4a5b").unwrap();
+	let (ds, _) = new_ds.create_ds(node_id).await;
+	let ds = ds.with_lock_timeout(Some(Duration::from_millis(500)));
+	// Insert an initial key
+	let tx = ds.transaction(Write, Optimistic).await.unwrap();
+	tx.set(&"test", &"some text".as_bytes().to_vec()).await.unwrap();
+	tx.commit().await.unwrap();
+	// Lock the key in a pessimistic transaction
+	let tx1 = ds.transaction(Write, Pessimistic).await.unwrap();
+	tx1.set(&"test", &"other text 1".as_bytes().to_vec()).await.unwrap();
+	// A second writer times out waiting for the lock
+	let tx2 = ds.transaction(Write, Pessimistic).await.unwrap();
+	let err = tx2.set(&"test", &"other text 2".as_bytes().to_vec()).await.unwrap_err();
+	assert!(err.to_string().contains("waiting to acquire a lock"), "{err}");
+	tx2.cancel().await.unwrap();
+	// The first writer still commits
+	tx1.commit().await.unwrap();
+	let tx = ds.transaction(Read, Optimistic).await.unwrap();
+	let val = tx.get(&"test", None).await.unwrap().unwrap();
+	assert_eq!(val, b"other text 1");
+	tx.cancel().await.unwrap();
+}
+
+macro_rules! define_tests {
+	($new_ds:ident) => {
+		#[tokio::test]
+		#[serial_test::serial]
+		async fn lock_timeout() {
+			super::lock_timeout::lock_timeout($new_ds).await;
+		}
+	};
+}
+pub(crate) use define_tests;
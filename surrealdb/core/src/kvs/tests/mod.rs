@@ -20,6 +20,8 @@ macro_rules! include_tests {
 	};
 }
 
+#[cfg(feature = "kv-tikv")]
+mod lock_timeout;
 #[cfg(feature = "kv-rocksdb")]
 mod metrics;
 
@@ -33,6 +35,7 @@ mod read_and_deletion_only;
 mod snapshot;
 #[cfg(feature = "kv-mem")]
 mod tx_cache_test;
+mod versioning;
 
 #[derive(Clone, Debug)]
 pub(crate) enum Kvs {
@@ -87,6 +90,7 @@ mod mem {
 		multireader,
 		multiwriter_different_keys,
 		multiwriter_same_keys_conflict,
+		versioning,
 	);
 }
 
@@ -120,7 +124,8 @@ mod rocksdb {
 		multiwriter_different_keys,
 		multiwriter_same_keys_conflict,
 		read_and_deletion_only,
-		metrics
+		metrics,
+		versioning,
 	);
 }
 
@@ -153,6 +158,7 @@ mod surrealkv {
 		multireader,
 		multiwriter_different_keys,
 		multiwriter_same_keys_conflict,
+		versioning,
 	);
 }
 
@@ -187,5 +193,7 @@ mod tikv {
 		multireader,
 		multiwriter_different_keys,
 		multiwriter_same_keys_allow,
+		lock_timeout,
+		versioning,
 	);
 }
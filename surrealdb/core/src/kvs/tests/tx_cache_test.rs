@@ -95,6 +95,8 @@ async fn test_single_tx_cache_invalidation_on_index_put() {
 		index: Index::Idx,
 		comment: None,
 		prepare_remove: false,
+		cond: None,
+		rebuilding: None,
 	};
 	tx.put_tb_index(ns, db, &tb, &ix_def).await.unwrap();
 
@@ -128,6 +130,8 @@ async fn test_single_tx_cache_invalidation_on_index_delete() {
 		index: Index::Idx,
 		comment: None,
 		prepare_remove: false,
+		cond: None,
+		rebuilding: None,
 	};
 	tx.put_tb_index(ns, db, &tb, &ix_def).await.unwrap();
 
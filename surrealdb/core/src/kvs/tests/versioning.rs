@@ -0,0 +1,34 @@
+use uuid::Uuid;
+
+use super::{CreateDs, Kvs};
+use crate::types::PublicDatetime;
+
+pub async fn snapshot_at_requires_versioning(new_ds: impl CreateDs) {
+	// Create a new datastore
+	let node_id = Uuid::parse_str("8b2e7c41-3d5f-4a96-b1c8-0e9f2a7d6c35").unwrap();
+	let (ds, kvs) = new_ds.create_ds(node_id).await;
+	// Open a snapshot of the datastore
+	let res = ds.snapshot_at(PublicDatetime::now()).await;
+	match kvs {
+		// The memory datastore always keeps historical versions
+		Kvs::Mem => {
+			res.unwrap().cancel().await.unwrap();
+		}
+		// The other test datastores are not created with versioning enabled
+		Kvs::Rocksdb | Kvs::SurrealKV | Kvs::Tikv => {
+			let err = res.err().expect("snapshot should be rejected");
+			assert!(err.to_string().contains("does not support versioned queries"), "{err}");
+		}
+	}
+}
+
+macro_rules! define_tests {
+	($new_ds:ident) => {
+		#[tokio::test]
+		#[serial_test::serial]
+		async fn snapshot_at_requires_versioning() {
+			super::versioning::snapshot_at_requires_versioning($new_ds).await;
+		}
+	};
+}
+pub(crate) use define_tests;
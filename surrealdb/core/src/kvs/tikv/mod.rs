@@ -151,6 +151,21 @@ impl Transactable for Transaction {
 		"tikv"
 	}
 
+	/// Get the version of the engine
+	fn version(&self) -> Option<&'static str> {
+		option_env!("SURREAL_ENGINE_VERSION_TIKV")
+	}
+
+	/// Check if savepoints are supported
+	fn supports_savepoints(&self) -> bool {
+		true
+	}
+
+	/// Check if pessimistic locking is supported
+	fn supports_pessimistic_locking(&self) -> bool {
+		true
+	}
+
 	/// Check if closed
 	fn closed(&self) -> bool {
 		self.done.load(Ordering::Relaxed)
@@ -1,13 +1,15 @@
 use std::fmt;
 use std::fmt::Debug;
+use std::future::Future;
 use std::ops::Range;
+use std::time::Duration;
 
 use futures::stream::Stream;
 
 use super::api::{ScanLimit, Transactable};
 use super::batch::Batch;
 use super::scanner::{Direction, Scanner};
-use super::{IntoBytes, Key, Result, Val};
+use super::{Error, IntoBytes, Key, Result, Val};
 use crate::kvs::timestamp::{BoxTimeStamp, BoxTimeStampImpl};
 
 /// Specifies whether the transaction is read-only or writeable.
@@ -37,6 +39,8 @@ impl From<bool> for LockType {
 pub struct Transactor {
 	// The underlying transaction
 	pub(super) inner: Box<dyn Transactable>,
+	// The maximum duration a write waits to acquire a lock
+	pub(super) lock_timeout: Option<Duration>,
 }
 
 impl fmt::Display for Transactor {
@@ -64,6 +68,38 @@ impl Transactor {
 		self.inner.kind()
 	}
 
+	/// Get the version of the underlying datastore, if it is known.
+	pub(super) fn version(&self) -> Option<&'static str> {
+		self.inner.version()
+	}
+
+	/// Check if the underlying datastore supports savepoints.
+	pub(super) fn supports_savepoints(&self) -> bool {
+		self.inner.supports_savepoints()
+	}
+
+	/// Check if the underlying datastore supports pessimistic locking.
+	pub(super) fn supports_pessimistic_locking(&self) -> bool {
+		self.inner.supports_pessimistic_locking()
+	}
+
+	/// Check if the underlying datastore supports versioned queries.
+	pub(super) fn supports_versioning(&self) -> bool {
+		self.inner.supports_versioning()
+	}
+
+	/// Run a write which may wait for a lock held by another transaction,
+	/// failing once it has waited longer than the lock timeout.
+	async fn locking<T>(&self, write: impl Future<Output = Result<T>>) -> Result<T> {
+		match self.lock_timeout {
+			Some(timeout) => match tokio::time::timeout(timeout, write).await {
+				Ok(res) => res,
+				Err(_) => Err(Error::LockTimeout(timeout)),
+			},
+			None => write.await,
+		}
+	}
+
 	/// Check if transaction is finished.
 	///
 	/// If the transaction has been cancelled or committed,
@@ -170,7 +206,7 @@ impl Transactor {
 	{
 		let key = key.into_vec();
 		let val = val.into_vec();
-		self.inner.set(key, val).await
+		self.locking(self.inner.set(key, val)).await
 	}
 
 	/// Insert or replace a key in the datastore.
@@ -182,7 +218,7 @@ impl Transactor {
 	{
 		let key = key.into_vec();
 		let val = val.into_vec();
-		self.inner.replace(key, val).await
+		self.locking(self.inner.replace(key, val)).await
 	}
 
 	/// Insert a key if it doesn't exist in the datastore.
@@ -194,7 +230,7 @@ impl Transactor {
 	{
 		let key = key.into_vec();
 		let val = val.into_vec();
-		self.inner.put(key, val).await
+		self.locking(self.inner.put(key, val)).await
 	}
 
 	/// Update a key in the datastore if the current value matches a condition.
@@ -207,7 +243,7 @@ impl Transactor {
 		let key = key.into_vec();
 		let val = val.into_vec();
 		let chk = chk.map(|v| v.into_vec());
-		self.inner.putc(key, val, chk).await
+		self.locking(self.inner.putc(key, val, chk)).await
 	}
 
 	/// Delete a key from the datastore.
@@ -217,7 +253,7 @@ impl Transactor {
 		K: IntoBytes + Debug,
 	{
 		let key = key.into_vec();
-		self.inner.del(key).await
+		self.locking(self.inner.del(key)).await
 	}
 
 	/// Delete a key from the datastore if the current value matches a
@@ -230,7 +266,7 @@ impl Transactor {
 	{
 		let key = key.into_vec();
 		let chk = chk.map(|v| v.into_vec());
-		self.inner.delc(key, chk).await
+		self.locking(self.inner.delc(key, chk)).await
 	}
 
 	/// Delete a prefixed range of keys from the datastore.
@@ -243,7 +279,7 @@ impl Transactor {
 		K: IntoBytes + Debug,
 	{
 		let key = key.into_vec();
-		self.inner.delp(key).await
+		self.locking(self.inner.delp(key)).await
 	}
 
 	/// Delete a range of keys from the datastore.
@@ -257,7 +293,7 @@ impl Transactor {
 	{
 		let beg = rng.start.into_vec();
 		let end = rng.end.into_vec();
-		self.inner.delr(beg..end).await
+		self.locking(self.inner.delr(beg..end)).await
 	}
 
 	/// Delete all versions of a key from the datastore.
@@ -267,7 +303,7 @@ impl Transactor {
 		K: IntoBytes + Debug,
 	{
 		let key = key.into_vec();
-		self.inner.clr(key).await
+		self.locking(self.inner.clr(key)).await
 	}
 
 	/// Delete all versions of a key from the datastore if the current value
@@ -280,7 +316,7 @@ impl Transactor {
 	{
 		let key = key.into_vec();
 		let chk = chk.map(|v| v.into_vec());
-		self.inner.clrc(key, chk).await
+		self.locking(self.inner.clrc(key, chk)).await
 	}
 
 	/// Delete all versions of a prefixed range of keys from the datastore.
@@ -293,7 +329,7 @@ impl Transactor {
 		K: IntoBytes + Debug,
 	{
 		let key = key.into_vec();
-		self.inner.clrp(key).await
+		self.locking(self.inner.clrp(key)).await
 	}
 
 	/// Delete all versions of a range of keys from the datastore.
@@ -307,7 +343,7 @@ impl Transactor {
 	{
 		let beg = rng.start.into_vec();
 		let end = rng.end.into_vec();
-		self.inner.clrr(beg..end).await
+		self.locking(self.inner.clrr(beg..end)).await
 	}
 
 	// --------------------------------------------------
@@ -1,9 +1,10 @@
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fmt::Debug;
 use std::ops::{Deref, Range};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 use anyhow::Result;
 use futures::TryStreamExt;
@@ -23,6 +24,7 @@ use crate::catalog::{
 	NamespaceDefinition, NamespaceId, Record, TableDefinition, TableId,
 };
 use crate::ctx::Context;
+use crate::dbs::QueryMetrics;
 use crate::dbs::node::Node;
 use crate::doc::CursorRecord;
 use crate::err::Error;
@@ -30,8 +32,10 @@ use crate::idx::planner::ScanDirection;
 use crate::key::database::sq::Sq;
 use crate::kvs::cache::tx::TransactionCache;
 use crate::kvs::index::{BatchId, BatchIdsCleanQueue, SharedIndexKey};
+use crate::kvs::result_cache::ResultCache;
 use crate::kvs::scanner::Direction;
 use crate::kvs::sequences::Sequences;
+use crate::kvs::stats::{ActiveTransaction, ReadCounters};
 use crate::kvs::{BoxTimeStamp, BoxTimeStampImpl, KVKey, KVValue, Transactor, cache};
 use crate::val::{RecordId, RecordIdKey, TableName};
 
@@ -69,6 +73,17 @@ pub struct Transaction {
 	/// Per index, track the pending append batch for cleanup after rollback (cancel or failed
 	/// commit).
 	pending_index_batches: Mutex<HashMap<SharedIndexKey, (BatchId, BatchIdsCleanQueue)>>,
+	/// The records written since they were last taken, when tracked for
+	/// audit events.
+	written: Option<parking_lot::Mutex<Vec<RecordId>>>,
+	/// The reads made since they were last taken, when tracked for statement
+	/// metrics.
+	reads: Option<ReadCounters>,
+	/// Counts this transaction as open in the datastore metrics, if tracked.
+	active: Option<ActiveTransaction>,
+	/// The result cache to invalidate when this transaction commits, and the
+	/// tables which have had records written, when a result cache is used.
+	result_cache: Option<(Arc<ResultCache>, parking_lot::Mutex<BTreeSet<TableName>>)>,
 }
 
 impl Deref for Transaction {
@@ -96,6 +111,95 @@ impl Transaction {
 			async_event_trigger,
 			trigger_async_event: AtomicBool::new(false),
 			pending_index_batches: Mutex::new(HashMap::new()),
+			written: None,
+			reads: None,
+			active: None,
+			result_cache: None,
+		}
+	}
+
+	/// Invalidate the cached results which read the tables this transaction
+	/// writes records to, when it commits.
+	pub(crate) fn with_result_cache(mut self, cache: Arc<ResultCache>) -> Self {
+		self.result_cache = Some((cache, Default::default()));
+		self
+	}
+
+	/// Fail writes which wait longer than the timeout to acquire a lock, when
+	/// the storage engine supports pessimistic locking.
+	pub(crate) fn with_lock_timeout(mut self, timeout: Duration) -> Self {
+		if self.tr.supports_pessimistic_locking() {
+			self.tr.lock_timeout = Some(timeout);
+		}
+		self
+	}
+
+	/// Count this transaction as open until it is dropped.
+	pub(crate) fn with_active(mut self, active: ActiveTransaction) -> Self {
+		self.active = Some(active);
+		self
+	}
+
+	/// Track the records written in this transaction, for audit events and
+	/// COMMIT summaries.
+	pub(crate) fn with_written_records(mut self) -> Self {
+		self.written = Some(Default::default());
+		self
+	}
+
+	/// Takes the records written since this was last called, if the written
+	/// records are tracked.
+	pub(crate) fn take_written_records(&self) -> Vec<RecordId> {
+		match &self.written {
+			Some(written) => std::mem::take(&mut *written.lock()),
+			None => Vec::new(),
+		}
+	}
+
+	/// Count the reads made in this transaction, for statement metrics.
+	pub(crate) fn with_read_metrics(mut self) -> Self {
+		self.reads = Some(Default::default());
+		self
+	}
+
+	/// Takes the counts of the reads made since this was last called, if the
+	/// reads are counted.
+	pub(crate) fn take_read_metrics(&self) -> Option<QueryMetrics> {
+		self.reads.as_ref().map(ReadCounters::take)
+	}
+
+	/// Records that records were read, if the reads are counted.
+	pub(crate) fn record_scanned(&self, count: usize) {
+		if let Some(reads) = &self.reads {
+			reads.records_scanned(count);
+		}
+	}
+
+	/// Records that records were read through an index, if the reads are
+	/// counted.
+	pub(crate) fn record_index_used(&self) {
+		if let Some(reads) = &self.reads {
+			reads.index_used();
+		}
+	}
+
+	/// Records that bytes were read, if the reads are counted.
+	fn record_bytes_read(&self, count: usize) {
+		if let Some(reads) = &self.reads {
+			reads.bytes_read(count);
+		}
+	}
+
+	/// Records that a record was written, if the written records are tracked.
+	fn record_written(&self, tb: &TableName, id: &RecordIdKey) {
+		if let Some((_, tables)) = &self.result_cache {
+			tables.lock().insert(tb.clone());
+		}
+		if let Some(written) = &self.written {
+			written.lock().push(RecordId {
+				table: tb.clone(),
+				key: id.clone(),
+			});
 		}
 	}
 
@@ -115,6 +219,31 @@ impl Transaction {
 		Arc::new(self)
 	}
 
+	/// Get the name of the underlying storage engine
+	pub(crate) fn engine(&self) -> &'static str {
+		self.tr.kind()
+	}
+
+	/// Get the version of the underlying storage engine, if it is known
+	pub(crate) fn engine_version(&self) -> Option<&'static str> {
+		self.tr.version()
+	}
+
+	/// Check if the underlying storage engine supports savepoints
+	pub(crate) fn supports_savepoints(&self) -> bool {
+		self.tr.supports_savepoints()
+	}
+
+	/// Check if the underlying storage engine supports pessimistic locking
+	pub(crate) fn supports_pessimistic_locking(&self) -> bool {
+		self.tr.supports_pessimistic_locking()
+	}
+
+	/// Check if the underlying storage engine supports versioned queries
+	pub(crate) fn supports_versioning(&self) -> bool {
+		self.tr.supports_versioning()
+	}
+
 	/// Check if the transaction is finished.
 	///
 	/// If the transaction has been cancelled or committed,
@@ -125,6 +254,15 @@ impl Transaction {
 		self.tr.closed()
 	}
 
+	/// Check if the transaction is writeable.
+	///
+	/// If the transaction was created as read-only, then
+	/// any attempt to modify data within the transaction
+	/// will result in a [`crate::kvs::Error::TransactionReadonly`] error.
+	pub fn writeable(&self) -> bool {
+		self.tr.writeable()
+	}
+
 	/// Cancel a transaction.
 	///
 	/// This reverses all changes made within the transaction.
@@ -161,6 +299,10 @@ impl Transaction {
 			// Notify after commit so queued events are visible to workers.
 			self.async_event_trigger.notify_one();
 		}
+		// Invalidate the cached results which read the written tables
+		if let Some((cache, tables)) = &self.result_cache {
+			cache.records_written(tables.lock().iter());
+		}
 		Ok(())
 	}
 
@@ -194,6 +336,9 @@ impl Transaction {
 	{
 		let key = key.encode_key()?;
 		let val = self.tr.get(key, version).await.map_err(Error::from)?;
+		if let Some(val) = &val {
+			self.record_bytes_read(val.len());
+		}
 		val.map(K::ValueType::kv_decode_value).transpose()
 	}
 
@@ -208,11 +353,9 @@ impl Transaction {
 		K: KVKey + Debug,
 	{
 		let keys = keys.iter().map(|k| k.encode_key()).collect::<Result<Vec<_>>>()?;
-		self.tr
-			.getm(keys, version)
-			.await
-			.map_err(Error::from)?
-			.into_iter()
+		let vals = self.tr.getm(keys, version).await.map_err(Error::from)?;
+		self.record_bytes_read(vals.iter().flatten().map(Vec::len).sum());
+		vals.into_iter()
 			.map(|v| match v {
 				Some(v) => K::ValueType::kv_decode_value(v).map(Some),
 				None => Ok(None),
@@ -230,13 +373,9 @@ impl Transaction {
 		K: KVKey + Debug,
 	{
 		let key = key.encode_key()?;
-		self.tr
-			.getp(key, version)
-			.await
-			.map_err(Error::from)?
-			.into_iter()
-			.map(|(k, v)| Ok((k, K::ValueType::kv_decode_value(v)?)))
-			.collect()
+		let vals = self.tr.getp(key, version).await.map_err(Error::from)?;
+		self.record_bytes_read(vals.iter().map(|(_, v)| v.len()).sum());
+		vals.into_iter().map(|(k, v)| Ok((k, K::ValueType::kv_decode_value(v)?))).collect()
 	}
 
 	/// Retrieve a specific range of keys from the datastore.
@@ -254,13 +393,9 @@ impl Transaction {
 	{
 		let beg = rng.start.encode_key()?;
 		let end = rng.end.encode_key()?;
-		self.tr
-			.getr(beg..end, version)
-			.await
-			.map_err(Error::from)?
-			.into_iter()
-			.map(|(k, v)| Ok((k, K::ValueType::kv_decode_value(v)?)))
-			.collect()
+		let vals = self.tr.getr(beg..end, version).await.map_err(Error::from)?;
+		self.record_bytes_read(vals.iter().map(|(_, v)| v.len()).sum());
+		vals.into_iter().map(|(k, v)| Ok((k, K::ValueType::kv_decode_value(v)?))).collect()
 	}
 
 	/// Fetch many records by ID in a single batch, with cache awareness.
@@ -579,7 +714,9 @@ impl Transaction {
 		let beg = rng.start.encode_key()?;
 		let end = rng.end.encode_key()?;
 		let limit = limit.into();
-		Ok(self.tr.scan(beg..end, limit, skip, version).await.map_err(Error::from)?)
+		let vals = self.tr.scan(beg..end, limit, skip, version).await.map_err(Error::from)?;
+		self.record_bytes_read(vals.iter().map(|(_, v)| v.len()).sum());
+		Ok(vals)
 	}
 
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip_all)]
@@ -596,7 +733,9 @@ impl Transaction {
 		let beg = rng.start.encode_key()?;
 		let end = rng.end.encode_key()?;
 		let limit = limit.into();
-		Ok(self.tr.scanr(beg..end, limit, skip, version).await.map_err(Error::from)?)
+		let vals = self.tr.scanr(beg..end, limit, skip, version).await.map_err(Error::from)?;
+		self.record_bytes_read(vals.iter().map(|(_, v)| v.len()).sum());
+		Ok(vals)
 	}
 
 	/// Count the total number of keys within a range in the datastore.
@@ -720,6 +859,7 @@ impl Transaction {
 			)
 			.map_err(Error::from)
 			.map_err(Into::into)
+			.inspect_ok(|batch| self.record_bytes_read(batch.iter().map(|(_, v)| v.len()).sum()))
 	}
 
 	// --------------------------------------------------
@@ -2341,6 +2481,7 @@ impl TableProvider for Transaction {
 						key: id.clone(),
 					};
 					record.data.def(rid);
+					self.record_scanned(1);
 					// Convert to read-only format for better sharing and performance
 					Ok(record.into_read_only())
 				}
@@ -2351,7 +2492,10 @@ impl TableProvider for Transaction {
 			let qey = cache::tx::Lookup::Record(ns, db, tb, id);
 			match self.cache.get(&qey) {
 				// The entry is in the cache
-				Some(val) => val.try_into_record(),
+				Some(val) => {
+					self.record_scanned(1);
+					val.try_into_record()
+				}
 				// The entry is not in the cache
 				None => {
 					// Fetch the record from the datastore
@@ -2365,6 +2509,7 @@ impl TableProvider for Transaction {
 								key: id.clone(),
 							};
 							record.data.def(rid);
+							self.record_scanned(1);
 							// Convert to read-only format for better sharing and performance
 							let record = record.into_read_only();
 							let entry = cache::tx::Entry::Val(record.clone());
@@ -2407,6 +2552,7 @@ impl TableProvider for Transaction {
 		}
 		self.put(&key, &val).await?;
 		self.set_record_cache(ns, db, tb, id, record);
+		self.record_written(tb, id);
 		Ok(())
 	}
 
@@ -2428,6 +2574,7 @@ impl TableProvider for Transaction {
 		self.set(&key, &val).await?;
 		// Set the value in the cache
 		self.set_record_cache(ns, db, tb, id, record);
+		self.record_written(tb, id);
 		// Return nothing
 		Ok(())
 	}
@@ -2446,6 +2593,7 @@ impl TableProvider for Transaction {
 		// Clear the value from the cache
 		let qey = cache::tx::Lookup::Record(ns, db, tb, id);
 		self.cache.remove(qey);
+		self.record_written(tb, id);
 		// Return nothing
 		Ok(())
 	}
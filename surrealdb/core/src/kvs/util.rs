@@ -42,6 +42,16 @@ pub fn advance_key(key: &mut [u8]) {
 	}
 }
 
+/// Returns the first key which sorts after every key starting with the
+/// prefix, or `None` if there is no such key, because the prefix is empty or
+/// only contains `0xff` bytes.
+pub fn prefix_end(prefix: &[u8]) -> Option<Vec<u8>> {
+	let len = prefix.iter().rposition(|b| *b != 0xff)?;
+	let mut end = prefix[..=len].to_vec();
+	end[len] += 1;
+	Some(end)
+}
+
 pub fn to_prefix_range<K: KVKey>(key: K) -> Result<Range<Vec<u8>>> {
 	let start = key.encode_key()?;
 	let mut end = start.clone();
@@ -65,3 +75,18 @@ where
 	}
 	Ok(Arc::from(buf))
 }
+
+#[cfg(test)]
+mod tests {
+	use super::prefix_end;
+
+	#[test]
+	fn prefix_end_skips_every_key_with_the_prefix() {
+		assert_eq!(prefix_end(b"/*a"), Some(b"/*b".to_vec()));
+		// Trailing 0xff bytes can not be incremented, so they are dropped
+		assert_eq!(prefix_end(&[0x2f, 0x01, 0xff, 0xff]), Some(vec![0x2f, 0x02]));
+		// Some prefixes cover the end of the keyspace
+		assert_eq!(prefix_end(&[]), None);
+		assert_eq!(prefix_end(&[0xff, 0xff]), None);
+	}
+}
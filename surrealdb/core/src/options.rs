@@ -27,6 +27,10 @@ pub struct EngineOptions {
 	///
 	/// Default: 5 seconds
 	pub event_processing_interval: Duration,
+	/// Interval for purging expired records from tables with a TTL.
+	///
+	/// Default: 60 seconds
+	pub ttl_sweep_interval: Duration,
 }
 
 impl Default for EngineOptions {
@@ -38,6 +42,7 @@ impl Default for EngineOptions {
 			changefeed_gc_interval: Duration::from_secs(30),
 			index_compaction_interval: Duration::from_secs(5),
 			event_processing_interval: Duration::from_secs(5),
+			ttl_sweep_interval: Duration::from_secs(60),
 		}
 	}
 }
@@ -69,4 +74,9 @@ impl EngineOptions {
 		self.event_processing_interval = interval;
 		self
 	}
+
+	pub fn with_ttl_sweep_interval(mut self, interval: Duration) -> Self {
+		self.ttl_sweep_interval = interval;
+		self
+	}
 }
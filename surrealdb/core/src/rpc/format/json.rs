@@ -1,5 +1,8 @@
 use std::collections::BTreeMap;
 
+use chrono::{DateTime, Utc};
+use surrealdb_types::JsonOptions;
+
 use crate::cnf::{MAX_OBJECT_PARSING_DEPTH, MAX_QUERY_PARSING_DEPTH};
 use crate::syn;
 use crate::syn::parser::ParserSettings;
@@ -10,7 +13,15 @@ pub fn encode(value: PublicValue) -> anyhow::Result<Vec<u8>> {
 }
 
 pub fn encode_str(value: PublicValue) -> anyhow::Result<String> {
-	let v = value.into_json_value();
+	encode_str_with(value, JsonOptions::default())
+}
+
+pub fn encode_with(value: PublicValue, opts: JsonOptions) -> anyhow::Result<Vec<u8>> {
+	encode_str_with(value, opts).map(|x| x.into_bytes())
+}
+
+pub fn encode_str_with(value: PublicValue, opts: JsonOptions) -> anyhow::Result<String> {
+	let v = value.into_json_value_with(opts);
 	// Because we convert to serde_json::Value first we can guarantee that
 	// serialization wont fail.
 	Ok(serde_json::to_string(&v).expect("serialization to json string should not fail"))
@@ -57,3 +68,67 @@ pub fn json_to_value(json: serde_json::Value) -> PublicValue {
 		}
 	}
 }
+
+/// Converts strings which are valid ISO-8601 datetimes into datetimes,
+/// recursing into arrays and objects. Any other value is left unchanged.
+pub fn parse_datetimes(value: PublicValue) -> PublicValue {
+	match value {
+		PublicValue::String(s) => match DateTime::parse_from_rfc3339(&s) {
+			Ok(dt) => PublicValue::Datetime(dt.with_timezone(&Utc).into()),
+			Err(_) => PublicValue::String(s),
+		},
+		PublicValue::Array(a) => PublicValue::Array(PublicArray::from(
+			a.into_iter().map(parse_datetimes).collect::<Vec<_>>(),
+		)),
+		PublicValue::Object(o) => {
+			let map: BTreeMap<String, PublicValue> =
+				o.into_iter().map(|(k, v)| (k, parse_datetimes(v))).collect();
+			PublicValue::Object(PublicObject::from(map))
+		}
+		v => v,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn encode_big_ints() {
+		let value = PublicValue::Array(PublicArray::from(vec![
+			PublicValue::Number(PublicNumber::Int(1 << 53)),
+			PublicValue::Number(PublicNumber::Int(-(1 << 53))),
+			PublicValue::Number(PublicNumber::Int((1 << 53) - 1)),
+		]));
+		// By default integers are always encoded as numbers
+		let res = encode_str(value.clone()).unwrap();
+		assert_eq!(res, "[9007199254740992,-9007199254740992,9007199254740991]");
+		// Integers outside of the safe range can be encoded as strings
+		let opts = JsonOptions::default().with_big_ints_as_strings(true);
+		let res = encode_str_with(value, opts).unwrap();
+		assert_eq!(res, r#"["9007199254740992","-9007199254740992",9007199254740991]"#);
+	}
+
+	#[test]
+	fn decode_datetimes() {
+		let json = br#"{"name":"Tobie","created":"2024-01-01T00:00:00Z","tags":["2024-06-01T12:00:00+02:00","2024-06-01"]}"#;
+		// By default datetime strings are decoded as strings
+		let json = serde_json::from_slice(json).unwrap();
+		let PublicValue::Object(obj) = json_to_value(json) else {
+			panic!("expected an object");
+		};
+		assert!(matches!(obj.get("created"), Some(PublicValue::String(_))));
+		// Datetime strings can be parsed as datetimes
+		let PublicValue::Object(obj) = parse_datetimes(PublicValue::Object(obj)) else {
+			panic!("expected an object");
+		};
+		assert!(matches!(obj.get("name"), Some(PublicValue::String(_))));
+		assert!(matches!(obj.get("created"), Some(PublicValue::Datetime(_))));
+		let Some(PublicValue::Array(tags)) = obj.get("tags") else {
+			panic!("expected an array");
+		};
+		assert!(matches!(tags.first(), Some(PublicValue::Datetime(_))));
+		// Strings which are not full datetimes are left unchanged
+		assert!(matches!(tags.get(1), Some(PublicValue::String(_))));
+	}
+}
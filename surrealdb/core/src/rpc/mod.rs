@@ -6,6 +6,7 @@ mod response;
 
 pub mod format;
 pub mod request;
+pub mod sse;
 
 pub use error::{
 	bad_gql_config, bad_lq_config, deserialize, internal_error, invalid_params, invalid_request,
@@ -11,6 +11,7 @@ use crate::dbs::{QueryResult, QueryType, Session};
 use crate::iam::token::Token;
 use crate::kvs::{Datastore, LockType, TransactionType};
 use crate::rpc::args::extract_args;
+use crate::rpc::format::json;
 use crate::rpc::{
 	DbResult, Method, bad_lq_config, invalid_params, method_not_allowed, method_not_found,
 	session_exists, session_expired, session_not_found, types_error_from_anyhow,
@@ -190,6 +191,20 @@ pub trait RpcProtocol {
 			warn!("Capabilities denied RPC method call attempt, target: '{method}'");
 			return Err(method_not_allowed(method.to_string()));
 		}
+		// Parse any datetimes in the method data, if enabled
+		let params = match method {
+			Method::Query
+			| Method::Set
+			| Method::Insert
+			| Method::Create
+			| Method::Upsert
+			| Method::Update
+			| Method::Merge
+			| Method::Patch
+			| Method::Relate
+			| Method::InsertRelation => self.parse_datetimes(&session, params).await,
+			_ => params,
+		};
 		// Execute the desired method
 		match method {
 			Method::Ping => Ok(DbResult::Other(PublicValue::None)),
@@ -230,6 +245,31 @@ pub trait RpcProtocol {
 		}
 	}
 
+	/// Parses ISO-8601 strings in the method arguments as datetimes, when
+	/// enabled for the session. The first argument is the query text or the
+	/// target of the method, so it is always left unchanged.
+	async fn parse_datetimes(&self, session_id: &Option<Uuid>, params: PublicArray) -> PublicArray {
+		// A missing session is reported by the method itself
+		let Ok(session) = self.get_session(session_id) else {
+			return params;
+		};
+		if !session.read().await.auto_parse_datetimes {
+			return params;
+		}
+		params
+			.into_iter()
+			.enumerate()
+			.map(|(i, v)| {
+				if i == 0 {
+					v
+				} else {
+					json::parse_datetimes(v)
+				}
+			})
+			.collect::<Vec<_>>()
+			.into()
+	}
+
 	// ------------------------------
 	// Methods for authentication
 	// ------------------------------
@@ -799,12 +839,14 @@ pub trait RpcProtocol {
 			omit: vec![],
 			split: None,
 			group: None,
+			having: None,
 			order: None,
 			limit: None,
 			start: None,
 			fetch: None,
 			version: Expr::Literal(Literal::None),
 			timeout: Expr::Literal(Literal::None),
+			scan_timeout: Expr::Literal(Literal::None),
 			explain: None,
 			tempfiles: false,
 		};
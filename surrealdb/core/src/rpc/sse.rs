@@ -0,0 +1,104 @@
+//! Server-Sent Events framing for live query notifications
+
+use std::fmt::Write;
+
+use surrealdb_types::JsonOptions;
+
+use crate::rpc::DbResult;
+use crate::rpc::format::json;
+use crate::types::{PublicNotification, SurrealValue};
+
+/// Formats the notifications of a single live query subscription as
+/// Server-Sent Events.
+///
+/// Each notification is encoded as JSON, in the same shape as it is sent
+/// over the RPC protocol, and framed as an event with an id. Event ids start
+/// at 1 and increase by one for every notification formatted by the encoder,
+/// so one encoder should be used for each subscription.
+#[derive(Debug, Default)]
+pub struct SseEncoder {
+	last_id: u64,
+	json_options: JsonOptions,
+}
+
+impl SseEncoder {
+	/// Create a new encoder for a subscription
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Set the json encoding options for the notification data
+	pub fn with_json_options(mut self, opts: JsonOptions) -> Self {
+		self.json_options = opts;
+		self
+	}
+
+	/// Returns the id of the last event which was formatted
+	pub fn last_id(&self) -> u64 {
+		self.last_id
+	}
+
+	/// Formats the notification as an event, terminated by a blank line
+	pub fn encode(&mut self, notification: PublicNotification) -> anyhow::Result<String> {
+		let value = DbResult::Live(notification).into_value();
+		let data = json::encode_str_with(value, self.json_options)?;
+		self.last_id += 1;
+		let mut event = format!("id: {}\n", self.last_id);
+		// Every line of the data needs its own field, as a newline ends the field
+		for line in data.lines() {
+			writeln!(event, "data: {line}").expect("writing to a string should not fail");
+		}
+		event.push('\n');
+		Ok(event)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::types::{PublicAction, PublicRecordId, PublicUuid, PublicValue};
+
+	fn notification(action: PublicAction) -> PublicNotification {
+		PublicNotification::new(
+			PublicUuid::from(uuid::Uuid::nil()),
+			None,
+			action,
+			PublicValue::RecordId(PublicRecordId::new("person", "tobie")),
+			PublicValue::String("line one\nline two".to_owned()),
+		)
+	}
+
+	#[test]
+	fn encode_framing() {
+		let mut encoder = SseEncoder::new();
+		let event = encoder.encode(notification(PublicAction::Create)).unwrap();
+		// An event is an id field, then data fields, then a blank line
+		assert!(event.ends_with("\n\n"));
+		let mut lines = event.trim_end_matches('\n').split('\n');
+		assert_eq!(lines.next(), Some("id: 1"));
+		let data: String =
+			lines.map(|line| line.strip_prefix("data: ").expect("expected a data field")).collect();
+		// The data is the notification encoded as json
+		let data: serde_json::Value = serde_json::from_str(&data).unwrap();
+		assert_eq!(data["id"], "00000000-0000-0000-0000-000000000000");
+		assert_eq!(data["action"], "CREATE");
+		assert_eq!(data["result"], "line one\nline two");
+	}
+
+	#[test]
+	fn encode_ids_increase() {
+		let mut encoder = SseEncoder::new();
+		assert_eq!(encoder.last_id(), 0);
+		for (id, action) in
+			[(1, PublicAction::Create), (2, PublicAction::Update), (3, PublicAction::Delete)]
+		{
+			let event = encoder.encode(notification(action)).unwrap();
+			assert!(event.starts_with(&format!("id: {id}\n")));
+			assert_eq!(encoder.last_id(), id);
+		}
+		// Each subscription has its own sequence of ids
+		let mut other = SseEncoder::new();
+		let event = other.encode(notification(PublicAction::Killed)).unwrap();
+		assert!(event.starts_with("id: 1\n"));
+	}
+}
@@ -0,0 +1,89 @@
+use std::collections::BTreeSet;
+use std::convert::Infallible;
+
+use crate::expr::visit::{Visit, Visitor};
+use crate::expr::{Expr, Function, Literal, LogicalPlan, Param};
+use crate::sql::Ast;
+
+/// The identifiers which a parsed query refers to, found without running it.
+///
+/// This allows a query to be checked for mistakes such as unknown tables or
+/// unused parameters before it is run.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct QueryAnalysis {
+	/// The tables which are referred to by name, or through record ids
+	pub tables: BTreeSet<String>,
+	/// The parameters which are read, without the leading `$`
+	pub params: BTreeSet<String>,
+	/// The functions which are called, such as `string::len` or `fn::custom`
+	pub functions: BTreeSet<String>,
+}
+
+impl Ast {
+	/// Collects the tables, parameters, and functions which this query refers
+	/// to, by walking every statement of the query.
+	pub fn analyze(&self) -> QueryAnalysis {
+		let plan = LogicalPlan::from(self.clone());
+		let mut analysis = QueryAnalysis::default();
+		for expr in &plan.expressions {
+			let Ok(()) = expr.visit(&mut analysis);
+		}
+		analysis
+	}
+}
+
+impl Visitor for QueryAnalysis {
+	type Error = Infallible;
+
+	fn visit_expr(&mut self, expr: &Expr) -> Result<(), Self::Error> {
+		if let Expr::Table(table) = expr {
+			self.tables.insert(table.as_str().to_owned());
+		}
+		expr.visit(self)
+	}
+
+	fn visit_literal(&mut self, literal: &Literal) -> Result<(), Self::Error> {
+		if let Literal::RecordId(rid) = literal {
+			self.tables.insert(rid.table.as_str().to_owned());
+		}
+		literal.visit(self)
+	}
+
+	fn visit_param(&mut self, param: &Param) -> Result<(), Self::Error> {
+		self.params.insert(param.as_str().to_owned());
+		Ok(())
+	}
+
+	fn visit_function(&mut self, function: &Function) -> Result<(), Self::Error> {
+		// Embedded scripts have no name
+		if !matches!(function, Function::Script(_)) {
+			self.functions.insert(function.name());
+		}
+		function.visit(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn analyze_query() {
+		let ast = crate::syn::parse(
+			"LET $adults = (SELECT * FROM person WHERE age >= $min_age);
+			UPDATE $adults SET name = string::uppercase(name), updated = time::now();
+			RELATE user:tobie->likes->post:1 SET weight = fn::weight($adults);
+			SELECT count() FROM ONLY order:1;",
+		)
+		.unwrap();
+		let analysis = ast.analyze();
+		let set = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<BTreeSet<_>>();
+		assert_eq!(analysis.tables, set(&["likes", "order", "person", "post", "user"]));
+		assert_eq!(analysis.params, set(&["adults", "min_age"]));
+		assert_eq!(
+			analysis.functions,
+			set(&["count", "fn::weight", "string::uppercase", "time::now"])
+		);
+	}
+}
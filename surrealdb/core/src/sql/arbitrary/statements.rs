@@ -117,8 +117,12 @@ impl<'a> arbitrary::Arbitrary<'a> for DefineIndexStatement {
 		let comment = u.arbitrary()?;
 		let concurrently = u.arbitrary()?;
 
+		let mut cond = None;
 		let cols = match index {
 			Index::Uniq | Index::Idx => {
+				if matches!(index, Index::Uniq) {
+					cond = u.arbitrary()?;
+				}
 				let mut cols = vec![u.arbitrary()?];
 				cols.reserve_exact(u.arbitrary_len::<String>()?);
 				for _ in 1..cols.capacity() {
@@ -137,6 +141,7 @@ impl<'a> arbitrary::Arbitrary<'a> for DefineIndexStatement {
 			cols,
 			index,
 			comment,
+			cond,
 			concurrently,
 		})
 	}
@@ -199,6 +204,11 @@ impl<'a> arbitrary::Arbitrary<'a> for SelectStatement {
 			with: u.arbitrary()?,
 			cond: u.arbitrary()?,
 			split,
+			having: if group.is_some() {
+				u.arbitrary()?
+			} else {
+				None
+			},
 			group,
 			order,
 			limit: u.arbitrary()?,
@@ -206,6 +216,7 @@ impl<'a> arbitrary::Arbitrary<'a> for SelectStatement {
 			fetch: u.arbitrary()?,
 			version: u.arbitrary()?,
 			timeout: u.arbitrary()?,
+			scan_timeout: u.arbitrary()?,
 			explain: u.arbitrary()?,
 			tempfiles: u.arbitrary()?,
 		})
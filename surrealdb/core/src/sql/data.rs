@@ -12,6 +12,7 @@ pub(crate) enum Data {
 	UnsetExpression(Vec<Idiom>),
 	PatchExpression(Expr),
 	MergeExpression(Expr),
+	MergeEachExpression(Expr),
 	ReplaceExpression(Expr),
 	ContentExpression(Expr),
 	SingleExpression(Expr),
@@ -76,6 +77,9 @@ impl ToSql for Data {
 			Self::MergeExpression(v) => {
 				write_sql!(f, sql_fmt, "MERGE {v}");
 			}
+			Self::MergeEachExpression(v) => {
+				write_sql!(f, sql_fmt, "MERGE EACH {v}");
+			}
 			Self::ReplaceExpression(v) => {
 				write_sql!(f, sql_fmt, "REPLACE {v}");
 			}
@@ -132,6 +136,7 @@ impl From<Data> for crate::expr::Data {
 			}
 			Data::PatchExpression(v) => Self::PatchExpression(v.into()),
 			Data::MergeExpression(v) => Self::MergeExpression(v.into()),
+			Data::MergeEachExpression(v) => Self::MergeEachExpression(v.into()),
 			Data::ReplaceExpression(v) => Self::ReplaceExpression(v.into()),
 			Data::ContentExpression(v) => Self::ContentExpression(v.into()),
 			Data::SingleExpression(v) => Self::SingleExpression(v.into()),
@@ -158,6 +163,7 @@ impl From<crate::expr::Data> for Data {
 			}
 			crate::expr::Data::PatchExpression(v) => Self::PatchExpression(v.into()),
 			crate::expr::Data::MergeExpression(v) => Self::MergeExpression(v.into()),
+			crate::expr::Data::MergeEachExpression(v) => Self::MergeEachExpression(v.into()),
 			crate::expr::Data::ReplaceExpression(v) => Self::ReplaceExpression(v.into()),
 			crate::expr::Data::ContentExpression(v) => Self::ContentExpression(v.into()),
 			crate::expr::Data::SingleExpression(v) => Self::SingleExpression(v.into()),
@@ -1,25 +1,44 @@
 use surrealdb_types::{SqlFormat, ToSql};
 
-#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-pub struct Explain(pub bool);
+pub enum Explain {
+	/// `EXPLAIN`: describe the plan without executing the statement
+	#[default]
+	Plan,
+	/// `EXPLAIN FULL`: execute the statement and describe the plan
+	Full,
+	/// `EXPLAIN ANALYZE`: execute the statement and annotate the plan with
+	/// the documents scanned and returned, and the time spent, per iterator
+	Analyze,
+}
 
 impl ToSql for Explain {
 	fn fmt_sql(&self, f: &mut String, _fmt: SqlFormat) {
 		f.push_str("EXPLAIN");
-		if self.0 {
-			f.push_str(" FULL");
+		match self {
+			Self::Plan => {}
+			Self::Full => f.push_str(" FULL"),
+			Self::Analyze => f.push_str(" ANALYZE"),
 		}
 	}
 }
 
 impl From<Explain> for crate::expr::Explain {
 	fn from(v: Explain) -> Self {
-		Self(v.0)
+		match v {
+			Explain::Plan => Self::Plan,
+			Explain::Full => Self::Full,
+			Explain::Analyze => Self::Analyze,
+		}
 	}
 }
 impl From<crate::expr::Explain> for Explain {
 	fn from(v: crate::expr::Explain) -> Self {
-		Self(v.0)
+		match v {
+			crate::expr::Explain::Plan => Self::Plan,
+			crate::expr::Explain::Full => Self::Full,
+			crate::expr::Explain::Analyze => Self::Analyze,
+		}
 	}
 }
@@ -0,0 +1,42 @@
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum IdType {
+	#[default]
+	Rand,
+	Uuid,
+	Ulid,
+	Sequence,
+}
+
+impl surrealdb_types::ToSql for IdType {
+	fn fmt_sql(&self, f: &mut String, _sql_fmt: surrealdb_types::SqlFormat) {
+		match self {
+			IdType::Rand => f.push_str("ID TYPE RAND"),
+			IdType::Uuid => f.push_str("ID TYPE UUID"),
+			IdType::Ulid => f.push_str("ID TYPE ULID"),
+			IdType::Sequence => f.push_str("ID TYPE SEQUENCE"),
+		}
+	}
+}
+
+impl From<IdType> for crate::expr::IdType {
+	fn from(v: IdType) -> Self {
+		match v {
+			IdType::Rand => crate::expr::IdType::Rand,
+			IdType::Uuid => crate::expr::IdType::Uuid,
+			IdType::Ulid => crate::expr::IdType::Ulid,
+			IdType::Sequence => crate::expr::IdType::Sequence,
+		}
+	}
+}
+
+impl From<crate::expr::IdType> for IdType {
+	fn from(v: crate::expr::IdType) -> Self {
+		match v {
+			crate::expr::IdType::Rand => IdType::Rand,
+			crate::expr::IdType::Uuid => IdType::Uuid,
+			crate::expr::IdType::Ulid => IdType::Ulid,
+			crate::expr::IdType::Sequence => IdType::Sequence,
+		}
+	}
+}
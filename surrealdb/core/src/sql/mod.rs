@@ -3,6 +3,7 @@
 pub(crate) mod access;
 pub(crate) mod access_type;
 pub(crate) mod algorithm;
+pub(crate) mod analysis;
 pub(crate) mod ast;
 pub(crate) mod base;
 pub(crate) mod block;
@@ -20,6 +21,7 @@ pub(crate) mod file;
 pub(crate) mod filter;
 pub(crate) mod function;
 pub(crate) mod group;
+pub(crate) mod id_type;
 pub(crate) mod idiom;
 pub(crate) mod kind;
 pub(crate) mod language;
@@ -45,6 +47,8 @@ pub(crate) mod table_type;
 #[cfg(test)]
 mod test_to_sql;
 pub(crate) mod tokenizer;
+pub(crate) mod ttl;
+pub(crate) mod unknown_fields;
 pub(crate) mod user;
 pub(crate) mod view;
 pub(crate) mod with;
@@ -57,6 +61,7 @@ pub(crate) mod arbitrary;
 
 pub(crate) use self::access_type::AccessType;
 pub(crate) use self::algorithm::Algorithm;
+pub use self::analysis::QueryAnalysis;
 #[cfg(not(feature = "arbitrary"))]
 pub(crate) use self::ast::Ast;
 #[cfg(feature = "arbitrary")]
@@ -76,6 +81,7 @@ pub(crate) use self::fetch::{Fetch, Fetchs};
 pub(crate) use self::field::{Field, Fields};
 pub(crate) use self::function::{Function, FunctionCall};
 pub(crate) use self::group::{Group, Groups};
+pub(crate) use self::id_type::IdType;
 pub(crate) use self::idiom::Idiom;
 pub(crate) use self::index::Index;
 pub(crate) use self::kind::Kind;
@@ -106,5 +112,7 @@ pub(crate) use self::statements::{
 	UpdateStatement, UpsertStatement,
 };
 pub(crate) use self::table_type::TableType;
+pub(crate) use self::ttl::Ttl;
+pub(crate) use self::unknown_fields::UnknownFields;
 pub(crate) use self::view::View;
 pub(crate) use self::with::With;
@@ -1,8 +1,8 @@
 use surrealdb_types::{SqlFormat, ToSql, write_sql};
 
 use crate::fmt::CoverStmts;
-use crate::sql::Field;
 use crate::sql::field::{Fields, Selector};
+use crate::sql::{Expr, Field, Part};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -11,6 +11,7 @@ pub(crate) enum Output {
 	None,
 	Null,
 	Diff,
+	Changed,
 	After,
 	Before,
 	Fields(Fields),
@@ -26,6 +27,7 @@ impl ToSql for Output {
 			Self::None => f.push_str("NONE"),
 			Self::Null => f.push_str("NULL"),
 			Self::Diff => f.push_str("DIFF"),
+			Self::Changed => f.push_str("CHANGED"),
 			Self::After => f.push_str("AFTER"),
 			Self::Before => f.push_str("BEFORE"),
 			Self::Fields(v) => {
@@ -41,7 +43,11 @@ impl ToSql for Output {
 								// Check for a expression with none on the left like `NONE + 1`
 								// which will be mistaken for the `NONE` clause formatted above.
 								let has_left_none = expr.has_left_none_null();
-								if has_left_none {
+								// A lone `changed` field would be mistaken for the `CHANGED`
+								// clause of an `UPDATE` or `UPSERT` statement.
+								let is_changed =
+									alias.is_none() && fields.len() == 1 && is_changed_field(expr);
+								if has_left_none || is_changed {
 									f.push('(');
 									expr.fmt_sql(f, fmt);
 									f.push(')');
@@ -69,12 +75,23 @@ impl ToSql for Output {
 	}
 }
 
+/// Returns whether the expression is a plain `changed` field.
+fn is_changed_field(expr: &Expr) -> bool {
+	match expr {
+		Expr::Idiom(x) => {
+			matches!(x.0.as_slice(), [Part::Field(f)] if f.eq_ignore_ascii_case("changed"))
+		}
+		_ => false,
+	}
+}
+
 impl From<Output> for crate::expr::Output {
 	fn from(v: Output) -> Self {
 		match v {
 			Output::None => Self::None,
 			Output::Null => Self::Null,
 			Output::Diff => Self::Diff,
+			Output::Changed => Self::Changed,
 			Output::After => Self::After,
 			Output::Before => Self::Before,
 			Output::Fields(v) => Self::Fields(v.into()),
@@ -88,6 +105,7 @@ impl From<crate::expr::Output> for Output {
 			crate::expr::Output::None => Self::None,
 			crate::expr::Output::Null => Self::Null,
 			crate::expr::Output::Diff => Self::Diff,
+			crate::expr::Output::Changed => Self::Changed,
 			crate::expr::Output::After => Self::After,
 			crate::expr::Output::Before => Self::Before,
 			crate::expr::Output::Fields(v) => Self::Fields(v.into()),
@@ -2,7 +2,7 @@ use surrealdb_types::{SqlFormat, ToSql, write_sql};
 
 use super::DefineKind;
 use crate::fmt::{CoverStmts, Fmt};
-use crate::sql::{Expr, Index, Literal};
+use crate::sql::{Cond, Expr, Index, Literal};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub(crate) struct DefineIndexStatement {
@@ -12,6 +12,7 @@ pub(crate) struct DefineIndexStatement {
 	pub cols: Vec<Expr>,
 	pub index: Index,
 	pub comment: Expr,
+	pub cond: Option<Cond>,
 	pub concurrently: bool,
 }
 
@@ -35,6 +36,9 @@ impl ToSql for DefineIndexStatement {
 		if Index::Idx != self.index {
 			write_sql!(f, sql_fmt, " {}", self.index);
 		}
+		if let Some(v) = &self.cond {
+			write_sql!(f, sql_fmt, " {}", v);
+		}
 		if !matches!(self.comment, Expr::Literal(Literal::None)) {
 			write_sql!(f, sql_fmt, " COMMENT {}", CoverStmts(&self.comment));
 		}
@@ -53,6 +57,7 @@ impl From<DefineIndexStatement> for crate::expr::statements::DefineIndexStatemen
 			cols: v.cols.into_iter().map(From::from).collect(),
 			index: v.index.into(),
 			comment: v.comment.into(),
+			cond: v.cond.map(Into::into),
 			concurrently: v.concurrently,
 		}
 	}
@@ -67,6 +72,7 @@ impl From<crate::expr::statements::DefineIndexStatement> for DefineIndexStatemen
 			cols: v.cols.into_iter().map(From::from).collect(),
 			index: v.index.into(),
 			comment: v.comment.into(),
+			cond: v.cond.map(Into::into),
 			concurrently: v.concurrently,
 		}
 	}
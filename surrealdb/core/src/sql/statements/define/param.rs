@@ -2,13 +2,14 @@ use surrealdb_types::{SqlFormat, ToSql, write_sql};
 
 use super::DefineKind;
 use crate::fmt::{CoverStmts, EscapeKwFreeIdent};
-use crate::sql::{Expr, Literal, Permission};
+use crate::sql::{Expr, Kind, Literal, Permission};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub(crate) struct DefineParamStatement {
 	pub kind: DefineKind,
 	pub name: String,
+	pub param_kind: Option<Kind>,
 	pub value: Expr,
 	pub comment: Expr,
 	pub permissions: Permission,
@@ -22,7 +23,11 @@ impl ToSql for DefineParamStatement {
 			DefineKind::Overwrite => write_sql!(f, fmt, " OVERWRITE"),
 			DefineKind::IfNotExists => write_sql!(f, fmt, " IF NOT EXISTS"),
 		}
-		write_sql!(f, fmt, " ${} VALUE {}", EscapeKwFreeIdent(&self.name), CoverStmts(&self.value));
+		write_sql!(f, fmt, " ${}", EscapeKwFreeIdent(&self.name));
+		if let Some(ref v) = self.param_kind {
+			write_sql!(f, fmt, " TYPE {}", v);
+		}
+		write_sql!(f, fmt, " VALUE {}", CoverStmts(&self.value));
 		if !matches!(self.comment, Expr::Literal(Literal::None)) {
 			write_sql!(f, fmt, " COMMENT {}", CoverStmts(&self.comment));
 		}
@@ -36,6 +41,7 @@ impl From<DefineParamStatement> for crate::expr::statements::DefineParamStatemen
 		Self {
 			kind: v.kind.into(),
 			name: v.name,
+			param_kind: v.param_kind.map(Into::into),
 			value: v.value.into(),
 			comment: v.comment.into(),
 			permissions: v.permissions.into(),
@@ -48,6 +54,7 @@ impl From<crate::expr::statements::DefineParamStatement> for DefineParamStatemen
 		DefineParamStatement {
 			kind: v.kind.into(),
 			name: v.name,
+			param_kind: v.param_kind.map(Into::into),
 			value: v.value.into(),
 			comment: v.comment.into(),
 			permissions: v.permissions.into(),
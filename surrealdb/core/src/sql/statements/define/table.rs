@@ -3,7 +3,7 @@ use surrealdb_types::{SqlFormat, ToSql, write_sql};
 use super::DefineKind;
 use crate::fmt::{CoverStmts, EscapeKwFreeIdent};
 use crate::sql::changefeed::ChangeFeed;
-use crate::sql::{Expr, Literal, Permissions, TableType, View};
+use crate::sql::{Expr, IdType, Literal, Permissions, TableType, Ttl, UnknownFields, View};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -13,9 +13,12 @@ pub(crate) struct DefineTableStatement {
 	pub name: Expr,
 	pub drop: bool,
 	pub full: bool,
+	pub unknown_fields: UnknownFields,
 	pub view: Option<View>,
 	pub permissions: Permissions,
 	pub changefeed: Option<ChangeFeed>,
+	pub ttl: Option<Ttl>,
+	pub id_type: IdType,
 	pub comment: Expr,
 	pub table_type: TableType,
 }
@@ -28,9 +31,12 @@ impl Default for DefineTableStatement {
 			name: Expr::Literal(Literal::None),
 			drop: false,
 			full: false,
+			unknown_fields: UnknownFields::Reject,
 			view: None,
 			permissions: Permissions::none(),
 			changefeed: None,
+			ttl: None,
+			id_type: IdType::Rand,
 			comment: Expr::Literal(Literal::None),
 			table_type: TableType::default(),
 		}
@@ -83,6 +89,12 @@ impl ToSql for DefineTableStatement {
 		} else {
 			" SCHEMALESS"
 		});
+		if self.unknown_fields != UnknownFields::Reject {
+			write_sql!(f, sql_fmt, " {}", self.unknown_fields);
+		}
+		if self.id_type != IdType::Rand {
+			write_sql!(f, sql_fmt, " {}", self.id_type);
+		}
 		if !matches!(self.comment, Expr::Literal(Literal::None)) {
 			write_sql!(f, sql_fmt, " COMMENT {}", CoverStmts(&self.comment));
 		}
@@ -92,6 +104,9 @@ impl ToSql for DefineTableStatement {
 		if let Some(ref v) = self.changefeed {
 			write_sql!(f, sql_fmt, " {}", v);
 		}
+		if let Some(ref v) = self.ttl {
+			write_sql!(f, sql_fmt, " {}", v);
+		}
 		if sql_fmt.is_pretty() {
 			f.push('\n');
 			let inner_fmt = sql_fmt.increment();
@@ -111,9 +126,12 @@ impl From<DefineTableStatement> for crate::expr::statements::DefineTableStatemen
 			name: v.name.into(),
 			drop: v.drop,
 			full: v.full,
+			unknown_fields: v.unknown_fields.into(),
 			view: v.view.map(Into::into),
 			permissions: v.permissions.into(),
 			changefeed: v.changefeed.map(Into::into),
+			ttl: v.ttl.map(Into::into),
+			id_type: v.id_type.into(),
 			comment: v.comment.into(),
 			table_type: v.table_type.into(),
 		}
@@ -129,9 +147,12 @@ impl From<crate::expr::statements::DefineTableStatement> for DefineTableStatemen
 			name: v.name.into(),
 			drop: v.drop,
 			full: v.full,
+			unknown_fields: v.unknown_fields.into(),
 			view: v.view.map(Into::into),
 			permissions: v.permissions.into(),
 			changefeed: v.changefeed.map(Into::into),
+			ttl: v.ttl.map(Into::into),
+			id_type: v.id_type.into(),
 			comment: v.comment.into(),
 			table_type: v.table_type.into(),
 		}
@@ -7,6 +7,8 @@ use crate::fmt::EscapeKwFreeIdent;
 pub struct OptionStatement {
 	pub name: String,
 	pub what: bool,
+	/// A named value for the option, such as `OPTION DIV_BY_ZERO = NONE`
+	pub value: Option<String>,
 }
 
 impl OptionStatement {
@@ -14,13 +16,22 @@ impl OptionStatement {
 		Self {
 			name: "IMPORT".to_string(),
 			what: true,
+			value: None,
 		}
 	}
 }
 
 impl ToSql for OptionStatement {
 	fn fmt_sql(&self, f: &mut String, fmt: SqlFormat) {
-		if self.what {
+		if let Some(value) = &self.value {
+			write_sql!(
+				f,
+				fmt,
+				"OPTION {} = {}",
+				EscapeKwFreeIdent(&self.name),
+				EscapeKwFreeIdent(value)
+			)
+		} else if self.what {
 			write_sql!(f, fmt, "OPTION {}", EscapeKwFreeIdent(&self.name))
 		} else {
 			write_sql!(f, fmt, "OPTION {} = FALSE", EscapeKwFreeIdent(&self.name))
@@ -33,6 +44,7 @@ impl From<OptionStatement> for crate::expr::statements::OptionStatement {
 		crate::expr::statements::OptionStatement {
 			name: v.name,
 			what: v.what,
+			value: v.value,
 		}
 	}
 }
@@ -42,6 +54,7 @@ impl From<crate::expr::statements::OptionStatement> for OptionStatement {
 		OptionStatement {
 			name: v.name,
 			what: v.what,
+			value: v.value,
 		}
 	}
 }
@@ -18,12 +18,16 @@ pub struct SelectStatement {
 	pub cond: Option<Cond>,
 	pub split: Option<Splits>,
 	pub group: Option<Groups>,
+	pub having: Option<Cond>,
 	pub order: Option<Ordering>,
 	pub limit: Option<Limit>,
 	pub start: Option<Start>,
 	pub fetch: Option<Fetchs>,
 	pub version: Expr,
 	pub timeout: Expr,
+	/// The SCAN TIMEOUT clause, which bounds the time spent iterating over
+	/// records, after which the records gathered so far are still processed.
+	pub scan_timeout: Expr,
 	pub explain: Option<Explain>,
 	pub tempfiles: bool,
 }
@@ -51,6 +55,9 @@ impl ToSql for SelectStatement {
 		if let Some(ref v) = self.group {
 			write_sql!(f, fmt, " {v}");
 		}
+		if let Some(ref v) = self.having {
+			write_sql!(f, fmt, " HAVING {}", CoverStmts(&v.0));
+		}
 		if let Some(ref v) = self.order {
 			write_sql!(f, fmt, " {v}");
 		}
@@ -69,6 +76,9 @@ impl ToSql for SelectStatement {
 		if !matches!(self.timeout, Expr::Literal(Literal::None)) {
 			write_sql!(f, fmt, " TIMEOUT {}", CoverStmts(&self.timeout));
 		}
+		if !matches!(self.scan_timeout, Expr::Literal(Literal::None)) {
+			write_sql!(f, fmt, " SCAN TIMEOUT {}", CoverStmts(&self.scan_timeout));
+		}
 		if let Some(ref v) = self.explain {
 			write_sql!(f, fmt, " {v}");
 		}
@@ -86,12 +96,14 @@ impl From<SelectStatement> for crate::expr::statements::SelectStatement {
 			cond: v.cond.map(Into::into),
 			split: v.split.map(Into::into),
 			group: v.group.map(Into::into),
+			having: v.having.map(Into::into),
 			order: v.order.map(Into::into),
 			limit: v.limit.map(Into::into),
 			start: v.start.map(Into::into),
 			fetch: v.fetch.map(Into::into),
 			version: v.version.into(),
 			timeout: v.timeout.into(),
+			scan_timeout: v.scan_timeout.into(),
 			explain: v.explain.map(Into::into),
 			tempfiles: v.tempfiles,
 		}
@@ -109,12 +121,14 @@ impl From<crate::expr::statements::SelectStatement> for SelectStatement {
 			cond: v.cond.map(Into::into),
 			split: v.split.map(Into::into),
 			group: v.group.map(Into::into),
+			having: v.having.map(Into::into),
 			order: v.order.map(Into::into),
 			limit: v.limit.map(Into::into),
 			start: v.start.map(Into::into),
 			fetch: v.fetch.map(Into::into),
 			version: v.version.into(),
 			timeout: v.timeout.into(),
+			scan_timeout: v.scan_timeout.into(),
 			explain: v.explain.map(Into::into),
 			tempfiles: v.tempfiles,
 		}
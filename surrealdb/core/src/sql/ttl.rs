@@ -0,0 +1,35 @@
+use crate::sql::Idiom;
+use crate::types::PublicDuration;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Ttl {
+	pub duration: PublicDuration,
+	#[cfg_attr(feature = "arbitrary", arbitrary(with = crate::sql::arbitrary::local_idiom))]
+	pub field: Idiom,
+}
+
+impl surrealdb_types::ToSql for Ttl {
+	fn fmt_sql(&self, f: &mut String, sql_fmt: surrealdb_types::SqlFormat) {
+		use surrealdb_types::write_sql;
+		write_sql!(f, sql_fmt, "TTL {} ON {}", self.duration, self.field);
+	}
+}
+
+impl From<Ttl> for crate::expr::Ttl {
+	fn from(v: Ttl) -> Self {
+		crate::expr::Ttl {
+			duration: v.duration.into(),
+			field: v.field.into(),
+		}
+	}
+}
+
+impl From<crate::expr::Ttl> for Ttl {
+	fn from(v: crate::expr::Ttl) -> Self {
+		Ttl {
+			duration: v.duration.into(),
+			field: v.field.into(),
+		}
+	}
+}
@@ -0,0 +1,46 @@
+use crate::sql::Idiom;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum UnknownFields {
+	#[default]
+	Reject,
+	Ignore,
+	Store(
+		#[cfg_attr(feature = "arbitrary", arbitrary(with = crate::sql::arbitrary::local_idiom))]
+		Idiom,
+	),
+}
+
+impl surrealdb_types::ToSql for UnknownFields {
+	fn fmt_sql(&self, f: &mut String, sql_fmt: surrealdb_types::SqlFormat) {
+		use surrealdb_types::write_sql;
+		match self {
+			UnknownFields::Reject => f.push_str("ON UNKNOWN FIELD REJECT"),
+			UnknownFields::Ignore => f.push_str("ON UNKNOWN FIELD IGNORE"),
+			UnknownFields::Store(field) => {
+				write_sql!(f, sql_fmt, "ON UNKNOWN FIELD STORE {}", field)
+			}
+		}
+	}
+}
+
+impl From<UnknownFields> for crate::expr::UnknownFields {
+	fn from(v: UnknownFields) -> Self {
+		match v {
+			UnknownFields::Reject => crate::expr::UnknownFields::Reject,
+			UnknownFields::Ignore => crate::expr::UnknownFields::Ignore,
+			UnknownFields::Store(field) => crate::expr::UnknownFields::Store(field.into()),
+		}
+	}
+}
+
+impl From<crate::expr::UnknownFields> for UnknownFields {
+	fn from(v: crate::expr::UnknownFields) -> Self {
+		match v {
+			crate::expr::UnknownFields::Reject => UnknownFields::Reject,
+			crate::expr::UnknownFields::Ignore => UnknownFields::Ignore,
+			crate::expr::UnknownFields::Store(field) => UnknownFields::Store(field.into()),
+		}
+	}
+}
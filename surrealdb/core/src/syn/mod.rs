@@ -209,7 +209,11 @@ pub fn duration(input: &str) -> Result<PublicDuration> {
 pub fn record_id(input: &str) -> Result<PublicRecordId> {
 	trace!(target: TARGET, "Parsing SurrealQL record id");
 
-	parse_with(input.as_bytes(), async |parser, stk| parser.parse_value_record_id(stk).await)
+	parse_with(input.as_bytes(), async |parser, stk| {
+		let id = parser.parse_value_record_id(stk).await?;
+		parser.assert_finished()?;
+		Ok(id)
+	})
 }
 
 /// Parse a table name from a string.
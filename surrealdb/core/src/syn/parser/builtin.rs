@@ -46,6 +46,7 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("array::concat") => (PathKind::Function, None),
 		UniCase::ascii("array::difference") => (PathKind::Function, None),
 		UniCase::ascii("array::distinct") => (PathKind::Function, None),
+		UniCase::ascii("array::edit_script") => (PathKind::Function, None),
 		UniCase::ascii("array::every") => (PathKind::Function, None),
 		UniCase::ascii("array::fill") => (PathKind::Function, None),
 		UniCase::ascii("array::filter") => (PathKind::Function, None),
@@ -70,7 +71,10 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("array::map") => (PathKind::Function, None),
 		UniCase::ascii("array::matches") => (PathKind::Function, None),
 		UniCase::ascii("array::max") => (PathKind::Function, None),
+		UniCase::ascii("array::merge_sorted") => (PathKind::Function, None),
+		UniCase::ascii("array::merge_sorted_by") => (PathKind::Function, None),
 		UniCase::ascii("array::min") => (PathKind::Function, None),
+		UniCase::ascii("array::partition") => (PathKind::Function, None),
 		UniCase::ascii("array::pop") => (PathKind::Function, None),
 		UniCase::ascii("array::prepend") => (PathKind::Function, None),
 		UniCase::ascii("array::push") => (PathKind::Function, None),
@@ -87,9 +91,11 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("array::sort_natural") => (PathKind::Function, None),
 		UniCase::ascii("array::sort_lexical") => (PathKind::Function, None),
 		UniCase::ascii("array::sort_natural_lexical") => (PathKind::Function, None),
+		UniCase::ascii("array::sort_by_keys") => (PathKind::Function, None),
 		UniCase::ascii("array::swap") => (PathKind::Function, None),
 		UniCase::ascii("array::transpose") => (PathKind::Function, None),
 		UniCase::ascii("array::union") => (PathKind::Function, None),
+		UniCase::ascii("array::unique_by") => (PathKind::Function, None),
 		UniCase::ascii("array::windows") => (PathKind::Function, None),
 		UniCase::ascii("array::sort::asc") => (PathKind::Function, None),
 		UniCase::ascii("array::sort::desc") => (PathKind::Function, None),
@@ -97,6 +103,7 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("bytes::len") => (PathKind::Function, None),
 		//
 		UniCase::ascii("count") => (PathKind::Function, None),
+		UniCase::ascii("count::approx_distinct") => (PathKind::Function, None),
 		//
 		UniCase::ascii("crypto::blake3") => (PathKind::Function, None),
 		UniCase::ascii("crypto::joaat") => (PathKind::Function, None),
@@ -113,6 +120,9 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("crypto::scrypt::compare") => (PathKind::Function, None),
 		UniCase::ascii("crypto::scrypt::generate") => (PathKind::Function, None),
 		//
+		UniCase::ascii("debug::kv_scan") => (PathKind::Function, None),
+		UniCase::ascii("debug::table_checksum") => (PathKind::Function, None),
+		//
 		UniCase::ascii("duration::days") => (PathKind::Function, None),
 		UniCase::ascii("duration::hours") => (PathKind::Function, None),
 		UniCase::ascii("duration::micros") => (PathKind::Function, None),
@@ -138,6 +148,12 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("duration::set_second") => (PathKind::Function, None),
 		UniCase::ascii("duration::set_nanosecond") => (PathKind::Function, None),
 		//
+		UniCase::ascii("encoding::base32::decode") => (PathKind::Function, None),
+		UniCase::ascii("encoding::base32::encode") => (PathKind::Function, None),
+		UniCase::ascii("encoding::base58::decode") => (PathKind::Function, None),
+		UniCase::ascii("encoding::base58::encode") => (PathKind::Function, None),
+		UniCase::ascii("encoding::base62::decode") => (PathKind::Function, None),
+		UniCase::ascii("encoding::base62::encode") => (PathKind::Function, None),
 		UniCase::ascii("encoding::base64::decode") => (PathKind::Function, None),
 		UniCase::ascii("encoding::base64::encode") => (PathKind::Function, None),
 		UniCase::ascii("encoding::cbor::decode") => (PathKind::Function, None),
@@ -166,13 +182,21 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("geo::hash::decode") => (PathKind::Function, None),
 		UniCase::ascii("geo::hash::encode") => (PathKind::Function, None),
 		UniCase::ascii("geo::is_valid") => (PathKind::Function, None),
+		UniCase::ascii("geo::within") => (PathKind::Function, None),
+		//
+		UniCase::ascii("graph::expand") => (PathKind::Function, None),
+		UniCase::ascii("graph::topo_sort") => (PathKind::Function, None),
 		//
 		UniCase::ascii("http::head") => (PathKind::Function, None),
 		UniCase::ascii("http::get") => (PathKind::Function, None),
+		UniCase::ascii("http::get_with_meta") => (PathKind::Function, None),
+		UniCase::ascii("http::stream") => (PathKind::Function, None),
 		UniCase::ascii("http::put") => (PathKind::Function, None),
 		UniCase::ascii("http::post") => (PathKind::Function, None),
 		UniCase::ascii("http::patch") => (PathKind::Function, None),
 		UniCase::ascii("http::delete") => (PathKind::Function, None),
+		UniCase::ascii("http::options") => (PathKind::Function, None),
+		UniCase::ascii("http::trace") => (PathKind::Function, None),
 		//
 		UniCase::ascii("math::abs") => (PathKind::Function, None),
 		UniCase::ascii("math::acos") => (PathKind::Function, None),
@@ -185,6 +209,7 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("math::cos") => (PathKind::Function, None),
 		UniCase::ascii("math::cot") => (PathKind::Function, None),
 		UniCase::ascii("math::deg2rad") => (PathKind::Function, None),
+		UniCase::ascii("math::ema") => (PathKind::Function, None),
 		UniCase::ascii("math::fixed") => (PathKind::Function, None),
 		UniCase::ascii("math::floor") => (PathKind::Function, None),
 		UniCase::ascii("math::interquartile") => (PathKind::Function, None),
@@ -200,7 +225,9 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("math::midhinge") => (PathKind::Function, None),
 		UniCase::ascii("math::min") => (PathKind::Function, None),
 		UniCase::ascii("math::mode") => (PathKind::Function, None),
+		UniCase::ascii("math::moving_average") => (PathKind::Function, None),
 		UniCase::ascii("math::nearestrank") => (PathKind::Function, None),
+		UniCase::ascii("math::percent_rank") => (PathKind::Function, None),
 		UniCase::ascii("math::percentile") => (PathKind::Function, None),
 		UniCase::ascii("math::pow") => (PathKind::Function, None),
 		UniCase::ascii("math::product") => (PathKind::Function, None),
@@ -213,23 +240,31 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("math::stddev") => (PathKind::Function, None),
 		UniCase::ascii("math::sum") => (PathKind::Function, None),
 		UniCase::ascii("math::tan") => (PathKind::Function, None),
+		UniCase::ascii("math::time_weighted_avg") => (PathKind::Function, None),
 		UniCase::ascii("math::top") => (PathKind::Function, None),
 		UniCase::ascii("math::trimean") => (PathKind::Function, None),
 		UniCase::ascii("math::variance") => (PathKind::Function, None),
 		//
+		UniCase::ascii("meta::engine") => (PathKind::Function, None),
 		UniCase::ascii("meta::id") => (PathKind::Function, None),
 		UniCase::ascii("meta::tb") => (PathKind::Function, None),
 		//
 		UniCase::ascii("not") => (PathKind::Function, None),
 		//
+		UniCase::ascii("number::from_base") => (PathKind::Function, None),
+		UniCase::ascii("number::to_base") => (PathKind::Function, None),
+		//
+		UniCase::ascii("object::defaults") => (PathKind::Function, None),
 		UniCase::ascii("object::entries") => (PathKind::Function, None),
 		UniCase::ascii("object::extend") => (PathKind::Function, None),
+		UniCase::ascii("object::flatten") => (PathKind::Function, None),
 		UniCase::ascii("object::from_entries") => (PathKind::Function, None),
 		UniCase::ascii("object::is_empty") => (PathKind::Function, None),
 		UniCase::ascii("object::keys") => (PathKind::Function, None),
 		UniCase::ascii("object::len") => (PathKind::Function, None),
 		UniCase::ascii("object::matches") => (PathKind::Function, None),
 		UniCase::ascii("object::remove") => (PathKind::Function, None),
+		UniCase::ascii("object::unflatten") => (PathKind::Function, None),
 		UniCase::ascii("object::values") => (PathKind::Function, None),
 		//
 		UniCase::ascii("parse::email::host") => (PathKind::Function, None),
@@ -242,6 +277,9 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("parse::url::query") => (PathKind::Function, None),
 		UniCase::ascii("parse::url::scheme") => (PathKind::Function, None),
 		//
+		UniCase::ascii("query::escape_ident") => (PathKind::Function, None),
+		UniCase::ascii("query::escape_literal") => (PathKind::Function, None),
+		//
 		UniCase::ascii("rand") => (PathKind::Function, None),
 		UniCase::ascii("rand::bool") => (PathKind::Function, None),
 		UniCase::ascii("rand::duration") => (PathKind::Function, None),
@@ -251,14 +289,20 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("rand::int") => (PathKind::Function, None),
 		UniCase::ascii("rand::string") => (PathKind::Function, None),
 		UniCase::ascii("rand::time") => (PathKind::Function, None),
+		UniCase::ascii("rand::ulid::from_datetime") => (PathKind::Function, None),
 		UniCase::ascii("rand::ulid") => (PathKind::Function, None),
 		UniCase::ascii("rand::uuid::v4") => (PathKind::Function, None),
+		UniCase::ascii("rand::uuid::v5") => (PathKind::Function, None),
 		UniCase::ascii("rand::uuid::v7") => (PathKind::Function, None),
 		UniCase::ascii("rand::uuid") => (PathKind::Function, None),
+		UniCase::ascii("rand::weighted") => (PathKind::Function, None),
+		UniCase::ascii("rand::weighted_many") => (PathKind::Function, None),
 		//
 		UniCase::ascii("record::exists") => (PathKind::Function, None),
 		UniCase::ascii("record::id") => (PathKind::Function, None),
 		UniCase::ascii("record::is_edge") => (PathKind::Function, None),
+		UniCase::ascii("record::parse") => (PathKind::Function, None),
+		UniCase::ascii("record::range") => (PathKind::Function, None),
 		UniCase::ascii("record::table") => (PathKind::Function, None),
 		UniCase::ascii("record::tb") => (PathKind::Function, None),
 		//
@@ -299,6 +343,7 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("session::ac") => (PathKind::Function, None),
 		UniCase::ascii("session::db") => (PathKind::Function, None),
 		UniCase::ascii("session::id") => (PathKind::Function, None),
+		UniCase::ascii("session::info") => (PathKind::Function, None),
 		UniCase::ascii("session::ip") => (PathKind::Function, None),
 		UniCase::ascii("session::ns") => (PathKind::Function, None),
 		UniCase::ascii("session::origin") => (PathKind::Function, None),
@@ -311,9 +356,14 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("string::concat") => (PathKind::Function, None),
 		UniCase::ascii("string::contains") => (PathKind::Function, None),
 		UniCase::ascii("string::ends_with") => (PathKind::Function, None),
+		UniCase::ascii("string::fuzzy_match") => (PathKind::Function, None),
+		UniCase::ascii("string::fuzzy_score") => (PathKind::Function, None),
 		UniCase::ascii("string::join") => (PathKind::Function, None),
 		UniCase::ascii("string::len") => (PathKind::Function, None),
 		UniCase::ascii("string::lowercase") => (PathKind::Function, None),
+		UniCase::ascii("string::normalize_url") => (PathKind::Function, None),
+		UniCase::ascii("string::parse_kv") => (PathKind::Function, None),
+		UniCase::ascii("string::parse_template") => (PathKind::Function, None),
 		UniCase::ascii("string::repeat") => (PathKind::Function, None),
 		UniCase::ascii("string::replace") => (PathKind::Function, None),
 		UniCase::ascii("string::reverse") => (PathKind::Function, None),
@@ -325,6 +375,7 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("string::uppercase") => (PathKind::Function, None),
 		UniCase::ascii("string::words") => (PathKind::Function, None),
 		//
+		UniCase::ascii("string::distance::damerau") => (PathKind::Function, None),
 		UniCase::ascii("string::distance::damerau_levenshtein") => (PathKind::Function, None),
 		UniCase::ascii("string::distance::hamming") => (PathKind::Function, None),
 		UniCase::ascii("string::distance::levenshtein") => (PathKind::Function, None),
@@ -370,12 +421,17 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("string::similarity::sorensen_dice") => (PathKind::Function, None),
 		UniCase::ascii("string::matches") => (PathKind::Function, None),
 		//
+		UniCase::ascii("table::distinct_values") => (PathKind::Function, None),
+		//
+		UniCase::ascii("time::add_business_days") => (PathKind::Function, None),
 		UniCase::ascii("time::ceil") => (PathKind::Function, None),
 		UniCase::ascii("time::day") => (PathKind::Function, None),
 		UniCase::ascii("time::floor") => (PathKind::Function, None),
 		UniCase::ascii("time::format") => (PathKind::Function, None),
+		UniCase::ascii("time::format_zoned") => (PathKind::Function, None),
 		UniCase::ascii("time::group") => (PathKind::Function, None),
 		UniCase::ascii("time::hour") => (PathKind::Function, None),
+		UniCase::ascii("time::in_zone") => (PathKind::Function, None),
 		UniCase::ascii("time::max") => (PathKind::Function, None),
 		UniCase::ascii("time::micros") => (PathKind::Function, None),
 		UniCase::ascii("time::millis") => (PathKind::Function, None),
@@ -419,7 +475,9 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("type::file") => (PathKind::Function, None),
 		UniCase::ascii("type::float") => (PathKind::Function, None),
 		UniCase::ascii("type::geometry") => (PathKind::Function, None),
+		UniCase::ascii("type::id_from") => (PathKind::Function, None),
 		UniCase::ascii("type::int") => (PathKind::Function, None),
+		UniCase::ascii("type::is") => (PathKind::Function, None),
 		UniCase::ascii("type::number") => (PathKind::Function, None),
 		UniCase::ascii("type::of") => (PathKind::Function, None),
 		UniCase::ascii("type::point") => (PathKind::Function, None),
@@ -456,6 +514,8 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("type::is_string") => (PathKind::Function, Some(UniCase::ascii("type::is::string"))),
 		UniCase::ascii("type::is_uuid") => (PathKind::Function, Some(UniCase::ascii("type::is::uuid"))),
 		//
+		UniCase::ascii("value::canonicalize") => (PathKind::Function, None),
+		UniCase::ascii("value::deduplicate_refs") => (PathKind::Function, None),
 		UniCase::ascii("value::diff") => (PathKind::Function, None),
 		UniCase::ascii("value::patch") => (PathKind::Function, None),
 		//
@@ -512,6 +572,7 @@ pub(crate) static PATHS: phf::Map<
 		UniCase::ascii("time::MAXIMUM") => (PathKind::Constant(Constant::TimeMax), None),
 		UniCase::ascii("duration::MAX") => (PathKind::Constant(Constant::DurationMax), None),
 		//
+		UniCase::ascii("schema::diff") => (PathKind::Function, None),
 		UniCase::ascii("schema::table::exists") => (PathKind::Function, None),
 };
 
@@ -1,6 +1,7 @@
 use reblessive::Stk;
 
-use crate::sql::CreateStatement;
+use crate::sql::{CreateStatement, Data};
+use crate::syn::error::bail;
 use crate::syn::parser::{ParseResult, Parser};
 use crate::syn::token::t;
 
@@ -11,7 +12,11 @@ impl Parser<'_> {
 	) -> ParseResult<CreateStatement> {
 		let only = self.eat(t!("ONLY"));
 		let what = self.parse_what_list(stk).await?;
+		let before = self.peek().span;
 		let data = self.try_parse_data(stk).await?;
+		if let Some(Data::MergeEachExpression(_)) = data {
+			bail!("Unexpected MERGE EACH clause", @before.covers(self.last_span()) => "MERGE EACH can only be used in UPDATE and UPSERT statements");
+		}
 		let output = self.try_parse_output(stk).await?;
 		// VERSION is no longer supported in CREATE statements, it is left here for backwards
 		// compatibility.
@@ -596,6 +596,7 @@ impl Parser<'_> {
 		let mut res = DefineParamStatement {
 			name,
 			kind,
+			param_kind: None,
 			value: Expr::Literal(Literal::None),
 			comment: Expr::Literal(Literal::None),
 			permissions: Permission::default(),
@@ -603,6 +604,10 @@ impl Parser<'_> {
 
 		loop {
 			match self.peek_kind() {
+				t!("TYPE") => {
+					self.pop_peek();
+					res.param_kind = Some(stk.run(|ctx| self.parse_inner_kind(ctx)).await?);
+				}
 				t!("VALUE") => {
 					self.pop_peek();
 					res.value = stk.run(|ctx| self.parse_expr_field(ctx)).await?;
@@ -693,6 +698,24 @@ impl Parser<'_> {
 					self.pop_peek();
 					res.changefeed = Some(self.parse_changefeed()?);
 				}
+				t!("TTL") => {
+					self.pop_peek();
+					res.ttl = Some(self.parse_ttl()?);
+				}
+				t!("ON") => {
+					self.pop_peek();
+					res.unknown_fields = self.parse_unknown_fields()?;
+				}
+				TokenKind::Identifier => {
+					// ID is not a keyword, so it is matched as an identifier
+					let token = self.peek();
+					let ident = self.span_str(token.span);
+					if !ident.eq_ignore_ascii_case("ID") {
+						break;
+					}
+					self.pop_peek();
+					res.id_type = self.parse_id_type()?;
+				}
 				t!("AS") => {
 					self.pop_peek();
 					let peek = self.peek();
@@ -1034,6 +1057,7 @@ impl Parser<'_> {
 			cols: Vec::new(),
 			index: Index::Idx,
 			comment: Expr::Literal(Literal::None),
+			cond: None,
 			concurrently: false,
 		};
 
@@ -1053,6 +1077,7 @@ impl Parser<'_> {
 				t!("UNIQUE") => {
 					self.pop_peek();
 					res.index = Index::Uniq;
+					res.cond = self.try_parse_condition(stk).await?;
 				}
 				t!("COUNT") => {
 					self.pop_peek();
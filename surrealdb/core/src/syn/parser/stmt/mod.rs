@@ -526,12 +526,23 @@ impl Parser<'_> {
 	/// Expects `OPTION` to already be consumed.
 	pub(super) fn parse_option_stmt(&mut self) -> ParseResult<OptionStatement> {
 		let name = self.parse_ident()?;
+		let mut value = None;
 		let what = if self.eat(t!("=")) {
-			let next = self.next();
+			let next = self.peek();
 			match next.kind {
-				t!("true") => true,
-				t!("false") => false,
-				_ => unexpected!(self, next, "either 'true' or 'false'"),
+				t!("true") => {
+					self.pop_peek();
+					true
+				}
+				t!("false") => {
+					self.pop_peek();
+					false
+				}
+				x if Self::kind_is_identifier(x) => {
+					value = Some(self.parse_ident()?);
+					true
+				}
+				_ => unexpected!(self, next, "either 'true', 'false', or an option value"),
 			}
 		} else {
 			true
@@ -539,6 +550,7 @@ impl Parser<'_> {
 		Ok(OptionStatement {
 			name,
 			what,
+			value,
 		})
 	}
 
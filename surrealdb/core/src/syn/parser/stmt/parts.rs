@@ -7,8 +7,8 @@ use crate::sql::changefeed::ChangeFeed;
 use crate::sql::index::{Distance, VectorType};
 use crate::sql::reference::{Reference, ReferenceDeleteStrategy};
 use crate::sql::{
-	Base, Cond, Data, Explain, Expr, Fetch, Fetchs, Field, Fields, Group, Groups, Idiom, Literal,
-	Output, Permission, Permissions, View, With,
+	Base, Cond, Data, Explain, Expr, Fetch, Fetchs, Field, Fields, Group, Groups, IdType, Idiom,
+	Literal, Output, Permission, Permissions, Ttl, UnknownFields, View, With,
 };
 use crate::syn::error::bail;
 use crate::syn::parser::mac::{expected, unexpected};
@@ -50,7 +50,11 @@ impl Parser<'_> {
 			}
 			t!("MERGE") => {
 				self.pop_peek();
-				Data::MergeExpression(stk.run(|ctx| self.parse_expr_field(ctx)).await?)
+				if self.eat(t!("EACH")) {
+					Data::MergeEachExpression(stk.run(|ctx| self.parse_expr_field(ctx)).await?)
+				} else {
+					Data::MergeExpression(stk.run(|ctx| self.parse_expr_field(ctx)).await?)
+				}
 			}
 			t!("REPLACE") => {
 				self.pop_peek();
@@ -73,6 +77,24 @@ impl Parser<'_> {
 		self.parse_output(stk).await.map(Some)
 	}
 
+	/// Parses the output of an `UPDATE` or `UPSERT` statement if the next
+	/// token is `return`. Unlike other statements, these also accept `CHANGED`.
+	pub(crate) async fn try_parse_update_output(
+		&mut self,
+		stk: &mut Stk,
+	) -> ParseResult<Option<Output>> {
+		if !self.eat(t!("RETURN")) {
+			return Ok(None);
+		}
+		// CHANGED is not a reserved keyword, so it is only treated as such
+		// when it is not the start of a list of fields
+		if self.peek_is_changed_output() {
+			self.pop_peek();
+			return Ok(Some(Output::Changed));
+		}
+		self.parse_output(stk).await.map(Some)
+	}
+
 	/// Needed because some part of the RPC needs to call into the parser for
 	/// this specific part.
 	pub(crate) async fn parse_output(&mut self, stk: &mut Stk) -> ParseResult<Output> {
@@ -102,6 +124,24 @@ impl Parser<'_> {
 		Ok(res)
 	}
 
+	/// Returns whether the next tokens are a `CHANGED` output clause.
+	fn peek_is_changed_output(&mut self) -> bool {
+		let peek = self.peek();
+		if peek.kind != TokenKind::Identifier
+			|| !self.lexer.span_str(peek.span).eq_ignore_ascii_case("CHANGED")
+		{
+			return false;
+		}
+		matches!(
+			self.peek1().kind,
+			TokenKind::Eof
+				| t!(";") | t!(")")
+				| t!("}") | t!("TIMEOUT")
+				| t!("PARALLEL")
+				| t!("EXPLAIN")
+		)
+	}
+
 	/// Parses a statement timeout if the next token is `TIMEOUT`.
 	pub(crate) async fn try_parse_timeout(&mut self, stk: &mut Stk) -> ParseResult<Expr> {
 		if !self.eat(t!("TIMEOUT")) {
@@ -473,6 +513,53 @@ impl Parser<'_> {
 		})
 	}
 
+	/// Parses a table TTL production
+	///
+	/// # Parser State
+	/// Expects the parser to have already eating the `TTL` keyword
+	pub fn parse_ttl(&mut self) -> ParseResult<Ttl> {
+		let duration = self.next_token_value::<PublicDuration>()?;
+		expected!(self, t!("ON"));
+		let field = self.parse_local_idiom()?;
+
+		Ok(Ttl {
+			duration,
+			field,
+		})
+	}
+
+	/// Parses what happens to undefined fields on a schemafull table
+	///
+	/// # Parser State
+	/// Expects the parser to have already eating the `ON` keyword
+	pub fn parse_unknown_fields(&mut self) -> ParseResult<UnknownFields> {
+		expected!(self, t!("UNKNOWN"));
+		expected!(self, t!("FIELD"));
+		let next = self.next();
+		match next.kind {
+			t!("REJECT") => Ok(UnknownFields::Reject),
+			t!("IGNORE") => Ok(UnknownFields::Ignore),
+			t!("STORE") => Ok(UnknownFields::Store(self.parse_local_idiom()?)),
+			_ => unexpected!(self, next, "'REJECT', 'IGNORE' or 'STORE'"),
+		}
+	}
+
+	/// Parses the type of the ids generated for new records in a table
+	///
+	/// # Parser State
+	/// Expects the parser to have already eating the `ID` identifier
+	pub fn parse_id_type(&mut self) -> ParseResult<IdType> {
+		expected!(self, t!("TYPE"));
+		let next = self.next();
+		match next.kind {
+			t!("RAND") => Ok(IdType::Rand),
+			t!("UUID") => Ok(IdType::Uuid),
+			t!("ULID") => Ok(IdType::Ulid),
+			t!("SEQUENCE") => Ok(IdType::Sequence),
+			_ => unexpected!(self, next, "'RAND', 'UUID', 'ULID' or 'SEQUENCE'"),
+		}
+	}
+
 	/// Parses a reference
 	///
 	/// # Parser State
@@ -580,7 +667,21 @@ impl Parser<'_> {
 		Ok(name)
 	}
 	pub(super) fn try_parse_explain(&mut self) -> ParseResult<Option<Explain>> {
-		Ok(self.eat(t!("EXPLAIN")).then(|| Explain(self.eat(t!("FULL")))))
+		if !self.eat(t!("EXPLAIN")) {
+			return Ok(None);
+		}
+		if self.eat(t!("FULL")) {
+			return Ok(Some(Explain::Full));
+		}
+		// ANALYZE is not a reserved keyword, so check for an identifier
+		let peek = self.peek();
+		if matches!(peek.kind, TokenKind::Identifier)
+			&& self.lexer.span_str(peek.span).eq_ignore_ascii_case("ANALYZE")
+		{
+			self.pop_peek();
+			return Ok(Some(Explain::Analyze));
+		}
+		Ok(Some(Explain::Plan))
 	}
 
 	pub(super) fn try_parse_with(&mut self) -> ParseResult<Option<With>> {
@@ -1,7 +1,8 @@
 use reblessive::Stk;
 
 use crate::sql::statements::RelateStatement;
-use crate::sql::{Expr, Literal};
+use crate::sql::{Data, Expr, Literal};
+use crate::syn::error::bail;
 use crate::syn::parser::mac::{expected, expected_whitespace, unexpected};
 use crate::syn::parser::{ParseResult, Parser};
 use crate::syn::token::t;
@@ -17,7 +18,11 @@ impl Parser<'_> {
 		// UNIQUE is unused, parse it for backwards compatibility
 		self.eat(t!("UNIQUE"));
 
+		let before = self.peek().span;
 		let data = self.try_parse_data(stk).await?;
+		if let Some(Data::MergeEachExpression(_)) = data {
+			bail!("Unexpected MERGE EACH clause", @before.covers(self.last_span()) => "MERGE EACH can only be used in UPDATE and UPSERT statements");
+		}
 		let output = self.try_parse_output(stk).await?;
 		let timeout = self.try_parse_timeout(stk).await?;
 		Ok(RelateStatement {
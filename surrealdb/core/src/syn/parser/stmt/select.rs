@@ -3,10 +3,10 @@ use reblessive::Stk;
 use super::parts::MissingKind;
 use crate::sql::order::{OrderList, Ordering};
 use crate::sql::statements::SelectStatement;
-use crate::sql::{Expr, Fields, Limit, Literal, Order, Split, Splits, Start};
+use crate::sql::{Cond, Expr, Fields, Limit, Literal, Order, Split, Splits, Start};
 use crate::syn::parser::mac::expected;
 use crate::syn::parser::{ParseResult, Parser};
-use crate::syn::token::{Span, t};
+use crate::syn::token::{Span, TokenKind, t};
 
 impl Parser<'_> {
 	/// expects `select` to be eaten.
@@ -48,6 +48,11 @@ impl Parser<'_> {
 		let split = self.try_parse_split(&fields, fields_span)?;
 		let split_span = split.as_ref().map(|_| split_before.covers(self.last_span()));
 		let group = self.try_parse_group(&fields, fields_span, split_span)?;
+		let having = if group.is_some() {
+			self.try_parse_having(stk).await?
+		} else {
+			None
+		};
 		let order = self.try_parse_orders(&fields, fields_span)?;
 		let (limit, start) = if let t!("START") = self.peek_kind() {
 			let start = self.try_parse_start(stk).await?;
@@ -65,6 +70,7 @@ impl Parser<'_> {
 			Expr::Literal(Literal::None)
 		};
 		let timeout = self.try_parse_timeout(stk).await?;
+		let scan_timeout = self.try_parse_scan_timeout(stk).await?;
 		let tempfiles = self.eat(t!("TEMPFILES"));
 		let explain = self.try_parse_explain()?;
 
@@ -77,17 +83,49 @@ impl Parser<'_> {
 			cond,
 			split,
 			group,
+			having,
 			order,
 			limit,
 			start,
 			fetch,
 			version,
 			timeout,
+			scan_timeout,
 			tempfiles,
 			explain,
 		})
 	}
 
+	/// Parses a HAVING clause, which filters grouped results.
+	///
+	/// HAVING is not a reserved keyword, so it is parsed as an identifier.
+	async fn try_parse_having(&mut self, stk: &mut Stk) -> ParseResult<Option<Cond>> {
+		let peek = self.peek();
+		if !matches!(peek.kind, TokenKind::Identifier)
+			|| !self.lexer.span_str(peek.span).eq_ignore_ascii_case("HAVING")
+		{
+			return Ok(None);
+		}
+		self.pop_peek();
+		let v = stk.run(|ctx| self.parse_expr_field(ctx)).await?;
+		Ok(Some(Cond(v)))
+	}
+
+	/// Parses a SCAN TIMEOUT clause, which bounds the time spent iterating.
+	///
+	/// SCAN is not a reserved keyword, so it is parsed as an identifier.
+	async fn try_parse_scan_timeout(&mut self, stk: &mut Stk) -> ParseResult<Expr> {
+		let peek = self.peek();
+		if !matches!(peek.kind, TokenKind::Identifier)
+			|| !self.lexer.span_str(peek.span).eq_ignore_ascii_case("SCAN")
+		{
+			return Ok(Expr::Literal(Literal::None));
+		}
+		self.pop_peek();
+		expected!(self, t!("TIMEOUT"));
+		stk.run(|ctx| self.parse_expr_field(ctx)).await
+	}
+
 	pub(crate) fn try_parse_split(
 		&mut self,
 		fields: &Fields,
@@ -14,7 +14,7 @@ impl Parser<'_> {
 		let with = self.try_parse_with()?;
 		let data = self.try_parse_data(stk).await?;
 		let cond = self.try_parse_condition(stk).await?;
-		let output = self.try_parse_output(stk).await?;
+		let output = self.try_parse_update_output(stk).await?;
 		let timeout = self.try_parse_timeout(stk).await?;
 		let explain = self.try_parse_explain()?;
 
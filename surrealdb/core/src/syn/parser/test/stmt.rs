@@ -43,9 +43,9 @@ use crate::sql::statements::{
 use crate::sql::tokenizer::Tokenizer;
 use crate::sql::{
 	Algorithm, AssignOperator, Base, BinaryOperator, Block, Cond, Data, Dir, Explain, Expr, Fetch,
-	Fetchs, Field, Fields, Group, Groups, Idiom, Index, Kind, Literal, Lookup, Mock, Output, Param,
-	Part, Permission, Permissions, RecordIdKeyLit, RecordIdLit, Scoring, TableType, TopLevelExpr,
-	With,
+	Fetchs, Field, Fields, Group, Groups, IdType, Idiom, Index, Kind, Literal, Lookup, Mock,
+	Output, Param, Part, Permission, Permissions, RecordIdKeyLit, RecordIdLit, Scoring, TableType,
+	TopLevelExpr, Ttl, UnknownFields, With,
 };
 use crate::syn;
 use crate::syn::parser::ParserSettings;
@@ -1547,6 +1547,7 @@ fn parse_define_param() {
 		Expr::Define(Box::new(DefineStatement::Param(DefineParamStatement {
 			kind: DefineKind::Default,
 			name: "a".to_owned(),
+			param_kind: None,
 			value: Expr::Literal(Literal::Object(vec![
 				ObjectEntry {
 					key: "a".to_string(),
@@ -1576,6 +1577,7 @@ fn parse_define_table() {
 			name: Expr::Table("name".to_string()),
 			drop: true,
 			full: true,
+			unknown_fields: UnknownFields::Reject,
 			view: Some(crate::sql::View {
 				expr: Fields::Select(vec![Field::Single(Selector {
 					expr: ident_field("foo"),
@@ -1599,6 +1601,8 @@ fn parse_define_table() {
 				expiry: PublicDuration::from_secs(1),
 				store_diff: true,
 			}),
+			ttl: None,
+			id_type: IdType::Rand,
 			comment: Expr::Literal(Literal::None),
 
 			table_type: TableType::Normal,
@@ -1606,6 +1610,91 @@ fn parse_define_table() {
 	);
 }
 
+#[test]
+fn parse_define_table_ttl() {
+	let res = syn::parse_with(
+		r#"DEFINE TABLE session TTL 1d ON created_at"#.as_bytes(),
+		async |parser, stk| parser.parse_expr_inherit(stk).await,
+	)
+	.unwrap();
+
+	assert_eq!(
+		res,
+		Expr::Define(Box::new(DefineStatement::Table(DefineTableStatement {
+			name: Expr::Table("session".to_string()),
+			permissions: Permissions::none(),
+			ttl: Some(Ttl {
+				duration: PublicDuration::from_secs(86400),
+				field: Idiom::field("created_at".to_string()),
+			}),
+			..Default::default()
+		})))
+	);
+}
+
+#[test]
+fn parse_define_table_unknown_fields() {
+	let res = syn::parse_with(
+		r#"DEFINE TABLE event SCHEMAFULL ON UNKNOWN FIELD STORE extra"#.as_bytes(),
+		async |parser, stk| parser.parse_expr_inherit(stk).await,
+	)
+	.unwrap();
+
+	assert_eq!(
+		res,
+		Expr::Define(Box::new(DefineStatement::Table(DefineTableStatement {
+			name: Expr::Table("event".to_string()),
+			full: true,
+			unknown_fields: UnknownFields::Store(Idiom::field("extra".to_string())),
+			permissions: Permissions::none(),
+			table_type: TableType::Normal,
+			..Default::default()
+		})))
+	);
+
+	let res = syn::parse_with(
+		r#"DEFINE TABLE event SCHEMAFULL ON UNKNOWN FIELD IGNORE"#.as_bytes(),
+		async |parser, stk| parser.parse_expr_inherit(stk).await,
+	)
+	.unwrap();
+	let Expr::Define(stmt) = res else {
+		panic!("expected a define statement");
+	};
+	let DefineStatement::Table(stmt) = *stmt else {
+		panic!("expected a define table statement");
+	};
+	assert_eq!(stmt.unknown_fields, UnknownFields::Ignore);
+}
+
+#[test]
+fn parse_define_table_id_type() {
+	for (sql, id_type) in [
+		("DEFINE TABLE person ID TYPE RAND", IdType::Rand),
+		("DEFINE TABLE person ID TYPE UUID", IdType::Uuid),
+		("DEFINE TABLE person ID TYPE ULID", IdType::Ulid),
+		("DEFINE TABLE person SCHEMALESS id type sequence", IdType::Sequence),
+	] {
+		let res = syn::parse_with(sql.as_bytes(), async |parser, stk| {
+			parser.parse_expr_inherit(stk).await
+		})
+		.unwrap();
+		assert_eq!(
+			res,
+			Expr::Define(Box::new(DefineStatement::Table(DefineTableStatement {
+				name: Expr::Table("person".to_string()),
+				permissions: Permissions::none(),
+				id_type,
+				..Default::default()
+			})))
+		);
+	}
+
+	syn::parse_with("DEFINE TABLE person ID TYPE INT".as_bytes(), async |parser, stk| {
+		parser.parse_expr_inherit(stk).await
+	})
+	.unwrap_err();
+}
+
 #[test]
 fn parse_define_event() {
 	let res = syn::parse_with(
@@ -1706,6 +1795,7 @@ fn parse_define_index() {
 				},
 			}),
 			comment: Expr::Literal(Literal::None),
+			cond: None,
 			concurrently: false
 		})))
 	);
@@ -1725,6 +1815,7 @@ fn parse_define_index() {
 			cols: vec![Expr::Idiom(Idiom(vec![Part::Field("a".to_string())]))],
 			index: Index::Uniq,
 			comment: Expr::Literal(Literal::None),
+			cond: None,
 			concurrently: false
 		})))
 	);
@@ -1751,6 +1842,7 @@ fn parse_define_index() {
 				use_hashed_vector: true,
 			}),
 			comment: Expr::Literal(Literal::None),
+			cond: None,
 			concurrently: false
 		})))
 	);
@@ -1796,7 +1888,7 @@ fn parse_delete() {
 			cond: Some(Cond(Expr::Literal(Literal::Integer(2)))),
 			output: Some(Output::After),
 			timeout: Expr::Literal(Literal::Duration(PublicDuration::from_secs(1))),
-			explain: Some(Explain(true)),
+			explain: Some(Explain::Full),
 		}))
 	);
 }
@@ -1824,7 +1916,7 @@ fn parse_delete_2() {
 			cond: Some(Cond(Expr::Literal(Literal::Null))),
 			output: Some(Output::Null),
 			timeout: Expr::Literal(Literal::Duration(PublicDuration::from_secs(60 * 60))),
-			explain: Some(Explain(false)),
+			explain: Some(Explain::Plan),
 		}))
 	)
 }
@@ -1854,12 +1946,14 @@ pub fn parse_for() {
 					cond: None,
 					split: None,
 					group: None,
+					having: None,
 					order: None,
 					limit: None,
 					start: None,
 					fetch: None,
 					version: Expr::Literal(Literal::None),
 					timeout: Expr::Literal(Literal::None),
+					scan_timeout: Expr::Literal(Literal::None),
 					explain: None,
 					tempfiles: false
 				}))),
@@ -2210,12 +2304,14 @@ fn parse_insert_select() {
 				cond: None,
 				split: None,
 				group: None,
+				having: None,
 				order: None,
 				limit: None,
 				start: None,
 				fetch: None,
 				version: Expr::Literal(Literal::None),
 				timeout: Expr::Literal(Literal::None),
+				scan_timeout: Expr::Literal(Literal::None),
 				explain: None,
 				tempfiles: false
 			}))),
@@ -2228,6 +2324,64 @@ fn parse_insert_select() {
 	)
 }
 
+#[test]
+fn parse_select_having() {
+	let sql = "SELECT foo, count() AS total FROM bar GROUP BY foo HAVING total > 1";
+	let res =
+		syn::parse_with(sql.as_bytes(), async |parser, stk| parser.parse_expr_inherit(stk).await)
+			.unwrap();
+	let Expr::Select(stmt) = &res else {
+		panic!("expected a select statement, found {res:?}");
+	};
+	assert_eq!(stmt.group, Some(Groups(vec![Group(Idiom(vec![Part::Field("foo".to_owned())]))])));
+	assert_eq!(
+		stmt.having,
+		Some(Cond(Expr::Binary {
+			left: Box::new(Expr::Idiom(Idiom(vec![Part::Field("total".to_owned())]))),
+			op: BinaryOperator::MoreThan,
+			right: Box::new(Expr::Literal(Literal::Integer(1))),
+		}))
+	);
+	assert_eq!(surrealdb_types::ToSql::to_sql(&res), sql);
+	// HAVING is only valid after a GROUP clause
+	syn::parse_with("SELECT foo FROM bar HAVING foo > 1".as_bytes(), async |parser, stk| {
+		parser.parse_query(stk).await
+	})
+	.unwrap_err();
+}
+
+#[test]
+fn parse_select_scan_timeout() {
+	let sql = "SELECT * FROM bar TIMEOUT 1m SCAN TIMEOUT 1s";
+	let res =
+		syn::parse_with(sql.as_bytes(), async |parser, stk| parser.parse_expr_inherit(stk).await)
+			.unwrap();
+	let Expr::Select(stmt) = &res else {
+		panic!("expected a select statement, found {res:?}");
+	};
+	assert_eq!(stmt.timeout, Expr::Literal(Literal::Duration(PublicDuration::from_secs(60))));
+	assert_eq!(stmt.scan_timeout, Expr::Literal(Literal::Duration(PublicDuration::from_secs(1))));
+	assert_eq!(surrealdb_types::ToSql::to_sql(&res), sql);
+	// A field named scan is still a field
+	syn::parse_with("SELECT scan FROM bar".as_bytes(), async |parser, stk| {
+		parser.parse_query(stk).await
+	})
+	.unwrap();
+}
+
+#[test]
+fn parse_select_explain_analyze() {
+	let sql = "SELECT * FROM bar WHERE foo > 1 EXPLAIN ANALYZE";
+	let res =
+		syn::parse_with(sql.as_bytes(), async |parser, stk| parser.parse_expr_inherit(stk).await)
+			.unwrap();
+	let Expr::Select(stmt) = &res else {
+		panic!("expected a select statement, found {res:?}");
+	};
+	assert_eq!(stmt.explain, Some(Explain::Analyze));
+	assert_eq!(surrealdb_types::ToSql::to_sql(&res), sql);
+}
+
 #[test]
 fn parse_kill() {
 	let res = syn::parse_with(r#"KILL $param"#.as_bytes(), async |parser, stk| {
@@ -2313,7 +2467,21 @@ fn parse_option() {
 		res,
 		TopLevelExpr::Option(OptionStatement {
 			name: "value".to_owned(),
-			what: true
+			what: true,
+			value: None,
+		})
+	);
+
+	let res = syn::parse_with(r#"OPTION DIV_BY_ZERO = NONE"#.as_bytes(), async |parser, stk| {
+		parser.parse_top_level_expr(stk).await
+	})
+	.unwrap();
+	assert_eq!(
+		res,
+		TopLevelExpr::Option(OptionStatement {
+			name: "DIV_BY_ZERO".to_owned(),
+			what: true,
+			value: Some("NONE".to_owned()),
 		})
 	)
 }
@@ -2618,7 +2786,7 @@ fn parse_update() {
 			])),
 			output: Some(Output::Diff),
 			timeout: Expr::Literal(Literal::Duration(PublicDuration::from_secs(1))),
-			explain: Some(Explain(true))
+			explain: Some(Explain::Full)
 		}))
 	);
 }
@@ -2660,11 +2828,57 @@ fn parse_upsert() {
 			])),
 			output: Some(Output::Diff),
 			timeout: Expr::Literal(Literal::Duration(PublicDuration::from_secs(1))),
-			explain: Some(Explain(false))
+			explain: Some(Explain::Plan)
 		}))
 	);
 }
 
+#[test]
+fn parse_return_changed() {
+	fn output(sql: &str) -> Option<Output> {
+		let res = syn::parse_with(sql.as_bytes(), async |parser, stk| {
+			parser.parse_expr_inherit(stk).await
+		})
+		.unwrap();
+		match res {
+			Expr::Update(x) => x.output,
+			Expr::Upsert(x) => x.output,
+			Expr::Create(x) => x.output,
+			x => panic!("unexpected statement: {x:?}"),
+		}
+	}
+	fn fields(names: &[&str]) -> Option<Output> {
+		let fields = names
+			.iter()
+			.map(|x| {
+				Field::Single(Selector {
+					expr: ident_field(x),
+					alias: None,
+				})
+			})
+			.collect();
+		Some(Output::Fields(Fields::Select(fields)))
+	}
+
+	assert_eq!(output("UPDATE a RETURN CHANGED"), Some(Output::Changed));
+	assert_eq!(output("UPSERT a RETURN changed TIMEOUT 1s"), Some(Output::Changed));
+	// An escaped `changed`, or one followed by other fields, is a field
+	assert_eq!(output("UPDATE a RETURN `changed`"), fields(&["changed"]));
+	assert_eq!(output("UPDATE a RETURN changed, b"), fields(&["changed", "b"]));
+	assert_eq!(output("UPSERT a RETURN changed AS b"), {
+		Some(Output::Fields(Fields::Select(vec![Field::Single(Selector {
+			expr: ident_field("changed"),
+			alias: Some(Idiom(vec![Part::Field("b".to_string())])),
+		})])))
+	});
+	// Other statements have no `CHANGED` clause
+	assert_eq!(output("CREATE a RETURN changed"), fields(&["changed"]));
+	// A lone `changed` field is covered so that it is not parsed back as the clause
+	let sql = surrealdb_types::ToSql::to_sql(&fields(&["changed"]).unwrap());
+	assert_eq!(sql, "RETURN (changed)");
+	assert_eq!(output(&format!("UPDATE a {sql}")), fields(&["changed"]));
+}
+
 #[test]
 fn parse_access_grant() {
 	// User
@@ -33,9 +33,10 @@ use crate::sql::statements::{
 use crate::sql::tokenizer::Tokenizer;
 use crate::sql::{
 	Algorithm, AssignOperator, Base, BinaryOperator, Block, Cond, Data, Dir, Explain, Expr, Fetch,
-	Fetchs, Field, Fields, Function, FunctionCall, Group, Groups, Idiom, Index, Kind, Limit,
-	Literal, Lookup, Mock, Order, Output, Param, Part, Permission, Permissions, RecordIdKeyLit,
-	RecordIdLit, Scoring, Script, Split, Splits, Start, TableType, TopLevelExpr, With,
+	Fetchs, Field, Fields, Function, FunctionCall, Group, Groups, IdType, Idiom, Index, Kind,
+	Limit, Literal, Lookup, Mock, Order, Output, Param, Part, Permission, Permissions,
+	RecordIdKeyLit, RecordIdLit, Scoring, Script, Split, Splits, Start, TableType, TopLevelExpr,
+	UnknownFields, With,
 };
 use crate::syn::parser::StatementStream;
 use crate::types::{PublicDatetime, PublicDuration, PublicUuid};
@@ -241,6 +242,7 @@ fn statements() -> Vec<TopLevelExpr> {
 		TopLevelExpr::Expr(Expr::Define(Box::new(DefineStatement::Param(DefineParamStatement {
 			kind: DefineKind::Default,
 			name: "a".to_owned(),
+			param_kind: None,
 			value: Expr::Literal(Literal::Object(vec![
 				ObjectEntry {
 					key: "a".to_string(),
@@ -260,6 +262,7 @@ fn statements() -> Vec<TopLevelExpr> {
 			name: Expr::Table("name".to_string()),
 			drop: true,
 			full: true,
+			unknown_fields: UnknownFields::Reject,
 			view: Some(crate::sql::View {
 				expr: Fields::Select(vec![Field::Single(Selector {
 					expr: ident_field("foo"),
@@ -283,6 +286,8 @@ fn statements() -> Vec<TopLevelExpr> {
 				expiry: PublicDuration::from_secs(1),
 				store_diff: false,
 			}),
+			ttl: None,
+			id_type: IdType::Rand,
 			comment: Expr::Literal(Literal::None),
 
 			table_type: TableType::Normal,
@@ -342,6 +347,7 @@ fn statements() -> Vec<TopLevelExpr> {
 				},
 			}),
 			comment: Expr::Literal(Literal::None),
+			cond: None,
 			concurrently: false,
 		})))),
 		TopLevelExpr::Expr(Expr::Define(Box::new(DefineStatement::Index(DefineIndexStatement {
@@ -351,6 +357,7 @@ fn statements() -> Vec<TopLevelExpr> {
 			cols: vec![Expr::Idiom(Idiom(vec![Part::Field("a".to_string())]))],
 			index: Index::Uniq,
 			comment: Expr::Literal(Literal::None),
+			cond: None,
 			concurrently: false,
 		})))),
 		TopLevelExpr::Expr(Expr::Define(Box::new(DefineStatement::Analyzer(
@@ -382,7 +389,7 @@ fn statements() -> Vec<TopLevelExpr> {
 			cond: Some(Cond(Expr::Literal(Literal::Integer(2)))),
 			output: Some(Output::After),
 			timeout: Expr::Literal(Literal::Duration(PublicDuration::from_secs(1))),
-			explain: Some(Explain(true)),
+			explain: Some(Explain::Full),
 		}))),
 		TopLevelExpr::Expr(Expr::Delete(Box::new(DeleteStatement {
 			only: true,
@@ -402,7 +409,7 @@ fn statements() -> Vec<TopLevelExpr> {
 			cond: Some(Cond(Expr::Literal(Literal::Null))),
 			output: Some(Output::Null),
 			timeout: Expr::Literal(Literal::Duration(PublicDuration::from_secs(60 * 60))),
-			explain: Some(Explain(true)),
+			explain: Some(Explain::Full),
 		}))),
 		TopLevelExpr::Expr(Expr::Foreach(Box::new(ForeachStatement {
 			param: Param::new("foo".to_owned()),
@@ -419,12 +426,14 @@ fn statements() -> Vec<TopLevelExpr> {
 					cond: None,
 					split: None,
 					group: None,
+					having: None,
 					order: None,
 					limit: None,
 					start: None,
 					fetch: None,
 					version: Expr::Literal(Literal::None),
 					timeout: Expr::Literal(Literal::None),
+					scan_timeout: Expr::Literal(Literal::None),
 					explain: None,
 					tempfiles: false,
 				}))),
@@ -482,6 +491,7 @@ fn statements() -> Vec<TopLevelExpr> {
 				Group(Idiom(vec![Part::Field("foo".to_owned())])),
 				Group(Idiom(vec![Part::Field("bar".to_owned())])),
 			])),
+			having: None,
 			order: Some(Ordering::Order(OrderList(vec![Order {
 				value: Idiom(vec![Part::Field("foo".to_owned())]),
 				collate: true,
@@ -499,8 +509,9 @@ fn statements() -> Vec<TopLevelExpr> {
 			fetch: Some(Fetchs(vec![Fetch(ident_field("foo"))])),
 			version: Expr::Literal(Literal::Datetime(PublicDatetime::from(expected_datetime))),
 			timeout: Expr::Literal(Literal::None),
+			scan_timeout: Expr::Literal(Literal::None),
 			tempfiles: false,
-			explain: Some(Explain(true)),
+			explain: Some(Explain::Full),
 		}))),
 		TopLevelExpr::Expr(Expr::Select(Box::new(SelectStatement {
 			fields: Fields::Select(vec![
@@ -523,12 +534,14 @@ fn statements() -> Vec<TopLevelExpr> {
 				Split(Idiom(vec![Part::Field("bar".to_owned())])),
 			])),
 			group: None,
+			having: None,
 			order: None,
 			limit: None,
 			start: None,
 			fetch: None,
 			version: Expr::Literal(Literal::None),
 			timeout: Expr::Literal(Literal::None),
+			scan_timeout: Expr::Literal(Literal::None),
 			tempfiles: false,
 			explain: None,
 		}))),
@@ -665,7 +678,7 @@ fn statements() -> Vec<TopLevelExpr> {
 			])),
 			output: Some(Output::Diff),
 			timeout: Expr::Literal(Literal::Duration(PublicDuration::from_secs(1))),
-			explain: Some(Explain(true)),
+			explain: Some(Explain::Full),
 		}))),
 		TopLevelExpr::Expr(Expr::Upsert(Box::new(UpsertStatement {
 			only: true,
@@ -699,7 +712,7 @@ fn statements() -> Vec<TopLevelExpr> {
 			])),
 			output: Some(Output::Diff),
 			timeout: Expr::Literal(Literal::Duration(PublicDuration::from_secs(1))),
-			explain: Some(Explain(true)),
+			explain: Some(Explain::Full),
 		}))),
 		TopLevelExpr::Expr(Expr::FunctionCall(Box::new(FunctionCall {
 			receiver: Function::Script(Script(" ((1 + 1)) ".to_owned())),
@@ -78,6 +78,7 @@ keyword! {
 	Distance => "DISTANCE",
 	Drop => "DROP",
 	Duplicate => "DUPLICATE",
+	Each => "EACH",
 	Efc => "EFC",
 	Edgengram => "EDGENGRAM",
 	Event => "EVENT",
@@ -192,6 +193,7 @@ keyword! {
 	Snowball => "SNOWBALL",
 	Split => "SPLIT",
 	Start => "START",
+	Store => "STORE",
 	Strict => "STRICT",
 	Structure => "STRUCTURE",
 	System => "SYSTEM",
@@ -208,8 +210,10 @@ keyword! {
 	To => "TO",
 	Transaction => "TRANSACTION",
 	True => "true",
+	Ttl => "TTL",
 	Type => "TYPE",
 	Unique => "UNIQUE",
+	Unknown => "UNKNOWN",
 	Unset => "UNSET",
 	Update => "UPDATE",
 	Upsert => "UPSERT",
@@ -1,7 +1,7 @@
 use std::collections::{BTreeSet, HashSet, VecDeque};
 use std::ops::{Deref, DerefMut};
 
-use anyhow::{Result, ensure};
+use anyhow::{Result, bail, ensure};
 use revision::revisioned;
 use storekey::{BorrowDecode, Encode};
 use surrealdb_types::{SqlFormat, ToSql};
@@ -209,6 +209,60 @@ impl Array {
 
 		transposed_vec.into()
 	}
+
+	/// Transposes a 2d array, swapping its rows and columns.
+	///
+	/// Unlike [`Array::transpose`], every element of the input must be an
+	/// array, and all of these must be of the same length. An empty input
+	/// returns an empty array.
+	///
+	/// # Examples
+	///
+	/// ```ignore
+	/// fn array(sql: &str) -> Array {
+	///     unimplemented!();
+	/// }
+	///
+	/// assert_eq!(array("[[0, 1], [2, 3]]").transpose_strict()?, array("[[0, 2], [1, 3]]"));
+	/// assert_eq!(array("[[0, 1, 2]]").transpose_strict()?, array("[[0], [1], [2]]"));
+	/// // Ragged input is rejected
+	/// assert!(array("[[0, 1], [2]]").transpose_strict().is_err());
+	/// ```
+	pub(crate) fn transpose_strict(self) -> Result<Array> {
+		let mut rows = Vec::with_capacity(self.len());
+		for (idx, row) in self.into_iter().enumerate() {
+			match row {
+				Value::Array(row) => rows.push(row),
+				row => bail!(Error::InvalidFunctionArguments {
+					name: "array::transpose".to_string(),
+					message: format!(
+						"Expected an array of arrays, but element {idx} was `{}`",
+						row.to_sql()
+					),
+				}),
+			}
+		}
+		// All rows must have the same length as the first one
+		let width = rows.first().map(|x| x.len()).unwrap_or(0);
+		if let Some((idx, row)) = rows.iter().enumerate().find(|(_, x)| x.len() != width) {
+			bail!(Error::InvalidFunctionArguments {
+				name: "array::transpose".to_string(),
+				message: format!(
+					"All arrays must have the same length, but element 0 has length {width} and element {idx} has length {}",
+					row.len()
+				),
+			});
+		}
+
+		let mut transposed_vec = vec![Vec::with_capacity(rows.len()); width];
+		for row in rows {
+			for (jdx, v) in row.into_iter().enumerate() {
+				transposed_vec[jdx].push(v);
+			}
+		}
+
+		Ok(transposed_vec.into())
+	}
 }
 
 impl ToSql for Array {
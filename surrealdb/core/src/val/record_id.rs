@@ -444,12 +444,14 @@ impl RecordId {
 			cond: None,
 			split: None,
 			group: None,
+			having: None,
 			order: None,
 			limit: None,
 			start: None,
 			fetch: None,
 			version: Expr::Literal(Literal::None),
 			timeout: Expr::Literal(Literal::None),
+			scan_timeout: Expr::Literal(Literal::None),
 			explain: None,
 			tempfiles: false,
 		};
@@ -0,0 +1,120 @@
+use anyhow::{Result, bail};
+
+use crate::cnf::MAX_COMPUTATION_DEPTH;
+use crate::err::Error;
+use crate::val::{Object, Value};
+
+impl Value {
+	/// Returns the parts of `val` which differ from this value, in the form of
+	/// a merge patch. Nested objects are compared field by field, while arrays
+	/// and all other values are returned whole when they differ. Fields which
+	/// were removed are returned with a value of `NONE`.
+	pub(crate) fn changed(&self, val: &Value) -> Result<Value> {
+		self.changed_rec(val, 0)
+	}
+
+	fn changed_rec(&self, val: &Value, depth: u32) -> Result<Value> {
+		// Limit recursion depth.
+		if depth > *MAX_COMPUTATION_DEPTH {
+			bail!(Error::ComputationDepthExceeded);
+		}
+		match (self, val) {
+			(Value::Object(a), Value::Object(b)) => {
+				let mut res = Object::default();
+				// Loop over old keys
+				for key in a.keys() {
+					if !b.contains_key(key) {
+						res.insert(key.clone(), Value::None);
+					}
+				}
+				// Loop over new keys
+				for (key, new) in b.iter() {
+					match a.get(key) {
+						Some(old) if old == new => (),
+						Some(old @ Value::Object(_)) if new.is_object() => {
+							res.insert(key.clone(), old.changed_rec(new, depth + 1)?);
+						}
+						_ => {
+							res.insert(key.clone(), new.clone());
+						}
+					}
+				}
+				Ok(Value::Object(res))
+			}
+			(_, val) => Ok(val.clone()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn;
+
+	macro_rules! parse_val {
+		($input:expr) => {
+			crate::val::convert_public_value_to_internal(syn::value($input).unwrap())
+		};
+	}
+
+	#[test]
+	fn changed_none() {
+		let old = parse_val!("{ test: true, text: 'text', other: { something: true } }");
+		let now = parse_val!("{ test: true, text: 'text', other: { something: true } }");
+		let res = parse_val!("{}");
+		assert_eq!(res, old.changed(&now).unwrap());
+	}
+
+	#[test]
+	fn changed_add_and_change() {
+		let old = parse_val!("{ test: true, text: 'text' }");
+		let now = parse_val!("{ test: true, text: 'other', tags: ['a'] }");
+		let res = parse_val!("{ text: 'other', tags: ['a'] }");
+		assert_eq!(res, old.changed(&now).unwrap());
+	}
+
+	#[test]
+	fn changed_remove() {
+		let old = parse_val!("{ test: true, other: 'test' }");
+		let now = parse_val!("{ test: true }");
+		let mut res = Object::default();
+		res.insert("other".to_owned(), Value::None);
+		assert_eq!(Value::Object(res), old.changed(&now).unwrap());
+	}
+
+	#[test]
+	fn changed_embedded() {
+		let old = parse_val!("{ test: { other: 'test', same: 1 } }");
+		let now = parse_val!("{ test: { other: false, same: 1 } }");
+		let res = parse_val!("{ test: { other: false } }");
+		assert_eq!(res, old.changed(&now).unwrap());
+	}
+
+	#[test]
+	fn changed_array() {
+		let old = parse_val!("{ test: [1, 2, 3], same: [1] }");
+		let now = parse_val!("{ test: [1, 2, 4], same: [1] }");
+		let res = parse_val!("{ test: [1, 2, 4] }");
+		assert_eq!(res, old.changed(&now).unwrap());
+	}
+
+	#[test]
+	fn changed_depth_exceeded() {
+		fn nested(depth: u32, val: Value) -> Value {
+			(0..depth).fold(val, |val, _| {
+				let mut obj = Object::default();
+				obj.insert("a".to_owned(), val);
+				Value::Object(obj)
+			})
+		}
+		let depth = *MAX_COMPUTATION_DEPTH + 10;
+		let old = nested(depth, Value::from(1));
+		let now = nested(depth, Value::from(2));
+		let err = old.changed(&now).unwrap_err();
+		assert!(
+			matches!(err.downcast_ref(), Some(Error::ComputationDepthExceeded)),
+			"expected computation depth exceeded, got {err:?}"
+		);
+	}
+}
@@ -0,0 +1,64 @@
+use crate::val::Value;
+
+impl Value {
+	/// Returns a copy of this value, with any `NONE` values filled in from
+	/// `defaults` at the same path. Nested objects are filled in field by
+	/// field, while arrays and all other values are treated as a whole. Values
+	/// which are already present are never overwritten.
+	pub(crate) fn coalesce_deep(&self, defaults: &Value) -> Value {
+		match (self, defaults) {
+			(Value::None, defaults) => defaults.clone(),
+			(Value::Object(val), Value::Object(defaults)) => {
+				let mut res = val.clone();
+				for (key, default) in defaults.iter() {
+					let filled = match val.get(key) {
+						Some(v) => v.coalesce_deep(default),
+						None => default.clone(),
+					};
+					res.insert(key.clone(), filled);
+				}
+				Value::Object(res)
+			}
+			(val, _) => val.clone(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn;
+
+	macro_rules! parse_val {
+		($input:expr) => {
+			crate::val::convert_public_value_to_internal(syn::value($input).unwrap())
+		};
+	}
+
+	#[test]
+	fn coalesce_deep_none() {
+		let val = Value::None;
+		let defaults = parse_val!("{ test: true }");
+		assert_eq!(defaults, val.coalesce_deep(&defaults));
+	}
+
+	#[test]
+	fn coalesce_deep_nested_gaps() {
+		let val = parse_val!("{ name: 'test', settings: { theme: NONE, size: 10 } }");
+		let defaults = parse_val!(
+			"{ enabled: true, settings: { theme: 'dark', size: 12, limits: { max: 5 } } }"
+		);
+		let res = parse_val!(
+			"{ name: 'test', enabled: true, settings: { theme: 'dark', size: 10, limits: { max: 5 } } }"
+		);
+		assert_eq!(res, val.coalesce_deep(&defaults));
+	}
+
+	#[test]
+	fn coalesce_deep_preserves_present_values() {
+		let val = parse_val!("{ level: NULL, tags: ['a'], settings: 'custom' }");
+		let defaults = parse_val!("{ level: 1, tags: ['b', 'c'], settings: { theme: 'dark' } }");
+		assert_eq!(val, val.coalesce_deep(&defaults));
+	}
+}
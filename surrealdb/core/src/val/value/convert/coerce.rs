@@ -6,6 +6,7 @@ use geo::Point;
 use rust_decimal::Decimal;
 use surrealdb_types::ToSql;
 
+use crate::dbs::NullCoercion;
 use crate::expr::Kind;
 use crate::expr::kind::{GeometryKind, HasKind, KindLiteral};
 use crate::val::{
@@ -659,6 +660,25 @@ impl Value {
 		}
 	}
 
+	/// Try to coerce this value to the specified `Kind`, first coercing
+	/// between NONE and NULL according to the given policy
+	pub(crate) fn coerce_to_kind_with(
+		self,
+		kind: &Kind,
+		policy: NullCoercion,
+	) -> Result<Value, CoerceError> {
+		let this = match self {
+			Value::Null if policy.null_as_none() && kind.can_be_none() && !kind.can_be_null() => {
+				Value::None
+			}
+			Value::None if policy.none_as_null() && kind.can_be_null() && !kind.can_be_none() => {
+				Value::Null
+			}
+			x => x,
+		};
+		this.coerce_to_kind(kind)
+	}
+
 	/// Try to coerce this value to a Literal, returns a `Value` with the
 	/// coerced value
 	pub(crate) fn coerce_to_literal(self, literal: &KindLiteral) -> Result<Value, CoerceError> {
@@ -912,4 +932,57 @@ mod tests {
 		let result = value.coerce_to_kind(&kind);
 		assert!(result.is_err()); // Coercion from string is strict
 	}
+
+	#[test]
+	fn test_coerce_null_coercion_option() {
+		let kind = Kind::option(Kind::String);
+		// NULL is only coerced to NONE when the policy allows it
+		assert!(Value::Null.coerce_to_kind_with(&kind, NullCoercion::Strict).is_err());
+		assert!(Value::Null.coerce_to_kind_with(&kind, NullCoercion::NoneAsNull).is_err());
+		let result = Value::Null.coerce_to_kind_with(&kind, NullCoercion::NullAsNone);
+		assert_eq!(result.unwrap(), Value::None);
+		let result = Value::Null.coerce_to_kind_with(&kind, NullCoercion::Interchangeable);
+		assert_eq!(result.unwrap(), Value::None);
+		// NONE is left as is for every policy
+		for policy in [
+			NullCoercion::Strict,
+			NullCoercion::NullAsNone,
+			NullCoercion::NoneAsNull,
+			NullCoercion::Interchangeable,
+		] {
+			let result = Value::None.coerce_to_kind_with(&kind, policy);
+			assert_eq!(result.unwrap(), Value::None);
+		}
+	}
+
+	#[test]
+	fn test_coerce_null_coercion_null() {
+		for kind in [Kind::Null, Kind::either(vec![Kind::String, Kind::Null])] {
+			// NONE is only coerced to NULL when the policy allows it
+			assert!(Value::None.coerce_to_kind_with(&kind, NullCoercion::Strict).is_err());
+			assert!(Value::None.coerce_to_kind_with(&kind, NullCoercion::NullAsNone).is_err());
+			let result = Value::None.coerce_to_kind_with(&kind, NullCoercion::NoneAsNull);
+			assert_eq!(result.unwrap(), Value::Null);
+			let result = Value::None.coerce_to_kind_with(&kind, NullCoercion::Interchangeable);
+			assert_eq!(result.unwrap(), Value::Null);
+		}
+	}
+
+	#[test]
+	fn test_coerce_null_coercion_unaffected() {
+		// Kinds which allow both, or neither, are not affected by the policy
+		let both = Kind::either(vec![Kind::None, Kind::Null, Kind::String]);
+		let result = Value::Null.coerce_to_kind_with(&both, NullCoercion::Interchangeable);
+		assert_eq!(result.unwrap(), Value::Null);
+		let result = Value::None.coerce_to_kind_with(&both, NullCoercion::Interchangeable);
+		assert_eq!(result.unwrap(), Value::None);
+		let result = Value::Null.coerce_to_kind_with(&Kind::Any, NullCoercion::Interchangeable);
+		assert_eq!(result.unwrap(), Value::Null);
+		assert!(
+			Value::Null.coerce_to_kind_with(&Kind::String, NullCoercion::Interchangeable).is_err()
+		);
+		assert!(
+			Value::None.coerce_to_kind_with(&Kind::String, NullCoercion::Interchangeable).is_err()
+		);
+	}
 }
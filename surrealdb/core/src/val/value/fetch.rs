@@ -55,6 +55,7 @@ impl Value {
 							what: vec![what],
 							cond: g.cond.clone(),
 							limit: g.limit.clone(),
+							having: None,
 							order: g.order.clone(),
 							split: g.split.clone(),
 							group: g.group.clone(),
@@ -65,6 +66,7 @@ impl Value {
 							fetch: None,
 							version: Expr::Literal(Literal::None),
 							timeout: Expr::Literal(Literal::None),
+							scan_timeout: Expr::Literal(Literal::None),
 							explain: None,
 							tempfiles: false,
 						};
@@ -258,12 +260,14 @@ impl Value {
 					cond: None,
 					split: None,
 					group: None,
+					having: None,
 					order: None,
 					limit: None,
 					start: None,
 					fetch: None,
 					version: Expr::Literal(Literal::None),
 					timeout: Expr::Literal(Literal::None),
+					scan_timeout: Expr::Literal(Literal::None),
 					explain: None,
 					tempfiles: false,
 				};
@@ -456,6 +456,7 @@ impl Value {
 								what: vec![what],
 								cond: g.cond.clone(),
 								limit: g.limit.clone(),
+								having: None,
 								order: g.order.clone(),
 								split: g.split.clone(),
 								group: g.group.clone(),
@@ -466,6 +467,7 @@ impl Value {
 								fetch: None,
 								version: Expr::Literal(Literal::None),
 								timeout: Expr::Literal(Literal::None),
+								scan_timeout: Expr::Literal(Literal::None),
 								explain: None,
 								tempfiles: false,
 							};
@@ -1,4 +1,6 @@
 mod all;
+mod changed;
+mod coalesce;
 mod compare;
 mod cut;
 mod decrement;
@@ -549,3 +549,52 @@ async fn multi_index_concurrent_test_index_compaction() -> Result<()> {
 	}
 	Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn rebuild_index_serves_queries_until_ready() -> Result<()> {
+	let (_, dbs) = new_ds("test", "test", false).await?;
+	let dbs = Arc::new(dbs);
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let sql = "
+		DEFINE TABLE t;
+		FOR $i IN 0..2000 { CREATE t SET n = $i % 10 RETURN NONE };
+		DEFINE INDEX ix ON t FIELDS n;
+	";
+	for res in dbs.execute(sql, &ses, None).await? {
+		res.result?;
+	}
+	// Rebuild the index in the background
+	let rebuild = {
+		let (dbs, ses) = (dbs.clone(), ses.clone());
+		tokio::spawn(async move { dbs.execute("REBUILD INDEX ix ON t", &ses, None).await })
+	};
+	// The index keeps serving complete results while it is rebuilt
+	let mut expected = 200i64;
+	while !rebuild.is_finished() {
+		let sql = "
+			CREATE t SET n = 5 RETURN NONE;
+			count(SELECT * FROM t WHERE n = 5);
+			INFO FOR INDEX ix ON t;
+		";
+		let mut res = dbs.execute(sql, &ses, None).await?;
+		res.remove(0).result?;
+		expected += 1;
+		assert_eq!(res.remove(0).result?, Value::from_t(expected));
+		let info = res.remove(0).result?.to_sql();
+		assert!(["cleaning", "indexing", "ready"].iter().any(|s| info.contains(s)), "{info}");
+	}
+	rebuild.await??.remove(0).result?;
+	// The rebuilt index is used, and includes the records created in the meantime
+	let sql = "
+		count(SELECT * FROM t WHERE n = 5);
+		SELECT * FROM t WHERE n = 5 EXPLAIN;
+		INFO FOR INDEX ix ON t;
+	";
+	let mut res = dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.remove(0).result?, Value::from_t(expected));
+	let explain = res.remove(0).result?.to_sql();
+	assert!(explain.contains("index: 'ix'"), "{explain}");
+	let info = res.remove(0).result?.to_sql();
+	assert!(info.contains("status: 'ready'"), "{info}");
+	Ok(())
+}
@@ -72,6 +72,13 @@ pub struct StartCommandArguments {
 	#[arg(env = "SURREAL_ASYNC_EVENT_PROCESSING_INTERVAL", long = "async-event-interval", value_parser = super::validator::duration)]
 	#[arg(default_value = "5s")]
 	event_processing_interval: Duration,
+	#[arg(
+		help = "The interval at which to purge expired records from tables with a TTL",
+		help_heading = "Database"
+	)]
+	#[arg(env = "SURREAL_TTL_SWEEP_INTERVAL", long = "ttl-sweep-interval", value_parser = super::validator::duration)]
+	#[arg(default_value = "60s")]
+	ttl_sweep_interval: Duration,
 	//
 	// Authentication
 	#[arg(
@@ -186,6 +193,7 @@ pub async fn init<
 		changefeed_gc_interval,
 		index_compaction_interval,
 		event_processing_interval,
+		ttl_sweep_interval,
 		no_banner,
 		no_identification_headers,
 		allow_origin,
@@ -220,7 +228,8 @@ pub async fn init<
 		.with_node_membership_cleanup_interval(node_membership_cleanup_interval)
 		.with_changefeed_gc_interval(changefeed_gc_interval)
 		.with_index_compaction_interval(index_compaction_interval)
-		.with_event_processing_interval(event_processing_interval);
+		.with_event_processing_interval(event_processing_interval)
+		.with_ttl_sweep_interval(ttl_sweep_interval);
 	// Configure the config
 	let Some(bind) = listen_addresses.first().copied() else {
 		return Err(anyhow::anyhow!("No listen address provided"));
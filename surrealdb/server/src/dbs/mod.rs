@@ -35,6 +35,10 @@ pub struct StartCommandDbsOptions {
 	#[arg(env = "SURREAL_TRANSACTION_TIMEOUT", long)]
 	#[arg(value_parser = super::cli::validator::duration)]
 	transaction_timeout: Option<Duration>,
+	#[arg(help = "The maximum duration that a pessimistic transaction waits to acquire a lock")]
+	#[arg(env = "SURREAL_LOCK_TIMEOUT", long)]
+	#[arg(value_parser = super::cli::validator::duration)]
+	lock_timeout: Option<Duration>,
 	#[arg(help = "Whether to allow unauthenticated access", help_heading = "Authentication")]
 	#[arg(env = "SURREAL_UNAUTHENTICATED", long = "unauthenticated")]
 	#[arg(default_value_t = false)]
@@ -731,6 +735,7 @@ pub async fn init<C: TransactionBuilderFactory + BucketStoreProvider>(
 		strict_mode,
 		query_timeout,
 		transaction_timeout,
+		lock_timeout,
 		unauthenticated,
 		capabilities,
 		temporary_directory,
@@ -758,6 +763,10 @@ pub async fn init<C: TransactionBuilderFactory + BucketStoreProvider>(
 	if let Some(v) = transaction_timeout {
 		debug!("Maximum transaction processing timeout is {v:?}");
 	}
+	// Log specified lock timeout
+	if let Some(v) = lock_timeout {
+		debug!("Maximum lock acquisition timeout is {v:?}");
+	}
 	// Log whether authentication is disabled
 	if unauthenticated {
 		warn!(
@@ -790,6 +799,7 @@ pub async fn init<C: TransactionBuilderFactory + BucketStoreProvider>(
 	let builder = Datastore::builder()
 		.with_query_timeout(query_timeout)
 		.with_transaction_timeout(transaction_timeout)
+		.with_lock_timeout(lock_timeout)
 		.with_auth(!unauthenticated)
 		.with_capabilities(capabilities)
 		.with_notify(send)
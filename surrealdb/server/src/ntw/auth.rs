@@ -8,16 +8,19 @@ use futures_util::future::BoxFuture;
 use http::StatusCode;
 use http::request::Parts;
 use hyper::{Request, Response};
+use surrealdb::headers::{JSON_BIG_INTS, JSON_DATETIMES, RETURN_VERSION};
 use surrealdb_core::dbs::Session;
 use surrealdb_core::iam::verify::{basic, token};
+use surrealdb_types::JsonOptions;
 use tower_http::auth::AsyncAuthorizeRequest;
 use uuid::Uuid;
 
 use super::AppState;
 use super::client_ip::ExtractClientIP;
 use super::headers::{
-	SurrealAuthDatabase, SurrealAuthNamespace, SurrealDatabase, SurrealId, SurrealNamespace,
-	parse_typed_header,
+	SurrealAuthDatabase, SurrealAuthNamespace, SurrealDatabase, SurrealDefaultLimit, SurrealId,
+	SurrealMaxOutputDepth, SurrealNamespace, SurrealNullCoercion, SurrealOutputDepthPolicy,
+	parse_typed_header, parse_typed_header_value,
 };
 use crate::ntw::error::Error as NetError;
 
@@ -57,12 +60,17 @@ impl AsyncAuthorizeRequest<Body> for SurrealAuth {
 					Ok(Request::from_parts(parts, body))
 				}
 				Err(err) => {
+					// Invalid headers are a bad request, rather than a failed authentication
+					let status = match err.downcast_ref::<NetError>() {
+						Some(NetError::InvalidHeader(..)) => StatusCode::BAD_REQUEST,
+						_ => StatusCode::UNAUTHORIZED,
+					};
 					let unauthorized_response = Response::builder()
-						.status(StatusCode::UNAUTHORIZED)
+						.status(status)
 						.body(Body::new(err.to_string()))
 						.unwrap_or_else(|_| {
 							let mut resp = Response::new(Body::empty());
-							*resp.status_mut() = StatusCode::UNAUTHORIZED;
+							*resp.status_mut() = status;
 							resp
 						});
 					Err(unauthorized_response)
@@ -128,13 +136,66 @@ async fn check_auth(parts: &mut Parts) -> Result<Session> {
 	let ExtractClientIP(ip) =
 		parts.extract_with_state(&state).await.unwrap_or(ExtractClientIP(None));
 
+	// Check whether integers outside of the safe javascript range should be
+	// encoded as strings in json responses.
+	let big_ints_as_strings = parts
+		.headers
+		.get(&JSON_BIG_INTS)
+		.and_then(|v| v.to_str().ok())
+		.is_some_and(|v| v.eq_ignore_ascii_case("string"));
+
+	// Check whether ISO-8601 strings in incoming json should be parsed as
+	// datetimes, even for untyped fields.
+	let auto_parse_datetimes = parts
+		.headers
+		.get(&JSON_DATETIMES)
+		.and_then(|v| v.to_str().ok())
+		.is_some_and(|v| v.eq_ignore_ascii_case("parse"));
+
+	// Check whether the results of committed writes should include the
+	// version at which they can be read back.
+	let return_version = parts
+		.headers
+		.get(&RETURN_VERSION)
+		.and_then(|v| v.to_str().ok())
+		.is_some_and(|v| v.eq_ignore_ascii_case("true"));
+
+	// Check how NONE and NULL values should be coerced into one another.
+	let null_coercion = parse_typed_header_value::<SurrealNullCoercion, _>(
+		parts.extract::<TypedHeader<SurrealNullCoercion>>().await,
+	)?
+	.unwrap_or_default();
+
+	// Check how deeply the returned values may be nested, and what happens
+	// to values which are nested more deeply.
+	let max_output_depth = parse_typed_header_value::<SurrealMaxOutputDepth, _>(
+		parts.extract::<TypedHeader<SurrealMaxOutputDepth>>().await,
+	)?;
+	let output_depth_policy = parse_typed_header_value::<SurrealOutputDepthPolicy, _>(
+		parts.extract::<TypedHeader<SurrealOutputDepthPolicy>>().await,
+	)?
+	.unwrap_or_default();
+
+	// Check the LIMIT of SELECT statements which do not specify one
+	let default_limit = parse_typed_header_value::<SurrealDefaultLimit, _>(
+		parts.extract::<TypedHeader<SurrealDefaultLimit>>().await,
+	)?;
+
 	// Create session
 	let mut session = Session {
 		ip,
 		or,
+		pr: Some("http".to_owned()),
 		id,
 		ns,
 		db,
+		json_options: JsonOptions::default().with_big_ints_as_strings(big_ints_as_strings),
+		auto_parse_datetimes,
+		return_version,
+		null_coercion,
+		max_output_depth,
+		output_depth_policy,
+		default_limit,
 		..Session::default()
 	};
 
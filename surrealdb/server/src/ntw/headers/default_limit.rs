@@ -0,0 +1,51 @@
+use axum_extra::headers;
+use axum_extra::headers::Header;
+use http::{HeaderName, HeaderValue};
+use surrealdb::headers::DEFAULT_LIMIT;
+
+/// Typed header implementation for the default limit header.
+/// It's used to specify the LIMIT of SELECT statements which do not specify one.
+pub struct SurrealDefaultLimit(HeaderValue, String);
+
+impl Header for SurrealDefaultLimit {
+	fn name() -> &'static HeaderName {
+		&DEFAULT_LIMIT
+	}
+
+	fn decode<'i, I>(values: &mut I) -> Result<Self, headers::Error>
+	where
+		I: Iterator<Item = &'i HeaderValue>,
+	{
+		let value = values.next().ok_or_else(headers::Error::invalid)?.clone();
+		let string = value.to_str().map_err(|_| headers::Error::invalid())?.to_string();
+
+		Ok(SurrealDefaultLimit(value, string))
+	}
+
+	fn encode<E>(&self, values: &mut E)
+	where
+		E: Extend<HeaderValue>,
+	{
+		values.extend(std::iter::once(self.0.clone()));
+	}
+}
+
+impl std::ops::Deref for SurrealDefaultLimit {
+	type Target = String;
+
+	fn deref(&self) -> &Self::Target {
+		&self.1
+	}
+}
+
+impl From<SurrealDefaultLimit> for HeaderValue {
+	fn from(value: SurrealDefaultLimit) -> Self {
+		HeaderValue::from(&value)
+	}
+}
+
+impl From<&SurrealDefaultLimit> for HeaderValue {
+	fn from(value: &SurrealDefaultLimit) -> Self {
+		value.0.clone()
+	}
+}
@@ -0,0 +1,51 @@
+use axum_extra::headers;
+use axum_extra::headers::Header;
+use http::{HeaderName, HeaderValue};
+use surrealdb::headers::MAX_OUTPUT_DEPTH;
+
+/// Typed header implementation for the max output depth header.
+/// It's used to specify how deeply the returned values may be nested.
+pub struct SurrealMaxOutputDepth(HeaderValue, String);
+
+impl Header for SurrealMaxOutputDepth {
+	fn name() -> &'static HeaderName {
+		&MAX_OUTPUT_DEPTH
+	}
+
+	fn decode<'i, I>(values: &mut I) -> Result<Self, headers::Error>
+	where
+		I: Iterator<Item = &'i HeaderValue>,
+	{
+		let value = values.next().ok_or_else(headers::Error::invalid)?.clone();
+		let string = value.to_str().map_err(|_| headers::Error::invalid())?.to_string();
+
+		Ok(SurrealMaxOutputDepth(value, string))
+	}
+
+	fn encode<E>(&self, values: &mut E)
+	where
+		E: Extend<HeaderValue>,
+	{
+		values.extend(std::iter::once(self.0.clone()));
+	}
+}
+
+impl std::ops::Deref for SurrealMaxOutputDepth {
+	type Target = String;
+
+	fn deref(&self) -> &Self::Target {
+		&self.1
+	}
+}
+
+impl From<SurrealMaxOutputDepth> for HeaderValue {
+	fn from(value: SurrealMaxOutputDepth) -> Self {
+		HeaderValue::from(&value)
+	}
+}
+
+impl From<&SurrealMaxOutputDepth> for HeaderValue {
+	fn from(value: &SurrealMaxOutputDepth) -> Self {
+		value.0.clone()
+	}
+}
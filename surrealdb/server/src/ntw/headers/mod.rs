@@ -21,16 +21,24 @@ mod auth_db;
 mod auth_ns;
 mod content_type;
 mod db;
+mod default_limit;
 mod id;
+mod max_output_depth;
 mod ns;
+mod null_coercion;
+mod output_depth_policy;
 
 pub use accept::Accept;
 pub use auth_db::SurrealAuthDatabase;
 pub use auth_ns::SurrealAuthNamespace;
 pub use content_type::ContentType;
 pub use db::SurrealDatabase;
+pub use default_limit::SurrealDefaultLimit;
 pub use id::SurrealId;
+pub use max_output_depth::SurrealMaxOutputDepth;
 pub use ns::SurrealNamespace;
+pub use null_coercion::SurrealNullCoercion;
+pub use output_depth_policy::SurrealOutputDepthPolicy;
 
 /// Creates a Tower middleware layer that conditionally adds a response header.
 ///
@@ -122,3 +130,25 @@ where
 		},
 	}
 }
+
+/// Parses a typed HTTP header from an Axum extractor result, and then parses
+/// its value into the specified type.
+///
+/// # Returns
+/// - `Ok(Some(value))`: The header was present and its value was valid
+/// - `Ok(None)`: The header was missing (not an error)
+/// - `Err(Error::InvalidHeader)`: The header was present but its value was invalid
+pub fn parse_typed_header_value<H, T>(
+	header: Result<TypedHeader<H>, TypedHeaderRejection>,
+) -> Result<Option<T>, Error>
+where
+	H: std::ops::Deref<Target = String> + Header,
+	T: std::str::FromStr,
+	T::Err: std::fmt::Display,
+{
+	parse_typed_header(header)?
+		.map(|v| {
+			v.parse().map_err(|e: T::Err| Error::InvalidHeader(H::name().to_owned(), e.to_string()))
+		})
+		.transpose()
+}
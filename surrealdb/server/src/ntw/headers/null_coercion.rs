@@ -0,0 +1,51 @@
+use axum_extra::headers;
+use axum_extra::headers::Header;
+use http::{HeaderName, HeaderValue};
+use surrealdb::headers::NULL_COERCION;
+
+/// Typed header implementation for the null coercion header.
+/// It's used to specify how NONE and NULL values are coerced into one another.
+pub struct SurrealNullCoercion(HeaderValue, String);
+
+impl Header for SurrealNullCoercion {
+	fn name() -> &'static HeaderName {
+		&NULL_COERCION
+	}
+
+	fn decode<'i, I>(values: &mut I) -> Result<Self, headers::Error>
+	where
+		I: Iterator<Item = &'i HeaderValue>,
+	{
+		let value = values.next().ok_or_else(headers::Error::invalid)?.clone();
+		let string = value.to_str().map_err(|_| headers::Error::invalid())?.to_string();
+
+		Ok(SurrealNullCoercion(value, string))
+	}
+
+	fn encode<E>(&self, values: &mut E)
+	where
+		E: Extend<HeaderValue>,
+	{
+		values.extend(std::iter::once(self.0.clone()));
+	}
+}
+
+impl std::ops::Deref for SurrealNullCoercion {
+	type Target = String;
+
+	fn deref(&self) -> &Self::Target {
+		&self.1
+	}
+}
+
+impl From<SurrealNullCoercion> for HeaderValue {
+	fn from(value: SurrealNullCoercion) -> Self {
+		HeaderValue::from(&value)
+	}
+}
+
+impl From<&SurrealNullCoercion> for HeaderValue {
+	fn from(value: &SurrealNullCoercion) -> Self {
+		value.0.clone()
+	}
+}
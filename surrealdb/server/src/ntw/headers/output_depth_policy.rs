@@ -0,0 +1,51 @@
+use axum_extra::headers;
+use axum_extra::headers::Header;
+use http::{HeaderName, HeaderValue};
+use surrealdb::headers::OUTPUT_DEPTH_POLICY;
+
+/// Typed header implementation for the output depth policy header.
+/// It's used to specify what happens to values which are nested too deeply.
+pub struct SurrealOutputDepthPolicy(HeaderValue, String);
+
+impl Header for SurrealOutputDepthPolicy {
+	fn name() -> &'static HeaderName {
+		&OUTPUT_DEPTH_POLICY
+	}
+
+	fn decode<'i, I>(values: &mut I) -> Result<Self, headers::Error>
+	where
+		I: Iterator<Item = &'i HeaderValue>,
+	{
+		let value = values.next().ok_or_else(headers::Error::invalid)?.clone();
+		let string = value.to_str().map_err(|_| headers::Error::invalid())?.to_string();
+
+		Ok(SurrealOutputDepthPolicy(value, string))
+	}
+
+	fn encode<E>(&self, values: &mut E)
+	where
+		E: Extend<HeaderValue>,
+	{
+		values.extend(std::iter::once(self.0.clone()));
+	}
+}
+
+impl std::ops::Deref for SurrealOutputDepthPolicy {
+	type Target = String;
+
+	fn deref(&self) -> &Self::Target {
+		&self.1
+	}
+}
+
+impl From<SurrealOutputDepthPolicy> for HeaderValue {
+	fn from(value: SurrealOutputDepthPolicy) -> Self {
+		HeaderValue::from(&value)
+	}
+}
+
+impl From<&SurrealOutputDepthPolicy> for HeaderValue {
+	fn from(value: &SurrealOutputDepthPolicy) -> Self {
+		value.0.clone()
+	}
+}
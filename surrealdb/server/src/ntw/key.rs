@@ -13,6 +13,7 @@ use surrealdb_core::dbs::Session;
 use surrealdb_core::dbs::capabilities::RouteTarget;
 use surrealdb_core::iam::check::check_ns_db;
 use surrealdb_core::kvs::Datastore;
+use surrealdb_core::rpc::format::json::parse_datetimes;
 use surrealdb_core::{map, syn};
 use surrealdb_types::{Array, SurrealValue, Value, Variables, vars};
 use tower_http::limit::RequestBodyLimitLayer;
@@ -76,7 +77,12 @@ async fn execute_and_return(
 	let vars = if let Some(expr) = expr {
 		let mut value = db.execute(&expr, session, Some(vars.clone())).await?;
 		if let Some(resp) = value.pop() {
-			vars.insert("data".to_owned(), resp.result?);
+			let mut data = resp.result?;
+			// Parse ISO-8601 strings as datetimes, when enabled for the session
+			if session.auto_parse_datetimes {
+				data = parse_datetimes(data);
+			}
+			vars.insert("data".to_owned(), data);
 		}
 		vars
 	} else {
@@ -181,7 +187,7 @@ async fn create_all(
 	// Specify the request variables
 	let vars = Variables::from(map! {
 		String::from("table") => Value::String(table),
-		=> params.parse()
+		=> params.parse(session.auto_parse_datetimes)
 	});
 
 	execute_and_return(db, sql, &session, vars, accept.as_deref(), Some(data.to_string()))
@@ -210,7 +216,7 @@ async fn update_all(
 	// Specify the request variables
 	let vars = Variables::from(map! {
 		String::from("table") => Value::String(table),
-		=> params.parse()
+		=> params.parse(session.auto_parse_datetimes)
 	});
 	execute_and_return(db, sql, &session, vars, accept.as_deref(), Some(data.to_string()))
 		.await
@@ -238,7 +244,7 @@ async fn modify_all(
 	// Specify the request variables
 	let vars = Variables::from(map! {
 		String::from("table") => Value::String(table),
-		=> params.parse()
+		=> params.parse(session.auto_parse_datetimes)
 	});
 	execute_and_return(db, sql, &session, vars, accept.as_deref(), Some(data.to_string()))
 		.await
@@ -263,7 +269,7 @@ async fn delete_all(
 	// Specify the request variables
 	let vars = Variables::from(map! {
 		String::from("table") => Value::String(table),
-		=> params.parse()
+		=> params.parse(session.auto_parse_datetimes)
 	});
 	// Execute the query and return the result
 	execute_and_return(db, sql, &session, vars, accept.as_deref(), None)
@@ -338,7 +344,7 @@ async fn create_one(
 	let vars = Variables::from(map! {
 		String::from("table") => Value::String(table),
 		String::from("id") => rid,
-		=> params.parse()
+		=> params.parse(session.auto_parse_datetimes)
 	});
 	// Execute the query and return the result
 	execute_and_return(db, sql, &session, vars, accept.as_deref(), Some(data.to_string()))
@@ -374,7 +380,7 @@ async fn update_one(
 	let vars = Variables::from(map! {
 		String::from("table") => Value::String(table),
 		String::from("id") => rid,
-		=> params.parse()
+		=> params.parse(session.auto_parse_datetimes)
 	});
 	// Execute the query and return the result
 	execute_and_return(db, sql, &session, vars, accept.as_deref(), Some(data.to_string()))
@@ -410,7 +416,7 @@ async fn modify_one(
 	let vars = Variables::from(map! {
 		String::from("table") => Value::String(table),
 		String::from("id") => rid,
-		=> params.parse()
+		=> params.parse(session.auto_parse_datetimes)
 	});
 	// Execute the query and return the result
 	execute_and_return(db, sql, &session, vars, accept.as_deref(), Some(data.to_string()))
@@ -2,7 +2,7 @@ use axum::response::{IntoResponse, Response};
 use http::StatusCode;
 use http::header::{CONTENT_TYPE, HeaderValue};
 use serde::Serialize;
-use surrealdb_types::Value;
+use surrealdb_types::{JsonOptions, Value};
 
 use super::headers::Accept;
 
@@ -22,7 +22,12 @@ impl Output {
 	// subtle bugs and format differences.
 	#[deprecated]
 	pub fn json_value(val: &Value) -> Output {
-		match surrealdb_core::rpc::format::json::encode(val.clone()) {
+		Output::json_value_with(val, JsonOptions::default())
+	}
+
+	#[deprecated]
+	pub fn json_value_with(val: &Value, opts: JsonOptions) -> Output {
+		match surrealdb_core::rpc::format::json::encode_with(val.clone(), opts) {
 			Ok(v) => Output::Json(v),
 			Err(_) => Output::Fail,
 		}
@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 
 use serde::Deserialize;
+use surrealdb_core::rpc::format::json::parse_datetimes;
 use surrealdb_types::Value;
 
 #[derive(Default, Deserialize, Debug, Clone)]
@@ -10,8 +11,14 @@ pub struct Params {
 }
 
 impl Params {
-	pub fn parse(self) -> BTreeMap<String, Value> {
-		self.into()
+	/// Parses the parameters as values, also converting ISO-8601 strings into
+	/// datetimes when `auto_parse_datetimes` is enabled for the session.
+	pub fn parse(self, auto_parse_datetimes: bool) -> BTreeMap<String, Value> {
+		let params: BTreeMap<String, Value> = self.into();
+		if !auto_parse_datetimes {
+			return params;
+		}
+		params.into_iter().map(|(k, v)| (k, parse_datetimes(v))).collect()
 	}
 }
 
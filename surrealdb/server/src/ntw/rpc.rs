@@ -99,6 +99,8 @@ async fn get_handler(
 	};
 	// This session supports live queries
 	session.rt = true;
+	// This session uses the WebSocket protocol
+	session.pr = Some("ws".to_owned());
 	// Store the connection id in session
 	session.id = Some(id);
 	// Check if a connection with this id already exists
@@ -176,6 +178,8 @@ async fn post_handler(
 	{
 		return Err(NetError::InvalidType.into());
 	}
+	// Encode JSON responses with the options of the request session
+	let json = session.json_options;
 	// Use the shared HTTP instance with persistent sessions
 	let rpc = &*rpc_state.http;
 	// Update the default session (None key) with the session from middleware
@@ -198,10 +202,13 @@ async fn post_handler(
 			)
 			.await;
 			// Return the HTTP response
-			Ok(fmt.res_http(match res {
-				Ok(result) => DbResponse::success(None, None, result),
-				Err(err) => DbResponse::failure(None, None, err),
-			})?)
+			Ok(fmt.res_http(
+				match res {
+					Ok(result) => DbResponse::success(None, None, result),
+					Err(err) => DbResponse::failure(None, None, err),
+				},
+				json,
+			)?)
 		}
 		Err(err) => Err(err.into()),
 	}
@@ -11,6 +11,7 @@ use bytes::Bytes;
 use futures::{SinkExt, StreamExt};
 use surrealdb_core::dbs::Session;
 use surrealdb_core::dbs::capabilities::RouteTarget;
+use surrealdb_core::rpc::format::json::parse_datetimes;
 use surrealdb_types::{Array, SurrealValue, Value, Variables};
 use tower_http::limit::RequestBodyLimitLayer;
 
@@ -39,7 +40,11 @@ async fn post_handler(
 	Query(params): Query<BTreeMap<String, String>>,
 	sql: Bytes,
 ) -> Result<Output, ResponseError> {
-	let vars = Variables::from(params);
+	let mut vars = Variables::from(params);
+	// Parse ISO-8601 parameters as datetimes, when enabled for the session
+	if session.auto_parse_datetimes {
+		vars = vars.into_iter().map(|(k, v)| (k, parse_datetimes(v))).collect();
+	}
 	// Get a database reference
 	let db = &state.datastore;
 	// Check if capabilities allow querying the requested HTTP route
@@ -61,7 +66,7 @@ async fn post_handler(
 				let v = Value::Array(Array::from(
 					res.into_iter().map(|x| x.into_value()).collect::<Vec<Value>>(),
 				));
-				Ok(Output::json_value(&v))
+				Ok(Output::json_value_with(&v, session.json_options))
 			}
 			Some(Accept::ApplicationCbor) => {
 				let v = Value::Array(Array::from(
@@ -103,9 +108,12 @@ async fn handle_socket(state: AppState, ws: WebSocket, session: Session) {
 			// Execute the received sql query
 			let _ = match db.execute(sql, &session, None).await {
 				// Convert the response to JSON
-				Ok(v) => match surrealdb_core::rpc::format::json::encode_str(Value::Array(
-					Array::from(v.into_iter().map(|x| x.into_value()).collect::<Vec<_>>()),
-				)) {
+				Ok(v) => match surrealdb_core::rpc::format::json::encode_str_with(
+					Value::Array(Array::from(
+						v.into_iter().map(|x| x.into_value()).collect::<Vec<_>>(),
+					)),
+					session.json_options,
+				) {
 					// Send the JSON response to the client
 					Ok(v) => tx.send(Message::Text(v.into())).await,
 					// There was an error converting to JSON
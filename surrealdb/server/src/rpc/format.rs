@@ -5,7 +5,7 @@ use http::header::CONTENT_TYPE;
 use surrealdb_core::rpc::format::Format;
 use surrealdb_core::rpc::request::Request;
 use surrealdb_core::rpc::{DbResponse, invalid_request, parse_error};
-use surrealdb_types::{Error as TypesError, SurrealValue, Value};
+use surrealdb_types::{Error as TypesError, JsonOptions, SurrealValue, Value};
 
 use crate::ntw::headers::{Accept, ContentType};
 
@@ -47,8 +47,8 @@ impl From<&Format> for ContentType {
 pub trait WsFormat {
 	/// Process a WebSocket RPC request
 	fn req_ws(&self, msg: Message) -> Result<Request, TypesError>;
-	/// Process a WebSocket RPC response
-	fn res_ws(&self, res: DbResponse) -> Result<(usize, Message), TypesError>;
+	/// Process a WebSocket RPC response, encoding JSON with the given options
+	fn res_ws(&self, res: DbResponse, json: JsonOptions) -> Result<(usize, Message), TypesError>;
 }
 
 impl WsFormat for Format {
@@ -87,12 +87,13 @@ impl WsFormat for Format {
 		}
 	}
 
-	/// Process a WebSocket RPC response
-	fn res_ws(&self, res: DbResponse) -> Result<(usize, Message), TypesError> {
+	/// Process a WebSocket RPC response, encoding JSON with the given options
+	fn res_ws(&self, res: DbResponse, json: JsonOptions) -> Result<(usize, Message), TypesError> {
 		match self {
 			Format::Json => {
-				let val = surrealdb_core::rpc::format::json::encode_str(res.into_value())
-					.map_err(|_| parse_error())?;
+				let val =
+					surrealdb_core::rpc::format::json::encode_str_with(res.into_value(), json)
+						.map_err(|_| parse_error())?;
 				Ok((val.len(), Message::Text(val.into())))
 			}
 			Format::Cbor => {
@@ -114,8 +115,8 @@ impl WsFormat for Format {
 pub trait HttpFormat {
 	/// Process a HTTP RPC request
 	fn req_http(&self, body: Bytes) -> Result<Request, TypesError>;
-	/// Process a HTTP RPC response
-	fn res_http(&self, res: DbResponse) -> Result<AxumResponse, TypesError>;
+	/// Process a HTTP RPC response, encoding JSON with the given options
+	fn res_http(&self, res: DbResponse, json: JsonOptions) -> Result<AxumResponse, TypesError>;
 }
 
 impl HttpFormat for Format {
@@ -153,12 +154,11 @@ impl HttpFormat for Format {
 		}
 	}
 
-	/// Process a HTTP RPC response
-	fn res_http(&self, res: DbResponse) -> Result<AxumResponse, TypesError> {
+	/// Process a HTTP RPC response, encoding JSON with the given options
+	fn res_http(&self, res: DbResponse, json: JsonOptions) -> Result<AxumResponse, TypesError> {
 		let val = match self {
-			Format::Json => surrealdb_core::rpc::format::json::encode_str(res.into_value())
-				.map_err(|_| parse_error())?
-				.into_bytes(),
+			Format::Json => surrealdb_core::rpc::format::json::encode_with(res.into_value(), json)
+				.map_err(|_| parse_error())?,
 			Format::Cbor => surrealdb_core::rpc::format::cbor::encode(res.into_value())
 				.map_err(|_| parse_error())?,
 			Format::Flatbuffers => {
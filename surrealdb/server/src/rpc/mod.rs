@@ -121,11 +121,13 @@ pub async fn notifications(
 						let cx = Arc::new(cx.with_value(not_ctx));
 						// Get the WebSocket output format
 						let format = rpc.format;
+						// Get the WebSocket JSON encoding options
+						let json = rpc.json_options;
 						// Get the WebSocket sending channel
 						let sender = rpc.channel.clone();
 						// Send the notification to the client
 						// let future = message.send(cx, format, sender);
-						let future = crate::rpc::response::send(message, cx, format, sender);
+						let future = crate::rpc::response::send(message, cx, format, json, sender);
 						// Pus the future to the pipeline
 						futures.push(future);
 					}
@@ -4,6 +4,7 @@ use axum::extract::ws::Message;
 use opentelemetry::Context as TelemetryContext;
 use surrealdb_core::rpc::DbResponse;
 use surrealdb_core::rpc::format::Format;
+use surrealdb_types::JsonOptions;
 use tokio::sync::mpsc::Sender;
 use tracing::Span;
 
@@ -15,6 +16,7 @@ pub async fn send(
 	response: DbResponse,
 	cx: Arc<TelemetryContext>,
 	fmt: Format,
+	json: JsonOptions,
 	chn: Sender<Message>,
 ) {
 	// Get the request id
@@ -33,10 +35,10 @@ pub async fn send(
 		span.record("rpc.error_message", err.message());
 	}
 	// Process the response for the format
-	let (len, msg) = match fmt.res_ws(response) {
+	let (len, msg) = match fmt.res_ws(response, json) {
 		Ok((l, m)) => (l, m),
 		Err(err) => fmt
-			.res_ws(DbResponse::failure(id, session_id, err))
+			.res_ws(DbResponse::failure(id, session_id, err), json)
 			.expect("Serialising internal error should always succeed"),
 	};
 	// Send the message to the write channel
@@ -15,7 +15,7 @@ use surrealdb_core::kvs::{Datastore, LockType, Transaction, TransactionType};
 use surrealdb_core::mem::ALLOC;
 use surrealdb_core::rpc::format::Format;
 use surrealdb_core::rpc::{DbResponse, DbResult, Method, RpcProtocol};
-use surrealdb_types::{Array, Error as TypesError, HashMap, Value};
+use surrealdb_types::{Array, Error as TypesError, HashMap, JsonOptions, Value};
 use tokio::sync::RwLock;
 use tokio::sync::mpsc::{Receiver, Sender, channel};
 use tokio::task::JoinSet;
@@ -49,6 +49,8 @@ pub struct Websocket {
 	pub(crate) id: Uuid,
 	/// The request and response format for messages
 	pub(crate) format: Format,
+	/// The options for encoding JSON responses, from the connection session
+	pub(crate) json_options: JsonOptions,
 	/// The system state for all RPC WebSocket connections
 	pub(crate) state: Arc<RpcState>,
 	/// The datastore accessible to all RPC WebSocket connections
@@ -83,6 +85,7 @@ impl Websocket {
 		let rpc = Arc::new(Websocket {
 			id,
 			format,
+			json_options: session.json_options,
 			state: state.clone(),
 			shutdown: CancellationToken::new(),
 			canceller: CancellationToken::new(),
@@ -362,6 +365,7 @@ impl Websocket {
 					let cancel_chn = chn.clone();
 					let cancel_otel_cx = otel_cx.clone();
 					let cancel_format = rpc.format;
+					let cancel_json = rpc.json_options;
 					// Process the message
 					tokio::select! {
 						biased;
@@ -377,6 +381,7 @@ impl Websocket {
 								DbResponse::failure(req_id, req_session_id.map(Into::into), TypesError::internal(REQUEST_CANCELLED.to_string())),
 								cancel_otel_cx.clone(),
 								cancel_format,
+								cancel_json,
 								cancel_chn
 							)
 								.with_context(cancel_otel_cx.as_ref().clone())
@@ -391,6 +396,7 @@ impl Websocket {
 									DbResponse::failure(req.id, req.session_id.map(Into::into), TypesError::internal(SERVER_SHUTTING_DOWN.to_string())),
 									otel_cx.clone(),
 									rpc.format,
+									rpc.json_options,
 									chn
 								)
 									.with_context(otel_cx.as_ref().clone())
@@ -403,6 +409,7 @@ impl Websocket {
 									DbResponse::failure(req.id, req.session_id.map(Into::into), TypesError::internal(SERVER_OVERLOADED.to_string())),
 									otel_cx.clone(),
 									rpc.format,
+									rpc.json_options,
 									chn
 								)
 									.with_context(otel_cx.as_ref().clone())
@@ -427,6 +434,7 @@ impl Websocket {
 									},
 									otel_cx.clone(),
 									rpc.format,
+									rpc.json_options,
 									chn
 								)
 									.with_context(otel_cx.as_ref().clone())
@@ -441,6 +449,7 @@ impl Websocket {
 						DbResponse::failure(None, None, err),
 						otel_cx.clone(),
 						rpc.format,
+						rpc.json_options,
 						chn
 					)
 						.with_context(otel_cx.as_ref().clone())
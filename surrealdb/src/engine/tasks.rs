@@ -51,8 +51,9 @@ pub fn init(dbs: Arc<Datastore>, canceller: CancellationToken, opts: &EngineOpti
 	let task3 = spawn_task_node_membership_cleanup(dbs.clone(), canceller.clone(), opts);
 	let task4 = spawn_task_changefeed_cleanup(dbs.clone(), canceller.clone(), opts);
 	let task5 = spawn_task_index_compaction(dbs.clone(), canceller.clone(), opts);
-	let task6 = spawn_task_event_processing(dbs, canceller, opts);
-	Tasks(vec![task1, task2, task3, task4, task5, task6])
+	let task6 = spawn_task_event_processing(dbs.clone(), canceller.clone(), opts);
+	let task7 = spawn_task_ttl_sweep(dbs, canceller, opts);
+	Tasks(vec![task1, task2, task3, task4, task5, task6, task7])
 }
 
 fn spawn_task_node_membership_refresh(
@@ -264,6 +265,37 @@ fn spawn_task_event_processing(
 	}))
 }
 
+fn spawn_task_ttl_sweep(
+	dbs: Arc<Datastore>,
+	canceller: CancellationToken,
+	opts: &EngineOptions,
+) -> Task {
+	// Get the delay interval from the config
+	let interval = opts.ttl_sweep_interval;
+	// Spawn a future
+	Box::pin(spawn(async move {
+		// Log the interval frequency
+		trace!("Purging expired records every {interval:?}");
+		// Create a new time-based interval ticket
+		let mut ticker = interval_ticker(interval).await;
+		// Loop continuously until the task is cancelled
+		loop {
+			tokio::select! {
+				biased;
+				// Check if this has shutdown
+				_ = canceller.cancelled() => break,
+				// Receive a notification on the channel
+				Some(_) = ticker.next() => {
+					if let Err(e) = dbs.ttl_process(&interval).await {
+						error!("Error purging expired records: {e}");
+					}
+				}
+			}
+		}
+		trace!("Background task exited: Purging expired records");
+	}))
+}
+
 async fn interval_ticker(interval: Duration) -> IntervalStream {
 	#[cfg(not(target_family = "wasm"))]
 	use tokio::{time, time::MissedTickBehavior};
@@ -8,3 +8,10 @@ pub static DB: HeaderName = HeaderName::from_static("surreal-db");
 pub static AUTH_NS: HeaderName = HeaderName::from_static("surreal-auth-ns");
 pub static AUTH_DB: HeaderName = HeaderName::from_static("surreal-auth-db");
 pub static VERSION: HeaderName = HeaderName::from_static("surreal-version");
+pub static JSON_BIG_INTS: HeaderName = HeaderName::from_static("surreal-json-big-ints");
+pub static JSON_DATETIMES: HeaderName = HeaderName::from_static("surreal-json-datetimes");
+pub static RETURN_VERSION: HeaderName = HeaderName::from_static("surreal-return-version");
+pub static NULL_COERCION: HeaderName = HeaderName::from_static("surreal-null-coercion");
+pub static MAX_OUTPUT_DEPTH: HeaderName = HeaderName::from_static("surreal-max-output-depth");
+pub static OUTPUT_DEPTH_POLICY: HeaderName = HeaderName::from_static("surreal-output-depth-policy");
+pub static DEFAULT_LIMIT: HeaderName = HeaderName::from_static("surreal-default-limit");
@@ -5,12 +5,39 @@ use serde_json::{Map, Number as JsonNumber, Value as JsonValue, json};
 use crate::sql::ToSql;
 use crate::{Geometry, Number, Value};
 
+/// The largest integer magnitude which can be represented exactly by a
+/// JavaScript number (2^53 - 1).
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/// Options which control how a value is converted into json.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct JsonOptions {
+	/// Encode integers outside of the range ±(2^53 - 1) as strings, so that
+	/// clients which parse json numbers as doubles do not lose precision.
+	pub big_ints_as_strings: bool,
+}
+
+impl JsonOptions {
+	/// Set whether integers outside of the safe javascript range are encoded
+	/// as strings.
+	pub fn with_big_ints_as_strings(mut self, enabled: bool) -> Self {
+		self.big_ints_as_strings = enabled;
+		self
+	}
+}
+
 impl Value {
 	/// Converts the value into a json representation of the value.
 	/// Returns None if there are non serializable values present in the value.
 	// TODO: Remove the JsonValue intermediate and implement a json formatter for
 	// Value.
 	pub fn into_json_value(self) -> JsonValue {
+		self.into_json_value_with(JsonOptions::default())
+	}
+
+	/// Converts the value into a json representation of the value, using the
+	/// specified encoding options.
+	pub fn into_json_value_with(self, opts: JsonOptions) -> JsonValue {
 		// This function goes through some extra length to manually implement the
 		// encoding into json value. This is done to ensure clarity and stability in
 		// regards to how the value variants are converted.
@@ -22,6 +49,12 @@ impl Value {
 			Value::None | Value::Null => JsonValue::Null,
 			Value::Bool(boolean) => JsonValue::Bool(boolean),
 			Value::Number(number) => match number {
+				Number::Int(int)
+					if opts.big_ints_as_strings
+						&& !(-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&int) =>
+				{
+					JsonValue::String(int.to_string())
+				}
 				Number::Int(int) => JsonValue::Number(JsonNumber::from(int)),
 				Number::Float(float) => {
 					// This is replicating serde_json::to_string default behavior.
@@ -46,15 +79,19 @@ impl Value {
 				JsonValue::String(string)
 			}
 			Value::Array(array) => JsonValue::Array(
-				array.0.into_iter().map(Value::into_json_value).collect::<Vec<JsonValue>>(),
+				array
+					.0
+					.into_iter()
+					.map(|v| v.into_json_value_with(opts))
+					.collect::<Vec<JsonValue>>(),
 			),
 			Value::Set(set) => JsonValue::Array(
-				set.0.into_iter().map(Value::into_json_value).collect::<Vec<JsonValue>>(),
+				set.0.into_iter().map(|v| v.into_json_value_with(opts)).collect::<Vec<JsonValue>>(),
 			),
 			Value::Object(object) => {
 				let mut map = Map::with_capacity(object.len());
 				for (k, v) in object.0 {
-					map.insert(k, v.into_json_value());
+					map.insert(k, v.into_json_value_with(opts));
 				}
 				JsonValue::Object(map)
 			}
@@ -42,6 +42,7 @@ pub use self::datetime::Datetime;
 pub use self::duration::Duration;
 pub use self::file::File;
 pub use self::geometry::Geometry;
+pub use self::into_json::JsonOptions;
 pub use self::number::Number;
 pub use self::object::Object;
 pub use self::range::Range;